@@ -7,15 +7,16 @@ use std::{
 
 use dces::prelude::*;
 
-use super::WindowAdapter;
+use super::{RuntimeFonts, WindowAdapter};
 
 use crate::{
     event::*,
     layout::*,
     localization::Localization,
     render_object::*,
+    services::{Clipboard, ImageCache},
     shell::{ShellRequest, WindowRequest},
-    utils::Point,
+    utils::{Point, Rectangle, Transform},
     widget_base::*,
 };
 
@@ -29,6 +30,13 @@ pub struct ContextProvider {
     pub event_adapter: EventAdapter,
     pub message_adapter: MessageAdapter,
     pub mouse_position: Rc<Cell<Point>>,
+    pub scale_factor: Rc<Cell<f64>>,
+    /// Global bounds of the rendered widgets including their outer shadows.
+    pub render_bounds: Rc<RefCell<BTreeMap<Entity, Rectangle>>>,
+    /// The region that is redrawn by the current render pass, `None` if the whole window is redrawn.
+    pub render_region: Rc<Cell<Option<Rectangle>>>,
+    /// The global render transform of the widget that is currently rendered.
+    pub render_transform: Rc<Cell<Transform>>,
     pub window_sender: mpsc::Sender<WindowRequest>,
     pub shell_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
     pub application_name: String,
@@ -36,6 +44,14 @@ pub struct ContextProvider {
     pub raw_window_handle: Option<raw_window_handle::RawWindowHandle>,
     // todo thread safe
     pub localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+    /// The clipboard of the application, it is shared between all windows.
+    pub clipboard: Clipboard,
+    /// The fonts that are loaded at runtime, they are shared between all windows.
+    pub fonts: RuntimeFonts,
+    /// The number of runtime fonts that are registered at the render context of the window.
+    pub registered_fonts: Rc<Cell<usize>>,
+    /// The image cache of the application, it is shared between all windows.
+    pub image_cache: ImageCache,
 }
 
 impl ContextProvider {
@@ -45,6 +61,9 @@ impl ContextProvider {
         shell_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
         application_name: impl Into<String>,
         localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+        clipboard: Clipboard,
+        fonts: RuntimeFonts,
+        image_cache: ImageCache,
     ) -> Self {
         ContextProvider {
             render_objects: Rc::new(RefCell::new(BTreeMap::new())),
@@ -54,12 +73,20 @@ impl ContextProvider {
             event_adapter: EventAdapter::new(window_sender.clone()),
             message_adapter: MessageAdapter::new(window_sender.clone()),
             mouse_position: Rc::new(Cell::new(Point::new(0.0, 0.0))),
+            scale_factor: Rc::new(Cell::new(1.0)),
+            render_bounds: Rc::new(RefCell::new(BTreeMap::new())),
+            render_region: Rc::new(Cell::new(None)),
+            render_transform: Rc::new(Cell::new(Transform::default())),
             window_sender,
             shell_sender,
             application_name: application_name.into(),
             first_run: Rc::new(Cell::new(true)),
             raw_window_handle: None,
             localization,
+            clipboard,
+            fonts,
+            registered_fonts: Rc::new(Cell::new(0)),
+            image_cache,
         }
     }
 }
@@ -0,0 +1,122 @@
+use std::{
+    cell::{Cell, RefCell},
+    io,
+    path::Path,
+    rc::Rc,
+    sync::Arc,
+};
+
+use crate::render::{font_files, PlatformBackend};
+
+/// `RuntimeFonts` holds the fonts that are loaded at runtime. The fonts are registered on application level and
+/// shared between all windows, also between the windows that are opened later. The font data is not copied per
+/// window.
+///
+/// The font data is validated if a window registers the font at its render context, invalid fonts are ignored there.
+/// Use `Context::register_font_bytes` to get the validation error.
+///
+/// Files could not be read on the web, `register_font_file` and `register_font_directory` return always `Err` there.
+/// Use `register_font_bytes` instead.
+///
+/// # Examples
+/// ```text
+/// Application::new()
+///     .font("NotoSansCJK", std::fs::read("fonts/NotoSansCJK.otf").unwrap())
+///     .on_started(|ctx| {
+///         ctx.fonts().register_font_directory("fonts").unwrap();
+///     })
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct RuntimeFonts {
+    fonts: Rc<RefCell<Vec<(String, Arc<Vec<u8>>)>>>,
+}
+
+impl RuntimeFonts {
+    /// Creates a new empty font collection.
+    pub fn new() -> Self {
+        RuntimeFonts::default()
+    }
+
+    /// Registers a font from its bytes. An already registered font of the family is replaced.
+    pub fn register_font_bytes(
+        &self,
+        family: impl Into<String>,
+        font_data: impl Into<Arc<Vec<u8>>>,
+    ) {
+        self.fonts
+            .borrow_mut()
+            .push((family.into(), font_data.into()));
+    }
+
+    /// Loads and registers the font file of the given path.
+    pub fn register_font_file(
+        &self,
+        family: impl Into<String>,
+        path: impl AsRef<Path>,
+    ) -> io::Result<()> {
+        self.register_font_bytes(family, std::fs::read(path)?);
+        Ok(())
+    }
+
+    /// Loads all font files (`ttf`, `otf`) of the given directory. The file name without extension is used as
+    /// family key. Returns the families of the registered fonts.
+    pub fn register_font_directory(&self, path: impl AsRef<Path>) -> io::Result<Vec<String>> {
+        let mut families = vec![];
+
+        for (family, file) in font_files(path)? {
+            if self.register_font_file(family.as_str(), file).is_ok() {
+                families.push(family);
+            }
+        }
+
+        Ok(families)
+    }
+
+    /// Gets the number of registered fonts.
+    pub fn len(&self) -> usize {
+        self.fonts.borrow().len()
+    }
+
+    /// Returns `true` if no font is registered.
+    pub fn is_empty(&self) -> bool {
+        self.fonts.borrow().is_empty()
+    }
+
+    // Registers the fonts at the render context, that are registered after the first `registered` fonts and
+    // updates the count. Returns `true` if a new font is registered.
+    pub(crate) fn register_at(
+        &self,
+        render_context: &mut PlatformBackend,
+        registered: &Cell<usize>,
+    ) -> bool {
+        let fonts = self.fonts.borrow();
+
+        if registered.get() >= fonts.len() {
+            return false;
+        }
+
+        for (family, font_data) in &fonts[registered.get()..] {
+            // invalid fonts are ignored, they are already reported if registered by `Context`
+            let _ = render_context.register_font_bytes(family, font_data.clone());
+        }
+
+        registered.set(fonts.len());
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared() {
+        let fonts = RuntimeFonts::new();
+        let other = fonts.clone();
+
+        fonts.register_font_bytes("Font", vec![0, 1, 2]);
+        assert_eq!(other.len(), 1);
+        assert!(other.register_font_file("Missing", "missing.ttf").is_err());
+        assert_eq!(fonts.len(), 1);
+    }
+}
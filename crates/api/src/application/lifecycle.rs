@@ -0,0 +1,131 @@
+use std::{cell::RefCell, rc::Rc, sync::mpsc};
+
+use dces::prelude::Entity;
+
+use super::{create_window, RuntimeFonts, WindowAdapter};
+
+use crate::{
+    localization::Localization,
+    services::{Clipboard, ImageCache},
+    shell::{LifecycleEvent, ShellRequest},
+    theming::Theme,
+    widget_base::BuildContext,
+};
+
+/// Handler of a stage of the application life cycle.
+pub type LifecycleHandlerFn = dyn Fn(&ApplicationContext) + 'static;
+
+/// Handler that is called after a window with the given title is created.
+pub type WindowCreatedHandlerFn = dyn Fn(&ApplicationContext, &str) + 'static;
+
+/// The `ApplicationContext` provides access to the application inside of its life cycle handlers.
+#[derive(Clone)]
+pub struct ApplicationContext {
+    name: Box<str>,
+    theme: Theme,
+    request_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
+    localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+    clipboard: Clipboard,
+    fonts: RuntimeFonts,
+    image_cache: ImageCache,
+}
+
+impl ApplicationContext {
+    /// Creates a new application context.
+    pub fn new(
+        name: Box<str>,
+        theme: Theme,
+        request_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
+        localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+        clipboard: Clipboard,
+        fonts: RuntimeFonts,
+        image_cache: ImageCache,
+    ) -> Self {
+        ApplicationContext {
+            name,
+            theme,
+            request_sender,
+            localization,
+            clipboard,
+            fonts,
+            image_cache,
+        }
+    }
+
+    /// Gets the name of the application.
+    pub fn name(&self) -> &str {
+        &*self.name
+    }
+
+    /// Gets the fonts that are loaded at runtime. They are shared between all windows of the application.
+    pub fn fonts(&self) -> &RuntimeFonts {
+        &self.fonts
+    }
+
+    /// Gets the image cache of the application. It is shared between all windows of the application.
+    pub fn image_cache(&self) -> &ImageCache {
+        &self.image_cache
+    }
+
+    /// Creates and show a new window.
+    pub fn show_window<F: Fn(&mut BuildContext) -> Entity + 'static>(&self, create_fn: F) {
+        let (adapter, settings, receiver) = create_window(
+            self.name.clone(),
+            self.theme.clone(),
+            self.request_sender.clone(),
+            create_fn,
+            self.localization.clone(),
+            self.clipboard.clone(),
+            self.fonts.clone(),
+            self.image_cache.clone(),
+        );
+
+        self.request_sender
+            .send(ShellRequest::CreateWindow(adapter, settings, receiver))
+            .expect("ApplicationContext.show_window: Could not send shell request.");
+    }
+
+    /// Quits the application with all its windows.
+    pub fn quit(&self) {
+        self.request_sender
+            .send(ShellRequest::Quit)
+            .expect("ApplicationContext.quit: Could not send shell request.");
+    }
+}
+
+/// Contains the registered life cycle handlers of the application.
+#[derive(Clone, Default)]
+pub(crate) struct LifecycleHandlers {
+    pub started: Option<Rc<LifecycleHandlerFn>>,
+    pub window_created: Option<Rc<WindowCreatedHandlerFn>>,
+    pub all_windows_closed: Option<Rc<LifecycleHandlerFn>>,
+    pub about_to_quit: Option<Rc<LifecycleHandlerFn>>,
+}
+
+impl LifecycleHandlers {
+    /// Calls the matching handler of the given life cycle event.
+    pub fn handle(&self, ctx: &ApplicationContext, event: LifecycleEvent) {
+        match event {
+            LifecycleEvent::Started => {
+                if let Some(handler) = &self.started {
+                    handler(ctx);
+                }
+            }
+            LifecycleEvent::WindowCreated(title) => {
+                if let Some(handler) = &self.window_created {
+                    handler(ctx, title.as_str());
+                }
+            }
+            LifecycleEvent::AllWindowsClosed => {
+                if let Some(handler) = &self.all_windows_closed {
+                    handler(ctx);
+                }
+            }
+            LifecycleEvent::AboutToQuit => {
+                if let Some(handler) = &self.about_to_quit {
+                    handler(ctx);
+                }
+            }
+        }
+    }
+}
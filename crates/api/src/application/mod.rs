@@ -1,21 +1,26 @@
 //! This module contains the base elements of an OrbTk application (Application, WindowBuilder and Window).
 
-use std::sync::mpsc;
+use std::sync::{mpsc, Arc};
 
 use dces::prelude::Entity;
 
 use crate::{
     localization::Localization,
+    services::{Clipboard, ImageCache},
     shell::{Shell, ShellRequest},
     theming::Theme,
     widget_base::BuildContext,
 };
 
 pub use self::context_provider::*;
+pub use self::fonts::*;
+pub use self::lifecycle::*;
 pub use self::overlay::*;
 pub use self::window_adapter::*;
 
 mod context_provider;
+mod fonts;
+mod lifecycle;
 mod overlay;
 mod window_adapter;
 
@@ -27,6 +32,10 @@ pub struct Application {
     name: Box<str>,
     theme: Theme,
     localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+    clipboard: Clipboard,
+    fonts: RuntimeFonts,
+    image_cache: ImageCache,
+    lifecycle_handlers: LifecycleHandlers,
 }
 
 impl Default for Application {
@@ -55,6 +64,13 @@ impl Application {
         self
     }
 
+    /// Registers a font that is loaded at runtime, e.g. with `std::fs::read`. The font is shared between all
+    /// windows of the application.
+    pub fn font(self, family: &str, font_data: impl Into<Arc<Vec<u8>>>) -> Self {
+        self.fonts.register_font_bytes(family, font_data);
+        self
+    }
+
     /// Create a new application with the given name.
     pub fn from_name(name: impl Into<Box<str>>) -> Self {
         let (sender, receiver) = mpsc::channel();
@@ -65,6 +81,10 @@ impl Application {
             shell: Shell::new(receiver),
             theme: crate::theme_default::theme_default(),
             localization: None,
+            clipboard: Clipboard::new(),
+            fonts: RuntimeFonts::new(),
+            image_cache: ImageCache::new(),
+            lifecycle_handlers: LifecycleHandlers::default(),
         }
     }
 
@@ -76,6 +96,9 @@ impl Application {
             self.request_sender.clone(),
             create_fn,
             self.localization.clone(),
+            self.clipboard.clone(),
+            self.fonts.clone(),
+            self.image_cache.clone(),
         );
 
         self.shell
@@ -86,8 +109,61 @@ impl Application {
         self
     }
 
+    /// Registers a handler that is called after the application is started.
+    pub fn on_started<H: Fn(&ApplicationContext) + 'static>(mut self, handler: H) -> Self {
+        self.lifecycle_handlers.started = Some(Rc::new(handler));
+        self
+    }
+
+    /// Registers a handler that is called after a new window is created. The handler gets the title of the window.
+    pub fn on_window_created<H: Fn(&ApplicationContext, &str) + 'static>(
+        mut self,
+        handler: H,
+    ) -> Self {
+        self.lifecycle_handlers.window_created = Some(Rc::new(handler));
+        self
+    }
+
+    /// Registers a handler that is called after the last open window of the application is closed.
+    pub fn on_all_windows_closed<H: Fn(&ApplicationContext) + 'static>(
+        mut self,
+        handler: H,
+    ) -> Self {
+        self.lifecycle_handlers.all_windows_closed = Some(Rc::new(handler));
+        self
+    }
+
+    /// Registers a handler that is called before the application quits.
+    pub fn on_about_to_quit<H: Fn(&ApplicationContext) + 'static>(mut self, handler: H) -> Self {
+        self.lifecycle_handlers.about_to_quit = Some(Rc::new(handler));
+        self
+    }
+
+    /// Defines if the application quits after its last window is closed. Default is `true`.
+    ///
+    /// If set to `false` the application keeps running until `ApplicationContext::quit` or
+    /// `Context::quit_application` is called.
+    pub fn quit_on_last_window_closed(mut self, quit_on_last_window_closed: bool) -> Self {
+        self.shell
+            .quit_on_last_window_closed(quit_on_last_window_closed);
+        self
+    }
+
     /// Starts the application and run it until quit is requested.
     pub fn run(mut self) {
+        let ctx = ApplicationContext::new(
+            self.name.clone(),
+            self.theme.clone(),
+            self.request_sender.clone(),
+            self.localization.clone(),
+            self.clipboard.clone(),
+            self.fonts.clone(),
+            self.image_cache.clone(),
+        );
+        let lifecycle_handlers = self.lifecycle_handlers.clone();
+
+        self.shell
+            .lifecycle_handler(move |event| lifecycle_handlers.handle(&ctx, event));
         self.shell.run();
     }
 }
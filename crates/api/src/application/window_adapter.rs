@@ -6,8 +6,10 @@ use crate::{
     application::*,
     event::*,
     localization::Localization,
+    properties::{get_property_or_value, WindowGeometry},
     render,
-    services::{Clipboard, Settings},
+    render::{Image, RenderBackend, Svg},
+    services::{Clipboard, ImageCache, Settings},
     shell,
     shell::{ShellRequest, WindowRequest, WindowSettings},
     systems::*,
@@ -17,17 +19,22 @@ use crate::{
 };
 
 /// Represents a window. Each window has its own tree, event pipeline and shell.
-pub struct WindowAdapter {
-    world: World<Tree, StringComponentStore, render::RenderContext2D>,
+///
+/// The adapter draws the window on the render context it is run with. By default this is the `RenderContext2D` of
+/// the platform, but any other `RenderBackend` that draws the `Image` and `Svg` types of the platform could be used,
+/// e.g. a test double.
+pub struct WindowAdapter<R = render::RenderContext2D> {
+    world: World<Tree, StringComponentStore, R>,
     ctx: ContextProvider,
     registry: Rc<RefCell<Registry>>,
     old_clipboard_value: Option<String>,
+    clipboard_revision: u64,
 }
 
-impl WindowAdapter {
+impl<R: RenderBackend<Image = Image, Svg = Svg>> WindowAdapter<R> {
     /// Creates a new WindowAdapter.
     pub fn new(
-        world: World<Tree, StringComponentStore, render::RenderContext2D>,
+        world: World<Tree, StringComponentStore, R>,
         ctx: ContextProvider,
         registry: Rc<RefCell<Registry>>,
     ) -> Self {
@@ -36,11 +43,12 @@ impl WindowAdapter {
             ctx,
             registry,
             old_clipboard_value: None,
+            clipboard_revision: 0,
         }
     }
 }
 
-impl WindowAdapter {
+impl<R: RenderBackend<Image = Image, Svg = Svg>> WindowAdapter<R> {
     fn root(&mut self) -> Entity {
         self.world
             .entity_component_manager()
@@ -50,24 +58,34 @@ impl WindowAdapter {
     }
 }
 
-impl shell::WindowAdapter for WindowAdapter {
+impl<R: RenderBackend<Image = Image, Svg = Svg>> shell::WindowAdapter<R> for WindowAdapter<R> {
     fn clipboard_update(&mut self, value: &mut Option<String>) {
-        // internal clipboard value is new => update system clipboard value.
-        if self.registry.borrow().get::<Clipboard>("clipboard").get() != self.old_clipboard_value {
-            *value = self.registry.borrow().get::<Clipboard>("clipboard").get();
+        let system_value = value.take();
+        let mut registry = self.registry.borrow_mut();
+        let clipboard = registry.get_mut::<Clipboard>("clipboard");
 
-            self.old_clipboard_value = value.clone();
+        // internal clipboard value is new => update system clipboard value.
+        if clipboard.revision() != self.clipboard_revision {
+            self.clipboard_revision = clipboard.revision();
+
+            // content without plain text (e.g. an image) stays inside of the application
+            if let Some(text) = clipboard.get() {
+                *value = Some(text.clone());
+                self.old_clipboard_value = Some(text);
+            } else if system_value.is_some() {
+                self.old_clipboard_value = system_value;
+            }
 
             return;
         }
 
         //  system clipboard value is newer => update internal clipboard
-        if let Some(value) = value.clone() {
-            self.registry
-                .borrow_mut()
-                .get_mut::<Clipboard>("clipboard")
-                .set(value.clone());
-            self.old_clipboard_value = Some(value);
+        if let Some(system_value) = system_value {
+            if self.old_clipboard_value.as_ref() != Some(&system_value) {
+                clipboard.set(system_value.clone());
+                self.clipboard_revision = clipboard.revision();
+                self.old_clipboard_value = Some(system_value);
+            }
         }
     }
 
@@ -78,6 +96,21 @@ impl shell::WindowAdapter for WindowAdapter {
             .push_event_direct(root, WindowEvent::Resize { width, height });
     }
 
+    fn moved(&mut self, x: f64, y: f64) {
+        let root = self.root();
+        self.ctx
+            .event_adapter
+            .push_event_direct(root, WindowEvent::Move { x, y });
+    }
+
+    fn scale_factor_changed(&mut self, scale_factor: f64) {
+        let root = self.root();
+        self.ctx.scale_factor.set(scale_factor);
+        self.ctx
+            .event_adapter
+            .push_event_direct(root, WindowEvent::ScaleFactorChanged(scale_factor));
+    }
+
     fn mouse(&mut self, x: f64, y: f64) {
         let root = self.root();
         self.ctx.mouse_position.set(Point::new(x, y));
@@ -87,6 +120,12 @@ impl shell::WindowAdapter for WindowAdapter {
                 position: Point::new(x, y),
             },
         );
+        self.ctx.event_adapter.push_event(
+            root,
+            GlobalMouseMoveEvent {
+                position: Point::new(x, y),
+            },
+        );
     }
 
     fn scroll(&mut self, delta_x: f64, delta_y: f64) {
@@ -159,7 +198,7 @@ impl shell::WindowAdapter for WindowAdapter {
 
         self.ctx
             .event_adapter
-            .push_event_direct(root, SystemEvent::Quit);
+            .push_event_direct(root, WindowEvent::CloseRequested(CloseRequest::new()));
     }
 
     fn active(&mut self, active: bool) {
@@ -170,7 +209,24 @@ impl shell::WindowAdapter for WindowAdapter {
             .push_event_direct(root, WindowEvent::ActiveChanged(active));
     }
 
-    fn run(&mut self, render_context: &mut render::RenderContext2D) {
+    fn run(&mut self, render_context: &mut R) {
+        // fonts could be registered by the application or other windows since the last run
+        if self
+            .ctx
+            .fonts
+            .register_at(render_context, &self.ctx.registered_fonts)
+        {
+            let root = self.root();
+            let ecm = self.world.entity_component_manager();
+            let theme = ecm
+                .component_store()
+                .get::<Theme>("theme", root)
+                .unwrap()
+                .clone();
+
+            WidgetContainer::new(root, ecm, &theme, None).update_dirty(true);
+        }
+
         self.world.run_with_context(render_context);
     }
 
@@ -196,29 +252,99 @@ impl shell::WindowAdapter for WindowAdapter {
         );
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    fn screenshot(&mut self, path: &str, scale: f64) {
+        let root = self.root();
+        let ecm = self.world.entity_component_manager();
+        let theme = ecm
+            .component_store()
+            .get::<Theme>("theme", root)
+            .unwrap()
+            .clone();
+
+        let result =
+            match crate::render_object::render_to_image(root, ecm, &self.ctx, &theme, scale) {
+                Some(image) => image.save_png(path),
+                None => Err("The window is empty.".to_string()),
+            };
+
+        if let Err(err) = result {
+            crate::shell::CONSOLE.log(format!("Could not save screenshot: {}", err));
+        }
+    }
+
     fn set_raw_window_handle(&mut self, raw_window_handle: raw_window_handle::RawWindowHandle) {
         self.ctx.raw_window_handle = Some(raw_window_handle);
     }
 }
 
-/// Creates a `WindowAdapter` and a `WindowSettings` object from a window builder closure.
-pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
+// Restores the saved geometry of the window. Missing or corrupt settings are ignored.
+fn restore_geometry(store: &mut StringComponentStore, window: Entity, registry: &Registry) {
+    let id: String = get_property_or_value("id", window, store, String::default());
+
+    let geometry = match registry
+        .get::<Settings>("settings")
+        .load::<WindowGeometry>(&WindowGeometry::settings_key(&id))
+    {
+        Ok(geometry) if geometry.is_valid() => geometry,
+        _ => return,
+    };
+
+    let geometry = match shell::screen_size() {
+        Some(screen_size) => geometry.clamp(screen_size),
+        None => geometry,
+    };
+
+    if let Ok(constraint) = store.get_mut::<Constraint>("constraint", window) {
+        constraint.set_size(geometry.size.0, geometry.size.1);
+    }
+
+    if let Ok(position) = store.get_mut::<Point>("position", window) {
+        *position = Point::new(geometry.position.0, geometry.position.1);
+    }
+
+    if let Ok(maximized) = store.get_mut::<bool>("maximized", window) {
+        *maximized = geometry.maximized;
+    }
+}
+
+/// Creates a `WindowAdapter` and a `WindowSettings` object from a window builder closure. The adapter draws the
+/// window on the render backend `R`.
+#[allow(clippy::too_many_arguments)]
+pub fn create_window<
+    R: RenderBackend<Image = Image, Svg = Svg> + 'static,
+    F: Fn(&mut BuildContext) -> Entity + 'static,
+>(
     app_name: impl Into<String>,
     theme: Theme,
     request_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
     create_fn: F,
     localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
-) -> (WindowAdapter, WindowSettings, mpsc::Receiver<WindowRequest>) {
+    clipboard: Clipboard,
+    fonts: RuntimeFonts,
+    image_cache: ImageCache,
+) -> (
+    WindowAdapter<R>,
+    WindowSettings,
+    mpsc::Receiver<WindowRequest>,
+) {
     let app_name = app_name.into();
-    let mut world: World<Tree, StringComponentStore, render::RenderContext2D> =
+    let mut world: World<Tree, StringComponentStore, R> =
         World::from_stores(Tree::default(), StringComponentStore::default());
 
     let (sender, receiver) = mpsc::channel();
 
     let registry = Rc::new(RefCell::new(Registry::new()));
 
-    let context_provider =
-        ContextProvider::new(sender, request_sender, app_name.clone(), localization);
+    let context_provider = ContextProvider::new(
+        sender,
+        request_sender,
+        app_name.clone(),
+        localization,
+        clipboard.clone(),
+        fonts,
+        image_cache.clone(),
+    );
 
     if app_name.is_empty() {
         registry.borrow_mut().register(
@@ -232,9 +358,12 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         );
     };
 
-    registry
-        .borrow_mut()
-        .register("clipboard", Clipboard::new());
+    registry.borrow_mut().register("clipboard", clipboard);
+
+    registry.borrow_mut().register(
+        "image_cache",
+        image_cache.with_message_adapter(context_provider.message_adapter.clone()),
+    );
 
     let window = {
         let overlay = Overlay::new().build(&mut BuildContext::new(
@@ -270,6 +399,19 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         window
     };
 
+    if get_property_or_value(
+        "persist_geometry",
+        window,
+        world.entity_component_manager().component_store(),
+        false,
+    ) {
+        restore_geometry(
+            world.entity_component_manager().component_store_mut(),
+            window,
+            &*registry.borrow(),
+        );
+    }
+
     let constraint = *world
         .entity_component_manager()
         .component_store()
@@ -283,6 +425,7 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         .unwrap();
 
     let fonts = theme.fonts().clone();
+    let font_fallbacks = theme.font_fallbacks().to_vec();
 
     let settings = WindowSettings {
         title: world
@@ -308,7 +451,15 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
             .unwrap(),
         position: (position.x(), position.y()),
         size: (constraint.width(), constraint.height()),
+        scale_factor: world
+            .entity_component_manager()
+            .component_store()
+            .get::<f64>("scale_factor", window)
+            .ok()
+            .copied()
+            .filter(|scale_factor| *scale_factor > 0.0),
         fonts,
+        font_fallbacks,
     };
 
     // let mut global = Global::default();
@@ -367,3 +518,71 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         receiver,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::ClipboardContent;
+    use shell::WindowAdapter as _;
+
+    fn adapter(clipboard: Clipboard) -> WindowAdapter {
+        let (sender, _) = mpsc::channel();
+        let (request_sender, _) = mpsc::channel();
+        let registry = Rc::new(RefCell::new(Registry::new()));
+        registry
+            .borrow_mut()
+            .register("clipboard", clipboard.clone());
+
+        WindowAdapter::new(
+            World::from_stores(Tree::default(), StringComponentStore::default()),
+            ContextProvider::new(
+                sender,
+                request_sender,
+                "",
+                None,
+                clipboard,
+                RuntimeFonts::new(),
+                ImageCache::new(),
+            ),
+            registry,
+        )
+    }
+
+    #[test]
+    fn test_clipboard_update_text() {
+        let mut clipboard = Clipboard::new();
+        let mut adapter = adapter(clipboard.clone());
+
+        clipboard.set_content(ClipboardContent::new().text("text").html("<b>text</b>"));
+        let mut value = Some(String::from("system"));
+        adapter.clipboard_update(&mut value);
+
+        // only the plain text reaches the system clipboard
+        assert_eq!(value, Some(String::from("text")));
+        assert_eq!(clipboard.get_html(), Some(String::from("<b>text</b>")));
+    }
+
+    #[test]
+    fn test_clipboard_update_without_text() {
+        let mut clipboard = Clipboard::new();
+        let mut adapter = adapter(clipboard.clone());
+
+        clipboard.set_html("<b>html</b>");
+        let mut value = Some(String::from("system"));
+        adapter.clipboard_update(&mut value);
+
+        // the system clipboard keeps its value and the html stays inside of the application
+        assert_eq!(value, None);
+        assert_eq!(clipboard.get_html(), Some(String::from("<b>html</b>")));
+
+        let mut value = Some(String::from("system"));
+        adapter.clipboard_update(&mut value);
+        assert_eq!(clipboard.get_html(), Some(String::from("<b>html</b>")));
+
+        // a new value of the system clipboard replaces the content
+        let mut value = Some(String::from("new"));
+        adapter.clipboard_update(&mut value);
+        assert_eq!(clipboard.get(), Some(String::from("new")));
+        assert_eq!(clipboard.get_html(), None);
+    }
+}
@@ -0,0 +1,48 @@
+use std::rc::Rc;
+
+use dces::prelude::*;
+
+use super::*;
+
+use crate::{proc_macros::*, widget_base::*};
+
+/// `LinkClickedEvent` occurs when a link span of a rich text is clicked. It contains the entity of the widget
+/// that draws the text and the target of the link.
+#[derive(Clone, Event)]
+pub struct LinkClickedEvent(pub Entity, pub String);
+
+/// Defines the link clicked handler function.
+pub type LinkClickedHandlerFn = dyn Fn(&mut StatesContext, Entity, String) + 'static;
+
+/// Used to handle link clicked events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct LinkClickedEventHandler {
+    pub handler: Rc<LinkClickedHandlerFn>,
+}
+
+impl EventHandler for LinkClickedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<LinkClickedEvent>() {
+            (self.handler)(states, event.0, event.1.clone());
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<LinkClickedEvent>()
+    }
+}
+
+pub trait LinkClickedHandler: Sized + Widget {
+    /// Inserts a handler that is called with the target of a clicked link.
+    fn on_link_click<H: Fn(&mut StatesContext, Entity, String) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(LinkClickedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
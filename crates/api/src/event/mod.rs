@@ -13,6 +13,7 @@ pub use self::event_handler::*;
 pub use self::event_queue::*;
 pub use self::focus::*;
 pub use self::key::*;
+pub use self::link::*;
 pub use self::mouse::*;
 pub use self::system::*;
 pub use self::text_input::*;
@@ -25,6 +26,7 @@ mod event_handler;
 mod event_queue;
 mod focus;
 mod key;
+mod link;
 mod mouse;
 mod system;
 mod text_input;
@@ -15,6 +15,7 @@ pub fn check_mouse_condition(mouse_position: Point, widget: &WidgetContainer<'_>
         return false;
     }
 
+    let mouse_position = local_position(mouse_position, widget);
     let bounds = widget.get::<Rectangle>("bounds");
     let position = widget.get::<Point>("position");
 
@@ -26,6 +27,71 @@ pub fn check_mouse_condition(mouse_position: Point, widget: &WidgetContainer<'_>
     rect.contains(mouse_position)
 }
 
+/// Maps the given position on the window into the untransformed global coordinates of the widget, by the inverse of
+/// its global render transform.
+pub fn local_position(position: Point, widget: &WidgetContainer<'_>) -> Point {
+    match widget
+        .try_get::<Transform>("global_transform")
+        .and_then(|transform| transform.inverse())
+    {
+        Some(inverse) => inverse.transform_point(position),
+        None => position,
+    }
+}
+
+/// Maps the position of the mouse events into the untransformed global coordinates of the widget. Returns `None` if
+/// the event has no position or the widget is not transformed.
+pub fn local_event(event: &EventBox, widget: &WidgetContainer<'_>) -> Option<EventBox> {
+    match widget.try_get::<Transform>("global_transform") {
+        Some(transform) if !transform.is_identity() => {}
+        _ => return None,
+    }
+
+    if let Ok(e) = event.downcast_ref::<MouseDownEvent>() {
+        return Some(EventBox::new(
+            MouseDownEvent {
+                button: e.button,
+                position: local_position(e.position, widget),
+            },
+            event.strategy.clone(),
+            event.source,
+        ));
+    }
+
+    if let Ok(e) = event.downcast_ref::<MouseUpEvent>() {
+        return Some(EventBox::new(
+            MouseUpEvent {
+                button: e.button,
+                position: local_position(e.position, widget),
+            },
+            event.strategy.clone(),
+            event.source,
+        ));
+    }
+
+    if let Ok(e) = event.downcast_ref::<ClickEvent>() {
+        return Some(EventBox::new(
+            ClickEvent {
+                position: local_position(e.position, widget),
+            },
+            event.strategy.clone(),
+            event.source,
+        ));
+    }
+
+    if let Ok(e) = event.downcast_ref::<MouseMoveEvent>() {
+        return Some(EventBox::new(
+            MouseMoveEvent {
+                position: local_position(e.position, widget),
+            },
+            event.strategy.clone(),
+            event.source,
+        ));
+    }
+
+    None
+}
+
 /// `MouseMoveEvent` indicates if the mouse position is changed on the window.
 #[derive(Event)]
 pub struct MouseMoveEvent {
@@ -103,6 +169,16 @@ pub struct GlobalMouseUpEvent {
     pub position: Point,
 }
 
+/// `GlobalMouseMoveEvent` occurs when the mouse position is changed, also if the mouse is outside
+/// of the bounds of a widget.
+///
+/// Global events could not be handled and could be read on each state.
+#[derive(Event)]
+pub struct GlobalMouseMoveEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
 /// Defines the mouse handler function.
 pub type MouseHandlerFunction = dyn Fn(&mut StatesContext, Mouse) -> bool + 'static;
 
@@ -237,6 +313,26 @@ impl EventHandler for MouseMoveEventHandler {
     }
 }
 
+/// Defines an event handler for a global mouse move event. Global mouse move events could not be handled.
+#[derive(IntoHandler)]
+pub struct GlobalMouseMoveEventHandler {
+    handler: Rc<PositionDirectHandlerFunction>,
+}
+
+impl EventHandler for GlobalMouseMoveEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<GlobalMouseMoveEvent>() {
+            (self.handler)(state_context, event.position);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<GlobalMouseMoveEvent>()
+    }
+}
+
 /// Used to handle mouse enter event on a widget.
 #[derive(IntoHandler)]
 pub struct EnterEventHandler {
@@ -332,6 +428,13 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 
+    /// Insert a mouse handler for global move event.
+    fn on_global_mouse_move<H: Fn(&mut StatesContext, Point) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(GlobalMouseMoveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
     /// Insert a mouse enter handler.
     fn on_enter<H: Fn(&mut StatesContext, Point) + 'static>(self, handler: H) -> Self {
         self.insert_handler(EnterEventHandler {
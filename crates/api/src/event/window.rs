@@ -1,4 +1,10 @@
-use std::rc::Rc;
+use std::{
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 use super::*;
 
@@ -6,11 +12,51 @@ use crate::{proc_macros::*, widget_base::*};
 
 #[derive(Clone, Event)]
 pub enum WindowEvent {
-    Resize { width: f64, height: f64 },
+    Resize {
+        width: f64,
+        height: f64,
+    },
+
+    /// The window is moved to the given position on the screen.
+    Move {
+        x: f64,
+        y: f64,
+    },
     ActiveChanged(bool),
+
+    /// The scale factor of the window is changed. Widgets are laid out in logical units, the
+    /// scale factor describes the ratio between physical pixels and logical units.
+    ScaleFactorChanged(f64),
+
+    /// Closing the window is requested. If a handler cancels the request the window stays open.
+    CloseRequested(CloseRequest),
     None,
 }
 
+/// Request to close a window. All clones of the request share the same state, so a handler could
+/// cancel the request to keep the window open.
+#[derive(Clone, Debug, Default)]
+pub struct CloseRequest {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CloseRequest {
+    /// Creates a new close request.
+    pub fn new() -> Self {
+        CloseRequest::default()
+    }
+
+    /// Cancels the request, the window will not be closed.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` if the request is cancelled by a handler.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
 pub type WindowHandlerFn = dyn Fn(&mut StatesContext, WindowEvent) -> bool + 'static;
 
 #[derive(IntoHandler)]
@@ -3,7 +3,7 @@ use std::{cell::RefCell, collections::BTreeMap};
 use dces::prelude::*;
 
 use crate::{
-    proc_macros::IntoLayout, render::RenderContext2D, theming::*, tree::Tree, utils::prelude::*,
+    proc_macros::IntoLayout, render::PlatformBackend, theming::*, tree::Tree, utils::prelude::*,
     widget_base::mark_as_dirty,
 };
 
@@ -24,7 +24,7 @@ impl AbsoluteLayout {
 impl Layout for AbsoluteLayout {
     fn measure(
         &self,
-        render_context_2_d: &mut RenderContext2D,
+        render_context_2_d: &mut PlatformBackend,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
@@ -60,7 +60,7 @@ impl Layout for AbsoluteLayout {
 
     fn arrange(
         &self,
-        render_context_2_d: &mut RenderContext2D,
+        render_context_2_d: &mut PlatformBackend,
         _parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
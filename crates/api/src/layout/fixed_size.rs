@@ -10,33 +10,49 @@ use smallvec::SmallVec;
 
 use crate::{
     proc_macros::IntoLayout,
-    render::Image,
-    render::RenderContext2D,
+    render::PlatformBackend,
+    render::{Image, Svg},
+    render_object::{RichTextLayout, TextLayout, TextLayoutOptions},
     theming::*,
     tree::Tree,
     utils::prelude::*,
     widget_base::{mark_as_dirty, WidgetContainer},
 };
 
-use super::{component, component_try_mut, Layout};
+use super::{component, component_try_mut, request_layout_pass, Layout};
 
 /// Fixed size layout is defined by fixed bounds like the size of an image or the size of a text.
+///
+/// Text that is wrapped or trimmed is measured against the width, that was available on the last arrange. Stretched
+/// images are sized by the constraint and the alignment of the widget, their natural size is the desired size. SVG
+/// documents are drawn with the size of the constraint, if it is set.
 #[derive(Default, IntoLayout)]
 pub struct FixedSizeLayout {
     desired_size: RefCell<DirtySize>,
     old_alignment: Cell<(Alignment, Alignment)>,
+    available_width: Cell<Option<f64>>,
 }
 
 impl FixedSizeLayout {
     pub fn new() -> Self {
         FixedSizeLayout::default()
     }
+
+    // Gets the width the text is measured against.
+    fn text_width(&self, widget: &WidgetContainer) -> f64 {
+        let max_width = widget.get::<Constraint>("constraint").max_width();
+
+        match self.available_width.get() {
+            Some(available_width) => max_width.min(available_width),
+            None => max_width,
+        }
+    }
 }
 
 impl Layout for FixedSizeLayout {
     fn measure(
         &self,
-        render_context_2_d: &mut RenderContext2D,
+        render_context_2_d: &mut PlatformBackend,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
@@ -58,33 +74,63 @@ impl Layout for FixedSizeLayout {
             self.desired_size.borrow_mut().set_dirty(true);
         }
 
+        // scalable content keeps a size that is set by the constraint
+        let scalable = is_stretched(&widget) || widget.try_get::<Svg>("svg").is_some();
+
         let size = widget
             .try_get::<Image>("image")
             .map(|image| (Size::new(image.width(), image.height())))
+            .or_else(|| {
+                widget
+                    .try_get::<Svg>("svg")
+                    .map(|svg| Size::new(svg.width(), svg.height()))
+            })
+            .or_else(|| {
+                widget
+                    .try_get::<RichText>("rich_text")
+                    .filter(|rich_text| !rich_text.is_empty())
+                    .map(|rich_text| {
+                        RichTextLayout::new(
+                            render_context_2_d,
+                            rich_text,
+                            widget.get::<String>("font"),
+                            *widget.get::<f64>("font_size"),
+                            TextLayoutOptions::from_widget(&widget, self.text_width(&widget)),
+                        )
+                        .size()
+                    })
+            })
             .or_else(|| {
                 text(&widget).and_then(|text| {
                     let font = widget.get::<String>("font");
                     let font_size = widget.get::<f64>("font_size");
+                    let options = TextLayoutOptions::from_widget(&widget, self.text_width(&widget));
 
                     if text.is_empty() {
                         widget
                             .try_get::<String>("water_mark")
                             .filter(|water_mark| !water_mark.is_empty())
                             .map(|water_mark| {
-                                measure_text(
+                                TextLayout::new(
                                     render_context_2_d,
                                     &water_mark,
                                     font.as_str(),
                                     *font_size,
+                                    options,
                                 )
+                                .size()
                             })
                     } else {
-                        Some(measure_text(
-                            render_context_2_d,
-                            &text,
-                            font.as_str(),
-                            *font_size,
-                        ))
+                        Some(
+                            TextLayout::new(
+                                render_context_2_d,
+                                &text,
+                                font.as_str(),
+                                *font_size,
+                                options,
+                            )
+                            .size(),
+                        )
                     }
                 })
             })
@@ -104,7 +150,13 @@ impl Layout for FixedSizeLayout {
             });
 
         if let Some(size) = size {
-            if let Some(constraint) = component_try_mut::<Constraint>(ecm, entity, "constraint") {
+            if scalable {
+                let constraint: Constraint = component(ecm, entity, "constraint");
+                let (width, height) = constraint.perform((size.width(), size.height()));
+                self.desired_size.borrow_mut().set_size(width, height);
+            } else if let Some(constraint) =
+                component_try_mut::<Constraint>(ecm, entity, "constraint")
+            {
                 constraint.set_width(size.width());
                 constraint.set_height(size.height());
             }
@@ -139,8 +191,8 @@ impl Layout for FixedSizeLayout {
 
     fn arrange(
         &self,
-        render_context_2_d: &mut RenderContext2D,
-        _parent_size: (f64, f64),
+        render_context_2_d: &mut PlatformBackend,
+        parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
@@ -151,6 +203,46 @@ impl Layout for FixedSizeLayout {
             return (0.0, 0.0);
         }
 
+        // wrapped text is measured again, if the available width has changed
+        if depends_on_width(&WidgetContainer::new(entity, ecm, theme, None)) {
+            let margin: Thickness = component(ecm, entity, "margin");
+            let available_width = (parent_size.0 - margin.left() - margin.right()).max(0.0);
+
+            if self
+                .available_width
+                .get()
+                .map_or(true, |width| (width - available_width).abs() > 0.5)
+            {
+                self.available_width.set(Some(available_width));
+                request_layout_pass();
+            }
+        }
+
+        // stretched images fill the available space like other layouts
+        if is_stretched(&WidgetContainer::new(entity, ecm, theme, None)) {
+            let horizontal_alignment: Alignment = component(ecm, entity, "h_align");
+            let vertical_alignment: Alignment = component(ecm, entity, "v_align");
+            let margin: Thickness = component(ecm, entity, "margin");
+            let constraint: Constraint = component(ecm, entity, "constraint");
+
+            let size = constraint.perform((
+                horizontal_alignment.align_measure(
+                    parent_size.0,
+                    self.desired_size.borrow().width(),
+                    margin.left(),
+                    margin.right(),
+                ),
+                vertical_alignment.align_measure(
+                    parent_size.1,
+                    self.desired_size.borrow().height(),
+                    margin.top(),
+                    margin.bottom(),
+                ),
+            ));
+
+            self.desired_size.borrow_mut().set_size(size.0, size.1);
+        }
+
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
             bounds.set_width(self.desired_size.borrow().width());
             bounds.set_height(self.desired_size.borrow().height());
@@ -180,6 +272,28 @@ impl Layout for FixedSizeLayout {
     }
 }
 
+// Returns `true` if the widget draws an image, that is resized to fill its bounds.
+fn is_stretched(widget: &WidgetContainer) -> bool {
+    widget.try_get::<Image>("image").is_some()
+        && (widget
+            .try_get::<Stretch>("stretch")
+            .map_or(false, |stretch| *stretch != Stretch::None)
+            || widget
+                .try_get::<Thickness>("nine_slice")
+                .map_or(false, |nine_slice| *nine_slice != Thickness::default()))
+}
+
+// Returns `true` if the widget draws text, whose size depends on the available width.
+fn depends_on_width(widget: &WidgetContainer) -> bool {
+    let wraps_text = text(widget).is_some()
+        && TextLayoutOptions::from_widget(widget, f64::MAX).depends_on_width();
+    let wraps_rich_text = widget
+        .try_get::<RichText>("rich_text")
+        .map_or(false, |rich_text| !rich_text.is_empty());
+
+    wraps_text || wraps_rich_text
+}
+
 fn text(widget: &WidgetContainer) -> Option<String> {
     if let Some(localizable) = widget.try_get::<bool>("localizable") {
         if *localizable {
@@ -197,7 +311,7 @@ fn text(widget: &WidgetContainer) -> Option<String> {
 }
 
 fn measure_text(
-    render_context_2_d: &mut RenderContext2D,
+    render_context_2_d: &mut PlatformBackend,
     text: &str,
     font_family: &str,
     font_size: f64,
@@ -6,7 +6,7 @@ use std::{
 use dces::prelude::*;
 
 use crate::{
-    prelude::*, proc_macros::IntoLayout, render::RenderContext2D, theming::*, tree::Tree,
+    prelude::*, proc_macros::IntoLayout, render::PlatformBackend, theming::*, tree::Tree,
     utils::prelude::*,
 };
 
@@ -176,7 +176,7 @@ impl GridLayout {
 impl Layout for GridLayout {
     fn measure(
         &self,
-        render_context_2_d: &mut RenderContext2D,
+        render_context_2_d: &mut PlatformBackend,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
@@ -264,7 +264,7 @@ impl Layout for GridLayout {
 
     fn arrange(
         &self,
-        render_context_2_d: &mut RenderContext2D,
+        render_context_2_d: &mut PlatformBackend,
         parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
@@ -1,8 +1,8 @@
-use std::{any::Any, collections::BTreeMap};
+use std::{any::Any, cell::Cell, collections::BTreeMap};
 
 use dces::prelude::*;
 
-use crate::{render::RenderContext2D, theming::*, tree::Tree, utils::*};
+use crate::{render::PlatformBackend, theming::*, tree::Tree, utils::*};
 
 pub use self::absolute::*;
 pub use self::fixed_size::*;
@@ -23,7 +23,7 @@ pub trait Layout: Any {
     // Measure all children before the arrangement.
     fn measure(
         &self,
-        render_context_2_d: &mut RenderContext2D,
+        render_context_2_d: &mut PlatformBackend,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
@@ -33,7 +33,7 @@ pub trait Layout: Any {
     /// Arranges and sizes the children.
     fn arrange(
         &self,
-        render_context_2_d: &mut RenderContext2D,
+        render_context_2_d: &mut PlatformBackend,
         parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
@@ -42,6 +42,21 @@ pub trait Layout: Any {
     ) -> (f64, f64);
 }
 
+thread_local! {
+    static LAYOUT_PASS_REQUESTED: Cell<bool> = Cell::new(false);
+}
+
+/// Requests an additional measure and arrange pass of the current layout run. It is used by layouts whose desired
+/// size depends on the size that is available on arrange, e.g. of wrapped text.
+pub fn request_layout_pass() {
+    LAYOUT_PASS_REQUESTED.with(|requested| requested.set(true));
+}
+
+/// Returns `true` if an additional layout pass was requested and resets the request.
+pub fn take_layout_pass_request() -> bool {
+    LAYOUT_PASS_REQUESTED.with(|requested| requested.replace(false))
+}
+
 fn component<C: Component + Clone>(
     ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
     entity: Entity,
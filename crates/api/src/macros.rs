@@ -110,6 +110,10 @@ macro_rules! widget {
             clip: bool,
             #[property(f32)]
             opacity: f32,
+            #[property(CacheMode)]
+            cache_mode: CacheMode,
+            #[property(RenderTransform)]
+            render_transform: RenderTransform,
             #[property(Visibility)]
             visibility: Visibility,
             #[property(Selector)]
@@ -227,6 +231,16 @@ macro_rules! widget {
                 self.set_property("opacity", opacity)
             }
 
+            /// Sets or shares the cache mode property.
+            pub fn cache_mode(self, cache_mode: impl IntoPropertySource<CacheMode>) -> Self {
+                self.set_property("cache_mode", cache_mode)
+            }
+
+            /// Sets or shares the render transform property.
+            pub fn render_transform(self, render_transform: impl IntoPropertySource<RenderTransform>) -> Self {
+                self.set_property("render_transform", render_transform)
+            }
+
             /// Inserts a new width.
             pub fn width(mut self, width: impl Into<f64>) -> Self {
                 if !self.width.is_none() {
@@ -458,9 +472,12 @@ macro_rules! widget {
                 ctx.register_property("enabled", entity, this.enabled);
                 ctx.register_property("clip", entity, this.clip);
                 ctx.register_property("opacity", entity, this.opacity);
+                ctx.register_property("cache_mode", entity, this.cache_mode);
+                ctx.register_property("render_transform", entity, this.render_transform);
                 ctx.register_property("type_id", entity, TypeId::of::<$widget>());
                 ctx.register_property("type_name", entity, std::any::type_name::<$widget>().to_string());
                 ctx.register_property("dirty", entity, false);
+                ctx.register_property("global_transform", entity, Transform::default());
 
                 let mut constraint = this.constraint;
 
@@ -91,7 +91,23 @@ into_property_source!(PathBuf);
 
 // Implementation of PropertySource for utils types
 into_property_source!(utils::Alignment: &str);
+into_property_source!(utils::BorderBrushes: &str, utils::Brush, utils::Value);
+into_property_source!(utils::BorderStyle: &str, utils::Value);
 into_property_source!(utils::Brush: &str, utils::Color, utils::Value);
+into_property_source!(
+    utils::BoxShadow: &str,
+    String,
+    utils::Shadow,
+    Vec<utils::Shadow>,
+    utils::Value
+);
+into_property_source!(
+    utils::CornerRadius: i32,
+    f64,
+    (i32, i32, i32, i32),
+    (f64, f64, f64, f64),
+    utils::Value
+);
 into_property_source!(utils::Orientation: &str);
 into_property_source!(utils::Point: f64, i32, (i32, i32), (f64, f64));
 into_property_source!(utils::Size: f64, i32, (i32, i32), (f64, f64));
@@ -110,6 +126,12 @@ into_property_source!(
     utils::Value
 );
 into_property_source!(utils::SelectionMode: &str);
+into_property_source!(utils::Stretch: &str);
+into_property_source!(utils::TextAlignment: &str);
+into_property_source!(utils::TextTrimming: &str);
+into_property_source!(utils::TextWrap: &str);
+into_property_source!(utils::CacheMode: &str);
+into_property_source!(utils::RenderTransform: utils::Transform);
 into_property_source!(utils::Visibility: &str);
 into_property_source!(Vec<String>);
 into_property_source!(utils::Filter: &str, String, Vec<String>, Vec<&str>);
@@ -119,12 +141,20 @@ into_property_source!(theming::Selector: &str, String);
 into_property_source!(theming::Theme);
 
 // Implementation of render property types
-into_property_source!(render::Image: &str, String, (u32, u32, Vec<u32>));
+into_property_source!(
+    render::Image: &str,
+    String,
+    (u32, u32, Vec<u32>),
+    render::RenderTarget
+);
+into_property_source!(render::Svg: &str, String);
+into_property_source!(render::ImageFrames: &str, String);
 
 // Implementation of custom property types
 into_property_source!(Blocks: BlocksBuilder, &str, String);
 into_property_source!(utils::Constraint: utils::ConstraintBuilder);
 into_property_source!(DefaultRenderPipeline);
+into_property_source!(DefaultRenderPipeline2D);
 into_property_source!(ScrollViewerMode: (&str, &str));
 into_property_source!(SelectedEntities: HashSet<Entity>);
 into_property_source!(SelectedIndices: HashSet<usize>);
@@ -1,6 +1,6 @@
 use std::any::Any;
 
-use crate::render;
+use crate::{render, utils::Size};
 
 #[derive(Clone, PartialEq)]
 struct EmptyRenderPipeline;
@@ -26,6 +26,13 @@ impl render::RenderPipeline for EmptyRenderPipeline {
 #[derive(Clone, Debug)]
 pub struct DefaultRenderPipeline(pub Box<dyn render::PipelineTrait>);
 
+impl DefaultRenderPipeline {
+    /// Returns `true` if no pipeline is set.
+    pub fn is_empty(&self) -> bool {
+        self.0.as_any().is::<EmptyRenderPipeline>()
+    }
+}
+
 impl PartialEq for DefaultRenderPipeline {
     fn eq(&self, _other: &Self) -> bool {
         // todo this is workaround for property checking
@@ -38,3 +45,103 @@ impl Default for DefaultRenderPipeline {
         DefaultRenderPipeline(Box::new(EmptyRenderPipeline))
     }
 }
+
+#[derive(Clone, PartialEq)]
+struct EmptyRenderPipeline2D;
+
+impl render::Pipeline2DTrait for EmptyRenderPipeline2D {
+    fn box_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>().map_or(false, |a| self == a)
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn render::Pipeline2DTrait> {
+        Box::new(self.clone())
+    }
+}
+
+impl render::RenderPipeline2D for EmptyRenderPipeline2D {
+    fn draw(&self, _: &mut render::PlatformBackend, _: Size) {}
+}
+
+/// RenderPipeline2D object, that draws vector graphics with the 2D API of the render context.
+#[derive(Clone, Debug)]
+pub struct DefaultRenderPipeline2D(pub Box<dyn render::Pipeline2DTrait>);
+
+impl DefaultRenderPipeline2D {
+    /// Returns `true` if no pipeline is set.
+    pub fn is_empty(&self) -> bool {
+        self.0.as_any().is::<EmptyRenderPipeline2D>()
+    }
+}
+
+impl PartialEq for DefaultRenderPipeline2D {
+    fn eq(&self, other: &Self) -> bool {
+        // changes of the drawing are signaled by the draw version of the widget
+        self.0 == other.0
+    }
+}
+
+impl Default for DefaultRenderPipeline2D {
+    fn default() -> Self {
+        DefaultRenderPipeline2D(Box::new(EmptyRenderPipeline2D))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proc_macros::Pipeline2D,
+        render::{DisplayListRecorder, DrawCommand, Pipeline2DTrait, RenderPipeline2D},
+    };
+
+    // Fills the size of the widget without the given margin.
+    #[derive(Clone, PartialEq, Pipeline2D)]
+    struct RectPipeline(f64);
+
+    impl RenderPipeline2D for RectPipeline {
+        fn draw(&self, render_context: &mut render::PlatformBackend, size: Size) {
+            render_context.fill_rect(
+                self.0,
+                self.0,
+                size.width() - 2.0 * self.0,
+                size.height() - 2.0 * self.0,
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_render_pipeline_2_d() {
+        assert!(DefaultRenderPipeline2D::default().is_empty());
+
+        let pipeline = DefaultRenderPipeline2D(Box::new(RectPipeline(1.0)));
+        assert!(!pipeline.is_empty());
+        assert_eq!(pipeline, pipeline.clone());
+        assert_ne!(
+            pipeline,
+            DefaultRenderPipeline2D(Box::new(RectPipeline(2.0)))
+        );
+        assert_ne!(pipeline, DefaultRenderPipeline2D::default());
+    }
+
+    #[test]
+    fn test_draw() {
+        let mut recorder = DisplayListRecorder::new(20.0, 10.0);
+        DefaultRenderPipeline2D(Box::new(RectPipeline(1.0)))
+            .0
+            .draw(&mut recorder, Size::new(20.0, 10.0));
+
+        assert_eq!(
+            recorder.display_list().commands(),
+            &[DrawCommand::FillRect {
+                x: 1.0,
+                y: 1.0,
+                width: 18.0,
+                height: 8.0,
+            }]
+        );
+    }
+}
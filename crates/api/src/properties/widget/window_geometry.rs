@@ -0,0 +1,112 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Describes the geometry of a window. It is used to save and restore the geometry of a window
+/// with the `Settings` service.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    /// Position of the window on the screen.
+    pub position: (f64, f64),
+
+    /// Size of the window (not maximized).
+    pub size: (f64, f64),
+
+    /// Describes if the window is maximized.
+    pub maximized: bool,
+}
+
+impl WindowGeometry {
+    /// Creates a new window geometry.
+    pub fn new(position: (f64, f64), size: (f64, f64), maximized: bool) -> Self {
+        WindowGeometry {
+            position,
+            size,
+            maximized,
+        }
+    }
+
+    /// Gets the settings key that is used to store the geometry of the window with the given id.
+    pub fn settings_key(window_id: &str) -> String {
+        if window_id.is_empty() {
+            return "window_geometry".to_string();
+        }
+
+        format!("{}_geometry", window_id)
+    }
+
+    /// Checks if the geometry contains usable values, e.g. after it is loaded from a file.
+    pub fn is_valid(&self) -> bool {
+        self.position.0.is_finite()
+            && self.position.1.is_finite()
+            && self.size.0.is_finite()
+            && self.size.1.is_finite()
+            && self.size.0 > 0.0
+            && self.size.1 > 0.0
+    }
+
+    /// Clamps the geometry to the given screen size, so the window is completely visible on the screen.
+    /// A maximized window fills the whole screen.
+    pub fn clamp(&self, screen_size: (f64, f64)) -> Self {
+        if self.maximized {
+            return WindowGeometry::new((0.0, 0.0), screen_size, true);
+        }
+
+        let width = self.size.0.min(screen_size.0);
+        let height = self.size.1.min(screen_size.1);
+
+        WindowGeometry::new(
+            (
+                self.position.0.max(0.0).min(screen_size.0 - width),
+                self.position.1.max(0.0).min(screen_size.1 - height),
+            ),
+            (width, height),
+            false,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_key() {
+        assert_eq!(WindowGeometry::settings_key(""), "window_geometry");
+        assert_eq!(WindowGeometry::settings_key("main"), "main_geometry");
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(WindowGeometry::new((-10.0, 20.0), (100.0, 50.0), false).is_valid());
+        assert!(!WindowGeometry::new((0.0, 0.0), (0.0, 50.0), false).is_valid());
+        assert!(!WindowGeometry::new((0.0, 0.0), (100.0, -50.0), false).is_valid());
+        assert!(!WindowGeometry::new((std::f64::NAN, 0.0), (100.0, 50.0), false).is_valid());
+        assert!(!WindowGeometry::new((0.0, 0.0), (std::f64::INFINITY, 50.0), false).is_valid());
+    }
+
+    #[test]
+    fn test_clamp() {
+        let screen_size = (800.0, 600.0);
+
+        // inside of the screen
+        let geometry = WindowGeometry::new((10.0, 20.0), (100.0, 50.0), false);
+        assert_eq!(geometry.clamp(screen_size), geometry);
+
+        // outside of the screen
+        assert_eq!(
+            WindowGeometry::new((-10.0, 700.0), (100.0, 50.0), false).clamp(screen_size),
+            WindowGeometry::new((0.0, 550.0), (100.0, 50.0), false)
+        );
+
+        // bigger than the screen
+        assert_eq!(
+            WindowGeometry::new((10.0, 20.0), (1000.0, 700.0), false).clamp(screen_size),
+            WindowGeometry::new((0.0, 0.0), (800.0, 600.0), false)
+        );
+
+        // maximized
+        assert_eq!(
+            WindowGeometry::new((10.0, 20.0), (100.0, 50.0), true).clamp(screen_size),
+            WindowGeometry::new((0.0, 0.0), (800.0, 600.0), true)
+        );
+    }
+}
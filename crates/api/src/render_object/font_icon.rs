@@ -31,7 +31,7 @@ impl RenderObject for FontIconRenderObject {
 
         if !icon.is_empty() {
             ctx.render_context_2_d().begin_path();
-            ctx.render_context_2_d().set_font_family(icon_font);
+            ctx.render_context_2_d().set_font_family(&icon_font);
             ctx.render_context_2_d().set_font_size(icon_size);
             ctx.render_context_2_d().set_fill_style(icon_brush);
 
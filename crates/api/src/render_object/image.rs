@@ -1,25 +1,186 @@
 use crate::{proc_macros::IntoRenderObject, render::Image, render_object::*};
 
 /// Used to render an image.
+///
+/// The image is resized by the `stretch` property or drawn as nine slices if the `nine_slice` property is set.
 #[derive(Debug, IntoRenderObject)]
 pub struct ImageRenderObject;
 
 impl RenderObject for ImageRenderObject {
     fn render_self(&self, ctx: &mut Context, global_position: &Point) {
-        let (bounds, mut image) = {
+        let (bounds, image, stretch, nine_slice, h_align, v_align) = {
             let widget = ctx.widget();
             (
                 widget.clone::<Rectangle>("bounds"),
                 widget.try_clone::<Image>("image"),
+                widget.clone_or_default::<Stretch>("stretch"),
+                widget.clone_or_default::<Thickness>("nine_slice"),
+                widget.clone_or_default::<Alignment>("h_align"),
+                widget.clone_or_default::<Alignment>("v_align"),
             )
         };
 
-        if let Some(image) = &mut image {
-            ctx.render_context_2_d().draw_image(
-                image,
+        let image = match &image {
+            Some(image) => image,
+            None => return,
+        };
+
+        let size = Size::new(image.width(), image.height());
+        let destination = Rectangle::new(
+            (
                 bounds.x() + global_position.x(),
                 bounds.y() + global_position.y(),
+            ),
+            bounds.size(),
+        );
+
+        if nine_slice != Thickness::default() {
+            for (clip, rect) in nine_slices(size, nine_slice, destination) {
+                ctx.render_context_2_d().draw_image_with_clip_and_size(
+                    image,
+                    clip,
+                    rect.x(),
+                    rect.y(),
+                    rect.width(),
+                    rect.height(),
+                );
+            }
+
+            return;
+        }
+
+        if stretch == Stretch::None && size == destination.size() {
+            ctx.render_context_2_d()
+                .draw_image(image, destination.x(), destination.y());
+            return;
+        }
+
+        if let Some((clip, rect)) = stretch.fit(size, destination, h_align, v_align) {
+            ctx.render_context_2_d().draw_image_with_clip_and_size(
+                image,
+                clip,
+                rect.x(),
+                rect.y(),
+                rect.width(),
+                rect.height(),
             );
         }
     }
 }
+
+// Splits an image with the given size by the insets into nine parts and returns them with the rectangles they are
+// drawn to. The corners keep their size, the edges and the center are stretched. If the destination is smaller than
+// the corners, the corners are shrunk.
+fn nine_slices(
+    size: Size,
+    insets: Thickness,
+    destination: Rectangle,
+) -> Vec<(Rectangle, Rectangle)> {
+    let left = insets.left().max(0.0).min(size.width());
+    let right = insets.right().max(0.0).min(size.width() - left);
+    let top = insets.top().max(0.0).min(size.height());
+    let bottom = insets.bottom().max(0.0).min(size.height() - top);
+
+    let horizontal = (destination.width() / (left + right)).min(1.0);
+    let vertical = (destination.height() / (top + bottom)).min(1.0);
+
+    let columns = [
+        (0.0, left, destination.x(), left * horizontal),
+        (
+            left,
+            size.width() - left - right,
+            destination.x() + left * horizontal,
+            destination.width() - (left + right) * horizontal,
+        ),
+        (
+            size.width() - right,
+            right,
+            destination.x() + destination.width() - right * horizontal,
+            right * horizontal,
+        ),
+    ];
+
+    let rows = [
+        (0.0, top, destination.y(), top * vertical),
+        (
+            top,
+            size.height() - top - bottom,
+            destination.y() + top * vertical,
+            destination.height() - (top + bottom) * vertical,
+        ),
+        (
+            size.height() - bottom,
+            bottom,
+            destination.y() + destination.height() - bottom * vertical,
+            bottom * vertical,
+        ),
+    ];
+
+    let mut slices = vec![];
+
+    for (source_y, source_height, y, height) in rows.iter() {
+        for (source_x, source_width, x, width) in columns.iter() {
+            if *source_width <= 0.0 || *source_height <= 0.0 || *width <= 0.0 || *height <= 0.0 {
+                continue;
+            }
+
+            slices.push((
+                Rectangle::new((*source_x, *source_y), (*source_width, *source_height)),
+                Rectangle::new((*x, *y), (*width, *height)),
+            ));
+        }
+    }
+
+    slices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nine_slices() {
+        let slices = nine_slices(
+            Size::new(30.0, 30.0),
+            Thickness::new(10.0, 10.0, 10.0, 10.0),
+            Rectangle::new((5.0, 5.0), (100.0, 50.0)),
+        );
+
+        assert_eq!(slices.len(), 9);
+
+        // the corners keep their size
+        assert_eq!(
+            slices[0],
+            (
+                Rectangle::new((0.0, 0.0), (10.0, 10.0)),
+                Rectangle::new((5.0, 5.0), (10.0, 10.0))
+            )
+        );
+        assert_eq!(
+            slices[8],
+            (
+                Rectangle::new((20.0, 20.0), (10.0, 10.0)),
+                Rectangle::new((95.0, 45.0), (10.0, 10.0))
+            )
+        );
+
+        // the center is stretched
+        assert_eq!(
+            slices[4],
+            (
+                Rectangle::new((10.0, 10.0), (10.0, 10.0)),
+                Rectangle::new((15.0, 15.0), (80.0, 30.0))
+            )
+        );
+
+        // the corners are shrunk, if the destination is too small
+        let slices = nine_slices(
+            Size::new(30.0, 30.0),
+            Thickness::new(10.0, 0.0, 10.0, 0.0),
+            Rectangle::new((0.0, 0.0), (10.0, 30.0)),
+        );
+
+        assert_eq!(slices.len(), 2);
+        assert_eq!(slices[1].1, Rectangle::new((5.0, 0.0), (5.0, 30.0)));
+    }
+}
@@ -5,7 +5,7 @@ use std::{any::Any, collections::BTreeMap};
 use dces::prelude::*;
 
 use crate::{
-    application::ContextProvider, prelude::*, render::RenderContext2D, theming::*, tree::*,
+    application::ContextProvider, prelude::*, render::PlatformBackend, theming::*, tree::*,
     utils::*,
 };
 
@@ -15,6 +15,8 @@ pub use self::font_icon::*;
 pub use self::image::*;
 pub use self::pipeline::*;
 pub use self::rectangle::*;
+pub use self::screenshot::*;
+pub use self::svg::*;
 pub use self::text::*;
 
 mod cursor;
@@ -23,12 +25,14 @@ mod font_icon;
 mod image;
 mod pipeline;
 mod rectangle;
+mod screenshot;
+mod svg;
 mod text;
 
 pub trait RenderObject: Any {
     fn render(
         &self,
-        render_context: &mut RenderContext2D,
+        render_context: &mut PlatformBackend,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
         context_provider: &ContextProvider,
@@ -49,19 +53,96 @@ pub trait RenderObject: Any {
             .get::<Visibility>("visibility", entity)
         {
             if *visibility != Visibility::Visible {
+                context_provider.render_bounds.borrow_mut().remove(&entity);
                 return;
             }
         } else {
             return;
         }
 
-        render_context.begin_path();
-        render_context.set_alpha(
-            *ecm.component_store()
-                .get::<f32>("opacity", entity)
-                .unwrap_or(&1.0),
+        // the transform of the widget applies to the widget and its children
+        let parent_transform = context_provider.render_transform.get();
+        let transform = widget_transform(entity, ecm, &global_position, &parent_transform);
+
+        if let Ok(global_transform) = ecm
+            .component_store_mut()
+            .get_mut::<Transform>("global_transform", entity)
+        {
+            *global_transform = transform;
+        }
+
+        // widgets outside of the redrawn region are not drawn, but their children could be inside of it
+        let mut damaged = true;
+        let bounds_rect = render_bounds(
+            entity,
+            ecm,
+            &global_position,
+            &transform,
+            context_provider.scale_factor.get(),
         );
 
+        if let Some(rect) = bounds_rect {
+            if let Some(region) = context_provider.render_region.get() {
+                damaged = region.intersects(&rect);
+            }
+
+            context_provider
+                .render_bounds
+                .borrow_mut()
+                .insert(entity, rect);
+        }
+
+        let opacity = *ecm
+            .component_store()
+            .get::<f32>("opacity", entity)
+            .unwrap_or(&1.0);
+        let cache_mode = *ecm
+            .component_store()
+            .get::<CacheMode>("cache_mode", entity)
+            .unwrap_or(&CacheMode::None);
+
+        // a subtree with a cached layer or group opacity is drawn in an offscreen layer that is composited with the
+        // opacity of the widget, so that overlapping children do not show through each other
+        let layer = match bounds_rect {
+            Some(rect)
+                if !ecm.entity_store().children[&entity].is_empty()
+                    && (cache_mode == CacheMode::Layer || opacity < 1.0) =>
+            {
+                Some(rect)
+            }
+            _ => None,
+        };
+        let mut region = None;
+
+        if let Some(rect) = layer {
+            // the children are clipped to the layer, so nothing of the subtree is inside of the redrawn region
+            if !damaged {
+                return;
+            }
+
+            if cache_mode == CacheMode::Layer && render_context.has_layer(layer_key(entity)) {
+                render_context.draw_layer(layer_key(entity), opacity);
+                return;
+            }
+
+            // the whole subtree is drawn in the layer
+            region = context_provider.render_region.replace(None);
+            render_context.push_layer(rect.x(), rect.y(), rect.width(), rect.height());
+        }
+
+        if transform != parent_transform {
+            set_transform(render_context, &transform);
+            context_provider.render_transform.set(transform);
+        }
+
+        render_context.begin_path();
+        render_context.set_alpha(if layer.is_some() { 1.0 } else { opacity });
+
+        // outer shadows are drawn before the clip of the widget is applied, because they are painted outside of its bounds
+        if damaged {
+            render_box_shadow(render_context, entity, ecm, &global_position, false);
+        }
+
         // Could be unwrap because every widget has the clip property
         let clip = *ecm.component_store().get::<bool>("clip", entity).unwrap();
         if clip {
@@ -77,10 +158,14 @@ pub trait RenderObject: Any {
             }
         }
 
-        self.render_self(
-            &mut Context::new((entity, ecm), &theme, context_provider, render_context),
-            &global_position,
-        );
+        if damaged {
+            self.render_self(
+                &mut Context::new((entity, ecm), &theme, context_provider, render_context),
+                &global_position,
+            );
+
+            render_box_shadow(render_context, entity, ecm, &global_position, true);
+        }
 
         let mut global_pos = (0.0, 0.0);
 
@@ -117,7 +202,7 @@ pub trait RenderObject: Any {
         }
 
         // render debug border for each widget
-        if debug {
+        if debug && damaged {
             if let Ok(bounds) = ecm.component_store().get::<Rectangle>("bounds", entity) {
                 render_context.begin_path();
                 render_context.set_stroke_style(Brush::from("#0033cc"));
@@ -130,13 +215,28 @@ pub trait RenderObject: Any {
                 render_context.close_path();
             }
         }
+
+        if transform != parent_transform {
+            set_transform(render_context, &parent_transform);
+            context_provider.render_transform.set(parent_transform);
+        }
+
+        if layer.is_some() {
+            let key = match cache_mode {
+                CacheMode::Layer => Some(layer_key(entity)),
+                CacheMode::None => None,
+            };
+
+            render_context.pop_layer(opacity, key);
+            context_provider.render_region.set(region);
+        }
     }
 
     fn render_self(&self, _: &mut Context, _: &Point) {}
 
     fn render_children(
         &self,
-        render_context: &mut RenderContext2D,
+        render_context: &mut PlatformBackend,
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
         context_provider: &ContextProvider,
@@ -161,3 +261,119 @@ pub trait RenderObject: Any {
         }
     }
 }
+
+/// Gets the key of the cached layer of the given widget.
+pub fn layer_key(entity: Entity) -> u64 {
+    entity.0 as u64
+}
+
+/// Gets the global render transform of the given widget from the global position and transform of its parent.
+pub fn widget_transform(
+    entity: Entity,
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    global_position: &Point,
+    parent_transform: &Transform,
+) -> Transform {
+    let render_transform = match ecm
+        .component_store()
+        .get::<RenderTransform>("render_transform", entity)
+    {
+        Ok(render_transform) if !render_transform.is_identity() => render_transform,
+        _ => return *parent_transform,
+    };
+
+    match ecm.component_store().get::<Rectangle>("bounds", entity) {
+        Ok(bounds) => render_transform
+            .matrix(Rectangle::new(
+                (
+                    global_position.x() + bounds.x(),
+                    global_position.y() + bounds.y(),
+                ),
+                bounds.size(),
+            ))
+            .then(parent_transform),
+        _ => *parent_transform,
+    }
+}
+
+/// Gets the global bounds of the given widget including its outer shadows, that are drawn at the given scale factor.
+/// The global position is the position of its parent, the transform is the global render transform of the widget.
+pub fn render_bounds(
+    entity: Entity,
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    global_position: &Point,
+    transform: &Transform,
+    scale_factor: f64,
+) -> Option<Rectangle> {
+    let bounds = ecm
+        .component_store()
+        .get::<Rectangle>("bounds", entity)
+        .ok()?;
+
+    let rect = Rectangle::new(
+        (
+            global_position.x() + bounds.x(),
+            global_position.y() + bounds.y(),
+        ),
+        bounds.size(),
+    );
+
+    let rect = match ecm.component_store().get::<BoxShadow>("box_shadow", entity) {
+        Ok(box_shadow) if !box_shadow.is_empty() => box_shadow.bounds(rect, scale_factor),
+        _ => rect,
+    };
+
+    Some(transform.transform_rect(rect))
+}
+
+// Applies the given global transform to the render context.
+fn set_transform(render_context: &mut PlatformBackend, transform: &Transform) {
+    render_context.set_transform(
+        transform.m11,
+        transform.m12,
+        transform.m21,
+        transform.m22,
+        transform.m31,
+        transform.m32,
+    );
+}
+
+// Draws the outer or inset layers of the box shadow of the given widget. The first layer is drawn on top.
+fn render_box_shadow(
+    render_context: &mut PlatformBackend,
+    entity: Entity,
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    global_position: &Point,
+    inset: bool,
+) {
+    let box_shadow = match ecm.component_store().get::<BoxShadow>("box_shadow", entity) {
+        Ok(box_shadow) if !box_shadow.is_empty() => box_shadow,
+        _ => return,
+    };
+
+    let bounds = match ecm.component_store().get::<Rectangle>("bounds", entity) {
+        Ok(bounds) => bounds,
+        _ => return,
+    };
+
+    let radii = ecm
+        .component_store()
+        .get::<CornerRadius>("border_radius", entity)
+        .map(|border_radius| border_radius.radii())
+        .unwrap_or([0.0; 4]);
+
+    for shadow in box_shadow.shadows().iter().rev() {
+        if shadow.inset != inset {
+            continue;
+        }
+
+        render_context.draw_box_shadow(
+            global_position.x() + bounds.x(),
+            global_position.y() + bounds.y(),
+            bounds.width(),
+            bounds.height(),
+            radii,
+            shadow,
+        );
+    }
+}
@@ -1,23 +1,61 @@
 use crate::{proc_macros::IntoRenderObject, render_object::*};
 
+/// Used to render the raster pipeline and the 2D pipeline of a widget.
+///
+/// The 2D pipeline draws on the render context of the window, that is clipped and translated to the bounds of the
+/// widget. Both pipelines are optional.
 #[derive(Debug, IntoRenderObject)]
 pub struct PipelineRenderObject;
 
 impl RenderObject for PipelineRenderObject {
-    fn render_self(&self, ctx: &mut Context, _: &Point) {
-        let bounds = *ctx.widget().get::<Rectangle>("bounds");
-        let pipeline = ctx
-            .widget()
-            .get::<DefaultRenderPipeline>("render_pipeline")
-            .0
-            .clone();
-
-        ctx.render_context_2_d().draw_pipeline(
-            bounds.x(),
-            bounds.y(),
-            bounds.width(),
-            bounds.height(),
-            pipeline,
-        );
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let (bounds, pipeline, pipeline_2_d) = {
+            let widget = ctx.widget();
+            (
+                *widget.get::<Rectangle>("bounds"),
+                widget.try_clone::<DefaultRenderPipeline>("render_pipeline"),
+                widget.try_clone::<DefaultRenderPipeline2D>("render_pipeline_2_d"),
+            )
+        };
+
+        if let Some(pipeline) = pipeline {
+            if !pipeline.is_empty() {
+                ctx.render_context_2_d().draw_pipeline(
+                    bounds.x(),
+                    bounds.y(),
+                    bounds.width(),
+                    bounds.height(),
+                    pipeline.0,
+                );
+            }
+        }
+
+        let pipeline_2_d = match pipeline_2_d {
+            Some(pipeline_2_d) if !pipeline_2_d.is_empty() => pipeline_2_d,
+            _ => return,
+        };
+
+        if bounds.width() <= 0.0 || bounds.height() <= 0.0 {
+            return;
+        }
+
+        let rect = Rectangle::new(*global_position + bounds.position(), bounds.size());
+        let parent_transform = ctx.provider.render_transform.get();
+        let transform = Transform::translation(rect.x(), rect.y()).then(&parent_transform);
+
+        let render_context = ctx.render_context_2_d();
+        render_context.save();
+        render_context.begin_path();
+        render_context.rect(rect.x(), rect.y(), rect.width(), rect.height());
+        render_context.clip();
+        render_context.begin_path();
+        set_transform(render_context, &transform);
+
+        pipeline_2_d.0.draw(render_context, rect.size());
+
+        // the transform is not part of the saved state
+        render_context.begin_path();
+        render_context.restore();
+        set_transform(render_context, &parent_transform);
     }
 }
@@ -1,11 +1,13 @@
-use std::f64::consts::PI;
+use std::f64::consts::{FRAC_PI_4, PI};
 
 use crate::{
     proc_macros::IntoRenderObject,
-    render::RenderContext2D,
+    render::{Path2D, PlatformBackend},
     render_object::*,
     utils,
-    utils::{Brush, Point, Rectangle, Thickness},
+    utils::{
+        BorderBrushes, BorderStyle, Brush, CornerRadius, LineCap, Point, Rectangle, Thickness,
+    },
 };
 
 #[derive(Debug, IntoRenderObject)]
@@ -15,11 +17,12 @@ impl RectangleRenderObject {
     // Renders rectangle with border and without radius.
     fn render_bordered_rect_path(
         &self,
-        render_context_2_d: &mut RenderContext2D,
+        render_context_2_d: &mut PlatformBackend,
         rect: Rectangle,
         brush: utils::Brush,
         border_brush: utils::Brush,
         border_thickness: Thickness,
+        border_style: BorderStyle,
     ) {
         render_context_2_d.rect(rect.x(), rect.y(), rect.width(), rect.height());
 
@@ -28,40 +31,30 @@ impl RectangleRenderObject {
             render_context_2_d.fill();
         }
 
-        if !border_brush.is_transparent() {
-            render_context_2_d.set_line_width(border_thickness.left());
-            render_context_2_d.set_stroke_style(border_brush);
-            render_context_2_d.stroke();
-        }
+        self.stroke_border(
+            render_context_2_d,
+            border_thickness.left(),
+            border_brush,
+            border_style,
+        );
     }
 
     // Builds rectangle path with radius and without border.
     fn render_rounded_rect_path(
         &self,
-        render_context_2_d: &mut RenderContext2D,
+        render_context_2_d: &mut PlatformBackend,
         x: f64,
         y: f64,
         width: f64,
         height: f64,
-        radius: f64,
+        radius: CornerRadius,
     ) {
-        let r = x + width;
-        let b = y + height;
-        render_context_2_d.move_to(x + radius, y);
-        render_context_2_d.line_to(r - radius, y);
-        render_context_2_d.quadratic_curve_to(r, y, r, y + radius);
-        render_context_2_d.line_to(r, y + height - radius);
-        render_context_2_d.quadratic_curve_to(r, b, r - radius, b);
-        render_context_2_d.line_to(x + radius, b);
-        render_context_2_d.quadratic_curve_to(x, b, x, b - radius);
-        render_context_2_d.line_to(x, y + radius);
-        render_context_2_d.quadratic_curve_to(x, y, x + radius, y);
-        render_context_2_d.close_path();
+        render_context_2_d.rounded_rect(x, y, width, height, radius.radii());
     }
 
     fn render_circle(
         &self,
-        render_context_2_d: &mut RenderContext2D,
+        render_context_2_d: &mut PlatformBackend,
         x: f64,
         y: f64,
         width: f64,
@@ -72,9 +65,10 @@ impl RectangleRenderObject {
         render_context_2_d.close_path();
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn render_bordered_circle(
         &self,
-        render_context_2_d: &mut RenderContext2D,
+        render_context_2_d: &mut PlatformBackend,
         x: f64,
         y: f64,
         width: f64,
@@ -83,6 +77,7 @@ impl RectangleRenderObject {
         brush: utils::Brush,
         border_brush: utils::Brush,
         border_thickness: Thickness,
+        border_style: BorderStyle,
     ) {
         self.render_circle(render_context_2_d, x, y, width, height, radius);
 
@@ -91,22 +86,24 @@ impl RectangleRenderObject {
             render_context_2_d.fill();
         }
 
-        if !border_brush.is_transparent() {
-            render_context_2_d.set_line_width(border_thickness.left());
-            render_context_2_d.set_stroke_style(border_brush);
-            render_context_2_d.stroke();
-        }
+        self.stroke_border(
+            render_context_2_d,
+            border_thickness.left(),
+            border_brush,
+            border_style,
+        );
     }
 
     // Renders rectangle with border and radius.
     fn render_rounded_bordered_rect_path(
         &self,
-        render_context_2_d: &mut RenderContext2D,
+        render_context_2_d: &mut PlatformBackend,
         rect: Rectangle,
-        radius: f64,
+        radius: CornerRadius,
         brush: utils::Brush,
         border_brush: utils::Brush,
         border_thickness: Thickness,
+        border_style: BorderStyle,
     ) {
         self.render_rounded_rect_path(
             render_context_2_d,
@@ -122,30 +119,201 @@ impl RectangleRenderObject {
             render_context_2_d.fill();
         }
 
-        if !border_brush.is_transparent() {
-            render_context_2_d.set_line_width(border_thickness.left());
-            render_context_2_d.set_stroke_style(border_brush);
-            render_context_2_d.stroke();
+        self.stroke_border(
+            render_context_2_d,
+            border_thickness.left(),
+            border_brush,
+            border_style,
+        );
+    }
+
+    // Renders rectangle with different widths or brushes of the border sides. Each side is drawn from the middle of
+    // its first corner to the middle of its last corner.
+    fn render_border_sides(
+        &self,
+        render_context_2_d: &mut PlatformBackend,
+        rect: Rectangle,
+        radius: CornerRadius,
+        brush: utils::Brush,
+        border_brushes: [Brush; 4],
+        border_thickness: Thickness,
+        border_style: BorderStyle,
+    ) {
+        let CornerRadius {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        } = radius.fit(rect.width(), rect.height());
+
+        if !brush.is_transparent() {
+            self.render_rounded_rect_path(
+                render_context_2_d,
+                rect.x(),
+                rect.y(),
+                rect.width(),
+                rect.height(),
+                radius,
+            );
+            render_context_2_d.set_fill_style(brush);
+            render_context_2_d.fill();
+        }
+
+        let (left, top) = (rect.x(), rect.y());
+        let (right, bottom) = (left + rect.width(), top + rect.height());
+
+        // corners with center, radius and the angle of their middle
+        let corners = [
+            (left + top_left, top + top_left, top_left, PI + FRAC_PI_4),
+            (right - top_right, top + top_right, top_right, -FRAC_PI_4),
+            (
+                right - bottom_right,
+                bottom - bottom_right,
+                bottom_right,
+                FRAC_PI_4,
+            ),
+            (
+                left + bottom_left,
+                bottom - bottom_left,
+                bottom_left,
+                PI - FRAC_PI_4,
+            ),
+        ];
+
+        // widths of the sides in the order left, top, right and bottom, the first corner of a side has the index of
+        // the side before it
+        let widths = [
+            border_thickness.left(),
+            border_thickness.top(),
+            border_thickness.right(),
+            border_thickness.bottom(),
+        ];
+
+        for (side, border_brush) in border_brushes.iter().enumerate() {
+            let width = widths[side];
+
+            if width <= 0.0 || border_brush.is_transparent() {
+                continue;
+            }
+
+            let (previous, next) = ((side + 3) % 4, (side + 1) % 4);
+            let (x1, y1, r1, a1) = corners[previous];
+            let (x2, y2, r2, a2) = corners[side];
+            let a2 = a2 - FRAC_PI_4;
+
+            let start = Point::new(x1 + r1 * a1.cos(), y1 + r1 * a1.sin());
+            let end = Point::new(x2 + r2 * a2.cos(), y2 + r2 * a2.sin());
+
+            // square corners are closed by extending the side to the outer edge of the neighbour sides
+            let length = (end.x() - start.x())
+                .hypot(end.y() - start.y())
+                .max(f64::EPSILON);
+            let direction = (
+                (end.x() - start.x()) / length,
+                (end.y() - start.y()) / length,
+            );
+            let start_extension = if r1 > 0.0 {
+                0.0
+            } else {
+                widths[previous] / 2.0
+            };
+            let end_extension = if r2 > 0.0 { 0.0 } else { widths[next] / 2.0 };
+
+            let mut path = Path2D::new();
+
+            if r1 > 0.0 {
+                path.move_to(start.x(), start.y());
+                path.arc(x1, y1, r1, a1, a1 + FRAC_PI_4);
+            } else {
+                path.move_to(
+                    start.x() - direction.0 * start_extension,
+                    start.y() - direction.1 * start_extension,
+                );
+            }
+
+            path.line_to(
+                end.x() + direction.0 * end_extension,
+                end.y() + direction.1 * end_extension,
+            );
+
+            if r2 > 0.0 {
+                path.arc(x2, y2, r2, a2, a2 + FRAC_PI_4);
+            }
+
+            render_context_2_d.save();
+            self.apply_border_style(render_context_2_d, width, border_style);
+            render_context_2_d.set_stroke_style(border_brush.clone());
+            render_context_2_d.stroke_path(&path);
+            render_context_2_d.restore();
+        }
+    }
+
+    // Strokes the current path with the given border width, brush and style.
+    fn stroke_border(
+        &self,
+        render_context_2_d: &mut PlatformBackend,
+        width: f64,
+        border_brush: utils::Brush,
+        border_style: BorderStyle,
+    ) {
+        if border_brush.is_transparent() {
+            return;
         }
+
+        render_context_2_d.save();
+        self.apply_border_style(render_context_2_d, width, border_style);
+        render_context_2_d.set_stroke_style(border_brush);
+        render_context_2_d.stroke();
+        render_context_2_d.restore();
+    }
+
+    fn apply_border_style(
+        &self,
+        render_context_2_d: &mut PlatformBackend,
+        width: f64,
+        border_style: BorderStyle,
+    ) {
+        render_context_2_d.set_line_width(width);
+        render_context_2_d.set_line_dash(border_style.dash_pattern(width));
+
+        // dots are drawn by the round caps of the empty dashes
+        render_context_2_d.set_line_cap(if border_style == BorderStyle::Dotted {
+            LineCap::Round
+        } else {
+            LineCap::Butt
+        });
     }
 }
 
 impl RenderObject for RectangleRenderObject {
     fn render_self(&self, ctx: &mut Context, global_position: &Point) {
-        let (bounds, background, border_radius, border_thickness, border_brush) = {
+        let (
+            bounds,
+            background,
+            border_radius,
+            border_thickness,
+            border_brush,
+            border_brushes,
+            border_style,
+        ) = {
             let widget = ctx.widget();
             (
                 widget.clone::<Rectangle>("bounds"),
                 widget.get::<Brush>("background").clone(),
-                widget.clone_or_default::<f64>("border_radius"),
+                widget.clone_or_default::<CornerRadius>("border_radius"),
                 widget.clone_or_default::<Thickness>("border_width"),
                 widget.clone_or_default::<Brush>("border_brush"),
+                widget.clone_or_default::<BorderBrushes>("border_brushes"),
+                widget.clone_or_default::<BorderStyle>("border_style"),
             )
         };
 
+        let border_brushes = border_brushes.resolve(&border_brush);
+
         if (bounds.width() == 0.0
             || bounds.height() == 0.0
-            || (background.is_transparent() && border_brush.is_transparent()))
+            || (background.is_transparent()
+                && border_brushes.iter().all(|brush| brush.is_transparent())))
             && (border_thickness.left == 0.0
                 && border_thickness.top == 0.0
                 && border_thickness.right == 0.0
@@ -159,10 +327,18 @@ impl RenderObject for RectangleRenderObject {
             || border_thickness.right > 0.0
             || border_thickness.bottom > 0.0;
 
+        // sides with different widths or brushes are drawn one by one
+        let uniform_border = border_thickness.top == border_thickness.left
+            && border_thickness.right == border_thickness.left
+            && border_thickness.bottom == border_thickness.left
+            && border_brushes.iter().all(|brush| *brush == border_brush);
+
         ctx.render_context_2_d().begin_path();
 
         if (bounds.width() - bounds.height()).abs() < f64::EPSILON
-            && border_radius >= bounds.width() / 2.0
+            && border_radius.is_uniform()
+            && border_radius.top_left() >= bounds.width() / 2.0
+            && uniform_border
         {
             if !has_thickness {
                 self.render_circle(
@@ -171,7 +347,7 @@ impl RenderObject for RectangleRenderObject {
                     global_position.y() + bounds.y(),
                     bounds.width(),
                     bounds.height(),
-                    border_radius,
+                    border_radius.top_left(),
                 );
                 ctx.render_context_2_d().set_fill_style(background);
                 ctx.render_context_2_d().fill();
@@ -182,13 +358,24 @@ impl RenderObject for RectangleRenderObject {
                     global_position.y() + bounds.y(),
                     bounds.width(),
                     bounds.height(),
-                    border_radius,
+                    border_radius.top_left(),
                     background,
                     border_brush,
                     border_thickness,
+                    border_style,
                 );
             }
-        } else if border_radius > 0. && has_thickness {
+        } else if has_thickness && !uniform_border {
+            self.render_border_sides(
+                ctx.render_context_2_d(),
+                Rectangle::new(*global_position + bounds.position(), bounds.size()),
+                border_radius,
+                background,
+                border_brushes,
+                border_thickness,
+                border_style,
+            );
+        } else if !border_radius.is_zero() && has_thickness {
             self.render_rounded_bordered_rect_path(
                 ctx.render_context_2_d(),
                 Rectangle::new(*global_position + bounds.position(), bounds.size()),
@@ -196,8 +383,9 @@ impl RenderObject for RectangleRenderObject {
                 background,
                 border_brush,
                 border_thickness,
+                border_style,
             );
-        } else if border_radius > 0. {
+        } else if !border_radius.is_zero() {
             self.render_rounded_rect_path(
                 ctx.render_context_2_d(),
                 global_position.x() + bounds.x(),
@@ -216,6 +404,7 @@ impl RenderObject for RectangleRenderObject {
                 background,
                 border_brush,
                 border_thickness,
+                border_style,
             );
         } else {
             ctx.render_context_2_d().set_fill_style(background);
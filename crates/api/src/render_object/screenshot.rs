@@ -0,0 +1,211 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    rc::Rc,
+};
+
+use dces::prelude::*;
+
+use crate::{
+    application::ContextProvider,
+    render::{DisplayList, DisplayListRecorder, Image, PlatformBackend, RenderBackend, Svg},
+    theming::*,
+    tree::*,
+    utils::*,
+    widget_base::get_all_children,
+};
+
+use super::{render_bounds, set_transform, widget_transform};
+
+/// Renders the given widget with its children into an image. The image covers the bounds of the widget including
+/// its outer shadows and render transform, the transforms of its ancestors are not applied. Its size is the size
+/// of the widget in logical units multiplied by `scale`.
+///
+/// Returns `None` if the widget has no bounds, is empty or `scale` is not positive.
+///
+/// The widgets are rendered with their own render pass state, the global position and transform of the rendered
+/// widgets are restored afterwards. So it could be called between two render passes of the window, e.g. from the
+/// `update` of a state.
+///
+/// Not available on the web, the web render context draws only to the canvas of the browser window.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn render_to_image(
+    entity: Entity,
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    context_provider: &ContextProvider,
+    theme: &Theme,
+    scale: f64,
+) -> Option<Image> {
+    if scale <= 0.0 || !scale.is_finite() {
+        return None;
+    }
+
+    render_widget(
+        entity,
+        ecm,
+        context_provider,
+        theme,
+        scale,
+        crate::render::RenderContext2D::new,
+        |mut render_context| render_context.to_image(),
+    )
+}
+
+/// Records the drawing calls of the given widget with its children into a display list, e.g. to export it as SVG
+/// or PDF document. The display list covers the same area as the image of `render_to_image` in logical units.
+///
+/// Returns `None` if the widget has no bounds or is empty.
+pub fn record_display_list(
+    entity: Entity,
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    context_provider: &ContextProvider,
+    theme: &Theme,
+) -> Option<DisplayList> {
+    render_widget(
+        entity,
+        ecm,
+        context_provider,
+        theme,
+        1.0,
+        DisplayListRecorder::new,
+        DisplayListRecorder::into_display_list,
+    )
+}
+
+// Renders the widget with its children into a new render backend, that is created by `create` with the size in
+// physical pixels, and calls `f` with the render backend afterwards.
+fn render_widget<B, T>(
+    entity: Entity,
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    context_provider: &ContextProvider,
+    theme: &Theme,
+    scale: f64,
+    create: impl FnOnce(f64, f64) -> B,
+    f: impl FnOnce(B) -> T,
+) -> Option<T>
+where
+    B: RenderBackend<Image = Image, Svg = Svg> + 'static,
+{
+    let position = parent_position(ecm, entity);
+    let rect = render_bounds(
+        entity,
+        ecm,
+        &position,
+        &widget_transform(entity, ecm, &position, &Transform::default()),
+        scale,
+    )?;
+
+    if rect.width() <= 0.0 || rect.height() <= 0.0 {
+        return None;
+    }
+
+    let mut backend = create(
+        (rect.width() * scale).ceil(),
+        (rect.height() * scale).ceil(),
+    );
+    let render_context: &mut PlatformBackend = &mut backend;
+
+    for (key, font) in theme.fonts() {
+        render_context.register_font(key, *font);
+    }
+
+    context_provider
+        .fonts
+        .register_at(render_context, &Cell::new(0));
+
+    render_context.set_font_fallbacks(theme.font_fallbacks().to_vec());
+    render_context.set_scale_factor(scale);
+
+    // the render pass stores the global position and transform of each rendered widget
+    let mut entities = vec![entity];
+    get_all_children(&mut entities, entity, ecm.entity_store());
+
+    let components: Vec<(Entity, Option<Point>, Option<Transform>)> = entities
+        .into_iter()
+        .map(|entity| {
+            let store = ecm.component_store();
+            (
+                entity,
+                store.get::<Point>("position", entity).ok().copied(),
+                store
+                    .get::<Transform>("global_transform", entity)
+                    .ok()
+                    .copied(),
+            )
+        })
+        .collect();
+
+    // the widget is moved to the origin of the image
+    let transform = Transform::translation(-rect.x(), -rect.y());
+
+    // the states are borrowed while a state is updated
+    let provider = ContextProvider {
+        states: Rc::new(RefCell::new(BTreeMap::new())),
+        render_bounds: Rc::new(RefCell::new(BTreeMap::new())),
+        render_region: Rc::new(Cell::new(None)),
+        render_transform: Rc::new(Cell::new(transform)),
+        ..context_provider.clone()
+    };
+
+    let mut offsets = BTreeMap::new();
+
+    if let Some(parent) = ecm.entity_store().parent[&entity] {
+        offsets.insert(parent, (position.x(), position.y()));
+    }
+
+    render_context.start();
+    set_transform(render_context, &transform);
+    render_context.begin_path();
+
+    if let Some(render_object) = context_provider.render_objects.borrow().get(&entity) {
+        render_object.render(
+            render_context,
+            entity,
+            ecm,
+            &provider,
+            theme,
+            &mut offsets,
+            false,
+        );
+    }
+
+    render_context.finish();
+    let result = f(backend);
+
+    for (entity, position, global_transform) in components {
+        let store = ecm.component_store_mut();
+
+        if let (Some(position), Ok(value)) = (position, store.get_mut::<Point>("position", entity))
+        {
+            *value = position;
+        }
+
+        if let (Some(global_transform), Ok(value)) = (
+            global_transform,
+            store.get_mut::<Transform>("global_transform", entity),
+        ) {
+            *value = global_transform;
+        }
+    }
+
+    Some(result)
+}
+
+// Gets the global position of the parent of the given widget without the transforms of its ancestors.
+fn parent_position(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> Point {
+    let mut position = Point::default();
+    let mut parent = ecm.entity_store().parent[&entity];
+
+    while let Some(entity) = parent {
+        if let Ok(bounds) = ecm.component_store().get::<Rectangle>("bounds", entity) {
+            position = Point::new(position.x() + bounds.x(), position.y() + bounds.y());
+        }
+
+        parent = ecm.entity_store().parent[&entity];
+    }
+
+    position
+}
@@ -0,0 +1,51 @@
+use crate::{proc_macros::IntoRenderObject, render::Svg, render_object::*};
+
+/// Used to render a SVG document. The document is fitted into the bounds of the widget. If the `foreground` is a
+/// solid color, the document is filled with it.
+#[derive(Debug, IntoRenderObject)]
+pub struct SvgRenderObject;
+
+impl RenderObject for SvgRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let (bounds, svg, foreground) = {
+            let widget = ctx.widget();
+            (
+                widget.clone::<Rectangle>("bounds"),
+                widget.try_clone::<Svg>("svg"),
+                widget.try_clone::<Brush>("foreground"),
+            )
+        };
+
+        let svg = match svg {
+            Some(svg) if !svg.is_empty() => svg,
+            _ => return,
+        };
+
+        let color = match foreground {
+            Some(Brush::SolidColor(color)) => Some(color),
+            _ => None,
+        };
+
+        if let Some((_, rect)) = Stretch::Uniform.fit(
+            Size::new(svg.width(), svg.height()),
+            Rectangle::new(
+                (
+                    global_position.x() + bounds.x(),
+                    global_position.y() + bounds.y(),
+                ),
+                bounds.size(),
+            ),
+            Alignment::Center,
+            Alignment::Center,
+        ) {
+            ctx.render_context_2_d().draw_svg(
+                &svg,
+                rect.x(),
+                rect.y(),
+                rect.width(),
+                rect.height(),
+                color,
+            );
+        }
+    }
+}
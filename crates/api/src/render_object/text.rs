@@ -1,10 +1,9 @@
 use crate::{
     proc_macros::IntoRenderObject,
+    render::PlatformBackend,
     render_object::*,
-    utils::{Brush, Point, Rectangle},
+    utils::{Brush, Point, Rectangle, RichText, Size, TextAlignment, TextTrimming, TextWrap},
 };
-use memchr::memchr_iter;
-use std::iter;
 
 /// Used to render a text.
 #[derive(Debug, IntoRenderObject)]
@@ -12,6 +11,15 @@ pub struct TextRenderObject;
 
 impl RenderObject for TextRenderObject {
     fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        if let Some(rich_text) = ctx
+            .widget()
+            .try_clone::<RichText>("rich_text")
+            .filter(|rich_text| !rich_text.is_empty())
+        {
+            render_rich_text(ctx, &rich_text, global_position);
+            return;
+        }
+
         let (bounds, text, foreground, font, font_size, offset) = {
             let widget = ctx.widget();
             let text = text(&widget);
@@ -46,21 +54,20 @@ impl RenderObject for TextRenderObject {
             return;
         }
 
+        let options = TextLayoutOptions::from_widget(&ctx.widget(), bounds.width());
+        let layout = TextLayout::new(ctx.render_context_2_d(), &text, &font, font_size, options);
+
         ctx.render_context_2_d().begin_path();
-        ctx.render_context_2_d().set_font_family(font);
+        ctx.render_context_2_d().set_font_family(&font);
         ctx.render_context_2_d().set_font_size(font_size);
         ctx.render_context_2_d().set_fill_style(foreground);
 
-        let mut y_disp = 0.0;
-        let mut last_ofs = 0;
-        for i in memchr_iter(b'\n', text.as_bytes()).chain(iter::once(text.len())) {
+        for line in layout.lines() {
             ctx.render_context_2_d().fill_text(
-                &text[last_ofs..i],
-                global_position.x() + bounds.x() + offset,
-                global_position.y() + bounds.y() + y_disp,
+                &line.text,
+                global_position.x() + bounds.x() + offset + line.x,
+                global_position.y() + bounds.y() + line.y,
             );
-            y_disp += font_size * 1.15; // TODO: Make the space between lines customizable
-            last_ofs = i + 1; // + 1 to skip the end of line character
         }
 
         ctx.render_context_2_d().close_path();
@@ -84,3 +91,634 @@ fn text(widget: &WidgetContainer) -> String {
 
     String::default()
 }
+
+/// Describes how a `TextLayout` breaks, trims and aligns the lines of a text.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TextLayoutOptions {
+    /// The available width. Lines are wrapped or trimmed if they exceed it.
+    pub max_width: f64,
+
+    /// Describes how lines are wrapped.
+    pub wrap: TextWrap,
+
+    /// The distance between the tops of two lines. If it is `0.0` the distance is derived from the font size.
+    pub line_height: f64,
+
+    /// The maximum number of lines. If it is `0` the number of lines is not limited.
+    pub max_lines: usize,
+
+    /// Describes how lines are trimmed.
+    pub trimming: TextTrimming,
+
+    /// The horizontal alignment of the lines inside of the available width.
+    pub alignment: TextAlignment,
+}
+
+impl Default for TextLayoutOptions {
+    fn default() -> Self {
+        TextLayoutOptions {
+            max_width: f64::MAX,
+            wrap: TextWrap::None,
+            line_height: 0.0,
+            max_lines: 0,
+            trimming: TextTrimming::None,
+            alignment: TextAlignment::Left,
+        }
+    }
+}
+
+impl TextLayoutOptions {
+    /// Reads the options from the `text_wrap`, `line_height`, `max_lines`, `text_trimming` and `text_align`
+    /// properties of the widget. Properties the widget does not have are set to their defaults.
+    pub fn from_widget(widget: &WidgetContainer, max_width: f64) -> Self {
+        TextLayoutOptions {
+            max_width,
+            wrap: widget.try_clone("text_wrap").unwrap_or_default(),
+            line_height: widget.try_clone("line_height").unwrap_or_default(),
+            max_lines: widget.try_clone("max_lines").unwrap_or_default(),
+            trimming: widget.try_clone("text_trimming").unwrap_or_default(),
+            alignment: widget.try_clone("text_align").unwrap_or_default(),
+        }
+    }
+
+    /// Returns `true` if the size of the text depends on the available width.
+    pub fn depends_on_width(&self) -> bool {
+        self.wrap != TextWrap::None || self.trimming != TextTrimming::None
+    }
+}
+
+/// A line of a `TextLayout`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextLine {
+    /// The text of the line.
+    pub text: String,
+
+    /// Horizontal position of the line relative to the text origin.
+    pub x: f64,
+
+    /// Vertical position of the line relative to the text origin.
+    pub y: f64,
+
+    /// Width of the line.
+    pub width: f64,
+}
+
+/// Lays out a plain text in lines. The text is broken on explicit newlines and wrapped, trimmed and aligned
+/// as described by its `TextLayoutOptions`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextLayout {
+    lines: Vec<TextLine>,
+    size: Size,
+}
+
+impl TextLayout {
+    /// Lays out the text with the given font and font size.
+    pub fn new(
+        render_context_2_d: &mut PlatformBackend,
+        text: &str,
+        font: &str,
+        font_size: f64,
+        options: TextLayoutOptions,
+    ) -> Self {
+        let mut measure = |text: &str| render_context_2_d.measure(text, font_size, font).width;
+
+        let mut lines = vec![];
+        let mut trimmed = false;
+
+        for paragraph in text.split('\n') {
+            lines.append(&mut break_lines(
+                paragraph,
+                options.max_width,
+                options.wrap,
+                &mut measure,
+            ));
+
+            if options.max_lines > 0 && lines.len() > options.max_lines {
+                lines.truncate(options.max_lines);
+                trimmed = true;
+                break;
+            }
+        }
+
+        let last = lines.len().saturating_sub(1);
+        let advance = if options.line_height > 0.0 {
+            options.line_height
+        } else {
+            font_size * 1.15
+        };
+
+        let mut layout = TextLayout::default();
+
+        for (index, text) in lines.into_iter().enumerate() {
+            let text = if options.trimming == TextTrimming::Ellipsis {
+                trim_line(
+                    &text,
+                    options.max_width,
+                    trimmed && index == last,
+                    &mut measure,
+                )
+            } else {
+                text
+            };
+
+            let width = measure(&text);
+            layout.size.set_width(layout.size.width().max(width));
+            layout.lines.push(TextLine {
+                text,
+                x: 0.0,
+                y: index as f64 * advance,
+                width,
+            });
+        }
+
+        // a text without lines has the height of an empty line
+        layout
+            .size
+            .set_height(last as f64 * advance + font_size.ceil());
+
+        let available_width = if options.max_width < f64::MAX {
+            options.max_width
+        } else {
+            layout.size.width()
+        };
+
+        for line in &mut layout.lines {
+            line.x = match options.alignment {
+                TextAlignment::Left | TextAlignment::Start => 0.0,
+                TextAlignment::Right | TextAlignment::End => available_width - line.width,
+                TextAlignment::Center => (available_width - line.width) / 2.0,
+            };
+        }
+
+        layout
+    }
+
+    /// Gets the lines of the layout.
+    pub fn lines(&self) -> &[TextLine] {
+        &self.lines
+    }
+
+    /// Gets the size of the laid out text.
+    pub fn size(&self) -> Size {
+        self.size
+    }
+}
+
+// Breaks a paragraph (text without newlines) in lines that fit in the max width. Each word (or char) is measured once
+// and the widths are summed up to the width of the line.
+fn break_lines<F>(paragraph: &str, max_width: f64, wrap: TextWrap, measure: &mut F) -> Vec<String>
+where
+    F: FnMut(&str) -> f64,
+{
+    if wrap == TextWrap::None || max_width >= f64::MAX || measure(paragraph) <= max_width {
+        return vec![paragraph.to_string()];
+    }
+
+    let mut lines = vec![];
+    let mut line = String::new();
+    let mut line_width = 0.0;
+
+    let pieces: Vec<&str> = match wrap {
+        TextWrap::Word => text_pieces(paragraph),
+        _ => paragraph
+            .char_indices()
+            .map(|(i, c)| &paragraph[i..i + c.len_utf8()])
+            .collect(),
+    };
+
+    for piece in pieces {
+        let width = measure(piece);
+        let trimmed = piece.trim_end();
+
+        // trailing white space could exceed the line
+        let trimmed_width = if trimmed.len() < piece.len() {
+            measure(trimmed)
+        } else {
+            width
+        };
+
+        if line_width + trimmed_width <= max_width {
+            line.push_str(piece);
+            line_width += width;
+            continue;
+        }
+
+        if !line.is_empty() {
+            lines.push(line.trim_end().to_string());
+            line = String::new();
+            line_width = 0.0;
+        }
+
+        // words that are wider than the line are broken on char boundaries
+        if trimmed_width > max_width {
+            for c in piece.chars() {
+                let char_width = measure(c.encode_utf8(&mut [0; 4]));
+
+                if !line.is_empty() && line_width + char_width > max_width && !c.is_whitespace() {
+                    lines.push(std::mem::take(&mut line));
+                    line_width = 0.0;
+                }
+
+                line.push(c);
+                line_width += char_width;
+            }
+        } else {
+            line.push_str(piece);
+            line_width = width;
+        }
+    }
+
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line.trim_end().to_string());
+    }
+
+    lines
+}
+
+// Trims the line with an ellipsis if it exceeds the max width. If force is `true` the ellipsis is appended in any case.
+fn trim_line<F>(line: &str, max_width: f64, force: bool, measure: &mut F) -> String
+where
+    F: FnMut(&str) -> f64,
+{
+    if !force && measure(line) <= max_width {
+        return line.to_string();
+    }
+
+    let mut text = line.trim_end().to_string();
+
+    loop {
+        let candidate = format!("{}…", text);
+
+        if text.is_empty() || measure(&candidate) <= max_width {
+            return candidate;
+        }
+
+        text.pop();
+        text = text.trim_end().to_string();
+    }
+}
+
+// Trims the runs of a line with an ellipsis if they exceed the max width. Runs that start behind the max width are
+// removed. If force is `true` the ellipsis is appended in any case.
+fn trim_runs(
+    render_context_2_d: &mut PlatformBackend,
+    runs: &mut Vec<TextRun>,
+    max_width: f64,
+    force: bool,
+) {
+    runs.retain(|run| run.bounds.x() < max_width);
+
+    let run = match runs.last_mut() {
+        Some(run) => run,
+        None => return,
+    };
+
+    let (font, font_size) = (run.font.clone(), run.font_size);
+    let mut measure = |text: &str| render_context_2_d.measure(text, font_size, &font).width;
+
+    run.text = trim_line(&run.text, max_width - run.bounds.x(), force, &mut measure);
+    run.bounds.set_width(measure(&run.text));
+}
+
+/// A run of a `RichText` span on one line of a `RichTextLayout`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextRun {
+    /// Index of the span of the run.
+    pub span: usize,
+
+    /// The text of the run.
+    pub text: String,
+
+    /// The resolved font family of the run.
+    pub font: String,
+
+    /// The font size of the run.
+    pub font_size: f64,
+
+    /// The bounds of the run relative to the text origin.
+    pub bounds: Rectangle,
+}
+
+/// Lays out the spans of a `RichText` in lines. Spans are wrapped on word boundaries if a line exceeds the
+/// maximum width and on explicit newlines. The runs of a line share its baseline. The line height, max lines,
+/// trimming and alignment of the `TextLayoutOptions` are applied like to a plain text.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RichTextLayout {
+    runs: Vec<TextRun>,
+    size: Size,
+}
+
+impl RichTextLayout {
+    /// Lays out the rich text. Spans without font or font size use the given font and font size.
+    pub fn new(
+        render_context_2_d: &mut PlatformBackend,
+        rich_text: &RichText,
+        font: &str,
+        font_size: f64,
+        options: TextLayoutOptions,
+    ) -> Self {
+        let max_width = options.max_width;
+        let mut lines: Vec<(Vec<TextRun>, f64)> = vec![(vec![], font_size)];
+        let mut x = 0.0;
+
+        for (index, span) in rich_text.spans().iter().enumerate() {
+            let family = span.available_family(font, |family| render_context_2_d.has_font(family));
+            let size = span.font_size.unwrap_or(font_size);
+
+            for piece in text_pieces(&span.text) {
+                if piece == "\n" {
+                    lines.push((vec![], size));
+                    x = 0.0;
+                    continue;
+                }
+
+                let width = render_context_2_d.measure(piece, size, &family).width;
+
+                // trailing white space could exceed the line
+                if x > 0.0
+                    && x + width > max_width
+                    && x + render_context_2_d
+                        .measure(piece.trim_end(), size, &family)
+                        .width
+                        > max_width + 0.01
+                {
+                    lines.push((vec![], size));
+                    x = 0.0;
+                }
+
+                let line = lines.last_mut().unwrap();
+                line.1 = if line.0.is_empty() {
+                    size
+                } else {
+                    line.1.max(size)
+                };
+
+                match line.0.last_mut() {
+                    Some(run) if run.span == index => {
+                        run.text.push_str(piece);
+                        run.bounds.set_width(run.bounds.width() + width);
+                    }
+                    _ => line.0.push(TextRun {
+                        span: index,
+                        text: piece.to_string(),
+                        font: family.clone(),
+                        font_size: size,
+                        bounds: Rectangle::new((x, 0.0), (width, size)),
+                    }),
+                }
+
+                x += width;
+            }
+        }
+
+        let trimmed = options.max_lines > 0 && lines.len() > options.max_lines;
+        if trimmed {
+            lines.truncate(options.max_lines);
+        }
+
+        let last = lines.len() - 1;
+        let mut aligned_lines = Vec::with_capacity(lines.len());
+        let mut layout = RichTextLayout::default();
+        let mut y = 0.0;
+        let mut height = 0.0;
+
+        for (index, (mut runs, line_height)) in lines.into_iter().enumerate() {
+            if options.trimming == TextTrimming::Ellipsis {
+                trim_runs(
+                    render_context_2_d,
+                    &mut runs,
+                    max_width,
+                    trimmed && index == last,
+                );
+            }
+
+            let mut line_width: f64 = 0.0;
+
+            for run in &mut runs {
+                // the runs are aligned at the bottom of the line
+                run.bounds.set_y(y + line_height - run.font_size);
+                line_width = line_width.max(run.bounds.x() + run.bounds.width());
+            }
+
+            layout.size.set_width(layout.size.width().max(line_width));
+            aligned_lines.push((runs, line_width));
+
+            height = y + line_height;
+            y += if options.line_height > 0.0 {
+                options.line_height
+            } else {
+                line_height * 1.15
+            };
+        }
+
+        layout.size.set_height(height);
+
+        let available_width = if max_width < f64::MAX {
+            max_width
+        } else {
+            layout.size.width()
+        };
+
+        for (runs, line_width) in aligned_lines {
+            let offset = match options.alignment {
+                TextAlignment::Left | TextAlignment::Start => 0.0,
+                TextAlignment::Right | TextAlignment::End => available_width - line_width,
+                TextAlignment::Center => (available_width - line_width) / 2.0,
+            };
+
+            for mut run in runs {
+                run.bounds.set_x(run.bounds.x() + offset);
+                layout.runs.push(run);
+            }
+        }
+
+        layout
+    }
+
+    /// Gets the runs of the layout.
+    pub fn runs(&self) -> &[TextRun] {
+        &self.runs
+    }
+
+    /// Gets the size of the laid out text.
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Gets the index of the span at the given position relative to the text origin.
+    pub fn span_at(&self, position: Point) -> Option<usize> {
+        self.runs
+            .iter()
+            .find(|run| run.bounds.contains(position))
+            .map(|run| run.span)
+    }
+}
+
+// Splits the text in words with their trailing white space and newlines.
+fn text_pieces(text: &str) -> Vec<&str> {
+    let mut pieces = vec![];
+    let mut start = 0;
+    let mut white_space = false;
+
+    for (i, c) in text.char_indices() {
+        if c == '\n' {
+            if start < i {
+                pieces.push(&text[start..i]);
+            }
+            pieces.push(&text[i..i + 1]);
+            start = i + 1;
+            white_space = false;
+            continue;
+        }
+
+        if c.is_whitespace() {
+            white_space = true;
+        } else if white_space {
+            pieces.push(&text[start..i]);
+            start = i;
+            white_space = false;
+        }
+    }
+
+    if start < text.len() {
+        pieces.push(&text[start..]);
+    }
+
+    pieces
+}
+
+fn render_rich_text(ctx: &mut Context, rich_text: &RichText, global_position: &Point) {
+    let (bounds, foreground, font, font_size, offset) = {
+        let widget = ctx.widget();
+        (
+            *widget.get::<Rectangle>("bounds"),
+            widget.get::<Brush>("foreground").clone(),
+            widget.get::<String>("font").clone(),
+            *widget.get::<f64>("font_size"),
+            *widget.get::<f64>("offset"),
+        )
+    };
+
+    if bounds.width() == 0.0 || bounds.height() == 0.0 {
+        return;
+    }
+
+    let x = global_position.x() + bounds.x() + offset;
+    let y = global_position.y() + bounds.y();
+
+    let options = TextLayoutOptions::from_widget(&ctx.widget(), bounds.width());
+    let render_context = ctx.render_context_2_d();
+    let layout = RichTextLayout::new(render_context, rich_text, &font, font_size, options);
+
+    for run in layout.runs() {
+        let span = &rich_text.spans()[run.span];
+        let run_x = x + run.bounds.x();
+        let run_y = y + run.bounds.y();
+
+        if let Some(background) = &span.background {
+            render_context.set_fill_style(background.clone());
+            render_context.fill_rect(run_x, run_y, run.bounds.width(), run.bounds.height());
+        }
+
+        let foreground = span
+            .foreground
+            .clone()
+            .unwrap_or_else(|| foreground.clone());
+
+        if foreground.is_transparent() {
+            continue;
+        }
+
+        render_context.begin_path();
+        render_context.set_font_family(run.font.as_str());
+        render_context.set_font_size(run.font_size);
+        render_context.set_fill_style(foreground);
+        render_context.fill_text(&run.text, run_x, run_y);
+        render_context.close_path();
+
+        let thickness = (run.font_size / 14.0).max(1.0);
+        let width = render_context
+            .measure(run.text.trim_end(), run.font_size, run.font.as_str())
+            .width;
+
+        if span.underline {
+            render_context.fill_rect(run_x, run_y + run.font_size * 0.9, width, thickness);
+        }
+
+        if span.strikethrough {
+            render_context.fill_rect(run_x, run_y + run.font_size * 0.55, width, thickness);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // every char is 10 pixels wide
+    fn measure(text: &str) -> f64 {
+        text.chars().count() as f64 * 10.0
+    }
+
+    #[test]
+    fn test_break_lines() {
+        assert_eq!(
+            break_lines("hello world", 200.0, TextWrap::Word, &mut measure),
+            vec!["hello world"]
+        );
+        assert_eq!(
+            break_lines("hello world", 60.0, TextWrap::None, &mut measure),
+            vec!["hello world"]
+        );
+        assert_eq!(
+            break_lines("hello world again", 120.0, TextWrap::Word, &mut measure),
+            vec!["hello world", "again"]
+        );
+        assert_eq!(
+            break_lines("hello wonderful", 60.0, TextWrap::Word, &mut measure),
+            vec!["hello", "wonder", "ful"]
+        );
+        assert_eq!(
+            break_lines("hello world", 40.0, TextWrap::Character, &mut measure),
+            vec!["hell", "o wo", "rld"]
+        );
+    }
+
+    #[test]
+    fn test_break_lines_measures_words_once() {
+        let paragraph = "word ".repeat(100);
+        let mut calls = 0;
+        let mut counting_measure = |text: &str| {
+            calls += 1;
+            measure(text)
+        };
+
+        let lines = break_lines(&paragraph, 100.0, TextWrap::Word, &mut counting_measure);
+
+        assert_eq!(lines.len(), 50);
+        assert_eq!(lines[0], "word word");
+        // the paragraph and each word with and without its trailing white space
+        assert_eq!(calls, 201);
+    }
+
+    #[test]
+    fn test_trim_line() {
+        assert_eq!(trim_line("hello", 50.0, false, &mut measure), "hello");
+        assert_eq!(trim_line("hello world", 50.0, false, &mut measure), "hell…");
+        assert_eq!(
+            trim_line("hello world", 70.0, false, &mut measure),
+            "hello…"
+        );
+        assert_eq!(trim_line("hello", 60.0, true, &mut measure), "hello…");
+    }
+
+    #[test]
+    fn test_text_pieces() {
+        assert_eq!(
+            text_pieces("Hello  wide\nworld "),
+            vec!["Hello  ", "wide", "\n", "world "]
+        );
+        assert_eq!(text_pieces(" a"), vec![" ", "a"]);
+        assert!(text_pieces("").is_empty());
+    }
+}
@@ -1,20 +1,158 @@
-/// Clipboard leads you read and store a value.
-///
-/// To access the value of systems clipboard it must be used in combination with a window shell.
+use std::sync::{Arc, Mutex};
+
+use ron::{de::from_str, ser::to_string};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::render::Image;
+
+/// MIME type of plain text.
+pub const MIME_TEXT_PLAIN: &str = "text/plain";
+
+/// MIME type of html text.
+pub const MIME_TEXT_HTML: &str = "text/html";
+
+/// MIME type of an image.
+pub const MIME_IMAGE: &str = "image/x-orbtk-image";
+
+#[derive(Debug)]
+pub enum ClipboardError {
+    Serialized(String),
+    Deserialized(String),
+}
+
+pub type ClipboardResult<T> = Result<T, ClipboardError>;
+
+/// Represents one format of the clipboard content.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClipboardValue {
+    /// Plain text.
+    Text(String),
+
+    /// Html text.
+    Html(String),
+
+    /// An image.
+    Image(Image),
+
+    /// App defined data in the `ron` format with its MIME type.
+    Data { mime: String, value: String },
+}
+
+impl ClipboardValue {
+    /// Gets the MIME type of the value.
+    pub fn mime(&self) -> &str {
+        match self {
+            ClipboardValue::Text(_) => MIME_TEXT_PLAIN,
+            ClipboardValue::Html(_) => MIME_TEXT_HTML,
+            ClipboardValue::Image(_) => MIME_IMAGE,
+            ClipboardValue::Data { mime, .. } => mime.as_str(),
+        }
+    }
+}
+
+/// Describes the content of the clipboard. The content could contain multiple representations (formats) of the same
+/// data, e.g. a html text with a plain text alternative. Each format is identified by its MIME type.
 ///
 /// # Examples
 /// ```
+/// let content = ClipboardContent::new()
+///     .text("Hello")
+///     .html("<b>Hello</b>");
+/// ```
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct ClipboardContent {
+    values: Vec<ClipboardValue>,
+}
+
+impl ClipboardContent {
+    /// Creates a new empty clipboard content.
+    pub fn new() -> Self {
+        ClipboardContent::default()
+    }
+
+    /// Inserts a plain text representation.
+    pub fn text(self, text: impl Into<String>) -> Self {
+        self.value(ClipboardValue::Text(text.into()))
+    }
+
+    /// Inserts a html representation.
+    pub fn html(self, html: impl Into<String>) -> Self {
+        self.value(ClipboardValue::Html(html.into()))
+    }
+
+    /// Inserts an image representation.
+    pub fn image(self, image: Image) -> Self {
+        self.value(ClipboardValue::Image(image))
+    }
+
+    /// Inserts app defined data with the given MIME type. The data is serialized in the `ron` format.
+    pub fn data<S: Serialize>(self, mime: impl Into<String>, data: &S) -> ClipboardResult<Self> {
+        let value = to_string(data).map_err(|e| ClipboardError::Serialized(e.to_string()))?;
+
+        Ok(self.value(ClipboardValue::Data {
+            mime: mime.into(),
+            value,
+        }))
+    }
+
+    /// Inserts a value. An existing value with the same MIME type is replaced.
+    pub fn value(mut self, value: ClipboardValue) -> Self {
+        self.values.retain(|v| v.mime() != value.mime());
+        self.values.push(value);
+        self
+    }
+
+    /// Gets the value with the given MIME type.
+    pub fn get(&self, mime: &str) -> Option<&ClipboardValue> {
+        self.values.iter().find(|v| v.mime() == mime)
+    }
+
+    /// Gets the MIME types of all available formats.
+    pub fn formats(&self) -> Vec<String> {
+        self.values.iter().map(|v| v.mime().to_string()).collect()
+    }
+
+    /// Returns `true` if the content contains no format.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// Clipboard leads you read and store a value. The clipboard could hold multiple formats of its content
+/// (plain text, html, image and app defined data).
+///
+/// To access the value of systems clipboard it must be used in combination with a window shell. Only the
+/// plain text format is exchanged with the system clipboard, none of the backends maps html, images or app defined
+/// data yet. These formats stay inside of the application and if the content has no plain text format, the system
+/// clipboard keeps its value. If a backend has no access to a system clipboard, the clipboard works only inside of
+/// the application.
+///
+/// Clones of a clipboard share the same content, the application registers one clipboard for all of its windows.
+///
+/// # Examples
+/// ```text
 /// impl State for MyState {
 ///     fn update(&mut self, registry: &mut Registry, _: &mut Context) {
-///         let mut clipboard = registry.get_mut;:<Clipboard>("clipboard");
+///         let mut clipboard = registry.get_mut::<Clipboard>("clipboard");
 ///         println!("{:?}", clipboard.get());
 ///         clipboard.set("paste");
+///
+///         if clipboard.has_format(MIME_TEXT_HTML) {
+///             println!("{:?}", clipboard.get_html());
+///         }
 ///     }
 /// }
 /// ```
 #[derive(Clone, Default, Debug)]
 pub struct Clipboard {
-    value: Option<String>,
+    inner: Arc<Mutex<ClipboardInner>>,
+}
+
+#[derive(Default, Debug)]
+struct ClipboardInner {
+    content: ClipboardContent,
+    revision: u64,
 }
 
 impl Clipboard {
@@ -23,15 +161,101 @@ impl Clipboard {
         Clipboard::default()
     }
 
-    /// Return the latest value of the clipboard.
+    fn value<T>(&self, mime: &str, f: impl FnOnce(&ClipboardValue) -> Option<T>) -> Option<T> {
+        self.inner.lock().unwrap().content.get(mime).and_then(f)
+    }
+
+    /// Return the latest plain text value of the clipboard.
     /// If there is no value present on the clipboard it will return `None`.
     pub fn get(&self) -> Option<String> {
-        self.value.clone()
+        self.value(MIME_TEXT_PLAIN, |value| match value {
+            ClipboardValue::Text(text) => Some(text.clone()),
+            _ => None,
+        })
     }
 
-    /// Sets the value of the clipboard.
+    /// Sets the plain text value of the clipboard. Replaces the complete content of the clipboard.
     pub fn set(&mut self, value: impl Into<String>) {
-        self.value = Some(value.into());
+        self.set_content(ClipboardContent::new().text(value));
+    }
+
+    /// Return the html value of the clipboard.
+    pub fn get_html(&self) -> Option<String> {
+        self.value(MIME_TEXT_HTML, |value| match value {
+            ClipboardValue::Html(html) => Some(html.clone()),
+            _ => None,
+        })
+    }
+
+    /// Sets the html value of the clipboard. Replaces the complete content of the clipboard.
+    pub fn set_html(&mut self, value: impl Into<String>) {
+        self.set_content(ClipboardContent::new().html(value));
+    }
+
+    /// Return the image of the clipboard.
+    pub fn get_image(&self) -> Option<Image> {
+        self.value(MIME_IMAGE, |value| match value {
+            ClipboardValue::Image(image) => Some(image.clone()),
+            _ => None,
+        })
+    }
+
+    /// Sets an image as value of the clipboard. Replaces the complete content of the clipboard.
+    pub fn set_image(&mut self, image: Image) {
+        self.set_content(ClipboardContent::new().image(image));
+    }
+
+    /// Deserializes the app defined data with the given MIME type. Returns `None` if the format is not available.
+    pub fn get_data<D: DeserializeOwned>(&self, mime: &str) -> Option<ClipboardResult<D>> {
+        self.value(mime, |value| match value {
+            ClipboardValue::Data { value, .. } => Some(
+                from_str(value.as_str()).map_err(|e| ClipboardError::Deserialized(e.to_string())),
+            ),
+            _ => None,
+        })
+    }
+
+    /// Serializes the given data with its MIME type as value of the clipboard. Replaces the complete content of the clipboard.
+    pub fn set_data<S: Serialize>(
+        &mut self,
+        mime: impl Into<String>,
+        data: &S,
+    ) -> ClipboardResult<()> {
+        let content = ClipboardContent::new().data(mime, data)?;
+        self.set_content(content);
+        Ok(())
+    }
+
+    /// Gets the complete content of the clipboard with all its formats.
+    pub fn content(&self) -> ClipboardContent {
+        self.inner.lock().unwrap().content.clone()
+    }
+
+    /// Sets the content of the clipboard with all its formats.
+    pub fn set_content(&mut self, content: ClipboardContent) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.content = content;
+        inner.revision += 1;
+    }
+
+    /// Gets the MIME types of all formats that are available on the clipboard.
+    pub fn formats(&self) -> Vec<String> {
+        self.inner.lock().unwrap().content.formats()
+    }
+
+    /// Returns `true` if the given format is available on the clipboard.
+    pub fn has_format(&self, mime: &str) -> bool {
+        self.inner.lock().unwrap().content.get(mime).is_some()
+    }
+
+    /// Removes the content of the clipboard.
+    pub fn clear(&mut self) {
+        self.set_content(ClipboardContent::new());
+    }
+
+    /// Gets the revision of the clipboard content. It is increased on each change of the content.
+    pub fn revision(&self) -> u64 {
+        self.inner.lock().unwrap().revision
     }
 }
 
@@ -47,4 +271,70 @@ mod tests {
         clipboard.set(test.clone());
         assert_eq!(test, clipboard.get().unwrap());
     }
+
+    #[test]
+    fn test_content() {
+        let mut clipboard = Clipboard::new();
+        clipboard.set_content(
+            ClipboardContent::new()
+                .text("test")
+                .html("<b>test</b>")
+                .text("replaced"),
+        );
+
+        assert_eq!(clipboard.formats(), vec![MIME_TEXT_HTML, MIME_TEXT_PLAIN]);
+        assert!(clipboard.has_format(MIME_TEXT_HTML));
+        assert!(!clipboard.has_format(MIME_IMAGE));
+        assert_eq!(clipboard.get(), Some(String::from("replaced")));
+        assert_eq!(clipboard.get_html(), Some(String::from("<b>test</b>")));
+
+        clipboard.set_html("html");
+        assert_eq!(clipboard.get(), None);
+
+        clipboard.clear();
+        assert!(clipboard.formats().is_empty());
+    }
+
+    #[test]
+    fn test_data() {
+        let mut clipboard = Clipboard::new();
+        clipboard
+            .set_data("application/x-test", &(5, String::from("test")))
+            .unwrap();
+
+        assert!(clipboard.has_format("application/x-test"));
+        assert_eq!(
+            clipboard
+                .get_data::<(i32, String)>("application/x-test")
+                .unwrap()
+                .unwrap(),
+            (5, String::from("test"))
+        );
+        assert!(clipboard
+            .get_data::<String>("application/x-other")
+            .is_none());
+        assert!(clipboard
+            .get_data::<String>("application/x-test")
+            .unwrap()
+            .is_err());
+    }
+
+    #[test]
+    fn test_revision() {
+        let mut clipboard = Clipboard::new();
+        assert_eq!(clipboard.revision(), 0);
+        clipboard.set("test");
+        clipboard.set("test");
+        assert_eq!(clipboard.revision(), 2);
+    }
+
+    #[test]
+    fn test_shared() {
+        let mut clipboard = Clipboard::new();
+        let other = clipboard.clone();
+        clipboard.set("shared");
+        assert_eq!(other.get(), Some(String::from("shared")));
+        assert_eq!(other.revision(), clipboard.revision());
+        assert_eq!(Clipboard::new().get(), None);
+    }
 }
@@ -0,0 +1,427 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use threadpool::*;
+
+use dces::entity::Entity;
+
+use crate::{render::Image, widget_base::MessageAdapter};
+
+/// Default memory budget of the image cache in bytes (64 MiB).
+pub const DEFAULT_IMAGE_CACHE_BUDGET: usize = 64 * 1024 * 1024;
+
+/// Describes the loading state of an image in the `ImageCache`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ImageLoadState {
+    /// The image is decoded in the background.
+    Loading,
+
+    /// The image is decoded.
+    Loaded(Image),
+
+    /// The image could not be loaded.
+    Failed(String),
+}
+
+/// This message is sent to the widgets that requested an image from the `ImageCache` after it is loaded or failed to
+/// load. It contains the source of the image.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageLoaded(pub String);
+
+// A widget that waits for an image with the message adapter of its window.
+#[derive(Clone, Debug)]
+struct Waiter {
+    entity: Entity,
+    message_adapter: MessageAdapter,
+}
+
+impl PartialEq for Waiter {
+    fn eq(&self, other: &Self) -> bool {
+        // entities of different windows could have the same id
+        self.entity == other.entity && self.message_adapter.same_pipeline(&other.message_adapter)
+    }
+}
+
+impl Waiter {
+    fn notify(&self, source: &str) {
+        self.message_adapter
+            .send_message(ImageLoaded(source.to_string()), self.entity);
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    state: ImageLoadState,
+    waiting: Vec<Waiter>,
+    size: usize,
+    last_used: u64,
+}
+
+#[derive(Debug, Default)]
+struct Storage {
+    entries: HashMap<String, Entry>,
+    clock: u64,
+    budget: usize,
+}
+
+impl Storage {
+    fn touch(&mut self, source: &str) -> Option<&mut Entry> {
+        self.clock += 1;
+        let clock = self.clock;
+
+        self.entries.get_mut(source).map(|entry| {
+            entry.last_used = clock;
+            entry
+        })
+    }
+
+    fn memory_usage(&self) -> usize {
+        self.entries.values().map(|entry| entry.size).sum()
+    }
+
+    // Removes the least recently used loaded images until the memory usage fits into the budget.
+    fn evict(&mut self) {
+        let mut usage = self.memory_usage();
+
+        while usage > self.budget {
+            let oldest = self
+                .entries
+                .iter()
+                .filter(|(_, entry)| entry.size > 0)
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(source, _)| source.clone());
+
+            match oldest.and_then(|source| self.entries.remove(&source)) {
+                Some(entry) => usage -= entry.size,
+                None => break,
+            }
+        }
+    }
+
+    // Stores the result of a load and returns the widgets that are waiting for it.
+    fn finish(&mut self, source: &str, result: Result<Image, String>) -> Vec<Waiter> {
+        let waiting = match self.touch(source) {
+            Some(entry) => {
+                match result {
+                    Ok(image) => {
+                        entry.size = (image.width() * image.height()) as usize * 4;
+                        entry.state = ImageLoadState::Loaded(image);
+                    }
+                    Err(error) => entry.state = ImageLoadState::Failed(error),
+                }
+
+                std::mem::take(&mut entry.waiting)
+            }
+            None => vec![],
+        };
+
+        self.evict();
+        waiting
+    }
+}
+
+/// `ImageCache` represents a global service that loads and decodes images in the background and shares them between
+/// widgets. Images are identified by their source, each source is loaded only once. If the memory usage of the cached
+/// images exceeds the budget, the least recently used images are removed from the cache.
+///
+/// The application has one cache with one thread pool, that is shared between all of its windows. The pixels of a
+/// cached image are shared with the widgets that show it and are not copied.
+///
+/// If a requested image is loaded or failed to load, an `ImageLoaded` message is sent to the requesting widget.
+///
+/// # Examples
+/// ```
+/// impl State for MyState {
+///     fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
+///         registry
+///             .get::<ImageCache>("image_cache")
+///             .load("path/to/image.png", ctx.entity());
+///     }
+///
+///     fn messages(&mut self, mut messages: MessageReader, registry: &mut Registry, ctx: &mut Context) {
+///         for ImageLoaded(source) in messages.read::<ImageLoaded>() {
+///             if let Some(ImageLoadState::Loaded(image)) = registry.get::<ImageCache>("image_cache").get(&source) {
+///                 ctx.widget().set("image", image);
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ImageCache {
+    storage: Arc<Mutex<Storage>>,
+    message_adapter: Option<MessageAdapter>,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pool: ThreadPool,
+}
+
+impl Default for ImageCache {
+    fn default() -> Self {
+        ImageCache::new()
+    }
+}
+
+impl ImageCache {
+    /// Creates a new `ImageCache` service with the default memory budget. Use `with_message_adapter` to get a cache
+    /// that notifies the widgets of a window.
+    pub fn new() -> Self {
+        ImageCache {
+            storage: Arc::new(Mutex::new(Storage {
+                budget: DEFAULT_IMAGE_CACHE_BUDGET,
+                ..Storage::default()
+            })),
+            message_adapter: None,
+
+            #[cfg(not(target_arch = "wasm32"))]
+            pool: ThreadPool::new(4),
+        }
+    }
+
+    /// Creates a handle of the cache, that shares the images and the thread pool with it and sends the `ImageLoaded`
+    /// messages with the given message adapter.
+    pub fn with_message_adapter(&self, message_adapter: MessageAdapter) -> Self {
+        ImageCache {
+            message_adapter: Some(message_adapter),
+            ..self.clone()
+        }
+    }
+
+    /// Gets the memory budget of the cache in bytes.
+    pub fn budget(&self) -> usize {
+        self.storage().budget
+    }
+
+    /// Sets the memory budget of the cache in bytes. Cached images that exceed the new budget are removed.
+    pub fn set_budget(&mut self, budget: usize) {
+        let mut storage = self.storage();
+        storage.budget = budget;
+        storage.evict();
+    }
+
+    /// Gets the memory in bytes that is used by the cached images.
+    pub fn memory_usage(&self) -> usize {
+        self.storage().memory_usage()
+    }
+
+    /// Requests the image with the given source for the given widget. If the image is not cached, it is loaded in the
+    /// background and an `ImageLoaded` message is sent to the widget after it is loaded. Returns the current loading
+    /// state of the image.
+    pub fn load(&self, source: impl Into<String>, entity: Entity) -> ImageLoadState {
+        let source = source.into();
+        let waiter = self.message_adapter.clone().map(|message_adapter| Waiter {
+            entity,
+            message_adapter,
+        });
+
+        {
+            let mut storage = self.storage();
+
+            if let Some(entry) = storage.touch(&source) {
+                if entry.state == ImageLoadState::Loading {
+                    if let Some(waiter) = waiter {
+                        if !entry.waiting.contains(&waiter) {
+                            entry.waiting.push(waiter);
+                        }
+                    }
+                }
+
+                return entry.state.clone();
+            }
+
+            let last_used = storage.clock;
+            storage.entries.insert(
+                source.clone(),
+                Entry {
+                    state: ImageLoadState::Loading,
+                    waiting: waiter.into_iter().collect(),
+                    size: 0,
+                    last_used,
+                },
+            );
+        }
+
+        self.decode(source);
+        ImageLoadState::Loading
+    }
+
+    /// Gets the loading state of the image with the given source. Returns `None` if the image was not requested or
+    /// is removed from the cache.
+    pub fn get(&self, source: &str) -> Option<ImageLoadState> {
+        self.storage()
+            .touch(source)
+            .map(|entry| entry.state.clone())
+    }
+
+    /// Removes the image with the given source from the cache. The next request loads it again.
+    pub fn remove(&self, source: &str) {
+        self.storage().entries.remove(source);
+    }
+
+    /// Removes all images from the cache.
+    pub fn clear(&self) {
+        self.storage().entries.clear();
+    }
+
+    /// Returns the number of images in the cache.
+    pub fn len(&self) -> usize {
+        self.storage().entries.len()
+    }
+
+    /// Returns `true` if the cache contains no images.
+    pub fn is_empty(&self) -> bool {
+        self.storage().entries.is_empty()
+    }
+
+    fn storage(&self) -> std::sync::MutexGuard<Storage> {
+        self.storage
+            .lock()
+            .expect("ImageCache: Cannot lock image storage.")
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn decode(&self, source: String) {
+        let storage = self.storage.clone();
+
+        self.pool.execute(move || {
+            let result = Image::from_path(source.as_str());
+            let waiting = storage
+                .lock()
+                .expect("ImageCache: Cannot lock image storage.")
+                .finish(&source, result);
+
+            for waiter in waiting {
+                waiter.notify(&source);
+            }
+        })
+    }
+
+    // The browser loads images in the background by itself.
+    #[cfg(target_arch = "wasm32")]
+    fn decode(&self, source: String) {
+        let result = Image::from_path(source.as_str());
+        let waiting = self.storage().finish(&source, result);
+
+        for waiter in waiting {
+            waiter.notify(&source);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+
+    fn waiter(entity: Entity, message_adapter: &MessageAdapter) -> Waiter {
+        Waiter {
+            entity,
+            message_adapter: message_adapter.clone(),
+        }
+    }
+
+    fn storage(budget: usize) -> Storage {
+        Storage {
+            budget,
+            ..Storage::default()
+        }
+    }
+
+    fn request(storage: &mut Storage, source: &str, waiter: Waiter) {
+        storage.clock += 1;
+        let last_used = storage.clock;
+
+        storage.entries.insert(
+            source.to_string(),
+            Entry {
+                state: ImageLoadState::Loading,
+                waiting: vec![waiter],
+                size: 0,
+                last_used,
+            },
+        );
+    }
+
+    #[test]
+    fn test_finish() {
+        let message_adapter = MessageAdapter::new(mpsc::channel().0);
+        let mut storage = storage(1024);
+        request(&mut storage, "one.png", waiter(Entity(1), &message_adapter));
+        storage
+            .touch("one.png")
+            .unwrap()
+            .waiting
+            .push(waiter(Entity(2), &message_adapter));
+
+        let waiting = storage.finish("one.png", Image::from_data(4, 4, vec![0; 16]));
+
+        assert_eq!(
+            waiting,
+            vec![
+                waiter(Entity(1), &message_adapter),
+                waiter(Entity(2), &message_adapter)
+            ]
+        );
+        assert_eq!(storage.memory_usage(), 64);
+        assert!(storage.touch("one.png").unwrap().waiting.is_empty());
+
+        request(&mut storage, "two.png", waiter(Entity(1), &message_adapter));
+        storage.finish("two.png", Err(String::from("error")));
+
+        assert_eq!(
+            storage.touch("two.png").unwrap().state,
+            ImageLoadState::Failed(String::from("error"))
+        );
+    }
+
+    #[test]
+    fn test_evict() {
+        let message_adapter = MessageAdapter::new(mpsc::channel().0);
+        let mut storage = storage(128);
+
+        for source in &["one.png", "two.png", "three.png"] {
+            request(&mut storage, source, waiter(Entity(1), &message_adapter));
+            storage.finish(source, Image::from_data(4, 4, vec![0; 16]));
+        }
+
+        // the least recently used image is removed
+        assert_eq!(storage.memory_usage(), 128);
+        assert!(storage.touch("one.png").is_none());
+
+        storage.touch("two.png");
+        storage.budget = 64;
+        storage.evict();
+
+        assert!(storage.touch("two.png").is_some());
+        assert!(storage.touch("three.png").is_none());
+    }
+
+    #[test]
+    fn test_shared_between_windows() {
+        let cache = ImageCache::new();
+        let first = cache.with_message_adapter(MessageAdapter::new(mpsc::channel().0));
+        let second = cache.with_message_adapter(MessageAdapter::new(mpsc::channel().0));
+
+        first.storage().entries.insert(
+            String::from("one.png"),
+            Entry {
+                state: ImageLoadState::Loading,
+                waiting: vec![],
+                size: 0,
+                last_used: 0,
+            },
+        );
+
+        // the same entity id of two windows waits twice
+        first.load("one.png", Entity(1));
+        first.load("one.png", Entity(1));
+        second.load("one.png", Entity(1));
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.storage().entries["one.png"].waiting.len(), 2);
+    }
+}
@@ -1,7 +1,9 @@
 //! This module contains global services.
 //!
 pub use self::clipboard::*;
+pub use self::image_cache::*;
 pub use self::settings::*;
 
 mod clipboard;
+mod image_cache;
 mod settings;
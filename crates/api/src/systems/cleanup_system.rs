@@ -2,7 +2,12 @@ use std::{cell::RefCell, rc::Rc};
 
 use dces::prelude::*;
 
-use crate::{prelude::*, render::RenderContext2D, theming::Theme, tree::Tree};
+use crate::{
+    prelude::*,
+    render::{Image, RenderBackend, Svg},
+    theming::Theme,
+    tree::Tree,
+};
 
 /// Handles the inner cleanup while window is closing.
 #[derive(Constructor)]
@@ -11,11 +16,13 @@ pub struct CleanupSystem {
     registry: Rc<RefCell<Registry>>,
 }
 
-impl System<Tree, StringComponentStore, RenderContext2D> for CleanupSystem {
+impl<R: RenderBackend<Image = Image, Svg = Svg>> System<Tree, StringComponentStore, R>
+    for CleanupSystem
+{
     fn run_with_context(
         &self,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        render_context: &mut RenderContext2D,
+        render_context: &mut R,
     ) {
         // let mut shell = self.shell.borrow_mut();
         let root = ecm.entity_store().root();
@@ -2,7 +2,14 @@ use std::{cell::RefCell, rc::Rc};
 
 use dces::prelude::*;
 
-use crate::{prelude::*, render::RenderContext2D, theming::Theme, tree::Tree, utils::*};
+use crate::{
+    prelude::*,
+    render::{Image, PlatformBackend, RenderBackend, Svg},
+    shell::WindowRequest,
+    theming::Theme,
+    tree::Tree,
+    utils::*,
+};
 
 /// The `EventStateSystem` pops events from the event queue and delegates the events to the corresponding event handlers of the widgets and updates the states.
 #[derive(Constructor)]
@@ -84,7 +91,7 @@ impl EventStateSystem {
         entity: Entity,
         theme: &Theme,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        render_context: &mut RenderContext2D,
+        render_context: &mut PlatformBackend,
     ) {
         {
             let registry = &mut self.registry.borrow_mut();
@@ -103,6 +110,9 @@ impl EventStateSystem {
             drop(ctx);
         }
         self.context_provider.states.borrow_mut().remove(&entity);
+        self.context_provider
+            .message_adapter
+            .remove_delayed_messages_for_entity(entity);
 
         ecm.remove_entity(entity);
         self.context_provider.layouts.borrow_mut().remove(&entity);
@@ -152,6 +162,42 @@ impl EventStateSystem {
         false
     }
 
+    // Passes the close request to all handlers of the window. If no handler cancels the request,
+    // the window will be closed.
+    fn process_close_request(
+        &self,
+        event: &EventBox,
+        request: &CloseRequest,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        let message_adapter = self.context_provider.message_adapter.clone();
+
+        if let Some(handlers) = self
+            .context_provider
+            .handler_map
+            .borrow()
+            .get(&event.source)
+        {
+            for handler in handlers {
+                handler.handle_event(
+                    &mut StatesContext::new(
+                        &mut *self.context_provider.states.borrow_mut(),
+                        ecm,
+                        &message_adapter,
+                    ),
+                    &event,
+                );
+            }
+        }
+
+        if !request.is_cancelled() {
+            self.context_provider
+                .window_sender
+                .send(WindowRequest::Close)
+                .expect("EventStateSystem::process_close_request: could not send close request.");
+        }
+    }
+
     fn process_bottom_up_event(
         &self,
         mouse_position: Point,
@@ -440,6 +486,18 @@ impl EventStateSystem {
         let mut handled = false;
 
         for node in matching_nodes.iter().rev() {
+            // handlers of transformed widgets get the mouse position in the untransformed coordinates of the widget
+            let transformed_event = local_event(
+                event,
+                &WidgetContainer::new(
+                    *node,
+                    ecm,
+                    &theme,
+                    Some(&self.context_provider.event_adapter),
+                ),
+            );
+            let event = transformed_event.as_ref().unwrap_or(event);
+
             if let Some(handlers) = self.context_provider.handler_map.borrow().get(node) {
                 handled = handlers.iter().any(|handler| {
                     handler.handle_event(
@@ -464,11 +522,13 @@ impl EventStateSystem {
     }
 }
 
-impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
+impl<R: RenderBackend<Image = Image, Svg = Svg>> System<Tree, StringComponentStore, R>
+    for EventStateSystem
+{
     fn run_with_context(
         &self,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        render_context: &mut RenderContext2D,
+        render_context: &mut R,
     ) {
         let mut update = false;
 
@@ -485,6 +545,14 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                         }
                     }
 
+                    if let Ok(WindowEvent::CloseRequested(request)) =
+                        event.downcast_ref::<WindowEvent>()
+                    {
+                        self.process_close_request(&event, request, ecm);
+                        update = true;
+                        continue;
+                    }
+
                     match event.strategy {
                         EventStrategy::Direct => {
                             if event.strategy == EventStrategy::Direct {
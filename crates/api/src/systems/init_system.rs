@@ -2,7 +2,12 @@ use std::{cell::RefCell, rc::Rc};
 
 use dces::prelude::*;
 
-use crate::{prelude::*, render::RenderContext2D, theming::Selector, tree::Tree};
+use crate::{
+    prelude::*,
+    render::{Image, RenderBackend, Svg},
+    theming::Selector,
+    tree::Tree,
+};
 
 /// This system is used to initializes the widgets.
 #[derive(Constructor)]
@@ -11,11 +16,13 @@ pub struct InitSystem {
     registry: Rc<RefCell<Registry>>,
 }
 
-impl System<Tree, StringComponentStore, RenderContext2D> for InitSystem {
+impl<R: RenderBackend<Image = Image, Svg = Svg>> System<Tree, StringComponentStore, R>
+    for InitSystem
+{
     fn run_with_context(
         &self,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        render_context: &mut RenderContext2D,
+        render_context: &mut R,
     ) {
         let root = ecm.entity_store().root();
 
@@ -1,6 +1,14 @@
 use dces::prelude::*;
 
-use crate::{prelude::*, render::RenderContext2D, tree::Tree, utils::*};
+use crate::{
+    prelude::*,
+    render::{Image, RenderBackend, Svg},
+    tree::Tree,
+    utils::*,
+};
+
+// Limits the number of layout passes per run, if layouts request additional passes.
+const MAX_LAYOUT_PASSES: usize = 3;
 
 /// The `LayoutSystem` builds per iteration the layout of the current ui. The layout parts are calculated by the layout objects of layout widgets.
 #[derive(Constructor)]
@@ -8,20 +16,28 @@ pub struct LayoutSystem {
     context_provider: ContextProvider,
 }
 
-impl System<Tree, StringComponentStore, RenderContext2D> for LayoutSystem {
+impl<R: RenderBackend<Image = Image, Svg = Svg>> System<Tree, StringComponentStore, R>
+    for LayoutSystem
+{
     fn run_with_context(
         &self,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        render_context: &mut RenderContext2D,
+        render_context: &mut R,
     ) {
         let root = ecm.entity_store().root();
 
+        // the render system applies the new scale factor after the layout is updated
+        let scale_factor_changed =
+            (render_context.scale_factor() - self.context_provider.scale_factor.get()).abs()
+                > f64::EPSILON;
+
         if ecm
             .component_store()
             .get::<Vec<Entity>>("dirty_widgets", root)
             .unwrap()
             .is_empty()
             && !self.context_provider.first_run.get()
+            && !scale_factor_changed
         {
             return;
         }
@@ -40,22 +56,30 @@ impl System<Tree, StringComponentStore, RenderContext2D> for LayoutSystem {
             .unwrap()
             .clone();
 
-        self.context_provider.layouts.borrow()[&root].measure(
-            render_context,
-            root,
-            ecm,
-            &self.context_provider.layouts.borrow(),
-            &theme,
-        );
+        take_layout_pass_request();
+
+        for _ in 0..MAX_LAYOUT_PASSES {
+            self.context_provider.layouts.borrow()[&root].measure(
+                render_context,
+                root,
+                ecm,
+                &self.context_provider.layouts.borrow(),
+                &theme,
+            );
 
-        self.context_provider.layouts.borrow()[&root].arrange(
-            render_context,
-            window_size,
-            root,
-            ecm,
-            &self.context_provider.layouts.borrow(),
-            &theme,
-        );
+            self.context_provider.layouts.borrow()[&root].arrange(
+                render_context,
+                window_size,
+                root,
+                ecm,
+                &self.context_provider.layouts.borrow(),
+                &theme,
+            );
+
+            if !take_layout_pass_request() {
+                break;
+            }
+        }
 
         // if self.debug_flag.get() {
         //     println!("\n------ End layout update   ------\n");
@@ -2,7 +2,12 @@ use std::{cell::RefCell, rc::Rc};
 
 use dces::prelude::*;
 
-use crate::{prelude::*, render::RenderContext2D, theming::Theme, tree::Tree};
+use crate::{
+    prelude::*,
+    render::{Image, PlatformBackend, RenderBackend, Svg},
+    theming::Theme,
+    tree::Tree,
+};
 
 /// The `PostLayoutStateSystem` calls the update_post_layout methods of widget states.
 #[derive(Constructor)]
@@ -17,7 +22,7 @@ impl PostLayoutStateSystem {
         entity: Entity,
         theme: &Theme,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        render_context: &mut RenderContext2D,
+        render_context: &mut PlatformBackend,
     ) {
         {
             let mut ctx = Context::new(
@@ -34,6 +39,9 @@ impl PostLayoutStateSystem {
             drop(ctx);
         }
         self.context_provider.states.borrow_mut().remove(&entity);
+        self.context_provider
+            .message_adapter
+            .remove_delayed_messages_for_entity(entity);
 
         ecm.remove_entity(entity);
         self.context_provider.layouts.borrow_mut().remove(&entity);
@@ -48,11 +56,13 @@ impl PostLayoutStateSystem {
     }
 }
 
-impl System<Tree, StringComponentStore, RenderContext2D> for PostLayoutStateSystem {
+impl<R: RenderBackend<Image = Image, Svg = Svg>> System<Tree, StringComponentStore, R>
+    for PostLayoutStateSystem
+{
     fn run_with_context(
         &self,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        render_context: &mut RenderContext2D,
+        render_context: &mut R,
     ) {
         // todo fix
         // if !self.shell.borrow().update() || !self.shell.borrow().running() {
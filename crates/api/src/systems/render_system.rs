@@ -2,19 +2,174 @@ use std::collections::BTreeMap;
 
 use dces::prelude::*;
 
-use crate::{prelude::*, render::RenderContext2D, tree::Tree};
+use crate::{
+    prelude::*,
+    render::{Image, PlatformBackend, RenderBackend, Svg},
+    render_object::{layer_key, render_bounds, widget_transform},
+    tree::Tree,
+};
 
 /// The `RenderSystem` iterates over all visual widgets and used its render objects to draw them on the screen.
+///
+/// Only the region covered by the old and new bounds of the changed widgets is redrawn. The whole tree is redrawn on
+/// the first run, after the scale factor has changed, if the render context was invalidated or if the root is dirty.
+/// Cached layers of widgets are removed if the widget or one of its children is dirty.
 #[derive(Constructor)]
 pub struct RenderSystem {
     context_provider: ContextProvider,
 }
 
-impl System<Tree, StringComponentStore, RenderContext2D> for RenderSystem {
+impl RenderSystem {
+    // Removes the widgets that are not part of the tree anymore from the rendered bounds and returns them with their
+    // bounds.
+    fn take_removed_widgets(
+        &self,
+        ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    ) -> Vec<(Entity, Rectangle)> {
+        let mut last_bounds = self.context_provider.render_bounds.borrow_mut();
+
+        let removed: Vec<Entity> = last_bounds
+            .keys()
+            .filter(|entity| !ecm.entity_store().children.contains_key(*entity))
+            .copied()
+            .collect();
+
+        removed
+            .into_iter()
+            .filter_map(|entity| last_bounds.remove(&entity).map(|rect| (entity, rect)))
+            .collect()
+    }
+
+    // Removes the cached layers of the dirty widgets and their ancestors and of the removed widgets. The ancestors of
+    // removed widgets are not known anymore, so all layers that overlap them are removed.
+    fn invalidate_layers(
+        &self,
+        ecm: &EntityComponentManager<Tree, StringComponentStore>,
+        render_context: &mut PlatformBackend,
+        dirty_widgets: &[Entity],
+        removed_widgets: &[(Entity, Rectangle)],
+    ) {
+        let mut removed_region = None;
+
+        for (entity, rect) in removed_widgets {
+            render_context.remove_layer(layer_key(*entity));
+            join_region(&mut removed_region, *rect);
+        }
+
+        if let Some(removed_region) = removed_region {
+            for (entity, rect) in self.context_provider.render_bounds.borrow().iter() {
+                if rect.intersects(&removed_region) {
+                    render_context.remove_layer(layer_key(*entity));
+                }
+            }
+        }
+
+        for widget in dirty_widgets {
+            let mut entity = Some(*widget);
+
+            while let Some(current) = entity {
+                render_context.remove_layer(layer_key(current));
+                entity = ecm.entity_store().parent.get(&current).copied().flatten();
+            }
+        }
+    }
+
+    // Collects the region that has to be redrawn from the bounds the dirty widgets were last rendered with, their
+    // current bounds and the bounds of removed widgets. Returns `None` if the whole tree has to be redrawn.
+    fn damage_region(
+        &self,
+        ecm: &EntityComponentManager<Tree, StringComponentStore>,
+        dirty_widgets: &[Entity],
+        removed_widgets: &[(Entity, Rectangle)],
+    ) -> Option<Rectangle> {
+        let root = ecm.entity_store().root();
+
+        if dirty_widgets.contains(&root) {
+            return None;
+        }
+
+        let last_bounds = self.context_provider.render_bounds.borrow();
+        let mut region: Option<Rectangle> = None;
+
+        for (_, rect) in removed_widgets {
+            join_region(&mut region, *rect);
+        }
+
+        for widget in dirty_widgets {
+            if !ecm.entity_store().children.contains_key(widget) {
+                continue;
+            }
+
+            if let Some(rect) = last_bounds.get(widget) {
+                join_region(&mut region, *rect);
+            }
+
+            let (position, transform) = global_position(ecm, *widget);
+
+            if let Some(rect) = render_bounds(
+                *widget,
+                ecm,
+                &position,
+                &widget_transform(*widget, ecm, &position, &transform),
+                self.context_provider.scale_factor.get(),
+            ) {
+                join_region(&mut region, rect);
+            }
+        }
+
+        // anti aliased edges could exceed the bounds
+        region.map(|r| {
+            Rectangle::new(
+                (r.x() - 1.0, r.y() - 1.0),
+                (r.width() + 2.0, r.height() + 2.0),
+            )
+        })
+    }
+}
+
+// Extends the region by the given rectangle.
+fn join_region(region: &mut Option<Rectangle>, rect: Rectangle) {
+    match region {
+        Some(region) => region.join_with_rectangle(&rect),
+        None => *region = Some(rect),
+    }
+}
+
+// Gets the global position and the global render transform of the parent of the given widget.
+fn global_position(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> (Point, Transform) {
+    let mut ancestors = vec![];
+    let mut parent = ecm.entity_store().parent[&entity];
+
+    while let Some(entity) = parent {
+        ancestors.push(entity);
+        parent = ecm.entity_store().parent[&entity];
+    }
+
+    let mut position = Point::default();
+    let mut transform = Transform::default();
+
+    // the transform of an ancestor depends on the position of its parent
+    for entity in ancestors.into_iter().rev() {
+        transform = widget_transform(entity, ecm, &position, &transform);
+
+        if let Ok(bounds) = ecm.component_store().get::<Rectangle>("bounds", entity) {
+            position = Point::new(position.x() + bounds.x(), position.y() + bounds.y());
+        }
+    }
+
+    (position, transform)
+}
+
+impl<R: RenderBackend<Image = Image, Svg = Svg>> System<Tree, StringComponentStore, R>
+    for RenderSystem
+{
     fn run_with_context(
         &self,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        render_context: &mut RenderContext2D,
+        render_context: &mut R,
     ) {
         let root = ecm.entity_store().root();
 
@@ -24,10 +179,41 @@ impl System<Tree, StringComponentStore, RenderContext2D> for RenderSystem {
             .unwrap()
             .clone();
 
-        if dirty_widgets.is_empty() && !self.context_provider.first_run.get() {
+        let scale_factor = self.context_provider.scale_factor.get();
+        let scale_factor_changed =
+            (render_context.scale_factor() - scale_factor).abs() > f64::EPSILON;
+
+        let removed_widgets = self.take_removed_widgets(ecm);
+
+        if dirty_widgets.is_empty()
+            && removed_widgets.is_empty()
+            && !self.context_provider.first_run.get()
+            && !scale_factor_changed
+        {
             return;
         }
 
+        let region = if self.context_provider.first_run.get()
+            || scale_factor_changed
+            || render_context.invalidated()
+        {
+            None
+        } else {
+            self.damage_region(ecm, &dirty_widgets, &removed_widgets)
+        };
+
+        match region {
+            Some(_) => {
+                self.invalidate_layers(ecm, render_context, &dirty_widgets, &removed_widgets)
+            }
+            None => render_context.clear_layers(),
+        }
+
+        // widgets are rendered in logical units, the render context scales them to physical pixels
+        if scale_factor_changed {
+            render_context.set_scale_factor(scale_factor);
+        }
+
         // reset the dirty flag of all dirty widgets to `false`
         for widget in dirty_widgets {
             if let Ok(dirty) = ecm.component_store_mut().get_mut::<bool>("dirty", widget) {
@@ -57,7 +243,13 @@ impl System<Tree, StringComponentStore, RenderContext2D> for RenderSystem {
 
         // CONSOLE.time("render");
 
-        render_context.start();
+        self.context_provider.render_region.set(region);
+
+        match region {
+            Some(region) => render_context.start_region(region),
+            None => render_context.start(),
+        }
+
         render_context.begin_path();
         self.context_provider.render_objects.borrow()[&root].render(
             render_context,
@@ -1,4 +1,9 @@
-use std::{collections::BTreeMap, sync::mpsc};
+use std::{
+    collections::BTreeMap,
+    io,
+    path::Path,
+    sync::{mpsc, Arc},
+};
 
 use dces::prelude::*;
 
@@ -7,7 +12,7 @@ use raw_window_handle::RawWindowHandle;
 use crate::{
     application::{create_window, ContextProvider},
     prelude::*,
-    render::RenderContext2D,
+    render::{font_files, PlatformBackend},
     shell::{ShellRequest, WindowRequest},
     theming::prelude::*,
     tree::Tree,
@@ -27,7 +32,7 @@ pub struct Context<'a> {
     pub(crate) provider: &'a ContextProvider,
     new_states: BTreeMap<Entity, Box<dyn State>>,
     remove_widget_list: Vec<Entity>,
-    render_context: &'a mut RenderContext2D,
+    render_context: &'a mut PlatformBackend,
 }
 
 impl<'a> Drop for Context<'a> {
@@ -48,7 +53,7 @@ impl<'a> Context<'a> {
         ),
         theme: &Theme,
         provider: &'a ContextProvider,
-        render_context: &'a mut RenderContext2D,
+        render_context: &'a mut PlatformBackend,
     ) -> Self {
         Context {
             entity: ecs.0,
@@ -81,6 +86,11 @@ impl<'a> Context<'a> {
         None
     }
 
+    /// Gets the scale factor of the window, the ratio between physical pixels and logical units.
+    pub fn scale_factor(&self) -> f64 {
+        self.provider.scale_factor.get()
+    }
+
     // -- Widgets --
 
     /// Returns a specific widget.
@@ -431,6 +441,9 @@ impl<'a> Context<'a> {
             self.provider.shell_sender.clone(),
             create_fn,
             self.provider.localization.clone(),
+            self.provider.clipboard.clone(),
+            self.provider.fonts.clone(),
+            self.provider.image_cache.clone(),
         );
         self.provider
             .shell_sender
@@ -438,11 +451,81 @@ impl<'a> Context<'a> {
             .expect("Context.show_window: Could not send shell request.");
     }
 
+    /// Quits the application with all its windows.
+    pub fn quit_application(&self) {
+        self.provider
+            .shell_sender
+            .send(ShellRequest::Quit)
+            .expect("Context.quit_application: Could not send shell request.");
+    }
+
     /// Returns a mutable reference of the 2d render ctx.
-    pub fn render_context_2_d(&mut self) -> &mut RenderContext2D {
+    pub fn render_context_2_d(&mut self) -> &mut PlatformBackend {
         self.render_context
     }
 
+    /// Registers a font that is loaded at runtime. The font is registered on application level and shared between
+    /// all windows. Returns an error if the font data is invalid.
+    pub fn register_font_bytes(
+        &mut self,
+        family: &str,
+        font_data: impl Into<Arc<Vec<u8>>>,
+    ) -> io::Result<()> {
+        let font_data = font_data.into();
+
+        // the fonts of the other windows are registered first, the new font is validated by the render context
+        self.provider
+            .fonts
+            .register_at(self.render_context, &self.provider.registered_fonts);
+        self.render_context
+            .register_font_bytes(family, font_data.clone())?;
+
+        self.provider.fonts.register_font_bytes(family, font_data);
+        self.provider
+            .registered_fonts
+            .set(self.provider.fonts.len());
+
+        Ok(())
+    }
+
+    /// Loads and registers the font file of the given path. Files could not be read on the web, there it returns
+    /// always `Err`.
+    pub fn register_font_file(&mut self, family: &str, path: impl AsRef<Path>) -> io::Result<()> {
+        self.register_font_bytes(family, std::fs::read(path)?)
+    }
+
+    /// Loads all font files (`ttf`, `otf`) of the given directory. The file name without extension is used as
+    /// family key. Returns the families of the registered fonts. Directories could not be read on the web, there
+    /// it returns always `Err`.
+    pub fn register_font_directory(&mut self, path: impl AsRef<Path>) -> io::Result<Vec<String>> {
+        let mut families = vec![];
+
+        for (family, file) in font_files(path)? {
+            if self.register_font_file(&family, file).is_ok() {
+                families.push(family);
+            }
+        }
+
+        Ok(families)
+    }
+
+    /// Renders the given widget with its children into an image, e.g. for thumbnails or drag visuals. The size of the
+    /// image is the size of the widget in logical units multiplied by `scale`. Returns `None` if the widget is empty.
+    ///
+    /// Use `WindowRequest::Screenshot` to save the content of the whole window as PNG.
+    ///
+    /// Not available on the web, the web render context draws only to the canvas of the browser window.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn render_to_image(&mut self, entity: Entity, scale: f64) -> Option<crate::render::Image> {
+        render_to_image(entity, self.ecm, self.provider, &self.theme, scale)
+    }
+
+    /// Records the drawing calls of the given widget with its children into a display list, that could be replayed or
+    /// exported as SVG or PDF document (not on the web). Returns `None` if the widget is empty.
+    pub fn record_display_list(&mut self, entity: Entity) -> Option<crate::render::DisplayList> {
+        record_display_list(entity, self.ecm, self.provider, &self.theme)
+    }
+
     /// Returns a keys collection of new added states.
     pub fn new_states_keys(&self) -> Vec<Entity> {
         self.new_states.keys().cloned().collect()
@@ -458,6 +541,9 @@ impl<'a> Context<'a> {
             self.render_context.register_font(key, *font);
         }
 
+        self.render_context
+            .set_font_fallbacks(self.theme.font_fallbacks().to_vec());
+
         // update on window to update all widgets in the tree
         self.window().update_dirty(true);
     }
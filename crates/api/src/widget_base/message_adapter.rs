@@ -3,8 +3,12 @@ use std::{
     collections::{BTreeMap, HashMap},
     marker::PhantomData,
     sync::{mpsc, Arc, Mutex},
+    time::Duration,
 };
 
+#[cfg(not(target_arch = "wasm32"))]
+use std::{cmp::Ordering, collections::BinaryHeap, sync::Condvar, time::Instant};
+
 use crate::shell::WindowRequest;
 
 use dces::entity::Entity;
@@ -56,6 +60,90 @@ impl MessageBox {
     }
 }
 
+// A message that is sent after its deadline. The earliest deadline is the greatest timer, so that the binary heap
+// pops it first.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+struct Timer {
+    deadline: Instant,
+    id: u64,
+    message: MessageBox,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PartialEq for Timer {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Eq for Timer {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PartialOrd for Timer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Ord for Timer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.deadline, other.id).cmp(&(self.deadline, self.id))
+    }
+}
+
+// The pending delayed messages.
+#[derive(Debug, Default)]
+struct TimerQueue {
+    next_id: u64,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    timers: BinaryHeap<Timer>,
+
+    // `true` if the timer thread is running, it stops if no timer is pending
+    #[cfg(not(target_arch = "wasm32"))]
+    running: bool,
+
+    // the browser schedules the messages, a message is sent only if it is still pending
+    #[cfg(target_arch = "wasm32")]
+    timers: HashMap<u64, MessageBox>,
+}
+
+impl TimerQueue {
+    fn cancel(&mut self, target: Entity) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let timers = std::mem::take(&mut self.timers);
+            self.timers = timers
+                .into_iter()
+                .filter(|timer| timer.message.target != target)
+                .collect();
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        self.timers.retain(|_, message| message.target != target);
+    }
+}
+
+#[derive(Debug, Default)]
+struct Timers {
+    queue: Mutex<TimerQueue>,
+
+    // wakes the timer thread if a timer is added
+    #[cfg(not(target_arch = "wasm32"))]
+    condvar: Condvar,
+}
+
+impl Timers {
+    fn queue(&self) -> std::sync::MutexGuard<TimerQueue> {
+        self.queue
+            .lock()
+            .expect("MessageAdapter: Cannot lock timers.")
+    }
+}
+
 /// The `MessageAdapter` is the thread save entry point to sent and read widget messages that are handled by the `message`
 /// method of a widget `State`,
 ///
@@ -81,6 +169,7 @@ impl MessageBox {
 pub struct MessageAdapter {
     messages: Arc<Mutex<BTreeMap<Entity, HashMap<TypeId, Vec<MessageBox>>>>>,
     window_sender: mpsc::Sender<WindowRequest>,
+    timers: Arc<Timers>,
 }
 
 impl MessageAdapter {
@@ -89,48 +178,135 @@ impl MessageAdapter {
         MessageAdapter {
             messages: Arc::new(Mutex::new(BTreeMap::new())),
             window_sender,
+            timers: Arc::new(Timers::default()),
         }
     }
 
     /// Send a new message to the message pipeline.
     pub fn send_message<M: Any + Send>(&self, message: M, target: Entity) {
-        if !self.messages.lock().unwrap().contains_key(&target) {
-            self.messages
-                .lock()
-                .expect("MessageAdapter::send_message: Cannot lock messages.")
-                .insert(target, HashMap::new());
+        self.deliver(MessageBox::new(message, target))
+            .expect("MessageAdapter::send_message: Cannot send redraw request.");
+    }
+
+    /// Sends a new message to the message pipeline after the given delay. It could be used to schedule repeating
+    /// work like animation frames without blocking the ui thread.
+    ///
+    /// All delayed messages of the adapter are scheduled by one timer thread. Pending messages of a removed widget
+    /// are dropped.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn send_message_delayed<M: Any + Send>(&self, message: M, target: Entity, delay: Duration) {
+        let mut queue = self.timers.queue();
+        queue.next_id += 1;
+
+        let timer = Timer {
+            deadline: Instant::now() + delay,
+            id: queue.next_id,
+            message: MessageBox::new(message, target),
+        };
+        queue.timers.push(timer);
+
+        if queue.running {
+            self.timers.condvar.notify_one();
+        } else {
+            queue.running = true;
+            let message_adapter = self.clone();
+            std::thread::spawn(move || message_adapter.run_timers());
         }
+    }
+
+    /// Sends a new message to the message pipeline after the given delay. It could be used to schedule repeating
+    /// work like animation frames without blocking the ui thread.
+    ///
+    /// Pending messages of a removed widget are dropped.
+    #[cfg(target_arch = "wasm32")]
+    pub fn send_message_delayed<M: Any + Send>(&self, message: M, target: Entity, delay: Duration) {
+        let id = {
+            let mut queue = self.timers.queue();
+            queue.next_id += 1;
+            let id = queue.next_id;
+            queue.timers.insert(id, MessageBox::new(message, target));
+            id
+        };
 
-        let type_id = TypeId::of::<M>();
+        let message_adapter = self.clone();
 
-        if !self
-            .messages
-            .lock()
-            .expect("MessageAdapter::send_message: Cannot lock messages.")
-            .get(&target)
-            .unwrap()
-            .contains_key(&type_id)
-        {
-            self.messages
-                .lock()
-                .expect("MessageAdapter::send_message: Cannot lock messages.")
-                .get_mut(&target)
-                .unwrap()
-                .insert(type_id, vec![]);
+        stdweb::web::set_timeout(
+            move || {
+                let message = message_adapter.timers.queue().timers.remove(&id);
+
+                if let Some(message) = message {
+                    message_adapter.deliver(message).expect(
+                        "MessageAdapter::send_message_delayed: Cannot send redraw request.",
+                    );
+                }
+            },
+            delay.as_millis() as u32,
+        );
+    }
+
+    // Sends the delayed messages after their deadline. Stops if no message is pending or the window is closed.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn run_timers(self) {
+        let mut queue = self.timers.queue();
+
+        loop {
+            let now = Instant::now();
+
+            match queue.timers.peek().map(|timer| timer.deadline) {
+                None => {
+                    queue.running = false;
+                    return;
+                }
+                Some(deadline) if deadline > now => {
+                    queue = self
+                        .timers
+                        .condvar
+                        .wait_timeout(queue, deadline - now)
+                        .expect("MessageAdapter: Cannot lock timers.")
+                        .0;
+                }
+                Some(_) => {
+                    // unwrap is ok because the queue contains the peeked timer
+                    let timer = queue.timers.pop().unwrap();
+
+                    // the message is delivered while the queue is locked, so a target that removes its delayed
+                    // messages could not receive it afterwards
+                    let delivered = self.deliver(timer.message).is_ok();
+
+                    // the window is closed
+                    if !delivered {
+                        queue.timers.clear();
+                        queue.running = false;
+                        return;
+                    }
+                }
+            }
         }
+    }
 
+    // Moves the message to the message pipeline of its target and requests a redraw of the window.
+    fn deliver(&self, message: MessageBox) -> Result<(), mpsc::SendError<WindowRequest>> {
         self.messages
             .lock()
             .expect("MessageAdapter::send_message: Cannot lock messages.")
-            .get_mut(&target)
-            .unwrap()
-            .get_mut(&type_id)
-            .unwrap()
-            .push(MessageBox::new(message, target));
-
-        self.window_sender
-            .send(WindowRequest::Redraw)
-            .expect("MessageAdapter::send_message: Cannot send redraw request.");
+            .entry(message.target)
+            .or_insert_with(HashMap::new)
+            .entry(message.message_type)
+            .or_insert_with(Vec::new)
+            .push(message);
+
+        self.window_sender.send(WindowRequest::Redraw)
+    }
+
+    /// Removes the pending delayed messages of the given target entity. No delayed message is delivered to the
+    /// target after it returns.
+    pub(crate) fn remove_delayed_messages_for_entity(&self, target: Entity) {
+        self.timers.queue().cancel(target);
+    }
+
+    /// Returns `true` if both adapters send the messages to the same message pipeline.
+    pub(crate) fn same_pipeline(&self, other: &MessageAdapter) -> bool {
+        Arc::ptr_eq(&self.messages, &other.messages)
     }
 
     /// Returns a list of entities that has messages.
@@ -261,3 +437,36 @@ where
         Some(self.messages.remove(0).downcast::<M>().unwrap())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_send_message_delayed() {
+        let (sender, receiver) = mpsc::channel();
+        let message_adapter = MessageAdapter::new(sender);
+
+        message_adapter.send_message_delayed(1, Entity(1), Duration::from_millis(20));
+        message_adapter.send_message_delayed(2, Entity(2), Duration::from_millis(10));
+        message_adapter.send_message_delayed(3, Entity(2), Duration::from_millis(30));
+
+        // pending messages of removed widgets are not sent
+        message_adapter.remove_delayed_messages_for_entity(Entity(1));
+
+        for _ in 0..2 {
+            assert!(receiver.recv_timeout(Duration::from_secs(5)).is_ok());
+        }
+
+        assert_eq!(message_adapter.entities(), vec![Entity(2)]);
+        assert_eq!(
+            message_adapter
+                .message_reader(Entity(2))
+                .read::<i32>()
+                .collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert!(receiver.recv_timeout(Duration::from_millis(50)).is_err());
+        assert!(message_adapter.timers.queue().timers.is_empty());
+    }
+}
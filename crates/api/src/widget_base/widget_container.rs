@@ -493,6 +493,14 @@ impl<'a> WidgetContainer<'a> {
                             self.update_value::<f64, Value>(&key, Value(value));
                         } else if self.is::<Thickness>(&key) {
                             self.update_value::<Thickness, Value>(&key, Value(value));
+                        } else if self.is::<BoxShadow>(&key) {
+                            self.update_value::<BoxShadow, Value>(&key, Value(value));
+                        } else if self.is::<CornerRadius>(&key) {
+                            self.update_value::<CornerRadius, Value>(&key, Value(value));
+                        } else if self.is::<BorderBrushes>(&key) {
+                            self.update_value::<BorderBrushes, Value>(&key, Value(value));
+                        } else if self.is::<BorderStyle>(&key) {
+                            self.update_value::<BorderStyle, Value>(&key, Value(value));
                         } else if self.is::<String>(&key) {
                             self.update_value::<String, Value>(&key, Value(value));
                         }
@@ -28,6 +28,29 @@ pub fn derive_pipeline(input: TokenStream) -> TokenStream {
     TokenStream::from(gen)
 }
 
+#[proc_macro_derive(Pipeline2D)]
+pub fn derive_pipeline_2_d(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let ident = &input.ident;
+
+    let gen = quote! {
+        impl Pipeline2DTrait for #ident {
+            fn box_eq(&self, other: &dyn Any) -> bool {
+                other.downcast_ref::<Self>().map_or(false, |a| self == a)
+            }
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+            fn clone_box(&self) -> Box<dyn Pipeline2DTrait> {
+                Box::new(self.clone())
+            }
+        }
+    };
+
+    TokenStream::from(gen)
+}
+
 #[proc_macro_derive(AsAny)]
 pub fn derive_as_any(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -0,0 +1,681 @@
+use std::{io, sync::Arc};
+
+use crate::{
+    platform::{Image, Svg},
+    utils::*,
+    Path2D, PipelineTrait, RenderTarget, TextCluster, TextMetrics,
+};
+
+/// Describes the 2D drawing API of a render context, that is used by render objects, layouts and systems to draw
+/// and measure the widgets.
+///
+/// It is implemented by the `RenderContext2D` of each platform. Other rasterizers or test doubles could implement it
+/// to be used as render context without changes on `orbtk_render`. The images and SVG documents that are drawn are
+/// associated types, so a backend could provide its own. The trait is object safe, so it could be used as trait
+/// object with fixed image types, e.g. `&mut PlatformBackend`.
+pub trait RenderBackend {
+    /// The type of the images that are drawn.
+    type Image;
+
+    /// The type of the SVG documents that are drawn.
+    type Svg;
+
+    // Frame
+
+    /// Set the background of the render context.
+    fn set_background(&mut self, background: Color);
+
+    /// Resizes the render context. The size is given in physical pixels.
+    fn resize(&mut self, width: f64, height: f64);
+
+    /// Sets the scale factor that describes the ratio between physical pixels and logical units.
+    fn set_scale_factor(&mut self, scale_factor: f64);
+
+    /// Gets the current scale factor.
+    fn scale_factor(&self) -> f64;
+
+    /// Starts a new render pipeline that redraws the whole render context.
+    fn start(&mut self);
+
+    /// Starts a new render pipeline that only redraws the given region in logical units.
+    fn start_region(&mut self, region: Rectangle);
+
+    /// Finishes the current render pipeline.
+    fn finish(&mut self);
+
+    /// Returns `true` if the content of the render context is not valid anymore and has to be redrawn completely.
+    fn invalidated(&self) -> bool;
+
+    /// Takes the regions in physical pixels that were redrawn since the last call.
+    fn take_damage(&mut self) -> Vec<Rectangle>;
+
+    // Fonts and text
+
+    /// Registers a new font file.
+    fn register_font(&mut self, family: &str, font_file: &'static [u8]);
+
+    /// Returns `true` if a font of the given family is registered.
+    fn has_font(&self, family: &str) -> bool;
+
+    /// Registers a new font from shared bytes. An already registered font of the family is replaced.
+    fn register_font_bytes(&mut self, family: &str, font_data: Arc<Vec<u8>>) -> io::Result<()>;
+
+    /// Sets the font families that are used in the given order for chars, that are missing in the selected font family.
+    fn set_font_fallbacks(&mut self, families: Vec<String>);
+
+    /// Draws (fills) a given text at the given (x, y) position.
+    fn fill_text(&mut self, text: &str, x: f64, y: f64);
+
+    /// Measures the given text with the given font size and family.
+    fn measure(&mut self, text: &str, font_size: f64, family: &str) -> TextMetrics {
+        self.set_font_family(family);
+        self.set_font_size(font_size);
+        self.measure_text(text)
+    }
+
+    /// Measures the given text with the current font.
+    fn measure_text(&mut self, text: &str) -> TextMetrics;
+
+    /// Gets the clusters of the shaped text in logical order. The start and end of a cluster are byte indices of the text.
+    fn measure_clusters(&mut self, text: &str, font_size: f64, family: &str) -> Vec<TextCluster>;
+
+    // Paths
+
+    /// Draws a filled rectangle with the current fill style.
+    fn fill_rect(&mut self, x: f64, y: f64, width: f64, height: f64);
+
+    /// Draws a stroked rectangle with the current stroke style.
+    fn stroke_rect(&mut self, x: f64, y: f64, width: f64, height: f64);
+
+    /// Fills the current path with the current fill style.
+    fn fill(&mut self);
+
+    /// Strokes the current path with the current stroke style.
+    fn stroke(&mut self);
+
+    /// Fills the given path with the current fill style. The current path is not changed.
+    fn fill_path(&mut self, path: &Path2D);
+
+    /// Strokes the given path with the current stroke style. The current path is not changed.
+    fn stroke_path(&mut self, path: &Path2D);
+
+    /// Creates a clipping path from the given path. The current path is not changed.
+    fn clip_path(&mut self, path: &Path2D);
+
+    /// Adds the segments of the given path to the current path.
+    fn add_path(&mut self, path: &Path2D);
+
+    /// Starts a new path by emptying the list of sub-paths.
+    fn begin_path(&mut self);
+
+    /// Adds a straight line from the current point to the start of the current sub-path.
+    fn close_path(&mut self);
+
+    /// Adds a rectangle to the current path.
+    fn rect(&mut self, x: f64, y: f64, width: f64, height: f64);
+
+    /// Adds a circular arc centered at (x, y) to the current path.
+    fn arc(&mut self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64);
+
+    /// Adds a circular arc with the given radius, that connects the line from the current point to (x1, y1) with
+    /// the line from (x1, y1) to (x2, y2).
+    fn arc_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, radius: f64);
+
+    /// Adds an elliptical arc centered at (x, y) and rotated by rotation to the current path.
+    #[allow(clippy::too_many_arguments)]
+    fn ellipse(
+        &mut self,
+        x: f64,
+        y: f64,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    );
+
+    /// Adds a rectangle with rounded corners to the current path. The radii are given in the order top left, top
+    /// right, bottom right and bottom left.
+    fn rounded_rect(&mut self, x: f64, y: f64, width: f64, height: f64, radii: [f64; 4]);
+
+    /// Begins a new sub-path at the given point.
+    fn move_to(&mut self, x: f64, y: f64);
+
+    /// Adds a straight line to the given point to the current sub-path.
+    fn line_to(&mut self, x: f64, y: f64);
+
+    /// Adds a quadratic Bézier curve to the current sub-path.
+    fn quadratic_curve_to(&mut self, cpx: f64, cpy: f64, x: f64, y: f64);
+
+    /// Adds a cubic Bézier curve to the current sub-path.
+    fn bezier_curve_to(&mut self, cp1x: f64, cp1y: f64, cp2x: f64, cp2y: f64, x: f64, y: f64);
+
+    /// Creates a clipping path from the current sub-paths.
+    fn clip(&mut self);
+
+    // Images
+
+    /// Draws a render target.
+    fn draw_render_target(&mut self, render_target: &RenderTarget, x: f64, y: f64);
+
+    /// Draws the image.
+    fn draw_image(&mut self, image: &Self::Image, x: f64, y: f64);
+
+    /// Draws the given part of the image.
+    fn draw_image_with_clip(&mut self, image: &Self::Image, clip: Rectangle, x: f64, y: f64);
+
+    /// Draws the given part of the image stretched to the given size.
+    fn draw_image_with_clip_and_size(
+        &mut self,
+        image: &Self::Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    );
+
+    /// Draws the SVG document with the given size. If a color is given, the document is filled with it.
+    fn draw_svg(
+        &mut self,
+        svg: &Self::Svg,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        color: Option<Color>,
+    );
+
+    /// Draws the given render pipeline.
+    fn draw_pipeline(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        pipeline: Box<dyn PipelineTrait>,
+    );
+
+    /// Draws the shadow of the rectangle with the given corner radii in the order top left, top right, bottom right
+    /// and bottom left.
+    fn draw_box_shadow(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radii: [f64; 4],
+        shadow: &Shadow,
+    );
+
+    // Styles
+
+    /// Sets the thickness of lines.
+    fn set_line_width(&mut self, line_width: f64);
+
+    /// Sets how the ends of lines are drawn.
+    fn set_line_cap(&mut self, line_cap: LineCap);
+
+    /// Sets how connected segments of lines are joined.
+    fn set_line_join(&mut self, line_join: LineJoin);
+
+    /// Sets the limit of the ratio between the length of a miter join and the line width.
+    fn set_miter_limit(&mut self, miter_limit: f64);
+
+    /// Sets the dash pattern of lines as alternating lengths of dashes and gaps.
+    fn set_line_dash(&mut self, segments: Vec<f64>);
+
+    /// Sets the offset of the dash pattern of lines.
+    fn set_line_dash_offset(&mut self, offset: f64);
+
+    /// Sets the rule that determines the inside of a path if it is filled or used as clip.
+    fn set_fill_rule(&mut self, fill_rule: FillRule);
+
+    /// Sets the alpha value.
+    fn set_alpha(&mut self, alpha: f32);
+
+    /// Specifies the font family.
+    fn set_font_family(&mut self, family: &str);
+
+    /// Specifies the font size.
+    fn set_font_size(&mut self, size: f64);
+
+    /// Specifies the brush to use inside shapes.
+    fn set_fill_style(&mut self, fill_style: Brush);
+
+    /// Specifies the brush to use for the outlines of shapes.
+    fn set_stroke_style(&mut self, stroke_style: Brush);
+
+    // Transformations
+
+    /// Sets the transformation. The transformation is applied in logical units.
+    fn set_transform(
+        &mut self,
+        h_scaling: f64,
+        h_skewing: f64,
+        v_skewing: f64,
+        v_scaling: f64,
+        h_moving: f64,
+        v_moving: f64,
+    );
+
+    /// Multiplies the current transformation with the given transformation, so that the content is transformed
+    /// relative to the current transformation.
+    fn transform(
+        &mut self,
+        h_scaling: f64,
+        h_skewing: f64,
+        v_skewing: f64,
+        v_scaling: f64,
+        h_moving: f64,
+        v_moving: f64,
+    );
+
+    // Layers
+
+    /// Redirects all drawing to a new offscreen layer with the given bounds in logical units, until the layer is
+    /// popped.
+    fn push_layer(&mut self, x: f64, y: f64, width: f64, height: f64);
+
+    /// Pops the current layer and draws it with the given alpha value. If a key is given the content of the layer is
+    /// cached.
+    fn pop_layer(&mut self, alpha: f32, key: Option<u64>);
+
+    /// Draws the cached layer with the given key and alpha value.
+    fn draw_layer(&mut self, key: u64, alpha: f32);
+
+    /// Returns `true` if a layer with the given key is cached.
+    fn has_layer(&self, key: u64) -> bool;
+
+    /// Removes the cached layer with the given key.
+    fn remove_layer(&mut self, key: u64);
+
+    /// Removes all cached layers.
+    fn clear_layers(&mut self);
+
+    // State
+
+    /// Saves the entire state of the canvas by pushing the current state onto a stack.
+    fn save(&mut self);
+
+    /// Restores the most recently saved canvas state.
+    fn restore(&mut self);
+
+    /// Fills the whole render context with the given brush.
+    fn clear(&mut self, brush: &Brush);
+}
+
+/// Render backend that draws the `Image` and `Svg` types of the platform. The render objects, layouts and systems of
+/// OrbTk draw on it, because the images of the widgets are platform images.
+pub type PlatformBackend = dyn RenderBackend<Image = Image, Svg = Svg>;
+
+// Implements `RenderBackend` for a render context by calling its inherent methods.
+macro_rules! impl_render_backend {
+    ($render_context:ty) => {
+        impl RenderBackend for $render_context {
+            type Image = Image;
+            type Svg = Svg;
+
+            fn set_background(&mut self, background: Color) {
+                <$render_context>::set_background(self, background)
+            }
+
+            fn resize(&mut self, width: f64, height: f64) {
+                <$render_context>::resize(self, width, height)
+            }
+
+            fn set_scale_factor(&mut self, scale_factor: f64) {
+                <$render_context>::set_scale_factor(self, scale_factor)
+            }
+
+            fn scale_factor(&self) -> f64 {
+                <$render_context>::scale_factor(self)
+            }
+
+            fn start(&mut self) {
+                <$render_context>::start(self)
+            }
+
+            fn start_region(&mut self, region: Rectangle) {
+                <$render_context>::start_region(self, region)
+            }
+
+            fn finish(&mut self) {
+                <$render_context>::finish(self)
+            }
+
+            fn invalidated(&self) -> bool {
+                <$render_context>::invalidated(self)
+            }
+
+            fn take_damage(&mut self) -> Vec<Rectangle> {
+                <$render_context>::take_damage(self)
+            }
+
+            fn register_font(&mut self, family: &str, font_file: &'static [u8]) {
+                <$render_context>::register_font(self, family, font_file)
+            }
+
+            fn has_font(&self, family: &str) -> bool {
+                <$render_context>::has_font(self, family)
+            }
+
+            fn register_font_bytes(
+                &mut self,
+                family: &str,
+                font_data: Arc<Vec<u8>>,
+            ) -> io::Result<()> {
+                <$render_context>::register_font_bytes(self, family, font_data)
+            }
+
+            fn set_font_fallbacks(&mut self, families: Vec<String>) {
+                <$render_context>::set_font_fallbacks(self, families)
+            }
+
+            fn fill_text(&mut self, text: &str, x: f64, y: f64) {
+                <$render_context>::fill_text(self, text, x, y)
+            }
+
+            fn measure(&mut self, text: &str, font_size: f64, family: &str) -> TextMetrics {
+                <$render_context>::measure(self, text, font_size, family)
+            }
+
+            fn measure_text(&mut self, text: &str) -> TextMetrics {
+                <$render_context>::measure_text(self, text)
+            }
+
+            fn measure_clusters(
+                &mut self,
+                text: &str,
+                font_size: f64,
+                family: &str,
+            ) -> Vec<TextCluster> {
+                <$render_context>::measure_clusters(self, text, font_size, family)
+            }
+
+            fn fill_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+                <$render_context>::fill_rect(self, x, y, width, height)
+            }
+
+            fn stroke_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+                <$render_context>::stroke_rect(self, x, y, width, height)
+            }
+
+            fn fill(&mut self) {
+                <$render_context>::fill(self)
+            }
+
+            fn stroke(&mut self) {
+                <$render_context>::stroke(self)
+            }
+
+            fn fill_path(&mut self, path: &Path2D) {
+                <$render_context>::fill_path(self, path)
+            }
+
+            fn stroke_path(&mut self, path: &Path2D) {
+                <$render_context>::stroke_path(self, path)
+            }
+
+            fn clip_path(&mut self, path: &Path2D) {
+                <$render_context>::clip_path(self, path)
+            }
+
+            fn add_path(&mut self, path: &Path2D) {
+                <$render_context>::add_path(self, path)
+            }
+
+            fn begin_path(&mut self) {
+                <$render_context>::begin_path(self)
+            }
+
+            fn close_path(&mut self) {
+                <$render_context>::close_path(self)
+            }
+
+            fn rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+                <$render_context>::rect(self, x, y, width, height)
+            }
+
+            fn arc(&mut self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64) {
+                <$render_context>::arc(self, x, y, radius, start_angle, end_angle)
+            }
+
+            fn arc_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, radius: f64) {
+                <$render_context>::arc_to(self, x1, y1, x2, y2, radius)
+            }
+
+            fn ellipse(
+                &mut self,
+                x: f64,
+                y: f64,
+                radius_x: f64,
+                radius_y: f64,
+                rotation: f64,
+                start_angle: f64,
+                end_angle: f64,
+            ) {
+                <$render_context>::ellipse(
+                    self,
+                    x,
+                    y,
+                    radius_x,
+                    radius_y,
+                    rotation,
+                    start_angle,
+                    end_angle,
+                )
+            }
+
+            fn rounded_rect(&mut self, x: f64, y: f64, width: f64, height: f64, radii: [f64; 4]) {
+                <$render_context>::rounded_rect(self, x, y, width, height, radii)
+            }
+
+            fn move_to(&mut self, x: f64, y: f64) {
+                <$render_context>::move_to(self, x, y)
+            }
+
+            fn line_to(&mut self, x: f64, y: f64) {
+                <$render_context>::line_to(self, x, y)
+            }
+
+            fn quadratic_curve_to(&mut self, cpx: f64, cpy: f64, x: f64, y: f64) {
+                <$render_context>::quadratic_curve_to(self, cpx, cpy, x, y)
+            }
+
+            fn bezier_curve_to(
+                &mut self,
+                cp1x: f64,
+                cp1y: f64,
+                cp2x: f64,
+                cp2y: f64,
+                x: f64,
+                y: f64,
+            ) {
+                <$render_context>::bezier_curve_to(self, cp1x, cp1y, cp2x, cp2y, x, y)
+            }
+
+            fn clip(&mut self) {
+                <$render_context>::clip(self)
+            }
+
+            fn draw_render_target(&mut self, render_target: &RenderTarget, x: f64, y: f64) {
+                <$render_context>::draw_render_target(self, render_target, x, y)
+            }
+
+            fn draw_image(&mut self, image: &Image, x: f64, y: f64) {
+                <$render_context>::draw_image(self, image, x, y)
+            }
+
+            fn draw_image_with_clip(&mut self, image: &Image, clip: Rectangle, x: f64, y: f64) {
+                <$render_context>::draw_image_with_clip(self, image, clip, x, y)
+            }
+
+            fn draw_image_with_clip_and_size(
+                &mut self,
+                image: &Image,
+                clip: Rectangle,
+                x: f64,
+                y: f64,
+                width: f64,
+                height: f64,
+            ) {
+                <$render_context>::draw_image_with_clip_and_size(
+                    self, image, clip, x, y, width, height,
+                )
+            }
+
+            fn draw_svg(
+                &mut self,
+                svg: &Svg,
+                x: f64,
+                y: f64,
+                width: f64,
+                height: f64,
+                color: Option<Color>,
+            ) {
+                <$render_context>::draw_svg(self, svg, x, y, width, height, color)
+            }
+
+            fn draw_pipeline(
+                &mut self,
+                x: f64,
+                y: f64,
+                width: f64,
+                height: f64,
+                pipeline: Box<dyn PipelineTrait>,
+            ) {
+                <$render_context>::draw_pipeline(self, x, y, width, height, pipeline)
+            }
+
+            fn draw_box_shadow(
+                &mut self,
+                x: f64,
+                y: f64,
+                width: f64,
+                height: f64,
+                radii: [f64; 4],
+                shadow: &Shadow,
+            ) {
+                <$render_context>::draw_box_shadow(self, x, y, width, height, radii, shadow)
+            }
+
+            fn set_line_width(&mut self, line_width: f64) {
+                <$render_context>::set_line_width(self, line_width)
+            }
+
+            fn set_line_cap(&mut self, line_cap: LineCap) {
+                <$render_context>::set_line_cap(self, line_cap)
+            }
+
+            fn set_line_join(&mut self, line_join: LineJoin) {
+                <$render_context>::set_line_join(self, line_join)
+            }
+
+            fn set_miter_limit(&mut self, miter_limit: f64) {
+                <$render_context>::set_miter_limit(self, miter_limit)
+            }
+
+            fn set_line_dash(&mut self, segments: Vec<f64>) {
+                <$render_context>::set_line_dash(self, segments)
+            }
+
+            fn set_line_dash_offset(&mut self, offset: f64) {
+                <$render_context>::set_line_dash_offset(self, offset)
+            }
+
+            fn set_fill_rule(&mut self, fill_rule: FillRule) {
+                <$render_context>::set_fill_rule(self, fill_rule)
+            }
+
+            fn set_alpha(&mut self, alpha: f32) {
+                <$render_context>::set_alpha(self, alpha)
+            }
+
+            fn set_font_family(&mut self, family: &str) {
+                <$render_context>::set_font_family(self, family)
+            }
+
+            fn set_font_size(&mut self, size: f64) {
+                <$render_context>::set_font_size(self, size)
+            }
+
+            fn set_fill_style(&mut self, fill_style: Brush) {
+                <$render_context>::set_fill_style(self, fill_style)
+            }
+
+            fn set_stroke_style(&mut self, stroke_style: Brush) {
+                <$render_context>::set_stroke_style(self, stroke_style)
+            }
+
+            fn set_transform(
+                &mut self,
+                h_scaling: f64,
+                h_skewing: f64,
+                v_skewing: f64,
+                v_scaling: f64,
+                h_moving: f64,
+                v_moving: f64,
+            ) {
+                <$render_context>::set_transform(
+                    self, h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+                )
+            }
+
+            fn transform(
+                &mut self,
+                h_scaling: f64,
+                h_skewing: f64,
+                v_skewing: f64,
+                v_scaling: f64,
+                h_moving: f64,
+                v_moving: f64,
+            ) {
+                <$render_context>::transform(
+                    self, h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+                )
+            }
+
+            fn push_layer(&mut self, x: f64, y: f64, width: f64, height: f64) {
+                <$render_context>::push_layer(self, x, y, width, height)
+            }
+
+            fn pop_layer(&mut self, alpha: f32, key: Option<u64>) {
+                <$render_context>::pop_layer(self, alpha, key)
+            }
+
+            fn draw_layer(&mut self, key: u64, alpha: f32) {
+                <$render_context>::draw_layer(self, key, alpha)
+            }
+
+            fn has_layer(&self, key: u64) -> bool {
+                <$render_context>::has_layer(self, key)
+            }
+
+            fn remove_layer(&mut self, key: u64) {
+                <$render_context>::remove_layer(self, key)
+            }
+
+            fn clear_layers(&mut self) {
+                <$render_context>::clear_layers(self)
+            }
+
+            fn save(&mut self) {
+                <$render_context>::save(self)
+            }
+
+            fn restore(&mut self) {
+                <$render_context>::restore(self)
+            }
+
+            fn clear(&mut self, brush: &Brush) {
+                <$render_context>::clear(self, brush)
+            }
+        }
+    };
+}
+
+impl_render_backend!(crate::platform::RenderContext2D);
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "miniraq"))]
+impl_render_backend!(crate::concurrent::RenderContext2D);
@@ -98,6 +98,150 @@ pub fn cubic_bezier_rect(p0: Point, p1: Point, p2: Point, p3: Point) -> Rectangl
     Rectangle::new(mi, Size::new(ma.x() - mi.x(), ma.y() - mi.y()))
 }
 
+/// Calculates the signed sweep of an arc from the start to the end angle in the given direction like the arc of a
+/// html canvas. A difference of a full circle or more results in a full circle.
+pub fn arc_sweep(start_angle: f64, end_angle: f64, anticlockwise: bool) -> f64 {
+    let (difference, sign) = if anticlockwise {
+        (start_angle - end_angle, -1.0)
+    } else {
+        (end_angle - start_angle, 1.0)
+    };
+
+    if difference >= TAU {
+        return sign * TAU;
+    }
+
+    sign * difference.rem_euclid(TAU)
+}
+
+/// Calculates the arc that connects the line from `p0` to `p1` with the line from `p1` to `p2` with the given radius.
+/// Returns the point where the arc starts, its center, its start and end angle and `true` if it is anticlockwise.
+/// Returns `None` if the points are on a line or the radius is zero, the arc is a straight line to `p1` then.
+pub fn arc_to_arc(
+    p0: Point,
+    p1: Point,
+    p2: Point,
+    radius: f64,
+) -> Option<(Point, Point, f64, f64, bool)> {
+    let (v1x, v1y) = (p0.x() - p1.x(), p0.y() - p1.y());
+    let (v2x, v2y) = (p2.x() - p1.x(), p2.y() - p1.y());
+    let len1 = v1x.hypot(v1y);
+    let len2 = v2x.hypot(v2y);
+
+    if radius <= 0.0 || len1 < f64::EPSILON || len2 < f64::EPSILON {
+        return None;
+    }
+
+    let (u1x, u1y) = (v1x / len1, v1y / len1);
+    let (u2x, u2y) = (v2x / len2, v2y / len2);
+    let cross = u1x * u2y - u1y * u2x;
+
+    if cross.abs() < 1e-9 {
+        return None;
+    }
+
+    // half of the angle between both lines
+    let half = (u1x * u2x + u1y * u2y).max(-1.0).min(1.0).acos() / 2.0;
+    let tangent = radius / half.tan();
+    let distance = radius / half.sin();
+
+    let (bx, by) = (u1x + u2x, u1y + u2y);
+    let bisector = bx.hypot(by);
+
+    let start = Point::new(p1.x() + u1x * tangent, p1.y() + u1y * tangent);
+    let end = Point::new(p1.x() + u2x * tangent, p1.y() + u2y * tangent);
+    let center = Point::new(
+        p1.x() + bx / bisector * distance,
+        p1.y() + by / bisector * distance,
+    );
+
+    let start_angle = (start.y() - center.y()).atan2(start.x() - center.x());
+    let end_angle = (end.y() - center.y()).atan2(end.x() - center.x());
+
+    Some((start, center, start_angle, end_angle, cross > 0.0))
+}
+
+/// Gets the point on an ellipse at the given angle.
+pub fn ellipse_point(
+    center: Point,
+    radius_x: f64,
+    radius_y: f64,
+    rotation: f64,
+    angle: f64,
+) -> Point {
+    let (sin, cos) = angle.sin_cos();
+    let (rotation_sin, rotation_cos) = rotation.sin_cos();
+    let (x, y) = (radius_x * cos, radius_y * sin);
+
+    Point::new(
+        center.x() + x * rotation_cos - y * rotation_sin,
+        center.y() + x * rotation_sin + y * rotation_cos,
+    )
+}
+
+/// Approximates an elliptical arc by cubic bezier curves. Returns the start point of the arc and the control points
+/// and end points of the curves.
+pub fn ellipse_curves(
+    center: Point,
+    radius_x: f64,
+    radius_y: f64,
+    rotation: f64,
+    start_angle: f64,
+    sweep: f64,
+) -> (Point, Vec<(Point, Point, Point)>) {
+    let count = (sweep.abs() / FRAC_PI_2).ceil().max(1.0);
+    let step = sweep / count;
+
+    // length of the tangents of a unit circle segment
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+
+    let map = |x: f64, y: f64| {
+        let (rotation_sin, rotation_cos) = rotation.sin_cos();
+        let (x, y) = (radius_x * x, radius_y * y);
+        Point::new(
+            center.x() + x * rotation_cos - y * rotation_sin,
+            center.y() + x * rotation_sin + y * rotation_cos,
+        )
+    };
+
+    let curves = (0..count as usize)
+        .map(|i| {
+            let (sin0, cos0) = (start_angle + step * i as f64).sin_cos();
+            let (sin1, cos1) = (start_angle + step * (i + 1) as f64).sin_cos();
+
+            (
+                map(cos0 - k * sin0, sin0 + k * cos0),
+                map(cos1 + k * sin1, sin1 - k * cos1),
+                map(cos1, sin1),
+            )
+        })
+        .collect();
+
+    (
+        ellipse_point(center, radius_x, radius_y, rotation, start_angle),
+        curves,
+    )
+}
+
+/// Normalizes a dash pattern like the line dash of a html canvas. A pattern with an odd number of values is repeated.
+/// Returns `None` if the pattern contains negative or not finite values.
+pub fn line_dash(segments: Vec<f64>) -> Option<Vec<f64>> {
+    if segments.iter().any(|s| !s.is_finite() || *s < 0.0) {
+        return None;
+    }
+
+    // a pattern without length draws a solid line
+    if segments.iter().all(|s| *s == 0.0) {
+        return Some(vec![]);
+    }
+
+    if segments.len() % 2 == 1 {
+        return Some(segments.iter().chain(segments.iter()).cloned().collect());
+    }
+
+    Some(segments)
+}
+
 /// A object used to keep a record of the AABB of a path.
 #[derive(Debug, Copy, Clone)]
 pub struct PathRect {
@@ -226,6 +370,45 @@ impl PathRect {
         }
     }
 
+    /// Records the drawing of an elliptical arc. The bounds of the complete ellipse are recorded.
+    #[allow(clippy::too_many_arguments)]
+    pub fn record_ellipse(
+        &mut self,
+        x: f64,
+        y: f64,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    ) {
+        let (sin, cos) = rotation.sin_cos();
+        let half_width = (radius_x * cos).hypot(radius_y * sin);
+        let half_height = (radius_x * sin).hypot(radius_y * cos);
+        let r = Rectangle::new(
+            (x - half_width, y - half_height),
+            (half_width * 2.0, half_height * 2.0),
+        );
+
+        if let Some(ref mut path_rect) = self.path_rect {
+            path_rect.join_with_rectangle(&r);
+        } else {
+            self.path_rect = Some(r);
+        }
+
+        let center = Point::new(x, y);
+        if self.first_path_point.is_none() {
+            self.first_path_point = Some(ellipse_point(
+                center,
+                radius_x,
+                radius_y,
+                rotation,
+                start_angle,
+            ));
+        }
+        self.last_path_point = ellipse_point(center, radius_x, radius_y, rotation, end_angle);
+    }
+
     /// Encloses the current `PathRect`.
     pub fn record_clip(&mut self) {
         let mut clip_rect = match self.path_rect {
@@ -257,6 +440,24 @@ impl PathRect {
         })
     }
 
+    /// Gets the last point of the path.
+    pub fn last_point(&self) -> Point {
+        self.last_path_point
+    }
+
+    /// Returns `true` if the path has a current point, that is the case after anything is added to the path.
+    pub fn has_current_point(&self) -> bool {
+        self.first_path_point.is_some()
+    }
+
+    /// Restores the path of the given `PathRect`, the current clip is kept.
+    pub fn restore_path(&mut self, path_rect: PathRect) {
+        *self = PathRect {
+            clip_rect: self.clip_rect,
+            ..path_rect
+        };
+    }
+
     /// Restores itself to a new life of service, if the the path is clipped that state is conserved.
     pub fn rebirth(&mut self) {
         *self = Self::new(self.clip_rect);
@@ -341,9 +542,264 @@ pub fn linear_gradient_ends_from_angle(angle: Angle, size: Size) -> Point {
     z
 }
 
+/// Gets the start and end point of a linear gradient that is drawn in the given frame.
+pub fn linear_gradient_ends(coords: &LinearGradientCoords, frame: Rectangle) -> (Point, Point) {
+    match coords {
+        LinearGradientCoords::Ends { start, end } => {
+            (frame.position() + *start, frame.position() + *end)
+        }
+        LinearGradientCoords::Angle {
+            angle,
+            displacement,
+        } => {
+            let z = linear_gradient_ends_from_angle(*angle, frame.size());
+            let disp = displacement.pixels(frame.size());
+            (
+                frame.position() + frame.size() / 2.0 + -z + disp,
+                frame.position() + frame.size() / 2.0 + z + disp,
+            )
+        }
+        LinearGradientCoords::Direction {
+            direction,
+            displacement,
+        } => {
+            let (start, end) = direction.cross(frame.width(), frame.height());
+            let displacement = displacement.pixels(frame.size());
+            (
+                start + frame.position() + displacement,
+                end + frame.position() + displacement,
+            )
+        }
+    }
+}
+
+/// Blurs the given alpha mask with the given radius in pixels. Three box blurs are applied to approximate
+/// a gaussian blur.
+pub fn box_blur(mask: &mut [u8], width: usize, height: usize, radius: usize) {
+    if radius == 0 || width == 0 || height == 0 {
+        return;
+    }
+
+    let mut line = vec![];
+
+    for _ in 0..3 {
+        for y in 0..height {
+            box_blur_line(mask, y * width, 1, width, radius, &mut line);
+        }
+
+        for x in 0..width {
+            box_blur_line(mask, x, width, height, radius, &mut line);
+        }
+    }
+}
+
+// Blurs one row or column of the mask. Pixels outside of the mask are transparent.
+fn box_blur_line(
+    mask: &mut [u8],
+    start: usize,
+    step: usize,
+    len: usize,
+    radius: usize,
+    line: &mut Vec<u8>,
+) {
+    line.clear();
+    line.extend((0..len).map(|i| mask[start + i * step]));
+
+    let size = (2 * radius + 1) as u32;
+    let mut sum: u32 = line.iter().take(radius + 1).map(|v| *v as u32).sum();
+
+    for i in 0..len {
+        mask[start + i * step] = ((sum + size / 2) / size) as u8;
+
+        if i + radius + 1 < len {
+            sum += line[i + radius + 1] as u32;
+        }
+
+        if i >= radius {
+            sum -= line[i - radius] as u32;
+        }
+    }
+}
+
+/// Converts a region given in logical units to the pixels it covers on a target with the given size in physical
+/// pixels. Returns `None` if the region is outside of the target.
+pub fn pixel_rect(
+    region: Rectangle,
+    scale_factor: f64,
+    width: f64,
+    height: f64,
+) -> Option<Rectangle> {
+    let left = (region.x() * scale_factor).floor().max(0.0);
+    let top = (region.y() * scale_factor).floor().max(0.0);
+    let right = ((region.x() + region.width()) * scale_factor)
+        .ceil()
+        .min(width);
+    let bottom = ((region.y() + region.height()) * scale_factor)
+        .ceil()
+        .min(height);
+
+    if right <= left || bottom <= top {
+        return None;
+    }
+
+    Some(Rectangle::new((left, top), (right - left, bottom - top)))
+}
+
+/// Converts a pixel with straight alpha to a pixel with premultiplied alpha. Both pixels are given as ARGB.
+pub fn premultiply(pixel: u32) -> u32 {
+    let a = pixel >> 24;
+
+    match a {
+        0 => return 0,
+        255 => return pixel,
+        _ => {}
+    }
+
+    let channel = |shift: u32| (((pixel >> shift) & 0xFF) * a + 127) / 255;
+
+    (a << 24) | (channel(16) << 16) | (channel(8) << 8) | channel(0)
+}
+
+/// Converts a pixel with premultiplied alpha to a pixel with straight alpha. Both pixels are given as ARGB.
+pub fn unpremultiply(pixel: u32) -> u32 {
+    let a = pixel >> 24;
+
+    match a {
+        0 => return 0,
+        255 => return pixel,
+        _ => {}
+    }
+
+    let channel = |shift: u32| (((pixel >> shift) & 0xFF) * 255 + a / 2) / a;
+
+    (a << 24) | (channel(16).min(255) << 16) | (channel(8).min(255) << 8) | channel(0).min(255)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::PathRect;
+    use super::{
+        arc_sweep, arc_to_arc, box_blur, ellipse_curves, line_dash, pixel_rect, premultiply,
+        unpremultiply, PathRect, TAU,
+    };
+    use crate::utils::{Point, Rectangle};
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_point_eq(left: Point, right: Point) {
+        assert!(
+            (left.x() - right.x()).abs() < 0.000_01 && (left.y() - right.y()).abs() < 0.000_01,
+            "{:?} != {:?}",
+            left,
+            right
+        );
+    }
+
+    #[test]
+    fn test_arc_sweep() {
+        assert!((arc_sweep(0.0, FRAC_PI_2, false) - FRAC_PI_2).abs() < f64::EPSILON);
+        assert!((arc_sweep(FRAC_PI_2, 0.0, false) - (TAU - FRAC_PI_2)).abs() < 0.000_01);
+        assert!((arc_sweep(FRAC_PI_2, 0.0, true) + FRAC_PI_2).abs() < f64::EPSILON);
+        assert!((arc_sweep(0.0, 3.0 * TAU, false) - TAU).abs() < f64::EPSILON);
+        assert!(arc_sweep(PI, PI, false).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_arc_to_arc() {
+        // a right angle with a corner at (10, 0)
+        let (start, center, start_angle, end_angle, anticlockwise) = arc_to_arc(
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+            5.0,
+        )
+        .unwrap();
+
+        assert_point_eq(start, Point::new(5.0, 0.0));
+        assert_point_eq(center, Point::new(5.0, 5.0));
+        assert!((start_angle + FRAC_PI_2).abs() < 0.000_01);
+        assert!(end_angle.abs() < 0.000_01);
+        assert!(!anticlockwise);
+
+        assert!(arc_to_arc(
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(20.0, 0.0),
+            5.0
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_ellipse_curves() {
+        let (start, curves) = ellipse_curves(Point::new(10.0, 10.0), 20.0, 10.0, 0.0, 0.0, TAU);
+
+        assert_eq!(curves.len(), 4);
+        assert_point_eq(start, Point::new(30.0, 10.0));
+        assert_point_eq(curves[0].2, Point::new(10.0, 20.0));
+        assert_point_eq(curves[3].2, start);
+
+        // the rotation is applied around the center
+        let (start, _) = ellipse_curves(Point::new(0.0, 0.0), 20.0, 10.0, FRAC_PI_2, 0.0, PI);
+        assert_point_eq(start, Point::new(0.0, 20.0));
+    }
+
+    #[test]
+    fn test_line_dash() {
+        assert_eq!(line_dash(vec![5.0, 2.0]), Some(vec![5.0, 2.0]));
+        assert_eq!(line_dash(vec![5.0]), Some(vec![5.0, 5.0]));
+        assert_eq!(line_dash(vec![0.0, 0.0]), Some(vec![]));
+        assert_eq!(line_dash(vec![5.0, -1.0]), None);
+    }
+
+    #[test]
+    fn test_pixel_rect() {
+        // the region is rounded out to whole physical pixels
+        assert_eq!(
+            pixel_rect(Rectangle::new((1.5, 2.2), (3.0, 4.0)), 2.0, 100.0, 100.0),
+            Some(Rectangle::new((3.0, 4.0), (6.0, 9.0)))
+        );
+
+        // and clamped to the target
+        assert_eq!(
+            pixel_rect(
+                Rectangle::new((-5.0, 90.0), (20.0, 20.0)),
+                1.0,
+                100.0,
+                100.0
+            ),
+            Some(Rectangle::new((0.0, 90.0), (15.0, 10.0)))
+        );
+
+        assert_eq!(
+            pixel_rect(
+                Rectangle::new((120.0, 0.0), (20.0, 20.0)),
+                1.0,
+                100.0,
+                100.0
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_box_blur() {
+        let mut mask = vec![0; 81];
+        mask[40] = 255;
+        box_blur(&mut mask, 9, 9, 1);
+
+        // the blur spreads the pixel and keeps it centered
+        assert!(mask[40] < 255);
+        assert!(mask[40] > 0);
+        assert!(mask[39] > 0 && mask[39] == mask[41]);
+        assert!(mask[31] > 0 && mask[31] == mask[49]);
+        assert_eq!(mask[0], 0);
+
+        let mut mask = vec![255; 400];
+        box_blur(&mut mask, 20, 20, 2);
+
+        // the inner of a filled mask is not changed, the borders fade out
+        assert_eq!(mask[210], 255);
+        assert!(mask[0] < 255);
+    }
 
     #[test]
     fn test_pathrect_lines() {
@@ -420,4 +876,35 @@ mod tests {
         assert!(98.0 - urect.width().abs() < f64::EPSILON);
         assert!(185.57550765359252 - urect.height().abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn test_pathrect_current_point() {
+        let mut rect = PathRect::new(None);
+        assert!(!rect.has_current_point());
+
+        rect.record_line_to(10.0, 10.0);
+        assert!(rect.has_current_point());
+
+        rect.rebirth();
+        assert!(!rect.has_current_point());
+    }
+
+    #[test]
+    fn test_premultiply() {
+        assert_eq!(premultiply(0xFF10_2030), 0xFF10_2030);
+        assert_eq!(premultiply(0x0010_2030), 0);
+
+        // half transparent red
+        assert_eq!(premultiply(0x80FF_0000), 0x8080_0000);
+        assert_eq!(unpremultiply(premultiply(0x80FF_4000)), 0x80FF_4000);
+    }
+
+    #[test]
+    fn test_unpremultiply() {
+        assert_eq!(unpremultiply(0xFF10_2030), 0xFF10_2030);
+        assert_eq!(unpremultiply(0x0010_2030), 0);
+
+        // half transparent red
+        assert_eq!(unpremultiply(0x8080_0000), 0x80FF_0000);
+    }
 }
@@ -1,10 +1,15 @@
 use std::{
+    collections::HashSet,
+    io,
+    path::Path,
     sync::{mpsc, Arc, Mutex},
     thread,
 };
 
-use crate::{platform, utils::*, PipelineTrait, RenderTarget, TextMetrics};
-use platform::Image;
+use crate::{
+    font_files, platform, utils::*, Path2D, PipelineTrait, RenderTarget, TextCluster, TextMetrics,
+};
+use platform::{Image, Svg};
 
 #[derive(Clone)]
 struct PipelineWrapper(pub Box<dyn PipelineTrait>);
@@ -20,15 +25,28 @@ impl PartialEq for PipelineWrapper {
 enum RenderTask {
     // Single tasks
     Start(),
+    StartRegion {
+        region: Rectangle,
+    },
     SetBackground(Color),
     Resize {
         width: f64,
         height: f64,
     },
+    SetScaleFactor {
+        scale_factor: f64,
+    },
     RegisterFont {
         family: String,
         font_file: &'static [u8],
     },
+    RegisterFontBytes {
+        family: String,
+        font_data: Arc<Vec<u8>>,
+    },
+    SetFontFallbacks {
+        families: Vec<String>,
+    },
 
     // Multi tasks
     FillRect {
@@ -50,6 +68,18 @@ enum RenderTask {
     },
     Fill(),
     Stroke(),
+    FillPath {
+        path: Path2D,
+    },
+    StrokePath {
+        path: Path2D,
+    },
+    ClipPath {
+        path: Path2D,
+    },
+    AddPath {
+        path: Path2D,
+    },
     BeginPath(),
     ClosePath(),
     Rectangle {
@@ -65,6 +95,29 @@ enum RenderTask {
         start_angle: f64,
         end_angle: f64,
     },
+    ArcTo {
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        radius: f64,
+    },
+    Ellipse {
+        x: f64,
+        y: f64,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    },
+    RoundedRect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radii: [f64; 4],
+    },
     MoveTo {
         x: f64,
         y: f64,
@@ -103,6 +156,22 @@ enum RenderTask {
         x: f64,
         y: f64,
     },
+    DrawImageWithClipAndSize {
+        image: Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    DrawSvg {
+        svg: Svg,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        color: Option<Color>,
+    },
     DrawPipeline {
         x: f64,
         y: f64,
@@ -110,10 +179,36 @@ enum RenderTask {
         height: f64,
         pipeline: PipelineWrapper,
     },
+    DrawBoxShadow {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radii: [f64; 4],
+        shadow: Shadow,
+    },
     Clip(),
     SetLineWidth {
         line_width: f64,
     },
+    SetLineCap {
+        line_cap: LineCap,
+    },
+    SetLineJoin {
+        line_join: LineJoin,
+    },
+    SetMiterLimit {
+        miter_limit: f64,
+    },
+    SetLineDash {
+        segments: Vec<f64>,
+    },
+    SetLineDashOffset {
+        offset: f64,
+    },
+    SetFillRule {
+        fill_rule: FillRule,
+    },
     SetAlpha {
         alpha: f32,
     },
@@ -142,13 +237,34 @@ enum RenderTask {
         h_moving: f64,
         v_moving: f64,
     },
+    PushLayer {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    PopLayer {
+        alpha: f32,
+        key: Option<u64>,
+    },
+    DrawLayer {
+        key: u64,
+        alpha: f32,
+    },
+    RemoveLayer {
+        key: u64,
+    },
+    ClearLayers(),
     Finish(),
     Terminate(),
 }
 
 // Used to send results to the main thread.
 enum RenderResult {
-    Finish { data: Vec<u32> },
+    Finish {
+        data: Vec<u32>,
+        damage: Vec<Rectangle>,
+    },
 }
 
 // Wrapper for the render thread.
@@ -159,12 +275,18 @@ struct RenderWorker {
 fn is_single_tasks(task: &RenderTask) -> bool {
     match task {
         RenderTask::Start() => true,
+        RenderTask::StartRegion { .. } => true,
         RenderTask::SetBackground(_) => true,
         RenderTask::Resize { .. } => true,
+        RenderTask::SetScaleFactor { .. } => true,
         RenderTask::RegisterFont { .. } => true,
+        RenderTask::RegisterFontBytes { .. } => true,
+        RenderTask::SetFontFallbacks { .. } => true,
         RenderTask::DrawRenderTarget { .. } => true,
         RenderTask::DrawImage { .. } => true,
         RenderTask::DrawImageWithClip { .. } => true,
+        RenderTask::DrawImageWithClipAndSize { .. } => true,
+        RenderTask::DrawSvg { .. } => true,
         RenderTask::DrawPipeline { .. } => true,
         RenderTask::SetTransform { .. } => true,
         RenderTask::Terminate { .. } => true,
@@ -196,6 +318,11 @@ impl RenderWorker {
                             render_context_2_d.start();
                             continue;
                         }
+                        RenderTask::StartRegion { region } => {
+                            tasks_collection.clear();
+                            render_context_2_d.start_region(region);
+                            continue;
+                        }
                         RenderTask::SetBackground(background) => {
                             render_context_2_d.set_background(background);
                             continue;
@@ -204,10 +331,24 @@ impl RenderWorker {
                             render_context_2_d.resize(width, height);
                             continue;
                         }
+                        RenderTask::SetScaleFactor { scale_factor } => {
+                            render_context_2_d.set_scale_factor(scale_factor);
+                            continue;
+                        }
                         RenderTask::RegisterFont { family, font_file } => {
                             render_context_2_d.register_font(family.as_str(), font_file);
                             continue;
                         }
+                        RenderTask::RegisterFontBytes { family, font_data } => {
+                            // the font is already validated by the measure context
+                            let _ =
+                                render_context_2_d.register_font_bytes(family.as_str(), font_data);
+                            continue;
+                        }
+                        RenderTask::SetFontFallbacks { families } => {
+                            render_context_2_d.set_font_fallbacks(families);
+                            continue;
+                        }
                         RenderTask::DrawRenderTarget {
                             render_target,
                             x,
@@ -221,6 +362,27 @@ impl RenderWorker {
                         RenderTask::DrawImageWithClip { image, clip, x, y } => {
                             render_context_2_d.draw_image_with_clip(&image, clip, x, y);
                         }
+                        RenderTask::DrawImageWithClipAndSize {
+                            image,
+                            clip,
+                            x,
+                            y,
+                            width,
+                            height,
+                        } => {
+                            render_context_2_d
+                                .draw_image_with_clip_and_size(&image, clip, x, y, width, height);
+                        }
+                        RenderTask::DrawSvg {
+                            svg,
+                            x,
+                            y,
+                            width,
+                            height,
+                            color,
+                        } => {
+                            render_context_2_d.draw_svg(&svg, x, y, width, height, color);
+                        }
                         RenderTask::DrawPipeline {
                             x,
                             y,
@@ -279,6 +441,18 @@ impl RenderWorker {
                             RenderTask::Stroke() => {
                                 render_context_2_d.stroke();
                             }
+                            RenderTask::FillPath { path } => {
+                                render_context_2_d.fill_path(&path);
+                            }
+                            RenderTask::StrokePath { path } => {
+                                render_context_2_d.stroke_path(&path);
+                            }
+                            RenderTask::ClipPath { path } => {
+                                render_context_2_d.clip_path(&path);
+                            }
+                            RenderTask::AddPath { path } => {
+                                render_context_2_d.add_path(&path);
+                            }
                             RenderTask::BeginPath() => {
                                 render_context_2_d.begin_path();
                             }
@@ -302,6 +476,43 @@ impl RenderWorker {
                             } => {
                                 render_context_2_d.arc(x, y, radius, start_angle, end_angle);
                             }
+                            RenderTask::ArcTo {
+                                x1,
+                                y1,
+                                x2,
+                                y2,
+                                radius,
+                            } => {
+                                render_context_2_d.arc_to(x1, y1, x2, y2, radius);
+                            }
+                            RenderTask::Ellipse {
+                                x,
+                                y,
+                                radius_x,
+                                radius_y,
+                                rotation,
+                                start_angle,
+                                end_angle,
+                            } => {
+                                render_context_2_d.ellipse(
+                                    x,
+                                    y,
+                                    radius_x,
+                                    radius_y,
+                                    rotation,
+                                    start_angle,
+                                    end_angle,
+                                );
+                            }
+                            RenderTask::RoundedRect {
+                                x,
+                                y,
+                                width,
+                                height,
+                                radii,
+                            } => {
+                                render_context_2_d.rounded_rect(x, y, width, height, radii);
+                            }
                             RenderTask::MoveTo { x, y } => {
                                 render_context_2_d.move_to(x, y);
                             }
@@ -324,9 +535,38 @@ impl RenderWorker {
                             RenderTask::SetLineWidth { line_width } => {
                                 render_context_2_d.set_line_width(line_width);
                             }
+                            RenderTask::SetLineCap { line_cap } => {
+                                render_context_2_d.set_line_cap(line_cap);
+                            }
+                            RenderTask::SetLineJoin { line_join } => {
+                                render_context_2_d.set_line_join(line_join);
+                            }
+                            RenderTask::SetMiterLimit { miter_limit } => {
+                                render_context_2_d.set_miter_limit(miter_limit);
+                            }
+                            RenderTask::SetLineDash { segments } => {
+                                render_context_2_d.set_line_dash(segments);
+                            }
+                            RenderTask::SetLineDashOffset { offset } => {
+                                render_context_2_d.set_line_dash_offset(offset);
+                            }
+                            RenderTask::SetFillRule { fill_rule } => {
+                                render_context_2_d.set_fill_rule(fill_rule);
+                            }
                             RenderTask::SetAlpha { alpha } => {
                                 render_context_2_d.set_alpha(alpha);
                             }
+                            RenderTask::DrawBoxShadow {
+                                x,
+                                y,
+                                width,
+                                height,
+                                radii,
+                                shadow,
+                            } => {
+                                render_context_2_d
+                                    .draw_box_shadow(x, y, width, height, radii, &shadow);
+                            }
                             RenderTask::Clip() => {
                                 render_context_2_d.clip();
                             }
@@ -351,12 +591,33 @@ impl RenderWorker {
                             RenderTask::Clear { brush } => {
                                 render_context_2_d.clear(&brush);
                             }
+                            RenderTask::PushLayer {
+                                x,
+                                y,
+                                width,
+                                height,
+                            } => {
+                                render_context_2_d.push_layer(x, y, width, height);
+                            }
+                            RenderTask::PopLayer { alpha, key } => {
+                                render_context_2_d.pop_layer(alpha, key);
+                            }
+                            RenderTask::DrawLayer { key, alpha } => {
+                                render_context_2_d.draw_layer(key, alpha);
+                            }
+                            RenderTask::RemoveLayer { key } => {
+                                render_context_2_d.remove_layer(key);
+                            }
+                            RenderTask::ClearLayers() => {
+                                render_context_2_d.clear_layers();
+                            }
                             RenderTask::Finish() => {
                                 sender
                                     .lock()
                                     .unwrap()
                                     .send(RenderResult::Finish {
                                         data: render_context_2_d.data().iter().copied().collect(),
+                                        damage: render_context_2_d.take_damage(),
                                     })
                                     .expect("Could not send render result to main thread.");
                                 finish_sender
@@ -379,12 +640,20 @@ impl RenderWorker {
 /// The RenderContext2D provides a concurrent render ctx.
 pub struct RenderContext2D {
     output: Vec<u32>,
+    width: f64,
+    height: f64,
     worker: RenderWorker,
     sender: mpsc::Sender<Vec<RenderTask>>,
     result_receiver: mpsc::Receiver<RenderResult>,
     finish_receiver: mpsc::Receiver<bool>,
     tasks: Vec<RenderTask>,
     measure_context: platform::RenderContext2D,
+    scale_factor: f64,
+    // the current transform in logical units
+    transform: Transform,
+    invalidated: bool,
+    damage: Vec<Rectangle>,
+    layers: HashSet<u64>,
 }
 
 impl Drop for RenderContext2D {
@@ -413,12 +682,19 @@ impl RenderContext2D {
 
         RenderContext2D {
             output: vec![0; width as usize * height as usize],
+            width,
+            height,
             worker,
             sender,
             result_receiver,
             finish_receiver,
             tasks: vec![],
             measure_context: platform::RenderContext2D::new(width, height),
+            scale_factor: 1.0,
+            transform: Transform::identity(),
+            invalidated: true,
+            damage: vec![],
+            layers: HashSet::new(),
         }
     }
 
@@ -447,6 +723,15 @@ impl RenderContext2D {
         self.sender
             .send(vec![RenderTask::Start()])
             .expect("Could not send start to render thread.");
+        self.invalidated = false;
+    }
+
+    /// Starts a new render pipeline that only redraws the given region in logical units. The region is cleared with
+    /// the background and everything drawn until `finish` is clipped to it.
+    pub fn start_region(&mut self, region: Rectangle) {
+        self.sender
+            .send(vec![RenderTask::StartRegion { region }])
+            .expect("Could not send start region to render thread.");
     }
 
     /// Finishes the current render pipeline.
@@ -455,11 +740,31 @@ impl RenderContext2D {
         self.send_tasks();
     }
 
-    /// Resizes the render ctx.
+    /// Resizes the render ctx. The size is given in physical pixels.
     pub fn resize(&mut self, width: f64, height: f64) {
+        self.width = width;
+        self.height = height;
         self.sender
             .send(vec![RenderTask::Resize { width, height }])
             .expect("Could not send resize to render thread.");
+        // the render thread resets the transform
+        self.transform = Transform::identity();
+        self.invalidated = true;
+    }
+
+    /// Sets the scale factor that describes the ratio between physical pixels and logical units.
+    /// All drawing operations are given in logical units and scaled by this factor.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+        self.sender
+            .send(vec![RenderTask::SetScaleFactor { scale_factor }])
+            .expect("Could not send scale factor to render thread.");
+        self.transform = Transform::identity();
+    }
+
+    /// Gets the current scale factor.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
     }
 
     /// Registers a new font file.
@@ -473,6 +778,57 @@ impl RenderContext2D {
             .expect("Could not send register font to render thread.");
     }
 
+    /// Returns `true` if a font of the given family is registered.
+    pub fn has_font(&self, family: &str) -> bool {
+        self.measure_context.has_font(family)
+    }
+
+    /// Registers a new font from owned bytes, e.g. a font that is loaded at runtime. An already registered font
+    /// of the family is replaced.
+    pub fn register_font_bytes(
+        &mut self,
+        family: &str,
+        font_data: impl Into<Arc<Vec<u8>>>,
+    ) -> io::Result<()> {
+        let font_data = font_data.into();
+        self.measure_context
+            .register_font_bytes(family, font_data.clone())?;
+        self.sender
+            .send(vec![RenderTask::RegisterFontBytes {
+                family: family.to_string(),
+                font_data,
+            }])
+            .expect("Could not send register font to render thread.");
+        Ok(())
+    }
+
+    /// Loads and registers the font file of the given path.
+    pub fn register_font_file(&mut self, family: &str, path: impl AsRef<Path>) -> io::Result<()> {
+        self.register_font_bytes(family, std::fs::read(path)?)
+    }
+
+    /// Loads all font files (`ttf`, `otf`) of the given directory. The file name without extension is used as
+    /// family key. Returns the families of the registered fonts.
+    pub fn register_font_directory(&mut self, path: impl AsRef<Path>) -> io::Result<Vec<String>> {
+        let mut families = vec![];
+
+        for (family, file) in font_files(path)? {
+            if self.register_font_file(&family, file).is_ok() {
+                families.push(family);
+            }
+        }
+
+        Ok(families)
+    }
+
+    /// Sets the font families that are used in the given order for chars, that are missing in the selected font family.
+    pub fn set_font_fallbacks(&mut self, families: Vec<String>) {
+        self.measure_context.set_font_fallbacks(families.clone());
+        self.sender
+            .send(vec![RenderTask::SetFontFallbacks { families }])
+            .expect("Could not send font fallbacks to render thread.");
+    }
+
     // Rectangles
 
     /// Draws a filled rectangle whose starting point is at the coordinates {x, y} with the
@@ -523,6 +879,17 @@ impl RenderContext2D {
         self.measure_context.measure_text(text)
     }
 
+    /// Gets the clusters of the shaped text in logical order. The start and end of a cluster are byte indices of the text.
+    pub fn measure_clusters(
+        &mut self,
+        text: &str,
+        font_size: f64,
+        family: impl Into<String>,
+    ) -> Vec<TextCluster> {
+        self.measure_context
+            .measure_clusters(text, font_size, family)
+    }
+
     /// Fills the current or given path with the current file style.
     pub fn fill(&mut self) {
         self.tasks.push(RenderTask::Fill());
@@ -533,6 +900,27 @@ impl RenderContext2D {
         self.tasks.push(RenderTask::Stroke());
     }
 
+    /// Fills the given path with the current fill style. The current path is not changed.
+    pub fn fill_path(&mut self, path: &Path2D) {
+        self.tasks.push(RenderTask::FillPath { path: path.clone() });
+    }
+
+    /// Strokes the given path with the current stroke style. The current path is not changed.
+    pub fn stroke_path(&mut self, path: &Path2D) {
+        self.tasks
+            .push(RenderTask::StrokePath { path: path.clone() });
+    }
+
+    /// Creates a clipping path from the given path. The current path is not changed.
+    pub fn clip_path(&mut self, path: &Path2D) {
+        self.tasks.push(RenderTask::ClipPath { path: path.clone() });
+    }
+
+    /// Adds the segments of the given path to the current path.
+    pub fn add_path(&mut self, path: &Path2D) {
+        self.tasks.push(RenderTask::AddPath { path: path.clone() });
+    }
+
     /// Starts a new path by emptying the list of sub-paths. Call this when you want to create a new path.
     pub fn begin_path(&mut self) {
         self.send_tasks();
@@ -567,6 +955,54 @@ impl RenderContext2D {
         });
     }
 
+    /// Adds a circular arc with the given radius, that connects the line from the current point to (x1, y1) with
+    /// the line from (x1, y1) to (x2, y2). If the lines are parallel, a straight line to (x1, y1) is added.
+    pub fn arc_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, radius: f64) {
+        self.tasks.push(RenderTask::ArcTo {
+            x1,
+            y1,
+            x2,
+            y2,
+            radius,
+        });
+    }
+
+    /// Creates an elliptical arc centered at (x, y) and rotated by rotation. The path starts at startAngle and ends
+    /// at endAngle.
+    #[allow(clippy::too_many_arguments)]
+    pub fn ellipse(
+        &mut self,
+        x: f64,
+        y: f64,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    ) {
+        self.tasks.push(RenderTask::Ellipse {
+            x,
+            y,
+            radius_x,
+            radius_y,
+            rotation,
+            start_angle,
+            end_angle,
+        });
+    }
+
+    /// Adds a rectangle with rounded corners to the current path. The radii are given in the order top left, top
+    /// right, bottom right and bottom left.
+    pub fn rounded_rect(&mut self, x: f64, y: f64, width: f64, height: f64, radii: [f64; 4]) {
+        self.tasks.push(RenderTask::RoundedRect {
+            x,
+            y,
+            width,
+            height,
+            radii,
+        });
+    }
+
     /// Begins a new sub-path at the point specified by the given {x, y} coordinates.
 
     pub fn move_to(&mut self, x: f64, y: f64) {
@@ -613,7 +1049,7 @@ impl RenderContext2D {
     }
 
     /// Draws the image.
-    pub fn draw_image(&mut self, image: &mut Image, x: f64, y: f64) {
+    pub fn draw_image(&mut self, image: &Image, x: f64, y: f64) {
         self.sender
             .send(vec![RenderTask::DrawImage {
                 image: image.clone(),
@@ -624,7 +1060,7 @@ impl RenderContext2D {
     }
 
     /// Draws the given part of the image.
-    pub fn draw_image_with_clip(&mut self, image: &mut Image, clip: Rectangle, x: f64, y: f64) {
+    pub fn draw_image_with_clip(&mut self, image: &Image, clip: Rectangle, x: f64, y: f64) {
         self.sender
             .send(vec![RenderTask::DrawImageWithClip {
                 image: image.clone(),
@@ -635,6 +1071,50 @@ impl RenderContext2D {
             .expect("Could not send clipped image to render thread.");
     }
 
+    /// Draws the given part of the image stretched to the given size.
+    pub fn draw_image_with_clip_and_size(
+        &mut self,
+        image: &Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        self.sender
+            .send(vec![RenderTask::DrawImageWithClipAndSize {
+                image: image.clone(),
+                clip,
+                x,
+                y,
+                width,
+                height,
+            }])
+            .expect("Could not send stretched image to render thread.");
+    }
+
+    /// Draws the SVG document with the given size. If a color is given, the document is filled with it.
+    pub fn draw_svg(
+        &mut self,
+        svg: &Svg,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        color: Option<Color>,
+    ) {
+        self.sender
+            .send(vec![RenderTask::DrawSvg {
+                svg: svg.clone(),
+                x,
+                y,
+                width,
+                height,
+                color,
+            }])
+            .expect("Could not send svg to render thread.");
+    }
+
     pub fn draw_pipeline(
         &mut self,
         x: f64,
@@ -654,6 +1134,27 @@ impl RenderContext2D {
             .expect("Could not send draw_pipeline to render thread.");
     }
 
+    /// Draws the shadow of the rectangle with the given corner radii. An outer shadow is drawn around the rectangle
+    /// and is not painted below of it, an inset shadow is drawn inside of the rectangle.
+    pub fn draw_box_shadow(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radii: [f64; 4],
+        shadow: &Shadow,
+    ) {
+        self.tasks.push(RenderTask::DrawBoxShadow {
+            x,
+            y,
+            width,
+            height,
+            radii,
+            shadow: *shadow,
+        });
+    }
+
     /// Creates a clipping path from the current sub-paths.
     /// Everything drawn after clip() is called appears inside the clipping path only.
     pub fn clip(&mut self) {
@@ -667,6 +1168,37 @@ impl RenderContext2D {
         self.tasks.push(RenderTask::SetLineWidth { line_width });
     }
 
+    /// Sets the shape used to draw the end points of lines.
+    pub fn set_line_cap(&mut self, line_cap: LineCap) {
+        self.tasks.push(RenderTask::SetLineCap { line_cap });
+    }
+
+    /// Sets the shape used to join two line segments where they meet.
+    pub fn set_line_join(&mut self, line_join: LineJoin) {
+        self.tasks.push(RenderTask::SetLineJoin { line_join });
+    }
+
+    /// Sets the miter limit ratio. Values that are not positive are ignored.
+    pub fn set_miter_limit(&mut self, miter_limit: f64) {
+        self.tasks.push(RenderTask::SetMiterLimit { miter_limit });
+    }
+
+    /// Sets the line dash pattern used when stroking lines. An empty list draws solid lines. If the number of
+    /// elements is odd, the elements are repeated. Patterns with negative values are ignored.
+    pub fn set_line_dash(&mut self, segments: Vec<f64>) {
+        self.tasks.push(RenderTask::SetLineDash { segments });
+    }
+
+    /// Sets the offset of the line dash pattern.
+    pub fn set_line_dash_offset(&mut self, offset: f64) {
+        self.tasks.push(RenderTask::SetLineDashOffset { offset });
+    }
+
+    /// Sets the rule that determines if a point is inside of a path on filling and clipping.
+    pub fn set_fill_rule(&mut self, fill_rule: FillRule) {
+        self.tasks.push(RenderTask::SetFillRule { fill_rule });
+    }
+
     /// Sets the alpha value,
     pub fn set_alpha(&mut self, alpha: f32) {
         self.tasks.push(RenderTask::SetAlpha { alpha });
@@ -687,16 +1219,14 @@ impl RenderContext2D {
 
     /// Specifies the fill color to use inside shapes.
     pub fn set_fill_style(&mut self, fill_style: impl Into<Brush>) {
-        self.tasks.push(RenderTask::SetFillStyle {
-            fill_style: fill_style.into(),
-        });
+        let fill_style = fill_style.into();
+        self.tasks.push(RenderTask::SetFillStyle { fill_style });
     }
 
     /// Specifies the fill stroke to use inside shapes.
     pub fn set_stroke_style(&mut self, stroke_style: impl Into<Brush>) {
-        self.tasks.push(RenderTask::SetStrokeStyle {
-            stroke_style: stroke_style.into(),
-        });
+        let stroke_style = stroke_style.into();
+        self.tasks.push(RenderTask::SetStrokeStyle { stroke_style });
     }
 
     // Transformations
@@ -711,6 +1241,9 @@ impl RenderContext2D {
         h_moving: f64,
         v_moving: f64,
     ) {
+        self.transform = Transform::new(
+            h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+        );
         self.tasks.push(RenderTask::SetTransform {
             h_scaling,
             h_skewing,
@@ -721,6 +1254,32 @@ impl RenderContext2D {
         });
     }
 
+    /// Multiplies the current transformation with the given transformation, so that the content is transformed
+    /// relative to the current transformation.
+    pub fn transform(
+        &mut self,
+        h_scaling: f64,
+        h_skewing: f64,
+        v_skewing: f64,
+        v_scaling: f64,
+        h_moving: f64,
+        v_moving: f64,
+    ) {
+        let transform = Transform::new(
+            h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+        )
+        .then(&self.transform);
+
+        self.set_transform(
+            transform.m11,
+            transform.m12,
+            transform.m21,
+            transform.m22,
+            transform.m31,
+            transform.m32,
+        );
+    }
+
     // Canvas states
 
     /// Saves the entire state of the canvas by pushing the current state onto a stack.
@@ -739,15 +1298,84 @@ impl RenderContext2D {
         self.tasks.push(RenderTask::Clear { brush });
     }
 
+    // Layers
+
+    /// Redirects all drawing to a new offscreen layer with the given bounds in logical units, until the layer is
+    /// popped. Drawing outside of the bounds is clipped.
+    pub fn push_layer(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.tasks.push(RenderTask::PushLayer {
+            x,
+            y,
+            width,
+            height,
+        });
+    }
+
+    /// Pops the current layer and draws it with the given alpha value. If a key is given the content of the layer is
+    /// cached and could be drawn again with `draw_layer` until it is removed.
+    pub fn pop_layer(&mut self, alpha: f32, key: Option<u64>) {
+        if let Some(key) = key {
+            self.layers.insert(key);
+        }
+
+        self.tasks.push(RenderTask::PopLayer { alpha, key });
+    }
+
+    /// Draws the cached layer with the given key and alpha value.
+    pub fn draw_layer(&mut self, key: u64, alpha: f32) {
+        self.tasks.push(RenderTask::DrawLayer { key, alpha });
+    }
+
+    /// Returns `true` if a layer with the given key is cached.
+    pub fn has_layer(&self, key: u64) -> bool {
+        self.layers.contains(&key)
+    }
+
+    /// Removes the cached layer with the given key.
+    pub fn remove_layer(&mut self, key: u64) {
+        if self.layers.remove(&key) {
+            self.tasks.push(RenderTask::RemoveLayer { key });
+        }
+    }
+
+    /// Removes all cached layers.
+    pub fn clear_layers(&mut self) {
+        self.layers.clear();
+        self.tasks.push(RenderTask::ClearLayers());
+    }
+
     pub fn data(&mut self) -> Option<&[u32]> {
-        if let Ok(RenderResult::Finish { data }) = self.result_receiver.recv() {
+        if let Ok(RenderResult::Finish { data, damage }) = self.result_receiver.recv() {
             self.output = data;
+            self.damage.extend(damage);
             Some(&self.output)
         } else {
             None
         }
     }
 
+    /// Returns `true` if the content of the render context is not valid anymore, e.g. after a resize, and has to be
+    /// redrawn completely with `start`.
+    pub fn invalidated(&self) -> bool {
+        self.invalidated
+    }
+
+    /// Takes the regions in physical pixels that were redrawn since the last call. The regions of a render pipeline
+    /// are available after its data was received.
+    pub fn take_damage(&mut self) -> Vec<Rectangle> {
+        std::mem::take(&mut self.damage)
+    }
+
+    /// Gets the content of the render context as image. It waits for the render pipeline
+    /// that is completed by `finish`.
+    pub fn to_image(&mut self) -> Image {
+        let (width, height) = (self.width as u32, self.height as u32);
+
+        self.data()
+            .and_then(|data| Image::from_data(width, height, data.to_vec()).ok())
+            .unwrap_or_default()
+    }
+
     pub fn data_mut(&mut self) -> &mut [u32] {
         &mut self.output
     }
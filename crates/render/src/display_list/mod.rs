@@ -0,0 +1,525 @@
+use crate::{
+    platform::{Image, Svg},
+    utils::*,
+    Path2D, PipelineTrait, PlatformBackend, RenderTarget,
+};
+
+pub use self::recorder::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+use self::vector::draw_vector;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod pdf;
+mod recorder;
+#[cfg(not(target_arch = "wasm32"))]
+mod svg;
+#[cfg(not(target_arch = "wasm32"))]
+mod vector;
+
+/// Describes a single drawing call of a render backend, that is recorded in a `DisplayList`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DrawCommand {
+    FillRect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    StrokeRect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    FillText {
+        text: String,
+        x: f64,
+        y: f64,
+    },
+    Fill,
+    Stroke,
+    FillPath {
+        path: Path2D,
+    },
+    StrokePath {
+        path: Path2D,
+    },
+    ClipPath {
+        path: Path2D,
+    },
+    AddPath {
+        path: Path2D,
+    },
+    BeginPath,
+    ClosePath,
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    Arc {
+        x: f64,
+        y: f64,
+        radius: f64,
+        start_angle: f64,
+        end_angle: f64,
+    },
+    ArcTo {
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        radius: f64,
+    },
+    Ellipse {
+        x: f64,
+        y: f64,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    },
+    RoundedRect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radii: [f64; 4],
+    },
+    MoveTo {
+        x: f64,
+        y: f64,
+    },
+    LineTo {
+        x: f64,
+        y: f64,
+    },
+    QuadraticCurveTo {
+        cpx: f64,
+        cpy: f64,
+        x: f64,
+        y: f64,
+    },
+    BezierCurveTo {
+        cp1x: f64,
+        cp1y: f64,
+        cp2x: f64,
+        cp2y: f64,
+        x: f64,
+        y: f64,
+    },
+    DrawRenderTarget {
+        render_target: RenderTarget,
+        x: f64,
+        y: f64,
+    },
+    DrawImage {
+        image: Image,
+        x: f64,
+        y: f64,
+    },
+    DrawImageWithClip {
+        image: Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+    },
+    DrawImageWithClipAndSize {
+        image: Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    DrawSvg {
+        svg: Svg,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        color: Option<Color>,
+    },
+    DrawPipeline {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        pipeline: Box<dyn PipelineTrait>,
+    },
+    DrawBoxShadow {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radii: [f64; 4],
+        shadow: Shadow,
+    },
+    Clip,
+    SetLineWidth {
+        line_width: f64,
+    },
+    SetLineCap {
+        line_cap: LineCap,
+    },
+    SetLineJoin {
+        line_join: LineJoin,
+    },
+    SetMiterLimit {
+        miter_limit: f64,
+    },
+    SetLineDash {
+        segments: Vec<f64>,
+    },
+    SetLineDashOffset {
+        offset: f64,
+    },
+    SetFillRule {
+        fill_rule: FillRule,
+    },
+    SetAlpha {
+        alpha: f32,
+    },
+    SetFontFamily {
+        family: String,
+    },
+    SetFontSize {
+        size: f64,
+    },
+    SetFillStyle {
+        fill_style: Brush,
+    },
+    SetStrokeStyle {
+        stroke_style: Brush,
+    },
+    SetTransform {
+        h_scaling: f64,
+        h_skewing: f64,
+        v_skewing: f64,
+        v_scaling: f64,
+        h_moving: f64,
+        v_moving: f64,
+    },
+    PushLayer {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    PopLayer {
+        alpha: f32,
+    },
+    Save,
+    Restore,
+}
+
+/// Holds the drawing calls that are recorded by a `DisplayListRecorder`. The display list could be replayed on a
+/// render context or exported as SVG or PDF document (not on the web), so widgets could produce vector output from
+/// the same render objects that draw them on the screen.
+///
+/// The size of the display list is given in logical units.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DisplayList {
+    width: f64,
+    height: f64,
+    commands: Vec<DrawCommand>,
+}
+
+impl DisplayList {
+    /// Creates a new empty display list with the given size.
+    pub fn new(width: f64, height: f64) -> Self {
+        DisplayList {
+            width,
+            height,
+            commands: vec![],
+        }
+    }
+
+    /// Gets the width.
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    /// Gets the height.
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Gets the recorded commands.
+    pub fn commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+
+    /// Returns `true` if no command is recorded.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Adds a command to the display list.
+    pub fn push(&mut self, command: DrawCommand) {
+        self.commands.push(command);
+    }
+
+    /// Draws the recorded commands on the given render context.
+    pub fn replay(&self, render_context: &mut PlatformBackend) {
+        for command in &self.commands {
+            match command.clone() {
+                DrawCommand::FillRect {
+                    x,
+                    y,
+                    width,
+                    height,
+                } => render_context.fill_rect(x, y, width, height),
+                DrawCommand::StrokeRect {
+                    x,
+                    y,
+                    width,
+                    height,
+                } => render_context.stroke_rect(x, y, width, height),
+                DrawCommand::FillText { text, x, y } => render_context.fill_text(&text, x, y),
+                DrawCommand::Fill => render_context.fill(),
+                DrawCommand::Stroke => render_context.stroke(),
+                DrawCommand::FillPath { path } => render_context.fill_path(&path),
+                DrawCommand::StrokePath { path } => render_context.stroke_path(&path),
+                DrawCommand::ClipPath { path } => render_context.clip_path(&path),
+                DrawCommand::AddPath { path } => render_context.add_path(&path),
+                DrawCommand::BeginPath => render_context.begin_path(),
+                DrawCommand::ClosePath => render_context.close_path(),
+                DrawCommand::Rect {
+                    x,
+                    y,
+                    width,
+                    height,
+                } => render_context.rect(x, y, width, height),
+                DrawCommand::Arc {
+                    x,
+                    y,
+                    radius,
+                    start_angle,
+                    end_angle,
+                } => render_context.arc(x, y, radius, start_angle, end_angle),
+                DrawCommand::ArcTo {
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    radius,
+                } => render_context.arc_to(x1, y1, x2, y2, radius),
+                DrawCommand::Ellipse {
+                    x,
+                    y,
+                    radius_x,
+                    radius_y,
+                    rotation,
+                    start_angle,
+                    end_angle,
+                } => render_context.ellipse(
+                    x,
+                    y,
+                    radius_x,
+                    radius_y,
+                    rotation,
+                    start_angle,
+                    end_angle,
+                ),
+                DrawCommand::RoundedRect {
+                    x,
+                    y,
+                    width,
+                    height,
+                    radii,
+                } => render_context.rounded_rect(x, y, width, height, radii),
+                DrawCommand::MoveTo { x, y } => render_context.move_to(x, y),
+                DrawCommand::LineTo { x, y } => render_context.line_to(x, y),
+                DrawCommand::QuadraticCurveTo { cpx, cpy, x, y } => {
+                    render_context.quadratic_curve_to(cpx, cpy, x, y)
+                }
+                DrawCommand::BezierCurveTo {
+                    cp1x,
+                    cp1y,
+                    cp2x,
+                    cp2y,
+                    x,
+                    y,
+                } => render_context.bezier_curve_to(cp1x, cp1y, cp2x, cp2y, x, y),
+                DrawCommand::DrawRenderTarget {
+                    render_target,
+                    x,
+                    y,
+                } => render_context.draw_render_target(&render_target, x, y),
+                DrawCommand::DrawImage { image, x, y } => render_context.draw_image(&image, x, y),
+                DrawCommand::DrawImageWithClip { image, clip, x, y } => {
+                    render_context.draw_image_with_clip(&image, clip, x, y)
+                }
+                DrawCommand::DrawImageWithClipAndSize {
+                    image,
+                    clip,
+                    x,
+                    y,
+                    width,
+                    height,
+                } => {
+                    render_context.draw_image_with_clip_and_size(&image, clip, x, y, width, height)
+                }
+                DrawCommand::DrawSvg {
+                    svg,
+                    x,
+                    y,
+                    width,
+                    height,
+                    color,
+                } => render_context.draw_svg(&svg, x, y, width, height, color),
+                DrawCommand::DrawPipeline {
+                    x,
+                    y,
+                    width,
+                    height,
+                    pipeline,
+                } => render_context.draw_pipeline(x, y, width, height, pipeline),
+                DrawCommand::DrawBoxShadow {
+                    x,
+                    y,
+                    width,
+                    height,
+                    radii,
+                    shadow,
+                } => render_context.draw_box_shadow(x, y, width, height, radii, &shadow),
+                DrawCommand::Clip => render_context.clip(),
+                DrawCommand::SetLineWidth { line_width } => {
+                    render_context.set_line_width(line_width)
+                }
+                DrawCommand::SetLineCap { line_cap } => render_context.set_line_cap(line_cap),
+                DrawCommand::SetLineJoin { line_join } => render_context.set_line_join(line_join),
+                DrawCommand::SetMiterLimit { miter_limit } => {
+                    render_context.set_miter_limit(miter_limit)
+                }
+                DrawCommand::SetLineDash { segments } => render_context.set_line_dash(segments),
+                DrawCommand::SetLineDashOffset { offset } => {
+                    render_context.set_line_dash_offset(offset)
+                }
+                DrawCommand::SetFillRule { fill_rule } => render_context.set_fill_rule(fill_rule),
+                DrawCommand::SetAlpha { alpha } => render_context.set_alpha(alpha),
+                DrawCommand::SetFontFamily { family } => render_context.set_font_family(&family),
+                DrawCommand::SetFontSize { size } => render_context.set_font_size(size),
+                DrawCommand::SetFillStyle { fill_style } => {
+                    render_context.set_fill_style(fill_style)
+                }
+                DrawCommand::SetStrokeStyle { stroke_style } => {
+                    render_context.set_stroke_style(stroke_style)
+                }
+                DrawCommand::SetTransform {
+                    h_scaling,
+                    h_skewing,
+                    v_skewing,
+                    v_scaling,
+                    h_moving,
+                    v_moving,
+                } => render_context.set_transform(
+                    h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+                ),
+                DrawCommand::PushLayer {
+                    x,
+                    y,
+                    width,
+                    height,
+                } => render_context.push_layer(x, y, width, height),
+                DrawCommand::PopLayer { alpha } => render_context.pop_layer(alpha, None),
+                DrawCommand::Save => render_context.save(),
+                DrawCommand::Restore => render_context.restore(),
+            }
+        }
+    }
+
+    /// Exports the display list as SVG document.
+    ///
+    /// Text is drawn with the font family of the render context and solid colors like on the screen, conic gradients
+    /// are drawn with the color of their first stop. Images are embedded as PNG, SVG images keep their vector data.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn to_svg(&self) -> String {
+        let mut writer = svg::SvgWriter::new(self.width, self.height);
+        draw_vector(self, &mut writer);
+        writer.finish()
+    }
+
+    /// Exports the display list as PDF document with a single page in the size of the display list, a logical unit
+    /// is exported as point.
+    ///
+    /// Gradients are drawn with the color of their first stop, box shadows are not exported and text is drawn with
+    /// the standard font Helvetica. SVG images are rasterized. Layers become transparency groups, that are drawn with
+    /// the alpha of the layer.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn to_pdf(&self) -> Vec<u8> {
+        let mut writer = pdf::PdfWriter::new(self.width, self.height);
+        draw_vector(self, &mut writer);
+        writer.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RenderBackend;
+
+    fn display_list() -> DisplayList {
+        let mut display_list = DisplayList::new(100.0, 50.0);
+        display_list.push(DrawCommand::SetFillStyle {
+            fill_style: Brush::from("#ff0000"),
+        });
+        display_list.push(DrawCommand::FillRect {
+            x: 10.0,
+            y: 10.0,
+            width: 20.0,
+            height: 20.0,
+        });
+        display_list.push(DrawCommand::FillText {
+            text: "a < b".to_string(),
+            x: 40.0,
+            y: 10.0,
+        });
+        display_list
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let svg = display_list().to_svg();
+
+        assert!(svg.contains("viewBox=\"0 0 100 50\""));
+        assert!(svg.contains("d=\"M10 10 L30 10 L30 30 L10 30 Z\""));
+        assert!(svg.contains("fill=\"#ff0000\""));
+        assert!(svg.contains(">a &lt; b</text>"));
+    }
+
+    #[test]
+    fn test_to_pdf() {
+        let pdf = String::from_utf8_lossy(&display_list().to_pdf()).to_string();
+
+        assert!(pdf.starts_with("%PDF-"));
+        assert!(pdf.contains("/MediaBox [0 0 100 50]"));
+        assert!(pdf.contains("10 10 m 30 10 l 30 30 l 10 30 l h"));
+        assert!(pdf.contains("(a < b) Tj"));
+        assert!(pdf.ends_with("%%EOF\n"));
+    }
+
+    #[test]
+    fn test_record_transform() {
+        let mut recorder = DisplayListRecorder::new(10.0, 10.0);
+        recorder.set_transform(1.0, 0.0, 0.0, 1.0, 10.0, 5.0);
+        recorder.transform(2.0, 0.0, 0.0, 2.0, 1.0, 0.0);
+
+        // the relative transform is recorded as the resulting transform
+        assert_eq!(
+            recorder.display_list().commands()[1],
+            DrawCommand::SetTransform {
+                h_scaling: 2.0,
+                h_skewing: 0.0,
+                v_skewing: 0.0,
+                v_scaling: 2.0,
+                h_moving: 11.0,
+                v_moving: 5.0,
+            }
+        );
+    }
+}
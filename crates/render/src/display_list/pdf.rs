@@ -0,0 +1,471 @@
+use std::mem;
+
+use crate::{platform::Image, utils::*};
+
+use super::vector::*;
+
+/// Writes the resolved operations of a display list as PDF document with a single page.
+pub struct PdfWriter {
+    width: f64,
+    height: f64,
+    content: String,
+    alphas: Vec<f32>,
+    images: Vec<Image>,
+    // the content of the finished layers, each layer is a form with a transparency group
+    forms: Vec<String>,
+    // the alpha and the content of the parent of each open layer
+    layers: Vec<(f32, String)>,
+}
+
+impl PdfWriter {
+    /// Creates a new writer for a page with the given size.
+    pub fn new(width: f64, height: f64) -> Self {
+        PdfWriter {
+            width,
+            height,
+            // the y axis of the page points upwards
+            content: format!("1 0 0 -1 0 {} cm\n", number(height)),
+            alphas: vec![],
+            images: vec![],
+            forms: vec![],
+            layers: vec![],
+        }
+    }
+
+    /// Finishes the document and returns its content.
+    pub fn finish(mut self) -> Vec<u8> {
+        while !self.layers.is_empty() {
+            self.pop_layer();
+        }
+
+        let mut document = Document::default();
+
+        let image_ids: Vec<usize> = (0..self.images.len()).map(|i| 6 + 2 * i).collect();
+        let form_ids: Vec<usize> = (0..self.forms.len())
+            .map(|i| 6 + 2 * self.images.len() + i)
+            .collect();
+        let x_objects: String = image_ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| format!("/Im{} {} 0 R ", i, id))
+            .chain(
+                form_ids
+                    .iter()
+                    .enumerate()
+                    .map(|(i, id)| format!("/Fm{} {} 0 R ", i, id)),
+            )
+            .collect();
+        let ext_g_states: String = self
+            .alphas
+            .iter()
+            .enumerate()
+            .map(|(i, alpha)| {
+                let alpha = number(*alpha as f64);
+                format!("/GS{} << /ca {} /CA {} >> ", i, alpha, alpha)
+            })
+            .collect();
+        let resources = format!(
+            "<< /Font << /F1 5 0 R >> /XObject << {}>> /ExtGState << {}>> >>",
+            x_objects, ext_g_states
+        );
+
+        document.object(b"<< /Type /Catalog /Pages 2 0 R >>".to_vec());
+        document.object(b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec());
+        document.object(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Contents 4 0 R /Resources {} >>",
+                number(self.width),
+                number(self.height),
+                resources,
+            )
+            .into_bytes(),
+        );
+        document.object(stream("", self.content.as_bytes()));
+        document.object(
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+        );
+
+        for (image, id) in self.images.iter().zip(image_ids) {
+            let mut color = Vec::with_capacity(image.data().len() * 3);
+            let mut alpha = Vec::with_capacity(image.data().len());
+
+            for pixel in image.to_rgba_image().pixels() {
+                color.extend_from_slice(&pixel.0[..3]);
+                alpha.push(pixel.0[3]);
+            }
+
+            let size = format!(
+                "/Width {} /Height {} /BitsPerComponent 8",
+                image.width() as u32,
+                image.height() as u32
+            );
+
+            document.object(stream(
+                &format!(
+                    "/Type /XObject /Subtype /Image {} /ColorSpace /DeviceRGB /SMask {} 0 R",
+                    size,
+                    id + 1
+                ),
+                &color,
+            ));
+            document.object(stream(
+                &format!(
+                    "/Type /XObject /Subtype /Image {} /ColorSpace /DeviceGray",
+                    size
+                ),
+                &alpha,
+            ));
+        }
+
+        // the layers are drawn in the flipped coordinate system of the page
+        for form in &self.forms {
+            document.object(stream(
+                &format!(
+                    "/Type /XObject /Subtype /Form /BBox [0 0 {} {}] /Group << /S /Transparency >> /Resources {}",
+                    number(self.width),
+                    number(self.height),
+                    resources
+                ),
+                form.as_bytes(),
+            ));
+        }
+
+        document.finish()
+    }
+
+    // Gets the name of a graphics state with the given alpha.
+    fn alpha(&mut self, alpha: f32) -> Option<String> {
+        if alpha >= 1.0 {
+            return None;
+        }
+
+        let index = match self.alphas.iter().position(|a| *a == alpha) {
+            Some(index) => index,
+            None => {
+                self.alphas.push(alpha);
+                self.alphas.len() - 1
+            }
+        };
+
+        Some(format!("/GS{} gs", index))
+    }
+
+    // Adds the operators of a shape, that is drawn with the given transform, alpha and painting operators.
+    fn shape(&mut self, path: &[PathOp], state: &DrawState, alpha: f32, operators: &str) {
+        let alpha = self.alpha(alpha).map(|gs| gs + "\n").unwrap_or_default();
+
+        self.content.push_str(&format!(
+            "q\n{} cm\n{}{}\n{}\nQ\n",
+            matrix(&state.transform),
+            alpha,
+            path_data(path),
+            operators
+        ));
+    }
+}
+
+impl VectorWriter for PdfWriter {
+    fn fill(&mut self, path: &[PathOp], state: &DrawState) {
+        if path.is_empty() {
+            return;
+        }
+
+        let color = brush_color(&state.config.fill_style);
+        let operator = match state.config.fill_rule {
+            FillRule::NonZero => "f",
+            FillRule::EvenOdd => "f*",
+        };
+
+        self.shape(
+            path,
+            state,
+            color.a() as f32 / 255.0 * state.config.alpha,
+            &format!("{} rg {}", rgb(color), operator),
+        );
+    }
+
+    fn stroke(&mut self, path: &[PathOp], state: &DrawState) {
+        if path.is_empty() {
+            return;
+        }
+
+        let config = &state.config;
+        let color = brush_color(&config.stroke_style);
+        let line_cap = match config.line_cap {
+            LineCap::Butt => 0,
+            LineCap::Round => 1,
+            LineCap::Square => 2,
+        };
+        let line_join = match config.line_join {
+            LineJoin::Miter => 0,
+            LineJoin::Round => 1,
+            LineJoin::Bevel => 2,
+        };
+        let line_dash = config
+            .line_dash
+            .iter()
+            .map(|segment| number(*segment))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        let operators = format!(
+            "{} RG {} w {} J {} j {} M [{}] {} d S",
+            rgb(color),
+            number(config.line_width),
+            line_cap,
+            line_join,
+            number(config.miter_limit),
+            line_dash,
+            number(config.line_dash_offset),
+        );
+
+        self.shape(
+            path,
+            state,
+            color.a() as f32 / 255.0 * config.alpha,
+            &operators,
+        );
+    }
+
+    fn clip(&mut self, path: &[PathOp], state: &DrawState) {
+        // the clip stays active until the state is restored, so the transform is applied to the points
+        let transform = state.transform;
+        let path: Vec<PathOp> = if path.is_empty() {
+            vec![PathOp::MoveTo(Point::default()), PathOp::Close]
+        } else {
+            path.iter()
+                .map(|op| match *op {
+                    PathOp::MoveTo(p) => PathOp::MoveTo(transform.transform_point(p)),
+                    PathOp::LineTo(p) => PathOp::LineTo(transform.transform_point(p)),
+                    PathOp::CurveTo(cp1, cp2, p) => PathOp::CurveTo(
+                        transform.transform_point(cp1),
+                        transform.transform_point(cp2),
+                        transform.transform_point(p),
+                    ),
+                    PathOp::Close => PathOp::Close,
+                })
+                .collect()
+        };
+
+        let operator = match state.config.fill_rule {
+            FillRule::NonZero => "W",
+            FillRule::EvenOdd => "W*",
+        };
+
+        self.content
+            .push_str(&format!("{}\n{} n\n", path_data(&path), operator));
+    }
+
+    fn fill_text(&mut self, text: &str, position: Point, state: &DrawState) {
+        let font_size = state.config.font_config.font_size;
+        let color = text_color(&state.config.fill_style);
+        let alpha = self
+            .alpha(color.a() as f32 / 255.0 * state.config.alpha)
+            .map(|gs| gs + "\n")
+            .unwrap_or_default();
+
+        // the text is centered vertically around the middle of the line like in the svg export
+        let baseline = position.y() + font_size.ceil() / 2.0 + 0.35 * font_size;
+
+        self.content.push_str(&format!(
+            "q\n{} cm\n{}{} rg\nBT /F1 {} Tf 1 0 0 -1 {} {} Tm ({}) Tj ET\nQ\n",
+            matrix(&state.transform),
+            alpha,
+            rgb(color),
+            number(font_size),
+            number(position.x()),
+            number(baseline),
+            text_string(text),
+        ));
+    }
+
+    fn draw_image(
+        &mut self,
+        image: &VectorImage,
+        clip: Rectangle,
+        rect: Rectangle,
+        state: &DrawState,
+    ) {
+        let image = match image.rasterize(clip, rect.size()) {
+            Some(image) => image,
+            None => return,
+        };
+
+        let index = self.images.len();
+        self.images.push(image);
+
+        let alpha = self
+            .alpha(state.config.alpha)
+            .map(|gs| gs + "\n")
+            .unwrap_or_default();
+
+        // the image is drawn into the unit square, which is flipped back
+        self.content.push_str(&format!(
+            "q\n{} cm\n{}{} 0 0 {} {} {} cm\n/Im{} Do\nQ\n",
+            matrix(&state.transform),
+            alpha,
+            number(rect.width()),
+            number(-rect.height()),
+            number(rect.x()),
+            number(rect.y() + rect.height()),
+            index,
+        ));
+    }
+
+    fn draw_box_shadow(&mut self, _: Rectangle, _: [f64; 4], _: &Shadow, _: &DrawState) {}
+
+    fn save(&mut self) {
+        self.content.push_str("q\n");
+    }
+
+    fn restore(&mut self) {
+        self.content.push_str("Q\n");
+    }
+
+    fn push_layer(&mut self, alpha: f32) {
+        let parent = mem::take(&mut self.content);
+        self.layers.push((alpha, parent));
+    }
+
+    // The content of the layer becomes a transparency group, that is drawn with the alpha of the layer, so
+    // overlapping shapes of the layer are not blended with each other.
+    fn pop_layer(&mut self) {
+        if let Some((alpha, parent)) = self.layers.pop() {
+            let content = mem::replace(&mut self.content, parent);
+            let index = self.forms.len();
+            self.forms.push(content);
+
+            let alpha = self.alpha(alpha).map(|gs| gs + "\n").unwrap_or_default();
+            self.content
+                .push_str(&format!("q\n{}/Fm{} Do\nQ\n", alpha, index));
+        }
+    }
+}
+
+// Writes the objects of a document and the table of their byte offsets.
+#[derive(Default)]
+struct Document {
+    data: Vec<u8>,
+    offsets: Vec<usize>,
+}
+
+impl Document {
+    fn object(&mut self, content: Vec<u8>) {
+        if self.data.is_empty() {
+            self.data
+                .extend_from_slice(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n");
+        }
+
+        self.offsets.push(self.data.len());
+        self.data
+            .extend_from_slice(format!("{} 0 obj\n", self.offsets.len()).as_bytes());
+        self.data.extend_from_slice(&content);
+        self.data.extend_from_slice(b"\nendobj\n");
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        let xref = self.data.len();
+        let mut table = format!("xref\n0 {}\n0000000000 65535 f \n", self.offsets.len() + 1);
+
+        for offset in &self.offsets {
+            table.push_str(&format!("{:010} 00000 n \n", offset));
+        }
+
+        table.push_str(&format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            self.offsets.len() + 1,
+            xref
+        ));
+
+        self.data.extend_from_slice(table.as_bytes());
+        self.data
+    }
+}
+
+fn stream(dictionary: &str, data: &[u8]) -> Vec<u8> {
+    let mut content = format!("<< {} /Length {} >>\nstream\n", dictionary, data.len()).into_bytes();
+    content.extend_from_slice(data);
+    content.extend_from_slice(b"\nendstream");
+    content
+}
+
+fn path_data(path: &[PathOp]) -> String {
+    path.iter()
+        .map(|op| match *op {
+            PathOp::MoveTo(p) => format!("{} {} m", number(p.x()), number(p.y())),
+            PathOp::LineTo(p) => format!("{} {} l", number(p.x()), number(p.y())),
+            PathOp::CurveTo(cp1, cp2, p) => format!(
+                "{} {} {} {} {} {} c",
+                number(cp1.x()),
+                number(cp1.y()),
+                number(cp2.x()),
+                number(cp2.y()),
+                number(p.x()),
+                number(p.y())
+            ),
+            PathOp::Close => "h".to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn matrix(transform: &Transform) -> String {
+    format!(
+        "{} {} {} {} {} {}",
+        number(transform.m11),
+        number(transform.m12),
+        number(transform.m21),
+        number(transform.m22),
+        number(transform.m31),
+        number(transform.m32)
+    )
+}
+
+fn rgb(color: Color) -> String {
+    format!(
+        "{} {} {}",
+        number(color.r() as f64 / 255.0),
+        number(color.g() as f64 / 255.0),
+        number(color.b() as f64 / 255.0)
+    )
+}
+
+// Encodes the text as string of the standard font, characters that are not part of Latin-1 are replaced by `?`.
+fn text_string(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '(' | ')' | '\\' => format!("\\{}", c),
+            ' '..='~' => c.to_string(),
+            c if (c as u32) < 0x100 => format!("\\{:03o}", c as u32),
+            _ => "?".to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layer() {
+        let mut writer = PdfWriter::new(100.0, 50.0);
+        writer.push_layer(0.5);
+        writer.save();
+        writer.restore();
+        writer.pop_layer();
+
+        let pdf = String::from_utf8_lossy(&writer.finish()).to_string();
+
+        assert!(pdf.contains("/GS0 << /ca 0.5 /CA 0.5 >>"));
+        assert!(pdf.contains("q\n/GS0 gs\n/Fm0 Do\nQ\n"));
+        assert!(pdf.contains("/Fm0 6 0 R"));
+        assert!(pdf.contains("/Subtype /Form /BBox [0 0 100 50] /Group << /S /Transparency >>"));
+    }
+
+    #[test]
+    fn test_text_string() {
+        assert_eq!(text_string("a (b)"), "a \\(b\\)");
+        assert_eq!(text_string("\u{e4}\u{263a}"), "\\344?");
+    }
+}
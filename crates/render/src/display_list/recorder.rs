@@ -0,0 +1,542 @@
+use std::{io, sync::Arc};
+
+use crate::{
+    platform::{Image, RenderContext2D, Svg},
+    utils::*,
+    Path2D, PipelineTrait, RenderBackend, RenderTarget, TextCluster, TextMetrics,
+};
+
+use super::{DisplayList, DrawCommand};
+
+/// Render backend that records the drawing calls into a `DisplayList` instead of drawing them. It could be used
+/// everywhere a render context is expected, e.g. to record the render objects of widgets.
+///
+/// Text is measured with the fonts that are registered on the recorder, nothing else is drawn. Layers are not
+/// cached, so a cached layer is recorded each time its content is drawn.
+///
+/// # Examples
+/// ```text
+/// let mut recorder = DisplayListRecorder::new(100.0, 50.0);
+/// // draw something
+/// let display_list = recorder.into_display_list();
+///
+/// std::fs::write("drawing.svg", display_list.to_svg()).unwrap();
+/// ```
+pub struct DisplayListRecorder {
+    display_list: DisplayList,
+    scale_factor: f64,
+    // the current transform, relative transforms are recorded as the resulting transform
+    transform: Transform,
+    // measures the text with the registered fonts
+    measure_context: RenderContext2D,
+}
+
+impl DisplayListRecorder {
+    /// Creates a new recorder with an empty display list of the given size in logical units.
+    pub fn new(width: f64, height: f64) -> Self {
+        DisplayListRecorder {
+            display_list: DisplayList::new(width, height),
+            scale_factor: 1.0,
+            transform: Transform::identity(),
+            measure_context: RenderContext2D::new(1.0, 1.0),
+        }
+    }
+
+    /// Gets the recorded display list.
+    pub fn display_list(&self) -> &DisplayList {
+        &self.display_list
+    }
+
+    /// Consumes the recorder and returns the recorded display list.
+    pub fn into_display_list(self) -> DisplayList {
+        self.display_list
+    }
+
+    fn record(&mut self, command: DrawCommand) {
+        self.display_list.push(command);
+    }
+}
+
+impl RenderBackend for DisplayListRecorder {
+    type Image = Image;
+    type Svg = Svg;
+
+    fn set_background(&mut self, _background: Color) {}
+
+    fn resize(&mut self, width: f64, height: f64) {
+        self.display_list.width = width / self.scale_factor;
+        self.display_list.height = height / self.scale_factor;
+    }
+
+    fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+    }
+
+    fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    // each render pipeline starts a new recording
+    fn start(&mut self) {
+        self.display_list.commands.clear();
+    }
+
+    fn start_region(&mut self, _region: Rectangle) {
+        self.display_list.commands.clear();
+    }
+
+    fn finish(&mut self) {}
+
+    fn invalidated(&self) -> bool {
+        false
+    }
+
+    fn take_damage(&mut self) -> Vec<Rectangle> {
+        vec![]
+    }
+
+    fn register_font(&mut self, family: &str, font_file: &'static [u8]) {
+        self.measure_context.register_font(family, font_file);
+    }
+
+    fn has_font(&self, family: &str) -> bool {
+        self.measure_context.has_font(family)
+    }
+
+    fn register_font_bytes(&mut self, family: &str, font_data: Arc<Vec<u8>>) -> io::Result<()> {
+        self.measure_context.register_font_bytes(family, font_data)
+    }
+
+    fn set_font_fallbacks(&mut self, families: Vec<String>) {
+        self.measure_context.set_font_fallbacks(families);
+    }
+
+    fn fill_text(&mut self, text: &str, x: f64, y: f64) {
+        self.record(DrawCommand::FillText {
+            text: text.to_string(),
+            x,
+            y,
+        });
+    }
+
+    // measuring is not drawing, so it is not recorded
+    fn measure(&mut self, text: &str, font_size: f64, family: &str) -> TextMetrics {
+        self.measure_context.measure(text, font_size, family)
+    }
+
+    fn measure_text(&mut self, text: &str) -> TextMetrics {
+        self.measure_context.measure_text(text)
+    }
+
+    fn measure_clusters(&mut self, text: &str, font_size: f64, family: &str) -> Vec<TextCluster> {
+        self.measure_context
+            .measure_clusters(text, font_size, family)
+    }
+
+    fn fill_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.record(DrawCommand::FillRect {
+            x,
+            y,
+            width,
+            height,
+        });
+    }
+
+    fn stroke_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.record(DrawCommand::StrokeRect {
+            x,
+            y,
+            width,
+            height,
+        });
+    }
+
+    fn fill(&mut self) {
+        self.record(DrawCommand::Fill);
+    }
+
+    fn stroke(&mut self) {
+        self.record(DrawCommand::Stroke);
+    }
+
+    fn fill_path(&mut self, path: &Path2D) {
+        self.record(DrawCommand::FillPath { path: path.clone() });
+    }
+
+    fn stroke_path(&mut self, path: &Path2D) {
+        self.record(DrawCommand::StrokePath { path: path.clone() });
+    }
+
+    fn clip_path(&mut self, path: &Path2D) {
+        self.record(DrawCommand::ClipPath { path: path.clone() });
+    }
+
+    fn add_path(&mut self, path: &Path2D) {
+        self.record(DrawCommand::AddPath { path: path.clone() });
+    }
+
+    fn begin_path(&mut self) {
+        self.record(DrawCommand::BeginPath);
+    }
+
+    fn close_path(&mut self) {
+        self.record(DrawCommand::ClosePath);
+    }
+
+    fn rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.record(DrawCommand::Rect {
+            x,
+            y,
+            width,
+            height,
+        });
+    }
+
+    fn arc(&mut self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64) {
+        self.record(DrawCommand::Arc {
+            x,
+            y,
+            radius,
+            start_angle,
+            end_angle,
+        });
+    }
+
+    fn arc_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, radius: f64) {
+        self.record(DrawCommand::ArcTo {
+            x1,
+            y1,
+            x2,
+            y2,
+            radius,
+        });
+    }
+
+    fn ellipse(
+        &mut self,
+        x: f64,
+        y: f64,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    ) {
+        self.record(DrawCommand::Ellipse {
+            x,
+            y,
+            radius_x,
+            radius_y,
+            rotation,
+            start_angle,
+            end_angle,
+        });
+    }
+
+    fn rounded_rect(&mut self, x: f64, y: f64, width: f64, height: f64, radii: [f64; 4]) {
+        self.record(DrawCommand::RoundedRect {
+            x,
+            y,
+            width,
+            height,
+            radii,
+        });
+    }
+
+    fn move_to(&mut self, x: f64, y: f64) {
+        self.record(DrawCommand::MoveTo { x, y });
+    }
+
+    fn line_to(&mut self, x: f64, y: f64) {
+        self.record(DrawCommand::LineTo { x, y });
+    }
+
+    fn quadratic_curve_to(&mut self, cpx: f64, cpy: f64, x: f64, y: f64) {
+        self.record(DrawCommand::QuadraticCurveTo { cpx, cpy, x, y });
+    }
+
+    fn bezier_curve_to(&mut self, cp1x: f64, cp1y: f64, cp2x: f64, cp2y: f64, x: f64, y: f64) {
+        self.record(DrawCommand::BezierCurveTo {
+            cp1x,
+            cp1y,
+            cp2x,
+            cp2y,
+            x,
+            y,
+        });
+    }
+
+    fn clip(&mut self) {
+        self.record(DrawCommand::Clip);
+    }
+
+    fn draw_render_target(&mut self, render_target: &RenderTarget, x: f64, y: f64) {
+        self.record(DrawCommand::DrawRenderTarget {
+            render_target: render_target.clone(),
+            x,
+            y,
+        });
+    }
+
+    fn draw_image(&mut self, image: &Image, x: f64, y: f64) {
+        self.record(DrawCommand::DrawImage {
+            image: image.clone(),
+            x,
+            y,
+        });
+    }
+
+    fn draw_image_with_clip(&mut self, image: &Image, clip: Rectangle, x: f64, y: f64) {
+        self.record(DrawCommand::DrawImageWithClip {
+            image: image.clone(),
+            clip,
+            x,
+            y,
+        });
+    }
+
+    fn draw_image_with_clip_and_size(
+        &mut self,
+        image: &Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        self.record(DrawCommand::DrawImageWithClipAndSize {
+            image: image.clone(),
+            clip,
+            x,
+            y,
+            width,
+            height,
+        });
+    }
+
+    fn draw_svg(
+        &mut self,
+        svg: &Svg,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        color: Option<Color>,
+    ) {
+        self.record(DrawCommand::DrawSvg {
+            svg: svg.clone(),
+            x,
+            y,
+            width,
+            height,
+            color,
+        });
+    }
+
+    fn draw_pipeline(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        pipeline: Box<dyn PipelineTrait>,
+    ) {
+        self.record(DrawCommand::DrawPipeline {
+            x,
+            y,
+            width,
+            height,
+            pipeline,
+        });
+    }
+
+    fn draw_box_shadow(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radii: [f64; 4],
+        shadow: &Shadow,
+    ) {
+        self.record(DrawCommand::DrawBoxShadow {
+            x,
+            y,
+            width,
+            height,
+            radii,
+            shadow: *shadow,
+        });
+    }
+
+    fn set_line_width(&mut self, line_width: f64) {
+        self.record(DrawCommand::SetLineWidth { line_width });
+    }
+
+    fn set_line_cap(&mut self, line_cap: LineCap) {
+        self.record(DrawCommand::SetLineCap { line_cap });
+    }
+
+    fn set_line_join(&mut self, line_join: LineJoin) {
+        self.record(DrawCommand::SetLineJoin { line_join });
+    }
+
+    fn set_miter_limit(&mut self, miter_limit: f64) {
+        self.record(DrawCommand::SetMiterLimit { miter_limit });
+    }
+
+    fn set_line_dash(&mut self, segments: Vec<f64>) {
+        self.record(DrawCommand::SetLineDash { segments });
+    }
+
+    fn set_line_dash_offset(&mut self, offset: f64) {
+        self.record(DrawCommand::SetLineDashOffset { offset });
+    }
+
+    fn set_fill_rule(&mut self, fill_rule: FillRule) {
+        self.record(DrawCommand::SetFillRule { fill_rule });
+    }
+
+    fn set_alpha(&mut self, alpha: f32) {
+        self.record(DrawCommand::SetAlpha { alpha });
+    }
+
+    fn set_font_family(&mut self, family: &str) {
+        self.measure_context.set_font_family(family);
+        self.record(DrawCommand::SetFontFamily {
+            family: family.to_string(),
+        });
+    }
+
+    fn set_font_size(&mut self, size: f64) {
+        self.measure_context.set_font_size(size);
+        self.record(DrawCommand::SetFontSize { size });
+    }
+
+    fn set_fill_style(&mut self, fill_style: Brush) {
+        self.record(DrawCommand::SetFillStyle { fill_style });
+    }
+
+    fn set_stroke_style(&mut self, stroke_style: Brush) {
+        self.record(DrawCommand::SetStrokeStyle { stroke_style });
+    }
+
+    fn set_transform(
+        &mut self,
+        h_scaling: f64,
+        h_skewing: f64,
+        v_skewing: f64,
+        v_scaling: f64,
+        h_moving: f64,
+        v_moving: f64,
+    ) {
+        self.transform = Transform::new(
+            h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+        );
+        self.record(DrawCommand::SetTransform {
+            h_scaling,
+            h_skewing,
+            v_skewing,
+            v_scaling,
+            h_moving,
+            v_moving,
+        });
+    }
+
+    fn transform(
+        &mut self,
+        h_scaling: f64,
+        h_skewing: f64,
+        v_skewing: f64,
+        v_scaling: f64,
+        h_moving: f64,
+        v_moving: f64,
+    ) {
+        let transform = Transform::new(
+            h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+        )
+        .then(&self.transform);
+
+        self.set_transform(
+            transform.m11,
+            transform.m12,
+            transform.m21,
+            transform.m22,
+            transform.m31,
+            transform.m32,
+        );
+    }
+
+    fn push_layer(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.record(DrawCommand::PushLayer {
+            x,
+            y,
+            width,
+            height,
+        });
+    }
+
+    fn pop_layer(&mut self, alpha: f32, _key: Option<u64>) {
+        self.record(DrawCommand::PopLayer { alpha });
+    }
+
+    fn draw_layer(&mut self, _key: u64, _alpha: f32) {}
+
+    fn has_layer(&self, _key: u64) -> bool {
+        false
+    }
+
+    fn remove_layer(&mut self, _key: u64) {}
+
+    fn clear_layers(&mut self) {}
+
+    fn save(&mut self) {
+        self.record(DrawCommand::Save);
+    }
+
+    fn restore(&mut self) {
+        self.record(DrawCommand::Restore);
+    }
+
+    fn clear(&mut self, _brush: &Brush) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let mut recorder = DisplayListRecorder::new(100.0, 50.0);
+        recorder.set_scale_factor(2.0);
+        recorder.stroke_rect(10.0, 10.0, 20.0, 20.0);
+        recorder.measure("text", 12.0, "Roboto-Regular");
+        recorder.push_layer(0.0, 0.0, 100.0, 50.0);
+        recorder.pop_layer(0.5, Some(1));
+
+        // composed calls, measuring and cached layers are not recorded
+        assert!(!recorder.has_layer(1));
+        assert_eq!(
+            recorder.into_display_list(),
+            DisplayList {
+                width: 100.0,
+                height: 50.0,
+                commands: vec![
+                    DrawCommand::StrokeRect {
+                        x: 10.0,
+                        y: 10.0,
+                        width: 20.0,
+                        height: 20.0,
+                    },
+                    DrawCommand::PushLayer {
+                        x: 0.0,
+                        y: 0.0,
+                        width: 100.0,
+                        height: 50.0,
+                    },
+                    DrawCommand::PopLayer { alpha: 0.5 },
+                ],
+            }
+        );
+    }
+}
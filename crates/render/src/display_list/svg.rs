@@ -0,0 +1,558 @@
+use crate::{
+    common::{build_unit_percent_gradient, linear_gradient_ends},
+    utils::*,
+    Path2D,
+};
+
+use super::vector::*;
+
+/// Writes the resolved operations of a display list as SVG document.
+pub struct SvgWriter {
+    width: f64,
+    height: f64,
+    defs: String,
+    // the content of the document followed by the content and alpha of the open layers
+    layers: Vec<(String, f32)>,
+    clip: Option<usize>,
+    saved_clips: Vec<Option<usize>>,
+    next_id: usize,
+}
+
+impl SvgWriter {
+    /// Creates a new writer for a document with the given size.
+    pub fn new(width: f64, height: f64) -> Self {
+        SvgWriter {
+            width,
+            height,
+            defs: String::new(),
+            layers: vec![(String::new(), 1.0)],
+            clip: None,
+            saved_clips: vec![],
+            next_id: 0,
+        }
+    }
+
+    /// Finishes the document and returns its content.
+    pub fn finish(mut self) -> String {
+        while self.layers.len() > 1 {
+            self.pop_layer();
+        }
+
+        format!(
+            concat!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" xmlns:xlink=\"http://www.w3.org/1999/xlink\" ",
+                "version=\"1.1\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n",
+                "<defs>\n{}</defs>\n{}</svg>\n"
+            ),
+            self.defs,
+            self.layers[0].0,
+            w = number(self.width),
+            h = number(self.height),
+        )
+    }
+
+    fn id(&mut self) -> usize {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    // Adds an element to the current layer, the element is clipped by the current clip.
+    fn element(&mut self, element: String) {
+        let element = match self.clip {
+            Some(id) => format!("<g clip-path=\"url(#clip{})\">{}</g>\n", id, element),
+            None => format!("{}\n", element),
+        };
+
+        if let Some((content, _)) = self.layers.last_mut() {
+            content.push_str(&element);
+        }
+    }
+
+    // Gets the attributes that paint the fill or stroke (given by `kind`) with the brush.
+    fn paint(&mut self, kind: &str, brush: &Brush, frame: Rectangle, alpha: f32) -> String {
+        let gradient = match brush {
+            Brush::SolidColor(color) => {
+                return solid_paint(kind, *color, alpha);
+            }
+            Brush::Gradient(gradient) => gradient,
+        };
+
+        let spread = if gradient.repeat { "repeat" } else { "pad" };
+        let id = self.id();
+
+        match &gradient.kind {
+            GradientKind::Linear(coords) => {
+                let (start, end) = linear_gradient_ends(coords, frame);
+                self.defs.push_str(&format!(
+                    concat!(
+                        "<linearGradient id=\"gradient{}\" gradientUnits=\"userSpaceOnUse\" ",
+                        "x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" spreadMethod=\"{}\">{}</linearGradient>\n"
+                    ),
+                    id,
+                    number(start.x()),
+                    number(start.y()),
+                    number(end.x()),
+                    number(end.y()),
+                    spread,
+                    gradient_stops(&gradient.stops, end.distance(start)),
+                ));
+            }
+            GradientKind::Radial(radial) => {
+                let (center, radii) = radial.resolve(frame.size());
+                let center = frame.position() + center;
+
+                // a gradient without extent is drawn with its last color
+                if radii.x() <= 0.0 || radii.y() <= 0.0 {
+                    let color = gradient
+                        .stops
+                        .last()
+                        .map(|stop| stop.color)
+                        .unwrap_or_default();
+                    return solid_paint(kind, color, alpha);
+                }
+
+                // maps the unit circle to the ellipse of the gradient
+                self.defs.push_str(&format!(
+                    concat!(
+                        "<radialGradient id=\"gradient{}\" gradientUnits=\"userSpaceOnUse\" ",
+                        "cx=\"0\" cy=\"0\" r=\"1\" gradientTransform=\"{}\" spreadMethod=\"{}\">{}</radialGradient>\n"
+                    ),
+                    id,
+                    matrix(&Transform::new(
+                        radii.x(),
+                        0.0,
+                        0.0,
+                        radii.y(),
+                        center.x(),
+                        center.y()
+                    )),
+                    spread,
+                    gradient_stops(&gradient.stops, radii.x()),
+                ));
+            }
+            GradientKind::Conic(_) => {
+                return solid_paint(kind, brush_color(brush), alpha);
+            }
+        }
+
+        format!(
+            "{k}=\"url(#gradient{})\" {k}-opacity=\"{}\"",
+            id,
+            number(alpha as f64),
+            k = kind
+        )
+    }
+
+    // Adds a clip path and returns its id, the clip path is clipped by the current clip.
+    fn clip_path(&mut self, path: &[PathOp], transform: &Transform, fill_rule: FillRule) -> usize {
+        let id = self.id();
+        let parent = self
+            .clip
+            .map(|parent| format!(" clip-path=\"url(#clip{})\"", parent))
+            .unwrap_or_default();
+
+        self.defs.push_str(&format!(
+            "<clipPath id=\"clip{}\"{}><path d=\"{}\" transform=\"{}\" clip-rule=\"{}\"/></clipPath>\n",
+            id,
+            parent,
+            path_data(path),
+            matrix(transform),
+            fill_rule_name(fill_rule),
+        ));
+
+        id
+    }
+}
+
+impl VectorWriter for SvgWriter {
+    fn fill(&mut self, path: &[PathOp], state: &DrawState) {
+        let frame = match path_bounds(path) {
+            Some(frame) => frame,
+            None => return,
+        };
+
+        let paint = self.paint("fill", &state.config.fill_style, frame, state.config.alpha);
+        self.element(format!(
+            "<path d=\"{}\" transform=\"{}\" {} fill-rule=\"{}\"/>",
+            path_data(path),
+            matrix(&state.transform),
+            paint,
+            fill_rule_name(state.config.fill_rule),
+        ));
+    }
+
+    fn stroke(&mut self, path: &[PathOp], state: &DrawState) {
+        let frame = match path_bounds(path) {
+            Some(frame) => frame,
+            None => return,
+        };
+
+        let config = &state.config;
+        let paint = self.paint("stroke", &config.stroke_style, frame, config.alpha);
+
+        let line_cap = match config.line_cap {
+            LineCap::Butt => "butt",
+            LineCap::Round => "round",
+            LineCap::Square => "square",
+        };
+        let line_join = match config.line_join {
+            LineJoin::Miter => "miter",
+            LineJoin::Round => "round",
+            LineJoin::Bevel => "bevel",
+        };
+        let line_dash = if config.line_dash.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " stroke-dasharray=\"{}\" stroke-dashoffset=\"{}\"",
+                config
+                    .line_dash
+                    .iter()
+                    .map(|segment| number(*segment))
+                    .collect::<Vec<String>>()
+                    .join(" "),
+                number(config.line_dash_offset),
+            )
+        };
+
+        self.element(format!(
+            concat!(
+                "<path d=\"{}\" transform=\"{}\" fill=\"none\" {} stroke-width=\"{}\" stroke-linecap=\"{}\" ",
+                "stroke-linejoin=\"{}\" stroke-miterlimit=\"{}\"{}/>"
+            ),
+            path_data(path),
+            matrix(&state.transform),
+            paint,
+            number(config.line_width),
+            line_cap,
+            line_join,
+            number(config.miter_limit),
+            line_dash,
+        ));
+    }
+
+    fn clip(&mut self, path: &[PathOp], state: &DrawState) {
+        // an empty clip hides everything
+        let path = if path.is_empty() {
+            vec![PathOp::MoveTo(Point::default()), PathOp::Close]
+        } else {
+            path.to_vec()
+        };
+
+        self.clip = Some(self.clip_path(&path, &state.transform, state.config.fill_rule));
+    }
+
+    fn fill_text(&mut self, text: &str, position: Point, state: &DrawState) {
+        let font_size = state.config.font_config.font_size;
+        let paint = solid_paint(
+            "fill",
+            text_color(&state.config.fill_style),
+            state.config.alpha,
+        );
+
+        self.element(format!(
+            concat!(
+                "<text x=\"{}\" y=\"{}\" transform=\"{}\" font-family=\"{}\" font-size=\"{}\" ",
+                "dominant-baseline=\"middle\" xml:space=\"preserve\" {}>{}</text>"
+            ),
+            number(position.x()),
+            number(position.y() + font_size.ceil() / 2.0),
+            matrix(&state.transform),
+            escape(&state.config.font_config.family),
+            number(font_size),
+            paint,
+            escape(text),
+        ));
+    }
+
+    fn draw_image(
+        &mut self,
+        image: &VectorImage,
+        clip: Rectangle,
+        rect: Rectangle,
+        state: &DrawState,
+    ) {
+        let size = image.size();
+
+        let (href, filter) = match image {
+            VectorImage::Raster(image) => match image.to_png() {
+                Ok(png) => (
+                    format!("data:image/png;base64,{}", base64(&png)),
+                    String::new(),
+                ),
+                Err(_) => return,
+            },
+            VectorImage::Svg(svg, color) => {
+                let href = format!("data:image/svg+xml;base64,{}", base64(svg.data()));
+
+                // the shape of the image is painted with the color
+                let filter = match color {
+                    Some(color) => {
+                        let id = self.id();
+                        self.defs.push_str(&format!(
+                            concat!(
+                                "<filter id=\"filter{}\"><feFlood flood-color=\"{}\" flood-opacity=\"{}\"/>",
+                                "<feComposite in2=\"SourceGraphic\" operator=\"in\"/></filter>\n"
+                            ),
+                            id,
+                            hex(*color),
+                            number(color.a() as f64 / 255.0),
+                        ));
+                        format!(" filter=\"url(#filter{})\"", id)
+                    }
+                    None => String::new(),
+                };
+
+                (href, filter)
+            }
+        };
+
+        self.element(format!(
+            concat!(
+                "<g transform=\"{}\" opacity=\"{}\"><svg x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" ",
+                "viewBox=\"{} {} {} {}\" preserveAspectRatio=\"none\"><image width=\"{}\" height=\"{}\" ",
+                "preserveAspectRatio=\"none\" xlink:href=\"{}\"{}/></svg></g>"
+            ),
+            matrix(&state.transform),
+            number(state.config.alpha as f64),
+            number(rect.x()),
+            number(rect.y()),
+            number(rect.width()),
+            number(rect.height()),
+            number(clip.x()),
+            number(clip.y()),
+            number(clip.width()),
+            number(clip.height()),
+            number(size.width()),
+            number(size.height()),
+            href,
+            filter,
+        ));
+    }
+
+    fn draw_box_shadow(
+        &mut self,
+        rect: Rectangle,
+        radii: [f64; 4],
+        shadow: &Shadow,
+        state: &DrawState,
+    ) {
+        let (shape, shape_radii) = shadow.shape(rect, radii);
+        let blur = shadow.blur.max(0.0);
+
+        // the area of the blurred shadow
+        let margin = 1.5 * blur + 1.0;
+        let area = if shadow.inset { rect } else { shape };
+        let area = Rectangle::new(
+            (area.x() - margin, area.y() - margin),
+            (area.width() + 2.0 * margin, area.height() + 2.0 * margin),
+        );
+
+        let mut box_path = Path2D::new();
+        box_path.rounded_rect(rect.x(), rect.y(), rect.width(), rect.height(), radii);
+
+        let mut shape_path = Path2D::new();
+        shape_path.rounded_rect(
+            shape.x(),
+            shape.y(),
+            shape.width(),
+            shape.height(),
+            shape_radii,
+        );
+
+        let mut area_path = Path2D::new();
+        area_path.rect(area.x(), area.y(), area.width(), area.height());
+
+        // an outer shadow is not drawn below of the rectangle, an inset shadow is a ring inside of the rectangle
+        let (clip, shadow_path) = if shadow.inset {
+            let mut ring = area_path;
+            ring.add_path(&shape_path);
+            (path_ops(&box_path), path_ops(&ring))
+        } else {
+            area_path.add_path(&box_path);
+            (path_ops(&area_path), path_ops(&shape_path))
+        };
+
+        let clip = self.clip_path(&clip, &state.transform, FillRule::EvenOdd);
+
+        let filter = if blur > 0.0 {
+            let id = self.id();
+            self.defs.push_str(&format!(
+                concat!(
+                    "<filter id=\"filter{}\" filterUnits=\"userSpaceOnUse\" x=\"{}\" y=\"{}\" width=\"{}\" ",
+                    "height=\"{}\"><feGaussianBlur stdDeviation=\"{}\"/></filter>\n"
+                ),
+                id,
+                number(area.x()),
+                number(area.y()),
+                number(area.width()),
+                number(area.height()),
+                number(blur / 2.0),
+            ));
+            format!(" filter=\"url(#filter{})\"", id)
+        } else {
+            String::new()
+        };
+
+        self.element(format!(
+            "<g clip-path=\"url(#clip{})\"><path d=\"{}\" transform=\"{}\" {} fill-rule=\"evenodd\"{}/></g>",
+            clip,
+            path_data(&shadow_path),
+            matrix(&state.transform),
+            solid_paint("fill", shadow.color, state.config.alpha),
+            filter,
+        ));
+    }
+
+    fn save(&mut self) {
+        self.saved_clips.push(self.clip);
+    }
+
+    fn restore(&mut self) {
+        if let Some(clip) = self.saved_clips.pop() {
+            self.clip = clip;
+        }
+    }
+
+    fn push_layer(&mut self, alpha: f32) {
+        self.layers.push((String::new(), alpha));
+    }
+
+    fn pop_layer(&mut self) {
+        if self.layers.len() < 2 {
+            return;
+        }
+
+        if let Some((content, alpha)) = self.layers.pop() {
+            if let Some((parent, _)) = self.layers.last_mut() {
+                parent.push_str(&format!(
+                    "<g opacity=\"{}\">\n{}</g>\n",
+                    number(alpha as f64),
+                    content
+                ));
+            }
+        }
+    }
+}
+
+fn solid_paint(kind: &str, color: Color, alpha: f32) -> String {
+    format!(
+        "{k}=\"{}\" {k}-opacity=\"{}\"",
+        hex(color),
+        number(color.a() as f64 / 255.0 * alpha as f64),
+        k = kind
+    )
+}
+
+fn gradient_stops(stops: &[GradientStop], length: f64) -> String {
+    build_unit_percent_gradient(stops, length, |position, color| {
+        format!(
+            "<stop offset=\"{}\" stop-color=\"{}\" stop-opacity=\"{}\"/>",
+            number(position),
+            hex(color),
+            number(color.a() as f64 / 255.0),
+        )
+    })
+    .concat()
+}
+
+fn fill_rule_name(fill_rule: FillRule) -> &'static str {
+    match fill_rule {
+        FillRule::NonZero => "nonzero",
+        FillRule::EvenOdd => "evenodd",
+    }
+}
+
+fn path_data(path: &[PathOp]) -> String {
+    path.iter()
+        .map(|op| match *op {
+            PathOp::MoveTo(p) => format!("M{} {}", number(p.x()), number(p.y())),
+            PathOp::LineTo(p) => format!("L{} {}", number(p.x()), number(p.y())),
+            PathOp::CurveTo(cp1, cp2, p) => format!(
+                "C{} {} {} {} {} {}",
+                number(cp1.x()),
+                number(cp1.y()),
+                number(cp2.x()),
+                number(cp2.y()),
+                number(p.x()),
+                number(p.y())
+            ),
+            PathOp::Close => "Z".to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn matrix(transform: &Transform) -> String {
+    format!(
+        "matrix({} {} {} {} {} {})",
+        number(transform.m11),
+        number(transform.m12),
+        number(transform.m21),
+        number(transform.m22),
+        number(transform.m31),
+        number(transform.m32)
+    )
+}
+
+fn hex(color: Color) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r(), color.g(), color.b())
+}
+
+fn escape(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&quot;".to_string(),
+            '\'' => "&apos;".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut text = String::with_capacity((data.len() + 2) / 3 * 4);
+
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let value = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                text.push(ALPHABET[(value >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                text.push('=');
+            }
+        }
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a < b & \"c\""), "a &lt; b &amp; &quot;c&quot;");
+    }
+}
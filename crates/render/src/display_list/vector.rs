@@ -0,0 +1,744 @@
+use crate::{
+    common::{arc_sweep, arc_to_arc, cubic_bezier_rect, ellipse_curves, line_dash},
+    platform::{Image, Svg},
+    utils::*,
+    Path2D, PathSegment, RenderConfig, RenderTarget,
+};
+
+use super::{DisplayList, DrawCommand};
+
+/// Describes a segment of a path that is resolved to lines and cubic curves.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathOp {
+    MoveTo(Point),
+    LineTo(Point),
+    CurveTo(Point, Point, Point),
+    Close,
+}
+
+/// Describes an image that is drawn by a vector writer.
+#[derive(Clone, Debug)]
+pub enum VectorImage {
+    Raster(Image),
+    Svg(Svg, Option<Color>),
+}
+
+impl VectorImage {
+    fn from_image(image: &Image) -> Self {
+        match image.svg() {
+            Some(svg) => VectorImage::Svg(svg.clone(), None),
+            None => VectorImage::Raster(image.clone()),
+        }
+    }
+
+    /// Gets the size of the image. The size of a SVG image is given in the units of the document.
+    pub fn size(&self) -> Size {
+        match self {
+            VectorImage::Raster(image) => Size::new(image.width(), image.height()),
+            VectorImage::Svg(svg, _) => Size::new(svg.width(), svg.height()),
+        }
+    }
+
+    /// Gets the given part of the image as raster image, that is drawn with the given size. SVG images are rasterized
+    /// with twice of the size, so they stay sharp if they are printed.
+    pub fn rasterize(&self, clip: Rectangle, size: Size) -> Option<Image> {
+        let (image, clip) = match self {
+            VectorImage::Raster(image) => (image.clone(), clip),
+            VectorImage::Svg(svg, color) => {
+                let scale_x = 2.0 * size.width() / clip.width();
+                let scale_y = 2.0 * size.height() / clip.height();
+                let image = svg.rasterize(
+                    (svg.width() * scale_x).ceil() as u32,
+                    (svg.height() * scale_y).ceil() as u32,
+                    *color,
+                )?;
+
+                // the aspect ratio of the document is kept by the rasterized image
+                let scale_x = image.width() / svg.width();
+                let scale_y = image.height() / svg.height();
+                let clip = Rectangle::new(
+                    (clip.x() * scale_x, clip.y() * scale_y),
+                    (clip.width() * scale_x, clip.height() * scale_y),
+                );
+
+                (image, clip)
+            }
+        };
+
+        crop_image(&image, clip)
+    }
+}
+
+/// Receives the drawing operations of a display list with resolved paths and drawing state.
+pub trait VectorWriter {
+    /// Fills the path with the fill style of the state.
+    fn fill(&mut self, path: &[PathOp], state: &DrawState);
+
+    /// Strokes the path with the stroke style and line style of the state.
+    fn stroke(&mut self, path: &[PathOp], state: &DrawState);
+
+    /// Intersects the current clip with the path until the state is restored.
+    fn clip(&mut self, path: &[PathOp], state: &DrawState);
+
+    /// Draws the text, the position is the top left corner of the text.
+    fn fill_text(&mut self, text: &str, position: Point, state: &DrawState);
+
+    /// Draws the given part of the image into the rectangle.
+    fn draw_image(
+        &mut self,
+        image: &VectorImage,
+        clip: Rectangle,
+        rect: Rectangle,
+        state: &DrawState,
+    );
+
+    /// Draws the shadow of the rectangle with the given corner radii.
+    fn draw_box_shadow(
+        &mut self,
+        rect: Rectangle,
+        radii: [f64; 4],
+        shadow: &Shadow,
+        state: &DrawState,
+    );
+
+    /// Saves the current clip.
+    fn save(&mut self);
+
+    /// Restores the last saved clip.
+    fn restore(&mut self);
+
+    /// Starts a group that is drawn with the given alpha value.
+    fn push_layer(&mut self, alpha: f32);
+
+    /// Ends the last group.
+    fn pop_layer(&mut self);
+}
+
+/// The state that is used to draw the resolved operations of a display list.
+#[derive(Clone, Debug, Default)]
+pub struct DrawState {
+    /// The current configuration, the font size is given like it is set on the render context.
+    pub config: RenderConfig,
+
+    /// The current transform in logical units.
+    pub transform: Transform,
+}
+
+/// Resolves the commands of the display list and draws them with the given writer.
+pub fn draw_vector(display_list: &DisplayList, writer: &mut impl VectorWriter) {
+    let mut state = DrawState::default();
+    let mut saved_configs = vec![];
+    let mut path = Path2D::new();
+    let mut layer_alphas = layer_alphas(display_list.commands()).into_iter();
+
+    for command in display_list.commands() {
+        match command {
+            DrawCommand::FillRect {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                let mut rect = Path2D::new();
+                rect.rect(*x, *y, *width, *height);
+                writer.fill(&path_ops(&rect), &state);
+            }
+            DrawCommand::StrokeRect {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                // the rectangle is added to the current path like on the render context
+                path.rect(*x, *y, *width, *height);
+                writer.stroke(&path_ops(&path), &state);
+            }
+            DrawCommand::FillText { text, x, y } => {
+                if !text.is_empty() {
+                    writer.fill_text(text, Point::new(*x, *y), &state);
+                }
+            }
+            DrawCommand::Fill => writer.fill(&path_ops(&path), &state),
+            DrawCommand::Stroke => writer.stroke(&path_ops(&path), &state),
+            DrawCommand::FillPath { path } => writer.fill(&path_ops(path), &state),
+            DrawCommand::StrokePath { path } => writer.stroke(&path_ops(path), &state),
+            DrawCommand::ClipPath { path } => writer.clip(&path_ops(path), &state),
+            DrawCommand::AddPath { path: other } => path.add_path(other),
+            DrawCommand::BeginPath => path = Path2D::new(),
+            DrawCommand::ClosePath => path.close_path(),
+            DrawCommand::Rect {
+                x,
+                y,
+                width,
+                height,
+            } => path.rect(*x, *y, *width, *height),
+            DrawCommand::Arc {
+                x,
+                y,
+                radius,
+                start_angle,
+                end_angle,
+            } => path.arc(*x, *y, *radius, *start_angle, *end_angle),
+            DrawCommand::ArcTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                radius,
+            } => path.arc_to(*x1, *y1, *x2, *y2, *radius),
+            DrawCommand::Ellipse {
+                x,
+                y,
+                radius_x,
+                radius_y,
+                rotation,
+                start_angle,
+                end_angle,
+            } => path.ellipse(
+                *x,
+                *y,
+                *radius_x,
+                *radius_y,
+                *rotation,
+                *start_angle,
+                *end_angle,
+            ),
+            DrawCommand::RoundedRect {
+                x,
+                y,
+                width,
+                height,
+                radii,
+            } => path.rounded_rect(*x, *y, *width, *height, *radii),
+            DrawCommand::MoveTo { x, y } => path.move_to(*x, *y),
+            DrawCommand::LineTo { x, y } => path.line_to(*x, *y),
+            DrawCommand::QuadraticCurveTo { cpx, cpy, x, y } => {
+                path.quadratic_curve_to(*cpx, *cpy, *x, *y)
+            }
+            DrawCommand::BezierCurveTo {
+                cp1x,
+                cp1y,
+                cp2x,
+                cp2y,
+                x,
+                y,
+            } => path.bezier_curve_to(*cp1x, *cp1y, *cp2x, *cp2y, *x, *y),
+            DrawCommand::DrawRenderTarget {
+                render_target,
+                x,
+                y,
+            } => {
+                let image = VectorImage::Raster(Image::from_render_target(render_target.clone()));
+                let size = image.size();
+                writer.draw_image(
+                    &image,
+                    Rectangle::new((0.0, 0.0), size),
+                    Rectangle::new((*x, *y), size),
+                    &state,
+                );
+            }
+            DrawCommand::DrawImage { image, x, y } => {
+                let image = VectorImage::from_image(image);
+                let size = image.size();
+                writer.draw_image(
+                    &image,
+                    Rectangle::new((0.0, 0.0), size),
+                    Rectangle::new((*x, *y), size),
+                    &state,
+                );
+            }
+            DrawCommand::DrawImageWithClip { image, clip, x, y } => writer.draw_image(
+                &VectorImage::from_image(image),
+                *clip,
+                Rectangle::new((*x, *y), clip.size()),
+                &state,
+            ),
+            DrawCommand::DrawImageWithClipAndSize {
+                image,
+                clip,
+                x,
+                y,
+                width,
+                height,
+            } => {
+                if clip.width() > 0.0 && clip.height() > 0.0 && *width > 0.0 && *height > 0.0 {
+                    writer.draw_image(
+                        &VectorImage::from_image(image),
+                        *clip,
+                        Rectangle::new((*x, *y), (*width, *height)),
+                        &state,
+                    );
+                }
+            }
+            DrawCommand::DrawSvg {
+                svg,
+                x,
+                y,
+                width,
+                height,
+                color,
+            } => {
+                if !svg.is_empty() && *width > 0.0 && *height > 0.0 {
+                    writer.draw_image(
+                        &VectorImage::Svg(svg.clone(), *color),
+                        Rectangle::new((0.0, 0.0), (svg.width(), svg.height())),
+                        Rectangle::new((*x, *y), (*width, *height)),
+                        &state,
+                    );
+                }
+            }
+            DrawCommand::DrawPipeline {
+                x,
+                y,
+                width,
+                height,
+                pipeline,
+            } => {
+                let mut render_target = RenderTarget::new(*width as u32, *height as u32);
+                pipeline.draw_pipeline(&mut render_target);
+
+                let image = VectorImage::Raster(Image::from_render_target(render_target));
+                let size = image.size();
+                writer.draw_image(
+                    &image,
+                    Rectangle::new((0.0, 0.0), size),
+                    Rectangle::new((*x, *y), size),
+                    &state,
+                );
+            }
+            DrawCommand::DrawBoxShadow {
+                x,
+                y,
+                width,
+                height,
+                radii,
+                shadow,
+            } => {
+                if shadow.color.a() > 0 && *width > 0.0 && *height > 0.0 {
+                    writer.draw_box_shadow(
+                        Rectangle::new((*x, *y), (*width, *height)),
+                        *radii,
+                        shadow,
+                        &state,
+                    );
+                }
+            }
+            DrawCommand::Clip => writer.clip(&path_ops(&path), &state),
+            DrawCommand::SetLineWidth { line_width } => state.config.line_width = *line_width,
+            DrawCommand::SetLineCap { line_cap } => state.config.line_cap = *line_cap,
+            DrawCommand::SetLineJoin { line_join } => state.config.line_join = *line_join,
+            DrawCommand::SetMiterLimit { miter_limit } => {
+                if *miter_limit > 0.0 && miter_limit.is_finite() {
+                    state.config.miter_limit = *miter_limit;
+                }
+            }
+            DrawCommand::SetLineDash { segments } => {
+                if let Some(line_dash) = line_dash(segments.clone()) {
+                    state.config.line_dash = line_dash;
+                }
+            }
+            DrawCommand::SetLineDashOffset { offset } => state.config.line_dash_offset = *offset,
+            DrawCommand::SetFillRule { fill_rule } => state.config.fill_rule = *fill_rule,
+            DrawCommand::SetAlpha { alpha } => state.config.alpha = *alpha,
+            DrawCommand::SetFontFamily { family } => {
+                state.config.font_config.family = family.clone()
+            }
+            DrawCommand::SetFontSize { size } => state.config.font_config.font_size = *size,
+            DrawCommand::SetFillStyle { fill_style } => {
+                state.config.fill_style = fill_style.clone()
+            }
+            DrawCommand::SetStrokeStyle { stroke_style } => {
+                state.config.stroke_style = stroke_style.clone()
+            }
+            DrawCommand::SetTransform {
+                h_scaling,
+                h_skewing,
+                v_skewing,
+                v_scaling,
+                h_moving,
+                v_moving,
+            } => {
+                state.transform = Transform::new(
+                    *h_scaling, *h_skewing, *v_skewing, *v_scaling, *h_moving, *v_moving,
+                )
+            }
+            DrawCommand::PushLayer { .. } => writer.push_layer(layer_alphas.next().unwrap_or(1.0)),
+            DrawCommand::PopLayer { .. } => writer.pop_layer(),
+            // like on the render context the transform is not part of the saved state
+            DrawCommand::Save => {
+                saved_configs.push(state.config.clone());
+                writer.save();
+            }
+            DrawCommand::Restore => {
+                if let Some(config) = saved_configs.pop() {
+                    state.config = config;
+                    writer.restore();
+                }
+            }
+        }
+    }
+}
+
+// Gets the alpha values of the layers in the order they are pushed. The alpha value is given when the layer is popped.
+fn layer_alphas(commands: &[DrawCommand]) -> Vec<f32> {
+    let mut alphas = vec![];
+    let mut open_layers = vec![];
+
+    for command in commands {
+        match command {
+            DrawCommand::PushLayer { .. } => {
+                open_layers.push(alphas.len());
+                alphas.push(1.0);
+            }
+            DrawCommand::PopLayer { alpha } => {
+                if let Some(index) = open_layers.pop() {
+                    alphas[index] = *alpha;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    alphas
+}
+
+/// Resolves the segments of the path to lines and cubic curves like they are drawn by the render context.
+pub fn path_ops(path: &Path2D) -> Vec<PathOp> {
+    let mut builder = PathOpsBuilder::default();
+
+    for segment in path.segments() {
+        match *segment {
+            PathSegment::MoveTo { x, y } => builder.move_to(Point::new(x, y)),
+            PathSegment::LineTo { x, y } => builder.line_to(Point::new(x, y)),
+            PathSegment::QuadraticCurveTo { cpx, cpy, x, y } => {
+                let control = Point::new(cpx, cpy);
+                let end = Point::new(x, y);
+                let start = builder.current_or(control);
+
+                // a quadratic curve is a cubic curve with control points at two thirds of the way to its control point
+                builder.curve_to(
+                    start + (control - start) * (2.0 / 3.0),
+                    end + (control - end) * (2.0 / 3.0),
+                    end,
+                );
+            }
+            PathSegment::BezierCurveTo {
+                cp1x,
+                cp1y,
+                cp2x,
+                cp2y,
+                x,
+                y,
+            } => {
+                builder.current_or(Point::new(cp1x, cp1y));
+                builder.curve_to(
+                    Point::new(cp1x, cp1y),
+                    Point::new(cp2x, cp2y),
+                    Point::new(x, y),
+                );
+            }
+            PathSegment::Arc {
+                x,
+                y,
+                radius,
+                start_angle,
+                end_angle,
+                anticlockwise,
+            } => builder.ellipse(
+                Point::new(x, y),
+                radius,
+                radius,
+                0.0,
+                start_angle,
+                arc_sweep(start_angle, end_angle, anticlockwise),
+            ),
+            PathSegment::ArcTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                radius,
+            } => {
+                let corner = Point::new(x1, y1);
+                let start = builder.current_or(corner);
+
+                match arc_to_arc(start, corner, Point::new(x2, y2), radius) {
+                    Some((start, center, start_angle, end_angle, anticlockwise)) => {
+                        builder.line_to(start);
+                        builder.ellipse(
+                            center,
+                            radius,
+                            radius,
+                            0.0,
+                            start_angle,
+                            arc_sweep(start_angle, end_angle, anticlockwise),
+                        );
+                    }
+                    None => builder.line_to(corner),
+                }
+            }
+            PathSegment::Ellipse {
+                x,
+                y,
+                radius_x,
+                radius_y,
+                rotation,
+                start_angle,
+                end_angle,
+            } => builder.ellipse(
+                Point::new(x, y),
+                radius_x,
+                radius_y,
+                rotation,
+                start_angle,
+                arc_sweep(start_angle, end_angle, false),
+            ),
+            PathSegment::Rect {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                builder.move_to(Point::new(x, y));
+                builder.line_to(Point::new(x + width, y));
+                builder.line_to(Point::new(x + width, y + height));
+                builder.line_to(Point::new(x, y + height));
+                builder.close();
+            }
+            PathSegment::ClosePath => builder.close(),
+        }
+    }
+
+    builder.ops
+}
+
+/// Gets the bounds of the path like they are used to draw its gradients. Returns `None` if the path is empty.
+pub fn path_bounds(path: &[PathOp]) -> Option<Rectangle> {
+    let mut bounds: Option<(Point, Point)> = None;
+    let mut current = Point::default();
+    let mut start = Point::default();
+
+    for op in path {
+        let (min, max) = match *op {
+            PathOp::MoveTo(point) => {
+                current = point;
+                start = point;
+                (point, point)
+            }
+            PathOp::LineTo(point) => {
+                current = point;
+                (point, point)
+            }
+            PathOp::CurveTo(cp1, cp2, end) => {
+                let rect = cubic_bezier_rect(current, cp1, cp2, end);
+                current = end;
+                (rect.position(), rect.position() + rect.size())
+            }
+            PathOp::Close => {
+                current = start;
+                continue;
+            }
+        };
+
+        bounds = Some(match bounds {
+            Some((bounds_min, bounds_max)) => (bounds_min.min(min), bounds_max.max(max)),
+            None => (min, max),
+        });
+    }
+
+    bounds.map(|(min, max)| Rectangle::new(min, (max.x() - min.x(), max.y() - min.y())))
+}
+
+/// Gets the color that is used if the brush could not be drawn as gradient, the color of the first stop of a gradient.
+pub fn brush_color(brush: &Brush) -> Color {
+    match brush {
+        Brush::SolidColor(color) => *color,
+        Brush::Gradient(gradient) => gradient
+            .stops
+            .first()
+            .map(|stop| stop.color)
+            .unwrap_or_default(),
+    }
+}
+
+/// Gets the color of text, text is drawn with solid colors or black like on the render context.
+pub fn text_color(brush: &Brush) -> Color {
+    match brush {
+        Brush::SolidColor(color) => *color,
+        _ => Color::from("#000000"),
+    }
+}
+
+/// Formats a number with at most three decimal places.
+pub fn number(value: f64) -> String {
+    let value = (value * 1000.0).round() / 1000.0;
+
+    // avoids negative zeros
+    if value == 0.0 {
+        return "0".to_string();
+    }
+
+    let text = format!("{:.3}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+// Gets the given part of the image. Returns `None` if the part is empty.
+fn crop_image(image: &Image, clip: Rectangle) -> Option<Image> {
+    let left = clip.x().max(0.0).floor() as usize;
+    let top = clip.y().max(0.0).floor() as usize;
+    let right = ((clip.x() + clip.width()).ceil().max(0.0) as usize).min(image.width() as usize);
+    let bottom = ((clip.y() + clip.height()).ceil().max(0.0) as usize).min(image.height() as usize);
+
+    if left >= right || top >= bottom {
+        return None;
+    }
+
+    if left == 0 && top == 0 && right == image.width() as usize && bottom == image.height() as usize
+    {
+        return Some(image.clone());
+    }
+
+    let stride = image.width() as usize;
+    let data = (top..bottom)
+        .flat_map(|y| {
+            image.data()[y * stride + left..y * stride + right]
+                .iter()
+                .copied()
+        })
+        .collect();
+
+    Image::from_data((right - left) as u32, (bottom - top) as u32, data).ok()
+}
+
+#[derive(Default)]
+struct PathOpsBuilder {
+    ops: Vec<PathOp>,
+    current: Option<Point>,
+    start: Point,
+}
+
+impl PathOpsBuilder {
+    // Gets the current point. If there is no sub-path a new one is started at the given point.
+    fn current_or(&mut self, point: Point) -> Point {
+        match self.current {
+            Some(current) => current,
+            None => {
+                self.move_to(point);
+                point
+            }
+        }
+    }
+
+    fn move_to(&mut self, point: Point) {
+        self.ops.push(PathOp::MoveTo(point));
+        self.current = Some(point);
+        self.start = point;
+    }
+
+    fn line_to(&mut self, point: Point) {
+        if self.current.is_none() {
+            self.move_to(point);
+            return;
+        }
+
+        self.ops.push(PathOp::LineTo(point));
+        self.current = Some(point);
+    }
+
+    fn curve_to(&mut self, cp1: Point, cp2: Point, end: Point) {
+        self.ops.push(PathOp::CurveTo(cp1, cp2, end));
+        self.current = Some(end);
+    }
+
+    // Adds an elliptical arc, that is connected with a line to the current point.
+    fn ellipse(
+        &mut self,
+        center: Point,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        start_angle: f64,
+        sweep: f64,
+    ) {
+        let (start, curves) =
+            ellipse_curves(center, radius_x, radius_y, rotation, start_angle, sweep);
+
+        self.line_to(start);
+
+        for (cp1, cp2, end) in curves {
+            self.curve_to(cp1, cp2, end);
+        }
+    }
+
+    fn close(&mut self) {
+        if self.current.is_some() {
+            self.ops.push(PathOp::Close);
+            self.current = Some(self.start);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_ops() {
+        let mut path = Path2D::new();
+        path.rect(0.0, 0.0, 10.0, 5.0);
+        path.line_to(0.0, 10.0);
+
+        assert_eq!(
+            path_ops(&path),
+            vec![
+                PathOp::MoveTo(Point::new(0.0, 0.0)),
+                PathOp::LineTo(Point::new(10.0, 0.0)),
+                PathOp::LineTo(Point::new(10.0, 5.0)),
+                PathOp::LineTo(Point::new(0.0, 5.0)),
+                PathOp::Close,
+                PathOp::LineTo(Point::new(0.0, 10.0)),
+            ]
+        );
+
+        let mut path = Path2D::new();
+        path.arc(5.0, 5.0, 5.0, 0.0, std::f64::consts::PI);
+
+        let ops = path_ops(&path);
+        assert_eq!(ops[0], PathOp::MoveTo(Point::new(10.0, 5.0)));
+        assert_eq!(ops.len(), 3);
+
+        let bounds = path_bounds(&ops).unwrap();
+        assert!((bounds.width() - 10.0).abs() < 1e-9);
+        assert!((bounds.y() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_layer_alphas() {
+        let commands = vec![
+            DrawCommand::PushLayer {
+                x: 0.0,
+                y: 0.0,
+                width: 10.0,
+                height: 10.0,
+            },
+            DrawCommand::PushLayer {
+                x: 0.0,
+                y: 0.0,
+                width: 5.0,
+                height: 5.0,
+            },
+            DrawCommand::PopLayer { alpha: 0.25 },
+            DrawCommand::PopLayer { alpha: 0.5 },
+        ];
+
+        assert_eq!(layer_alphas(&commands), vec![0.5, 0.25]);
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number(1.0), "1");
+        assert_eq!(number(-0.0001), "0");
+        assert_eq!(number(2.5), "2.5");
+        assert_eq!(number(1.0 / 3.0), "0.333");
+    }
+}
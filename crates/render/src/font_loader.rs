@@ -0,0 +1,57 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+/// Gets the font family key of a font file, that is the file name without extension. Returns `None` if the
+/// file is not a `ttf` or `otf` font.
+pub fn font_family(path: &Path) -> Option<String> {
+    let extension = path.extension()?.to_str()?.to_lowercase();
+
+    if extension != "ttf" && extension != "otf" {
+        return None;
+    }
+
+    path.file_stem()?.to_str().map(|s| s.to_string())
+}
+
+/// Gets the font files of the given directory with their font family keys, sorted by family.
+pub fn font_files(path: impl AsRef<Path>) -> io::Result<Vec<(String, PathBuf)>> {
+    let mut files = vec![];
+
+    for entry in std::fs::read_dir(path)? {
+        let path = entry?.path();
+
+        if !path.is_file() {
+            continue;
+        }
+
+        if let Some(family) = font_family(&path) {
+            files.push((family, path));
+        }
+    }
+
+    files.sort();
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::font_family;
+    use std::path::Path;
+
+    #[test]
+    fn test_font_family() {
+        assert_eq!(
+            font_family(Path::new("fonts/Roboto-Regular.ttf")),
+            Some("Roboto-Regular".to_string())
+        );
+        assert_eq!(
+            font_family(Path::new("NotoSansCJK.OTF")),
+            Some("NotoSansCJK".to_string())
+        );
+        assert_eq!(font_family(Path::new("fonts/LICENSE.txt")), None);
+        assert_eq!(font_family(Path::new("fonts/Roboto")), None);
+    }
+}
@@ -0,0 +1,233 @@
+use std::{fmt, sync::Arc, time::Duration};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+#[cfg(not(target_arch = "wasm32"))]
+use image::{
+    codecs::{gif::GifDecoder, png::PngDecoder},
+    AnimationDecoder, ImageFormat,
+};
+
+use crate::platform::Image;
+
+// Most viewers show frames without or with a very short delay for 100ms.
+#[cfg(not(target_arch = "wasm32"))]
+const MIN_FRAME_DELAY: Duration = Duration::from_millis(10);
+
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(100);
+
+/// Describes a single frame of an animated image.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImageFrame {
+    /// The complete image of the frame.
+    pub image: Image,
+
+    /// The time the frame is shown.
+    pub delay: Duration,
+}
+
+impl ImageFrame {
+    /// Creates a new frame.
+    pub fn new(image: Image, delay: Duration) -> Self {
+        ImageFrame { image, delay }
+    }
+}
+
+/// Holds the frames of an animated image like a GIF or an APNG with their delays. Images with a single frame
+/// are loaded as sequence with one frame.
+#[derive(Clone, Default)]
+pub struct ImageFrames {
+    frames: Arc<Vec<ImageFrame>>,
+    source: String,
+}
+
+impl fmt::Debug for ImageFrames {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ImageFrames ( source: {}, frames: {})",
+            self.source,
+            self.frames.len()
+        )
+    }
+}
+
+impl std::cmp::PartialEq for ImageFrames {
+    fn eq(&self, other: &Self) -> bool {
+        // sequences without a source are created in memory and compared by their frames
+        self.source == other.source
+            && (!self.source.is_empty()
+                || Arc::ptr_eq(&self.frames, &other.frames)
+                || self.frames == other.frames)
+    }
+}
+
+impl ImageFrames {
+    /// Creates a new sequence from the given frames.
+    pub fn new(frames: Vec<ImageFrame>) -> Self {
+        ImageFrames {
+            frames: Arc::new(frames),
+            source: String::new(),
+        }
+    }
+
+    /// Loads the frames from the bytes of an image file in memory. Supports animated GIF and APNG, other images are
+    /// loaded as single frame.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let frames = match image::guess_format(bytes) {
+            Ok(ImageFormat::Gif) => GifDecoder::new(bytes)
+                .map_err(|err| format!("Could not load gif: {}", err))?
+                .into_frames()
+                .collect_frames(),
+            Ok(ImageFormat::Png) => PngDecoder::new(bytes)
+                .map_err(|err| format!("Could not load png: {}", err))?
+                .apng()
+                .into_frames()
+                .collect_frames(),
+            _ => return Image::from_bytes(bytes).map(ImageFrames::from),
+        }
+        .map_err(|err| format!("Could not load frames: {}", err))?;
+
+        let frames = frames
+            .into_iter()
+            .map(|frame| {
+                let (numer, denom) = frame.delay().numer_denom_ms();
+                let delay = Duration::from_micros(numer as u64 * 1000 / denom.max(1) as u64);
+
+                Image::from_rgba_image(frame.into_buffer()).map(|image| {
+                    ImageFrame::new(
+                        image,
+                        if delay < MIN_FRAME_DELAY {
+                            DEFAULT_FRAME_DELAY
+                        } else {
+                            delay
+                        },
+                    )
+                })
+            })
+            .collect::<Result<Vec<ImageFrame>, String>>()?;
+
+        Ok(ImageFrames::new(frames))
+    }
+
+    /// Loads the frames from file path. Supports animated GIF and APNG, other images are loaded as single frame.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_path<P: AsRef<Path> + std::fmt::Debug + Clone>(path: P) -> Result<Self, String> {
+        let bytes = std::fs::read(path.clone())
+            .map_err(|_| format!("Could not load image width path: {:?}", path))?;
+
+        let mut frames = ImageFrames::from_bytes(&bytes)?;
+        frames.source = path.as_ref().to_string_lossy().to_string();
+        Ok(frames)
+    }
+
+    /// Loads the image from file path. The canvas of the browser draws only the first frame of an animated image.
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_path(path: impl Into<String>) -> Result<Self, String> {
+        let source = path.into();
+        let mut frames = ImageFrames::from(Image::from_path(source.as_str())?);
+        frames.source = source;
+        Ok(frames)
+    }
+
+    /// Gets the path the frames were loaded from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Gets the frame with the given index.
+    pub fn frame(&self, index: usize) -> Option<&ImageFrame> {
+        self.frames.get(index)
+    }
+
+    /// Gets all frames.
+    pub fn frames(&self) -> &[ImageFrame] {
+        &self.frames
+    }
+
+    /// Returns the number of frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns `true` if there is no frame.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns `true` if there is more than one frame to play.
+    pub fn is_animated(&self) -> bool {
+        self.frames.len() > 1
+    }
+
+    /// Gets the duration of one run through all frames.
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.delay).sum()
+    }
+}
+
+// --- Conversions ---
+
+impl From<Image> for ImageFrames {
+    fn from(image: Image) -> Self {
+        ImageFrames::new(vec![ImageFrame::new(image, Duration::default())])
+    }
+}
+
+impl From<&str> for ImageFrames {
+    fn from(s: &str) -> ImageFrames {
+        ImageFrames::from_path(s).unwrap_or_default()
+    }
+}
+
+impl From<String> for ImageFrames {
+    fn from(s: String) -> ImageFrames {
+        ImageFrames::from_path(s).unwrap_or_default()
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use image::{codecs::gif::GifEncoder, Delay, Frame, Rgba, RgbaImage};
+
+    use super::*;
+
+    #[test]
+    fn test_from_bytes() {
+        let mut bytes = vec![];
+
+        {
+            let mut encoder = GifEncoder::new(&mut bytes);
+            encoder
+                .encode_frames(vec![
+                    Frame::from_parts(
+                        RgbaImage::from_pixel(2, 2, Rgba([255, 0, 0, 255])),
+                        0,
+                        0,
+                        Delay::from_numer_denom_ms(200, 1),
+                    ),
+                    Frame::from_parts(
+                        RgbaImage::from_pixel(2, 2, Rgba([0, 0, 255, 255])),
+                        0,
+                        0,
+                        Delay::from_numer_denom_ms(0, 1),
+                    ),
+                ])
+                .unwrap();
+        }
+
+        let frames = ImageFrames::from_bytes(&bytes).unwrap();
+
+        assert_eq!(frames.len(), 2);
+        assert!(frames.is_animated());
+        assert_eq!(frames.frame(0).unwrap().delay, Duration::from_millis(200));
+        assert_eq!(frames.frame(0).unwrap().image.data()[0], 0xFFFF_0000);
+
+        // frames without delay are shown with the default delay
+        assert_eq!(frames.frame(1).unwrap().delay, DEFAULT_FRAME_DELAY);
+        assert_eq!(frames.duration(), Duration::from_millis(300));
+    }
+}
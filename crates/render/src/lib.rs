@@ -25,6 +25,22 @@ pub mod platform;
 #[cfg(all(not(target_arch = "wasm32"), feature = "miniraq"))]
 pub mod concurrent;
 
+#[cfg(any(
+    target_arch = "wasm32",
+    feature = "default",
+    feature = "orbraq",
+    feature = "miniraq"
+))]
+pub use self::display_list::*;
+
+#[cfg(any(
+    target_arch = "wasm32",
+    feature = "default",
+    feature = "orbraq",
+    feature = "miniraq"
+))]
+mod display_list;
+
 #[cfg(all(
     feature = "miniraq",
     any(
@@ -42,8 +58,16 @@ pub mod platform;
 #[cfg(target_arch = "wasm32")]
 pub use platform::RenderContext2D;
 
+pub use self::backend::*;
+pub use self::font_loader::*;
+pub use self::image_frames::*;
+pub use self::path::*;
 pub use self::render_target::*;
 
+mod backend;
+mod font_loader;
+mod image_frames;
+mod path;
 mod render_target;
 
 /// Defines the current configuration of the render ctx.
@@ -52,6 +76,12 @@ pub struct RenderConfig {
     pub fill_style: utils::Brush,
     pub stroke_style: utils::Brush,
     pub line_width: f64,
+    pub line_cap: utils::LineCap,
+    pub line_join: utils::LineJoin,
+    pub miter_limit: f64,
+    pub line_dash: Vec<f64>,
+    pub line_dash_offset: f64,
+    pub fill_rule: utils::FillRule,
     pub font_config: FontConfig,
     pub alpha: f32,
 }
@@ -62,6 +92,12 @@ impl Default for RenderConfig {
             fill_style: utils::Brush::default(),
             stroke_style: utils::Brush::default(),
             line_width: 1.,
+            line_cap: utils::LineCap::default(),
+            line_join: utils::LineJoin::default(),
+            miter_limit: 10.,
+            line_dash: vec![],
+            line_dash_offset: 0.,
+            fill_rule: utils::FillRule::default(),
             font_config: FontConfig::default(),
             alpha: 1.,
         }
@@ -75,6 +111,26 @@ pub struct TextMetrics {
     pub height: f64,
 }
 
+/// Describes a cluster of a shaped text. A cluster is the smallest unit of text, that could be selected or deleted,
+/// e.g. a char with its combining marks or a ligature.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct TextCluster {
+    /// Start index of the cluster in the text.
+    pub start: usize,
+
+    /// End index (exclusive) of the cluster in the text.
+    pub end: usize,
+
+    /// Horizontal position of the cluster in the rendered text.
+    pub x: f64,
+
+    /// Width of the cluster.
+    pub width: f64,
+
+    /// `true` if the cluster is part of a right-to-left run.
+    pub rtl: bool,
+}
+
 // Internal font helper.
 #[derive(Default, Clone, PartialEq, Debug)]
 pub struct FontConfig {
@@ -127,3 +183,42 @@ impl fmt::Debug for Box<dyn PipelineTrait> {
         write!(f, "Box<dyn PipelineTrait>")
     }
 }
+
+pub trait RenderPipeline2D {
+    /// Draws the content of the pipeline with the 2D API of the render context. The render context is clipped and
+    /// translated to the bounds of the widget, `size` is the size of the widget.
+    ///
+    /// Use `transform` to rotate, scale or move the content relative to the widget. `set_transform` replaces the
+    /// translation to the widget. The transform is reset after the pipeline is drawn.
+    fn draw(&self, render_context: &mut PlatformBackend, size: utils::Size);
+}
+
+/// Used to implement a custom render pipeline, that draws vector graphics with a render context.
+pub trait Pipeline2DTrait: RenderPipeline2D + Any + Send {
+    /// Equality for two Pipeline objects.
+    fn box_eq(&self, other: &dyn Any) -> bool;
+
+    /// Converts self to an any reference.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Clones self as box.
+    fn clone_box(&self) -> Box<dyn Pipeline2DTrait>;
+}
+
+impl PartialEq for Box<dyn Pipeline2DTrait> {
+    fn eq(&self, other: &Box<dyn Pipeline2DTrait>) -> bool {
+        self.box_eq(other.as_any())
+    }
+}
+
+impl Clone for Box<dyn Pipeline2DTrait> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+impl fmt::Debug for Box<dyn Pipeline2DTrait> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Box<dyn Pipeline2DTrait>")
+    }
+}
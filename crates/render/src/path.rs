@@ -0,0 +1,277 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::utils::CornerRadius;
+
+/// Describes a single segment of a `Path2D`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PathSegment {
+    MoveTo {
+        x: f64,
+        y: f64,
+    },
+    LineTo {
+        x: f64,
+        y: f64,
+    },
+    QuadraticCurveTo {
+        cpx: f64,
+        cpy: f64,
+        x: f64,
+        y: f64,
+    },
+    BezierCurveTo {
+        cp1x: f64,
+        cp1y: f64,
+        cp2x: f64,
+        cp2y: f64,
+        x: f64,
+        y: f64,
+    },
+    Arc {
+        x: f64,
+        y: f64,
+        radius: f64,
+        start_angle: f64,
+        end_angle: f64,
+        anticlockwise: bool,
+    },
+    ArcTo {
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        radius: f64,
+    },
+    Ellipse {
+        x: f64,
+        y: f64,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    },
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    ClosePath,
+}
+
+/// Describes a path independent of the current path of the render context. It could be filled, stroked or used as
+/// clip with `fill_path`, `stroke_path` and `clip_path` of the `RenderContext2D`.
+///
+/// # Examples
+/// ```text
+/// let mut path = Path2D::new();
+/// path.rounded_rect(0.0, 0.0, 100.0, 50.0, [4.0; 4]);
+///
+/// render_context_2_d.set_line_dash(vec![4.0, 2.0]);
+/// render_context_2_d.stroke_path(&path);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path2D {
+    segments: Vec<PathSegment>,
+}
+
+impl Path2D {
+    /// Creates a new empty path.
+    pub fn new() -> Self {
+        Path2D::default()
+    }
+
+    /// Gets the segments of the path.
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// Returns `true` if the path contains no segments.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Begins a new sub-path at the given point.
+    pub fn move_to(&mut self, x: f64, y: f64) {
+        self.segments.push(PathSegment::MoveTo { x, y });
+    }
+
+    /// Adds a straight line to the given point.
+    pub fn line_to(&mut self, x: f64, y: f64) {
+        self.segments.push(PathSegment::LineTo { x, y });
+    }
+
+    /// Adds a quadratic Bézier curve.
+    pub fn quadratic_curve_to(&mut self, cpx: f64, cpy: f64, x: f64, y: f64) {
+        self.segments
+            .push(PathSegment::QuadraticCurveTo { cpx, cpy, x, y });
+    }
+
+    /// Adds a cubic Bézier curve.
+    pub fn bezier_curve_to(&mut self, cp1x: f64, cp1y: f64, cp2x: f64, cp2y: f64, x: f64, y: f64) {
+        self.segments.push(PathSegment::BezierCurveTo {
+            cp1x,
+            cp1y,
+            cp2x,
+            cp2y,
+            x,
+            y,
+        });
+    }
+
+    /// Adds a circular arc centered at (x, y), that is drawn clockwise from the start to the end angle.
+    pub fn arc(&mut self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64) {
+        self.segments.push(PathSegment::Arc {
+            x,
+            y,
+            radius,
+            start_angle,
+            end_angle,
+            anticlockwise: false,
+        });
+    }
+
+    /// Adds a circular arc with the given radius, that connects the line from the current point to (x1, y1) with
+    /// the line from (x1, y1) to (x2, y2).
+    pub fn arc_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, radius: f64) {
+        self.segments.push(PathSegment::ArcTo {
+            x1,
+            y1,
+            x2,
+            y2,
+            radius,
+        });
+    }
+
+    /// Adds an elliptical arc centered at (x, y) and rotated by the given angle, that is drawn clockwise from the
+    /// start to the end angle.
+    #[allow(clippy::too_many_arguments)]
+    pub fn ellipse(
+        &mut self,
+        x: f64,
+        y: f64,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    ) {
+        self.segments.push(PathSegment::Ellipse {
+            x,
+            y,
+            radius_x,
+            radius_y,
+            rotation,
+            start_angle,
+            end_angle,
+        });
+    }
+
+    /// Adds a rectangle.
+    pub fn rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.segments.push(PathSegment::Rect {
+            x,
+            y,
+            width,
+            height,
+        });
+    }
+
+    /// Adds a rectangle with rounded corners. The radii are given in the order top left, top right, bottom right and
+    /// bottom left. If the radii of a side are larger than the side, all radii are reduced by the same factor.
+    pub fn rounded_rect(&mut self, x: f64, y: f64, width: f64, height: f64, radii: [f64; 4]) {
+        let [top_left, top_right, bottom_right, bottom_left] =
+            rounded_rect_radii(width, height, radii);
+        let right = x + width;
+        let bottom = y + height;
+
+        self.move_to(x + top_left, y);
+        self.line_to(right - top_right, y);
+        self.corner(right - top_right, y + top_right, top_right, -FRAC_PI_2);
+        self.line_to(right, bottom - bottom_right);
+        self.corner(
+            right - bottom_right,
+            bottom - bottom_right,
+            bottom_right,
+            0.0,
+        );
+        self.line_to(x + bottom_left, bottom);
+        self.corner(
+            x + bottom_left,
+            bottom - bottom_left,
+            bottom_left,
+            FRAC_PI_2,
+        );
+        self.line_to(x, y + top_left);
+        self.corner(x + top_left, y + top_left, top_left, PI);
+        self.close_path();
+    }
+
+    /// Adds the segments of the given path to this path.
+    pub fn add_path(&mut self, path: &Path2D) {
+        self.segments.extend_from_slice(&path.segments);
+    }
+
+    /// Closes the current sub-path with a straight line to its start.
+    pub fn close_path(&mut self) {
+        self.segments.push(PathSegment::ClosePath);
+    }
+
+    // Adds the quarter circle of a rounded corner.
+    fn corner(&mut self, x: f64, y: f64, radius: f64, start_angle: f64) {
+        if radius > 0.0 {
+            self.arc(x, y, radius, start_angle, start_angle + FRAC_PI_2);
+        }
+    }
+}
+
+// Reduces the radii of a rounded rectangle, so that the radii of each side fit into the side.
+fn rounded_rect_radii(width: f64, height: f64, radii: [f64; 4]) -> [f64; 4] {
+    let [top_left, top_right, bottom_right, bottom_left] = radii;
+
+    CornerRadius::new(top_left, top_right, bottom_right, bottom_left)
+        .fit(width, height)
+        .radii()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rounded_rect_radii() {
+        assert_eq!(
+            rounded_rect_radii(100.0, 50.0, [5.0, 10.0, 0.0, -2.0]),
+            [5.0, 10.0, 0.0, 0.0]
+        );
+
+        // the radii of the left side are too large
+        assert_eq!(
+            rounded_rect_radii(100.0, 20.0, [20.0, 0.0, 0.0, 20.0]),
+            [10.0, 0.0, 0.0, 10.0]
+        );
+    }
+
+    #[test]
+    fn test_rounded_rect() {
+        let mut path = Path2D::new();
+        path.rounded_rect(0.0, 0.0, 20.0, 10.0, [5.0, 0.0, 0.0, 0.0]);
+
+        // only the top left corner is rounded
+        assert_eq!(path.segments().len(), 7);
+        assert_eq!(path.segments()[0], PathSegment::MoveTo { x: 5.0, y: 0.0 });
+        assert_eq!(
+            path.segments()[5],
+            PathSegment::Arc {
+                x: 5.0,
+                y: 5.0,
+                radius: 5.0,
+                start_angle: PI,
+                end_angle: PI + FRAC_PI_2,
+                anticlockwise: false
+            }
+        );
+        assert_eq!(path.segments()[6], PathSegment::ClosePath);
+    }
+}
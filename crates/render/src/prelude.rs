@@ -1,4 +1,5 @@
 #[cfg(not(target_arch = "wasm32"))]
 pub use crate::platform::Font;
 pub use crate::platform::Image;
+pub use crate::platform::Svg;
 pub use crate::*;
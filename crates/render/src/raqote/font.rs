@@ -1,72 +1,245 @@
-use crate::utils::{Color, Rectangle};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use super::glyph_cache::{GlyphBitmap, GlyphCache, GlyphKey};
+use crate::{
+    utils::{Color, Rectangle},
+    TextCluster,
+};
+
+// Used to identify fonts in the glyph cache.
+static NEXT_FONT_ID: AtomicUsize = AtomicUsize::new(0);
+
+// A glyph of a shaped text in visual order.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ShapedGlyph {
+    id: u16,
+    // index of the font in the font chain, that provides the glyph
+    font: usize,
+    // byte offset of the first char of the glyph's cluster
+    cluster: usize,
+    // position of the pen before the glyph is drawn
+    pen: f64,
+    offset: (f64, f64),
+    advance: f64,
+    rtl: bool,
+}
+
+#[derive(Debug, Clone)]
+enum FontData {
+    Static(&'static [u8]),
+    Owned(Arc<Vec<u8>>),
+}
+
+impl FontData {
+    // Gets the bytes of the font.
+    fn bytes(&self) -> &[u8] {
+        match self {
+            FontData::Static(data) => data,
+            FontData::Owned(data) => data.as_slice(),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Font {
+    id: usize,
     inner: rusttype::Font<'static>,
+    // the face used by rustybuzz to shape text is parsed from these bytes on each shaping of a text
+    data: FontData,
 }
 
 impl Font {
     pub fn from_bytes(bytes: &'static [u8]) -> Result<Self, &'static str> {
-        rusttype::Font::try_from_bytes(bytes)
-            .map(|font| Font { inner: font })
+        Font::from_data(
+            rusttype::Font::try_from_bytes(bytes),
+            FontData::Static(bytes),
+        )
+    }
+
+    /// Creates a font from owned bytes e.g. the content of a font file loaded at runtime.
+    pub fn from_vec(bytes: Vec<u8>) -> Result<Self, &'static str> {
+        Font::from_shared(Arc::new(bytes))
+    }
+
+    /// Creates a font from shared bytes. Fonts of multiple render contexts could share the bytes to shape text,
+    /// the rasterizer keeps its own copy of them.
+    pub fn from_shared(bytes: Arc<Vec<u8>>) -> Result<Self, &'static str> {
+        let inner = rusttype::Font::try_from_vec(bytes.as_ref().clone());
+        Font::from_data(inner, FontData::Owned(bytes))
+    }
+
+    fn from_data(
+        inner: Option<rusttype::Font<'static>>,
+        data: FontData,
+    ) -> Result<Self, &'static str> {
+        inner
+            .map(|inner| Font {
+                id: NEXT_FONT_ID.fetch_add(1, Ordering::Relaxed),
+                inner,
+                data,
+            })
             .ok_or("Could not load font from bytes")
     }
 
-    pub fn measure_text(&self, text: &str, size: f64) -> (f64, f64) {
-        let scale = rusttype::Scale::uniform(size as f32);
-        let v_metrics = self.inner.v_metrics(scale);
-        let offset = rusttype::point(0.0, v_metrics.ascent);
+    /// Returns `true` if the font contains a glyph for the given char.
+    pub fn has_glyph(&self, c: char) -> bool {
+        self.inner.glyph(c).id().0 != 0
+    }
 
-        let pixel_height = size.ceil();
+    // Builds the font chain of the font with its fallbacks.
+    fn chain<'a>(&'a self, fallbacks: &[&'a Font]) -> Vec<&'a Font> {
+        let mut chain = Vec::with_capacity(fallbacks.len() + 1);
+        chain.push(self);
+        chain.extend_from_slice(fallbacks);
+        chain
+    }
+
+    // Gets the shaped glyphs of the text from the layout cache or shapes the text.
+    fn layout(
+        &self,
+        fallbacks: &[&Font],
+        text: &str,
+        size: f64,
+        cache: &mut GlyphCache,
+    ) -> Arc<Vec<ShapedGlyph>> {
+        let fonts = self.chain(fallbacks);
 
-        // Glyphs to draw for "RustType". Feel free to try other strings.
-        let glyphs: Vec<rusttype::PositionedGlyph> =
-            self.inner.layout(text, scale, offset).collect();
+        cache.layout(fonts.iter().map(|f| f.id).collect(), size, text, || {
+            shape(&fonts, text, size)
+        })
+    }
 
-        let width = glyphs
+    /// Measures the text. Chars that are missing in the font are measured with the first font of `fallbacks` that
+    /// contains them.
+    pub fn measure_text(
+        &self,
+        text: &str,
+        size: f64,
+        fallbacks: &[&Font],
+        cache: &mut GlyphCache,
+    ) -> (f64, f64) {
+        let width = self
+            .layout(fallbacks, text, size, cache)
             .iter()
-            .rev()
-            .map(|g| g.position().x as f32 + g.unpositioned().h_metrics().advance_width)
-            .next()
-            .unwrap_or(0.0)
-            .ceil() as f64;
+            .map(|g| g.advance)
+            .sum::<f64>()
+            .ceil();
+
+        (width, size.ceil())
+    }
+
+    /// Gets the clusters of the shaped text in logical order. A cluster is the smallest unit of text, that could
+    /// be selected or deleted, e.g. a char with its combining marks or a ligature.
+    pub fn measure_clusters(
+        &self,
+        text: &str,
+        size: f64,
+        fallbacks: &[&Font],
+        cache: &mut GlyphCache,
+    ) -> Vec<TextCluster> {
+        let mut clusters: Vec<TextCluster> = vec![];
+
+        for glyph in self.layout(fallbacks, text, size, cache).iter() {
+            match clusters.iter_mut().find(|c| c.start == glyph.cluster) {
+                Some(cluster) => {
+                    let right = (cluster.x + cluster.width).max(glyph.pen + glyph.advance);
+                    cluster.x = cluster.x.min(glyph.pen);
+                    cluster.width = right - cluster.x;
+                }
+                None => clusters.push(TextCluster {
+                    start: glyph.cluster,
+                    end: text.len(),
+                    x: glyph.pen,
+                    width: glyph.advance,
+                    rtl: glyph.rtl,
+                }),
+            }
+        }
+
+        clusters.sort_by_key(|c| c.start);
+
+        for i in 1..clusters.len() {
+            clusters[i - 1].end = clusters[i].start;
+        }
 
-        (width, pixel_height)
+        clusters
     }
 
+    // Rasterizes the glyph with the subpixel offset of the key.
+    fn rasterize(&self, key: &GlyphKey, size: f64) -> GlyphBitmap {
+        let offset = key.subpixel_offset();
+        let glyph = self
+            .inner
+            .glyph(rusttype::GlyphId(key.glyph))
+            .scaled(rusttype::Scale::uniform(size as f32))
+            .positioned(rusttype::point(offset.0, offset.1));
+
+        let bb = match glyph.pixel_bounding_box() {
+            Some(bb) => bb,
+            None => return GlyphBitmap::default(),
+        };
+
+        let width = bb.width() as usize;
+        let height = bb.height() as usize;
+        let mut coverage = vec![0; width * height];
+
+        glyph.draw(|x, y, v| {
+            coverage[y as usize * width + x as usize] = (v * 255.0).round() as u8;
+        });
+
+        GlyphBitmap {
+            left: bb.min.x,
+            top: bb.min.y,
+            width,
+            height,
+            coverage,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn render_text(
         &self,
         text: &str,
         data: &mut [u32],
-        transform: &raqote::Transform,
         width: f64,
         height: f64,
         config: (f64, Color, f32),
         position: (f64, f64),
+        fallbacks: &[&Font],
+        cache: &mut GlyphCache,
     ) {
         self.render_text_clipped(
             text,
             data,
-            transform,
             width,
             height,
             config,
             position,
             Rectangle::new((0.0, 0.0), (width, std::f64::MAX)),
+            fallbacks,
+            cache,
         );
     }
 
+    /// Renders the text. The glyphs are rasterized once per font size and subpixel offset and are taken from
+    /// the glyph cache afterwards.
+    #[allow(clippy::too_many_arguments)]
     pub fn render_text_clipped(
         &self,
         text: &str,
         data: &mut [u32],
-        transform: &raqote::Transform,
         width: f64,
         height: f64,
         config: (f64, Color, f32),
         position: (f64, f64),
         clip: Rectangle,
+        fallbacks: &[&Font],
+        cache: &mut GlyphCache,
     ) {
+        let fonts = self.chain(fallbacks);
         let scale = rusttype::Scale::uniform(config.0 as f32);
 
         // The origin of a line of text is at the baseline (roughly where non-descending letters sit).
@@ -74,69 +247,195 @@ impl Font {
         // v_metrics.ascent is the distance between the baseline and the highest edge of any glyph in
         // the font. That's enough to guarantee that there's no clipping.
         let v_metrics = self.inner.v_metrics(scale);
-        let offset = rusttype::point(0.0, v_metrics.ascent);
 
-        // Glyphs to draw for "RustType". Feel free to try other strings.
-        let glyphs: Vec<rusttype::PositionedGlyph> =
-            self.inner.layout(text, scale, offset).collect();
-
-        let pixel_width = glyphs
-            .iter()
-            .rev()
-            .map(|g| g.position().x as f32 + g.unpositioned().h_metrics().advance_width)
-            .next()
-            .unwrap_or(0.0)
-            .ceil() as i32;
+        let shaped_glyphs = self.layout(fallbacks, text, config.0, cache);
 
+        let pixel_width = shaped_glyphs.iter().map(|g| g.advance).sum::<f64>().ceil() as i32;
         let pixel_height = config.0.ceil() as i32;
 
-        for g in glyphs.iter() {
-            if let Some(bb) = g.pixel_bounding_box() {
-                g.draw(|off_x, off_y, v| {
-                    let off_x = off_x as i32 + bb.min.x;
-                    let off_y = off_y as i32 + bb.min.y;
-
-                    if off_x >= 0
-                        && off_x < pixel_width
-                        && off_y >= 0
-                        && off_y < pixel_height
-                        && position.0 + off_x as f64 >= clip.x()
-                        && position.0 + off_x as f64 <= clip.x() + clip.width()
-                        && position.1 + off_y as f64 >= clip.y()
-                        && position.1 + off_y as f64 <= clip.y() + clip.height()
-                        && position.0 + (off_x as f64) < width
-                        && position.1 + (off_y as f64) < height
-                    {
-                        // Alpha blending from orbclient
-                        let alpha = (config.2 * v * 255.0) as u32;
-                        let new = (alpha << 24) | (config.1.data & 0x00FF_FFFF);
-
-                        let absolute_position = transform.transform_point(raqote::Point::new(
-                            (position.0 + off_x as f64) as f32,
-                            (position.1 + off_y as f64) as f32,
-                        ));
-                        let index = (absolute_position.y as i32 * width as i32
-                            + absolute_position.x as i32)
-                            as usize;
-                        if index >= data.len() {
-                            return;
-                        }
-                        let old = &mut data[index];
-                        if alpha >= 255 {
-                            *old = new;
-                        } else if alpha > 0 {
-                            let n_alpha = 255 - alpha;
-                            let rb = ((n_alpha * (*old & 0x00FF_00FF))
-                                + (alpha * (new & 0x00FF_00FF)))
-                                >> 8;
-                            let ag = (n_alpha * ((*old & 0xFF00_FF00) >> 8))
-                                + (alpha * (0x0100_0000 | ((new & 0x0000_FF00) >> 8)));
-
-                            *old = (rb & 0x00FF_00FF) | (ag & 0xFF00_FF00);
-                        }
+        for g in shaped_glyphs.iter() {
+            let font = fonts[g.font];
+            let x = g.pen + g.offset.0;
+            let y = v_metrics.ascent as f64 + g.offset.1;
+            let key = GlyphKey::new(font.id, config.0, g.id, (x - x.floor(), y - y.floor()));
+
+            let bitmap = cache.glyph(key, || font.rasterize(&key, config.0));
+
+            for (i, v) in bitmap.coverage.iter().enumerate() {
+                if *v == 0 {
+                    continue;
+                }
+
+                let off_x = x.floor() as i32 + bitmap.left + (i % bitmap.width) as i32;
+                let off_y = y.floor() as i32 + bitmap.top + (i / bitmap.width) as i32;
+
+                if off_x >= 0
+                    && off_x < pixel_width
+                    && off_y >= 0
+                    && off_y < pixel_height
+                    && position.0 + off_x as f64 >= clip.x()
+                    && position.0 + off_x as f64 <= clip.x() + clip.width()
+                    && position.1 + off_y as f64 >= clip.y()
+                    && position.1 + off_y as f64 <= clip.y() + clip.height()
+                    && position.0 + (off_x as f64) < width
+                    && position.1 + (off_y as f64) < height
+                {
+                    // Alpha blending from orbclient
+                    let alpha = (config.2 * *v as f32) as u32;
+                    let new = (alpha << 24) | (config.1.data & 0x00FF_FFFF);
+
+                    let index = ((position.1 + off_y as f64) as i32 * width as i32
+                        + (position.0 + off_x as f64) as i32)
+                        as usize;
+                    if index >= data.len() {
+                        continue;
+                    }
+                    let old = &mut data[index];
+                    if alpha >= 255 {
+                        *old = new;
+                    } else if alpha > 0 {
+                        let n_alpha = 255 - alpha;
+                        let rb =
+                            ((n_alpha * (*old & 0x00FF_00FF)) + (alpha * (new & 0x00FF_00FF))) >> 8;
+                        let ag = (n_alpha * ((*old & 0xFF00_FF00) >> 8))
+                            + (alpha * (0x0100_0000 | ((new & 0x0000_FF00) >> 8)));
+
+                        *old = (rb & 0x00FF_00FF) | (ag & 0xFF00_FF00);
                     }
+                }
+            }
+        }
+    }
+}
+
+// Picks the font of the chain for the given char. The first font is preferred, the font of the previous char is kept
+// as long as it contains the char, so that combining marks stay with their base char.
+fn select_font(fonts: &[&Font], current: Option<usize>, c: char) -> usize {
+    if fonts[0].has_glyph(c) {
+        return 0;
+    }
+
+    if let Some(current) = current {
+        if fonts[current].has_glyph(c) {
+            return current;
+        }
+    }
+
+    fonts.iter().position(|f| f.has_glyph(c)).unwrap_or(0)
+}
+
+// Splits the text in segments of the same font. The segments are returned as byte ranges in logical order.
+fn font_segments(fonts: &[&Font], text: &str) -> Vec<(usize, std::ops::Range<usize>)> {
+    let mut segments: Vec<(usize, std::ops::Range<usize>)> = vec![];
+
+    for (i, c) in text.char_indices() {
+        let current = segments.last().map(|s| s.0);
+        let font = select_font(fonts, current, c);
+
+        match segments.last_mut() {
+            Some(segment) if segment.0 == font => segment.1.end = i + c.len_utf8(),
+            _ => segments.push((font, i..i + c.len_utf8())),
+        }
+    }
+
+    segments
+}
+
+// Shapes the text. The text is split in runs of the same direction by the unicode bidi algorithm and each run in
+// segments of the same font of the font chain. The glyphs of all runs are returned in visual order.
+fn shape(fonts: &[&Font], text: &str, size: f64) -> Vec<ShapedGlyph> {
+    let mut glyphs = vec![];
+
+    let bidi_info = unicode_bidi::BidiInfo::new(text, None);
+    let mut pen = 0.0;
+
+    for paragraph in &bidi_info.paragraphs {
+        let (levels, runs) = bidi_info.visual_runs(paragraph, paragraph.range.clone());
+
+        for run in runs {
+            let rtl = levels[run.start].is_rtl();
+
+            let mut segments = font_segments(fonts, &text[run.clone()]);
+            if rtl {
+                segments.reverse();
+            }
+
+            for (font_index, range) in segments {
+                let font = fonts[font_index];
+
+                let face = match rustybuzz::Face::from_slice(font.data.bytes(), 0) {
+                    Some(face) => face,
+                    None => continue,
+                };
+
+                // font units are scaled the same way as rusttype scales the glyphs
+                let v_metrics = font.inner.v_metrics_unscaled();
+                let factor = size / (v_metrics.ascent - v_metrics.descent) as f64;
+
+                let start = run.start + range.start;
+
+                let mut buffer = rustybuzz::UnicodeBuffer::new();
+                buffer.push_str(&text[start..run.start + range.end]);
+                buffer.set_direction(if rtl {
+                    rustybuzz::Direction::RightToLeft
+                } else {
+                    rustybuzz::Direction::LeftToRight
                 });
+                buffer.guess_segment_properties();
+
+                let output = rustybuzz::shape(&face, &[], buffer);
+
+                for (info, position) in output.glyph_infos().iter().zip(output.glyph_positions()) {
+                    let advance = position.x_advance as f64 * factor;
+
+                    glyphs.push(ShapedGlyph {
+                        id: info.glyph_id as u16,
+                        font: font_index,
+                        cluster: start + info.cluster as usize,
+                        pen,
+                        offset: (
+                            position.x_offset as f64 * factor,
+                            -position.y_offset as f64 * factor,
+                        ),
+                        advance,
+                        rtl,
+                    });
+
+                    pen += advance;
+                }
             }
         }
     }
+
+    glyphs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_font_segments() {
+        let roboto = Font::from_bytes(include_bytes!(
+            "../../../theme_default/assets/fonts/Roboto-Regular.ttf"
+        ))
+        .unwrap();
+        let icons = Font::from_bytes(include_bytes!(
+            "../../../theme_default/assets/fonts/MaterialIcons.ttf"
+        ))
+        .unwrap();
+
+        let text = "a \u{e5ca} b";
+        assert_eq!(
+            font_segments(&[&roboto, &icons], text),
+            vec![(0, 0..2), (1, 2..5), (0, 5..7)]
+        );
+
+        // without fallback all chars are taken from the selected font
+        assert_eq!(font_segments(&[&roboto], text), vec![(0, 0..7)]);
+
+        let (width, _) =
+            roboto.measure_text("\u{e5ca}", 16.0, &[&icons], &mut GlyphCache::default());
+        assert!(width > 0.0);
+    }
 }
@@ -0,0 +1,165 @@
+use std::{mem, sync::Arc};
+
+use super::{font::ShapedGlyph, lru_cache::LruCache};
+
+/// Default memory budget of the rasterized glyphs in bytes.
+pub const GLYPH_CACHE_BUDGET: usize = 4 * 1024 * 1024;
+
+/// Default memory budget of the shaped text layouts in bytes.
+pub const LAYOUT_CACHE_BUDGET: usize = 1024 * 1024;
+
+// Number of subpixel positions a glyph is rasterized for in each direction.
+const SUBPIXEL_STEPS: f64 = 4.0;
+
+/// Identifies a rasterized glyph by font, font size, glyph id and subpixel offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GlyphKey {
+    pub font: usize,
+    pub size: u64,
+    pub glyph: u16,
+    pub subpixel: (u8, u8),
+}
+
+impl GlyphKey {
+    pub fn new(font: usize, size: f64, glyph: u16, subpixel: (f64, f64)) -> Self {
+        GlyphKey {
+            font,
+            size: size.to_bits(),
+            glyph,
+            subpixel: (
+                (subpixel.0 * SUBPIXEL_STEPS) as u8,
+                (subpixel.1 * SUBPIXEL_STEPS) as u8,
+            ),
+        }
+    }
+
+    /// Gets the quantized subpixel offset the glyph is rasterized with.
+    pub fn subpixel_offset(&self) -> (f32, f32) {
+        (
+            (self.subpixel.0 as f64 / SUBPIXEL_STEPS) as f32,
+            (self.subpixel.1 as f64 / SUBPIXEL_STEPS) as f32,
+        )
+    }
+}
+
+/// The coverage of a rasterized glyph relative to its origin.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GlyphBitmap {
+    pub left: i32,
+    pub top: i32,
+    pub width: usize,
+    pub height: usize,
+    pub coverage: Vec<u8>,
+}
+
+// Identifies a shaped text by its font chain, font size and content.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct LayoutKey {
+    fonts: Vec<usize>,
+    size: u64,
+    text: String,
+}
+
+/// Caches rasterized glyphs and shaped text layouts, so that text that is drawn or measured again is neither
+/// rasterized nor shaped again.
+#[derive(Debug)]
+pub struct GlyphCache {
+    glyphs: LruCache<GlyphKey, Arc<GlyphBitmap>>,
+    layouts: LruCache<LayoutKey, Arc<Vec<ShapedGlyph>>>,
+}
+
+impl Default for GlyphCache {
+    fn default() -> Self {
+        GlyphCache::new(GLYPH_CACHE_BUDGET, LAYOUT_CACHE_BUDGET)
+    }
+}
+
+impl GlyphCache {
+    /// Creates a new cache with the given memory budgets in bytes.
+    pub fn new(glyph_budget: usize, layout_budget: usize) -> Self {
+        GlyphCache {
+            glyphs: LruCache::new(glyph_budget),
+            layouts: LruCache::new(layout_budget),
+        }
+    }
+
+    /// Gets the rasterized glyph of the key. If the glyph is not cached yet it is rasterized by the given function.
+    pub fn glyph<F>(&mut self, key: GlyphKey, rasterize: F) -> Arc<GlyphBitmap>
+    where
+        F: FnOnce() -> GlyphBitmap,
+    {
+        if let Some(bitmap) = self.glyphs.get(&key) {
+            return bitmap.clone();
+        }
+
+        let bitmap = Arc::new(rasterize());
+        let size = mem::size_of::<GlyphBitmap>() + bitmap.coverage.len();
+        self.glyphs.insert(key, bitmap.clone(), size);
+
+        bitmap
+    }
+
+    pub(super) fn layout<F>(
+        &mut self,
+        fonts: Vec<usize>,
+        size: f64,
+        text: &str,
+        shape: F,
+    ) -> Arc<Vec<ShapedGlyph>>
+    where
+        F: FnOnce() -> Vec<ShapedGlyph>,
+    {
+        let key = LayoutKey {
+            fonts,
+            size: size.to_bits(),
+            text: text.to_string(),
+        };
+
+        if let Some(layout) = self.layouts.get(&key) {
+            return layout.clone();
+        }
+
+        let layout = Arc::new(shape());
+        let size = mem::size_of::<LayoutKey>()
+            + key.text.len()
+            + key.fonts.len() * mem::size_of::<usize>()
+            + layout.len() * mem::size_of::<ShapedGlyph>();
+        self.layouts.insert(key, layout.clone(), size);
+
+        layout
+    }
+
+    /// Removes all cached glyphs and layouts.
+    pub fn clear(&mut self) {
+        self.glyphs.clear();
+        self.layouts.clear();
+    }
+
+    /// Gets the number of cached glyphs.
+    pub fn glyph_count(&self) -> usize {
+        self.glyphs.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glyph_key() {
+        let key = GlyphKey::new(1, 12.0, 4, (0.3, 0.8));
+        assert_eq!(key.subpixel, (1, 3));
+        assert_eq!(key.subpixel_offset(), (0.25, 0.75));
+
+        let mut cache = GlyphCache::default();
+        let mut rasterized = 0;
+        for _ in 0..3 {
+            cache.glyph(key, || {
+                rasterized += 1;
+                GlyphBitmap::default()
+            });
+        }
+        assert_eq!(rasterized, 1);
+        assert_eq!(cache.glyph_count(), 1);
+    }
+}
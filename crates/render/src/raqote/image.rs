@@ -1,11 +1,18 @@
-use std::{fmt, path::Path};
+use std::{fmt, path::Path, sync::Arc};
 
-use crate::RenderTarget;
+use super::Svg;
+use crate::{
+    common::{premultiply, unpremultiply},
+    RenderTarget,
+};
 
+/// An image with its pixels. The pixels are stored as ARGB with premultiplied alpha and are shared between the clones
+/// of an image, they are copied only if a clone is drawn into.
 #[derive(Clone, Default)]
 pub struct Image {
-    render_target: RenderTarget,
+    render_target: Arc<RenderTarget>,
     source: String,
+    svg: Option<Svg>,
 }
 
 impl fmt::Debug for Image {
@@ -16,7 +23,13 @@ impl fmt::Debug for Image {
 
 impl std::cmp::PartialEq for Image {
     fn eq(&self, other: &Self) -> bool {
+        // images without a source are created in memory and compared by their pixels
         self.source == other.source
+            && self.svg == other.svg
+            && (!self.source.is_empty()
+                || Arc::ptr_eq(&self.render_target, &other.render_target)
+                || (self.render_target == other.render_target
+                    && self.render_target.data == other.render_target.data))
     }
 }
 
@@ -24,37 +37,84 @@ impl Image {
     /// Creates a new image with the given width and height.
     pub fn new(width: u32, height: u32) -> Self {
         Image {
-            render_target: RenderTarget::new(width, height),
+            render_target: Arc::new(RenderTarget::new(width, height)),
             source: String::default(),
+            svg: None,
         }
     }
 
     /// Draws a u32 slice into the image.
     pub fn draw(&mut self, data: &[u32]) {
-        self.render_target.data.clone_from_slice(data);
+        Arc::make_mut(&mut self.render_target)
+            .data
+            .clone_from_slice(data);
     }
 
     /// Create a new image from a boxed slice of colors
     pub fn from_data(width: u32, height: u32, data: Vec<u32>) -> Result<Self, String> {
         Ok(Image {
-            render_target: RenderTarget::from_data(width, height, data).unwrap(),
+            render_target: Arc::new(RenderTarget::from_data(width, height, data).unwrap()),
             source: String::new(),
+            svg: None,
         })
     }
 
-    /// Creates a new image from an `RgbaImage`.
+    /// Creates a new image from an `RgbaImage` with straight alpha.
     pub fn from_rgba_image(image: image::RgbaImage) -> Result<Self, String> {
         let data: Vec<u32> = image
             .pixels()
             .map(|p| {
-                ((p[3] as u32) << 24) | ((p[0] as u32) << 16) | ((p[1] as u32) << 8) | (p[2] as u32)
+                premultiply(
+                    ((p[3] as u32) << 24)
+                        | ((p[0] as u32) << 16)
+                        | ((p[1] as u32) << 8)
+                        | (p[2] as u32),
+                )
             })
             .collect();
         Self::from_data(image.width(), image.height(), data)
     }
 
-    /// Load an image from file path. Supports BMP and PNG
+    /// Creates a new image from the pixels of the given render target.
+    pub fn from_render_target(render_target: RenderTarget) -> Self {
+        Image {
+            render_target: Arc::new(render_target),
+            source: String::new(),
+            svg: None,
+        }
+    }
+
+    /// Creates a new image from a SVG document. The document is rasterized again in the size the image is drawn
+    /// with, so that it stays crisp if it is stretched or the scale factor changes.
+    pub fn from_svg(svg: Svg) -> Self {
+        let mut image = svg
+            .rasterize(svg.width().ceil() as u32, svg.height().ceil() as u32, None)
+            .unwrap_or_default();
+        image.source = svg.source().to_string();
+        image.svg = Some(svg);
+        image
+    }
+
+    /// Loads an image from the bytes of an image file in memory. Supports BMP, PNG and SVG
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        match image::load_from_memory(bytes) {
+            Ok(img) => Self::from_rgba_image(img.to_rgba8()),
+            Err(err) => Svg::from_bytes(bytes)
+                .map(Image::from_svg)
+                .map_err(|_| format!("Could not load image from bytes: {}", err)),
+        }
+    }
+
+    /// Load an image from file path. Supports BMP, PNG and SVG
     pub fn from_path<P: AsRef<Path> + std::fmt::Debug + Clone>(path: P) -> Result<Self, String> {
+        if path
+            .as_ref()
+            .extension()
+            .map_or(false, |extension| extension == "svg")
+        {
+            return Svg::from_path(path).map(Image::from_svg);
+        }
+
         let img = image::open(path.clone());
 
         if let Ok(img) = img {
@@ -74,12 +134,55 @@ impl Image {
         self.render_target.height() as f64
     }
 
+    /// Gets the SVG document the image was created from.
+    pub fn svg(&self) -> Option<&Svg> {
+        self.svg.as_ref()
+    }
+
+    /// Converts the image to an `RgbaImage` with straight alpha.
+    pub fn to_rgba_image(&self) -> image::RgbaImage {
+        let mut rgba_image = image::RgbaImage::new(self.width() as u32, self.height() as u32);
+
+        for (pixel, argb) in rgba_image.pixels_mut().zip(self.data()) {
+            let argb = unpremultiply(*argb);
+            *pixel = image::Rgba([
+                (argb >> 16) as u8,
+                (argb >> 8) as u8,
+                argb as u8,
+                (argb >> 24) as u8,
+            ]);
+        }
+
+        rgba_image
+    }
+
+    /// Encodes the image as PNG.
+    pub fn to_png(&self) -> Result<Vec<u8>, String> {
+        let mut bytes = vec![];
+
+        image::codecs::png::PngEncoder::new(&mut bytes)
+            .encode(
+                &self.to_rgba_image(),
+                self.width() as u32,
+                self.height() as u32,
+                image::ColorType::Rgba8,
+            )
+            .map_err(|err| format!("Could not encode png: {}", err))?;
+
+        Ok(bytes)
+    }
+
+    /// Encodes the image as PNG and writes it to the given file path.
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        std::fs::write(path, self.to_png()?).map_err(|err| format!("Could not write png: {}", err))
+    }
+
     pub fn data(&self) -> &[u32] {
         &self.render_target.data
     }
 
     pub fn data_mut(&mut self) -> &mut [u32] {
-        &mut self.render_target.data
+        &mut Arc::make_mut(&mut self.render_target).data
     }
 }
 
@@ -111,4 +214,52 @@ impl From<String> for Image {
     }
 }
 
+impl From<RenderTarget> for Image {
+    fn from(render_target: RenderTarget) -> Image {
+        Image::from_render_target(render_target)
+    }
+}
+
 // --- Conversions ---
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{utils::Color, RenderContext2D};
+
+    #[test]
+    fn test_to_png() {
+        let image = Image::from_data(2, 1, vec![0xFFFF_0000, 0x8000_0080]).unwrap();
+        let png = image.to_png().unwrap();
+
+        assert_eq!(Image::from_bytes(&png).unwrap().data(), image.data());
+    }
+
+    #[test]
+    fn test_to_png_semi_transparent() {
+        let mut render_context = RenderContext2D::new(2.0, 1.0);
+        render_context.set_fill_style(Color::rgba(255, 0, 0, 128));
+        render_context.fill_rect(0.0, 0.0, 1.0, 1.0);
+        let image = render_context.to_image();
+        let png = image.to_png().unwrap();
+
+        // the png is encoded with straight alpha
+        let decoded = image::load_from_memory(&png).unwrap().to_rgba8();
+        assert_eq!(decoded.get_pixel(0, 0).0, [255, 0, 0, 128]);
+        assert_eq!(decoded.get_pixel(1, 0).0, [0, 0, 0, 0]);
+
+        assert_eq!(Image::from_bytes(&png).unwrap().data(), image.data());
+    }
+
+    #[test]
+    fn test_shared_pixels() {
+        let image = Image::from_data(2, 1, vec![0xFFFF_0000, 0x8000_00FF]).unwrap();
+        let mut other = image.clone();
+        assert_eq!(image.data().as_ptr(), other.data().as_ptr());
+
+        // a clone that is drawn into gets its own pixels
+        other.data_mut()[0] = 0;
+        assert_eq!(image.data()[0], 0xFFFF_0000);
+        assert_eq!(other.data()[0], 0);
+    }
+}
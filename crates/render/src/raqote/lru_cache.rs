@@ -0,0 +1,119 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+};
+
+// A least recently used cache with a memory budget. If the budget is exceeded the least recently used entries are
+// removed.
+#[derive(Debug)]
+pub(super) struct LruCache<K, V> {
+    entries: HashMap<K, (V, usize, u64)>,
+    order: BTreeMap<u64, K>,
+    budget: usize,
+    used: usize,
+    tick: u64,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    pub(super) fn new(budget: usize) -> Self {
+        LruCache {
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            budget,
+            used: 0,
+            tick: 0,
+        }
+    }
+
+    pub(super) fn get(&mut self, key: &K) -> Option<&V> {
+        let tick = self.tick + 1;
+
+        match self.entries.get_mut(key) {
+            Some(entry) => {
+                self.tick = tick;
+                self.order.remove(&entry.2);
+                self.order.insert(tick, key.clone());
+                entry.2 = tick;
+                Some(&entry.0)
+            }
+            None => None,
+        }
+    }
+
+    pub(super) fn insert(&mut self, key: K, value: V, size: usize) {
+        self.remove(&key);
+
+        while self.used + size > self.budget {
+            let oldest = match self.order.keys().next() {
+                Some(tick) => *tick,
+                None => break,
+            };
+
+            if let Some(key) = self.order.remove(&oldest) {
+                if let Some(entry) = self.entries.remove(&key) {
+                    self.used -= entry.1;
+                }
+            }
+        }
+
+        self.tick += 1;
+        self.used += size;
+        self.order.insert(self.tick, key.clone());
+        self.entries.insert(key, (value, size, self.tick));
+    }
+
+    // Removes the entry of the key and returns its value.
+    pub(super) fn remove(&mut self, key: &K) -> Option<V> {
+        let entry = self.entries.remove(key)?;
+        self.order.remove(&entry.2);
+        self.used -= entry.1;
+        Some(entry.0)
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+        self.used = 0;
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lru_cache() {
+        let mut cache = LruCache::new(3);
+        cache.insert(1, "a", 1);
+        cache.insert(2, "b", 1);
+        cache.insert(3, "c", 1);
+
+        // 1 is used, so 2 is the least recently used entry
+        assert_eq!(cache.get(&1), Some(&"a"));
+        cache.insert(4, "d", 1);
+
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.get(&1), Some(&"a"));
+        assert_eq!(cache.get(&3), Some(&"c"));
+        assert_eq!(cache.get(&4), Some(&"d"));
+
+        cache.insert(5, "e", 2);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&4), Some(&"d"));
+        assert_eq!(cache.get(&5), Some(&"e"));
+
+        // entries larger than the budget replace the whole cache
+        cache.insert(6, "f", 5);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.remove(&6), Some("f"));
+        assert_eq!(cache.remove(&6), None);
+        assert_eq!(cache.len(), 0);
+    }
+}
@@ -1,16 +1,40 @@
 use smallvec::SmallVec;
-use std::{cmp, collections::HashMap};
+use std::{cmp, collections::HashMap, io, mem, path::Path, sync::Arc};
 
-use crate::{common::*, utils::*, PipelineTrait, RenderConfig, RenderTarget, TextMetrics};
+use crate::{
+    common::*, font_files, utils::*, Path2D, PathSegment, PipelineTrait, RenderConfig,
+    RenderTarget, TextCluster, TextMetrics,
+};
 
 pub use self::font::*;
+pub use self::glyph_cache::{GlyphBitmap, GlyphCache, GlyphKey};
 pub use self::image::Image;
+pub use self::svg::Svg;
+
+use self::lru_cache::LruCache;
 
 mod font;
+mod glyph_cache;
 mod image;
+mod lru_cache;
+mod svg;
 
 type StatesOnStack = [(RenderConfig, PathRect, usize); 2];
 
+// Memory budget of the rasterized svg images in bytes.
+const SVG_CACHE_BUDGET: usize = 8 * 1024 * 1024;
+
+// Identifies a rasterized svg image by the id of the document, its size in pixels and its color.
+type SvgKey = (u64, u32, u32, Option<u32>);
+
+// An offscreen layer. Drawing is redirected to the layer until it is popped.
+struct Layer {
+    // the draw target that is active again after the layer is popped
+    draw_target: raqote::DrawTarget,
+    // the bounds of the layer in physical pixels of the window
+    rect: Rectangle,
+}
+
 /// The RenderContext2D trait, provides the rendering ctx. It is used for drawing shapes, text, images, and other objects.
 pub struct RenderContext2D {
     draw_target: raqote::DrawTarget,
@@ -18,8 +42,20 @@ pub struct RenderContext2D {
     config: RenderConfig,
     saved_states: SmallVec<StatesOnStack>,
     fonts: HashMap<String, Font>,
+    font_fallbacks: Vec<String>,
+    glyph_cache: GlyphCache,
     path_rect: PathRect,
     clips_count: usize,
+    scale_factor: f64,
+    // the current transform in logical units
+    transform: Transform,
+    region: Option<Rectangle>,
+    invalidated: bool,
+    damage: Vec<Rectangle>,
+    layers: Vec<Layer>,
+    cached_layers: HashMap<u64, (Image, Rectangle)>,
+    // the rasterized svg images, the least recently drawn are removed if the budget is exceeded
+    svg_cache: LruCache<SvgKey, Image>,
 
     background: Color,
 }
@@ -36,8 +72,18 @@ impl RenderContext2D {
             config: RenderConfig::default(),
             saved_states: SmallVec::<StatesOnStack>::new(),
             fonts: HashMap::new(),
+            font_fallbacks: vec![],
+            glyph_cache: GlyphCache::default(),
             path_rect: PathRect::new(None),
             clips_count: 0,
+            scale_factor: 1.0,
+            transform: Transform::identity(),
+            region: None,
+            invalidated: true,
+            damage: vec![],
+            layers: vec![],
+            cached_layers: HashMap::new(),
+            svg_cache: LruCache::new(SVG_CACHE_BUDGET),
             background: Color::default(),
         }
     }
@@ -47,8 +93,23 @@ impl RenderContext2D {
         self.background = background;
     }
 
+    /// Resizes the render context. The size is given in physical pixels.
     pub fn resize(&mut self, width: f64, height: f64) {
         self.draw_target = raqote::DrawTarget::new(width as i32, height as i32);
+        self.set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+        self.invalidated = true;
+    }
+
+    /// Sets the scale factor that describes the ratio between physical pixels and logical units.
+    /// All drawing operations are given in logical units and scaled by this factor.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+        self.set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+    }
+
+    /// Gets the current scale factor.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
     }
 
     /// Registers a new font file.
@@ -62,6 +123,48 @@ impl RenderContext2D {
         }
     }
 
+    /// Returns `true` if a font of the given family is registered.
+    pub fn has_font(&self, family: &str) -> bool {
+        self.fonts.contains_key(family)
+    }
+
+    /// Registers a new font from owned bytes, e.g. a font that is loaded at runtime. An already registered font
+    /// of the family is replaced.
+    pub fn register_font_bytes(
+        &mut self,
+        family: &str,
+        font_data: impl Into<Arc<Vec<u8>>>,
+    ) -> io::Result<()> {
+        let font = Font::from_shared(font_data.into())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        self.fonts.insert(family.to_string(), font);
+        Ok(())
+    }
+
+    /// Loads and registers the font file of the given path.
+    pub fn register_font_file(&mut self, family: &str, path: impl AsRef<Path>) -> io::Result<()> {
+        self.register_font_bytes(family, std::fs::read(path)?)
+    }
+
+    /// Loads all font files (`ttf`, `otf`) of the given directory. The file name without extension is used as
+    /// family key. Returns the families of the registered fonts.
+    pub fn register_font_directory(&mut self, path: impl AsRef<Path>) -> io::Result<Vec<String>> {
+        let mut families = vec![];
+
+        for (family, file) in font_files(path)? {
+            if self.register_font_file(&family, file).is_ok() {
+                families.push(family);
+            }
+        }
+
+        Ok(families)
+    }
+
+    /// Sets the font families that are used in the given order for chars, that are missing in the selected font family.
+    pub fn set_font_fallbacks(&mut self, families: Vec<String>) {
+        self.font_fallbacks = families;
+    }
+
     // Rectangles
 
     /// Draws a filled rectangle whose starting point is at the coordinates {x, y} with the specified width and height and whose style is determined by the fillStyle attribute.
@@ -105,37 +208,162 @@ impl RenderContext2D {
             return;
         }
 
-        // The borrow-checker forces the clone
-        let text_transform = self.draw_target.get_transform().to_owned();
-        if let Some(font) = self.fonts.get(&self.config.font_config.family) {
+        let scale_factor = self.scale_factor;
+        let origin = self.layer_origin();
+        let transform = *self.draw_target.get_transform();
+
+        // rotated, skewed, scaled or moved text is drawn through the transform of the draw target
+        if transform
+            != raqote::Transform::row_major(
+                scale_factor as f32,
+                0.0,
+                0.0,
+                scale_factor as f32,
+                -origin.0 as f32,
+                -origin.1 as f32,
+            )
+        {
+            self.fill_transformed_text(text, x, y, color, &transform);
+            return;
+        }
+
+        // text is rendered in physical pixels, only the origin is transformed
+        let position = transform.transform_point(raqote::Point::new(x as f32, y as f32));
+        let position = (position.x as f64, position.y as f64);
+        let font_size = self.config.font_config.font_size * scale_factor;
+
+        let fonts = font_chain(
+            &self.fonts,
+            &self.font_fallbacks,
+            &self.config.font_config.family,
+        );
+
+        // text is written directly in the pixel data, so the clips of the draw target do not apply to it
+        let mut clip = self.path_rect.get_clip().map(|rect| {
+            Rectangle::new(
+                (
+                    rect.x() * scale_factor - origin.0,
+                    rect.y() * scale_factor - origin.1,
+                ),
+                (rect.width() * scale_factor, rect.height() * scale_factor),
+            )
+        });
+
+        // the region is a clip of the window, not of the layers
+        if let (Some(region), true) = (self.region, self.layers.is_empty()) {
+            // the right and bottom edge of the region are exclusive
+            let region = Rectangle::new(
+                region.position(),
+                (region.width() - 1.0, region.height() - 1.0),
+            );
+
+            clip = Some(match clip {
+                Some(mut clip) => {
+                    clip.box_into(region);
+                    clip
+                }
+                None => region,
+            });
+        }
+
+        if let Some((font, fallbacks)) = fonts.split_first() {
             let width = self.draw_target.width() as f64;
             let height = self.draw_target.height() as f64;
 
-            if let Some(rect) = self.path_rect.get_clip() {
+            if let Some(clip) = clip {
                 font.render_text_clipped(
                     text,
                     self.draw_target.get_data_mut(),
-                    &text_transform,
                     width,
                     height,
-                    (self.config.font_config.font_size, color, self.config.alpha),
-                    (x, y),
-                    rect,
+                    (font_size, color, self.config.alpha),
+                    position,
+                    clip,
+                    fallbacks,
+                    &mut self.glyph_cache,
                 );
             } else {
                 font.render_text(
                     text,
                     self.draw_target.get_data_mut(),
-                    &text_transform,
                     width,
                     height,
-                    (self.config.font_config.font_size, color, self.config.alpha),
-                    (x, y),
+                    (font_size, color, self.config.alpha),
+                    position,
+                    fallbacks,
+                    &mut self.glyph_cache,
                 );
             }
         }
     }
 
+    // Rasterizes the text in its final size into an image, that is drawn with the given transform, so that the clips
+    // of the draw target apply to it.
+    fn fill_transformed_text(
+        &mut self,
+        text: &str,
+        x: f64,
+        y: f64,
+        color: Color,
+        transform: &raqote::Transform,
+    ) {
+        let scale =
+            ((transform.m11 * transform.m22 - transform.m12 * transform.m21).abs() as f64).sqrt();
+
+        if scale == 0.0 {
+            return;
+        }
+
+        let font_size = self.config.font_config.font_size * scale;
+
+        let fonts = font_chain(
+            &self.fonts,
+            &self.font_fallbacks,
+            &self.config.font_config.family,
+        );
+
+        if let Some((font, fallbacks)) = fonts.split_first() {
+            let (width, height) =
+                font.measure_text(text, font_size, fallbacks, &mut self.glyph_cache);
+
+            // the buffer and the rendered text must use the same stride
+            let (width, height) = (width.ceil(), height.ceil());
+
+            if width <= 0.0 || height <= 0.0 {
+                return;
+            }
+
+            let mut data = vec![0; width as usize * height as usize];
+
+            font.render_text(
+                text,
+                &mut data,
+                width,
+                height,
+                (font_size, color, 1.0),
+                (0.0, 0.0),
+                fallbacks,
+                &mut self.glyph_cache,
+            );
+
+            self.draw_target.draw_image_with_size_at(
+                (width / scale) as f32,
+                (height / scale) as f32,
+                x as f32,
+                y as f32,
+                &raqote::Image {
+                    data: &data,
+                    width: width as i32,
+                    height: height as i32,
+                },
+                &raqote::DrawOptions {
+                    alpha: self.config.alpha,
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
     pub fn measure(
         &mut self,
         text: &str,
@@ -155,8 +383,19 @@ impl RenderContext2D {
             return text_metrics;
         }
 
-        if let Some(font) = self.fonts.get(&self.config.font_config.family) {
-            let (width, height) = font.measure_text(text, self.config.font_config.font_size);
+        let fonts = font_chain(
+            &self.fonts,
+            &self.font_fallbacks,
+            &self.config.font_config.family,
+        );
+
+        if let Some((font, fallbacks)) = fonts.split_first() {
+            let (width, height) = font.measure_text(
+                text,
+                self.config.font_config.font_size,
+                fallbacks,
+                &mut self.glyph_cache,
+            );
 
             text_metrics.width = width;
             text_metrics.height = height;
@@ -165,12 +404,41 @@ impl RenderContext2D {
         text_metrics
     }
 
+    /// Gets the clusters of the shaped text in logical order. The start and end of a cluster are byte indices of the text.
+    pub fn measure_clusters(
+        &mut self,
+        text: &str,
+        font_size: f64,
+        family: impl Into<String>,
+    ) -> Vec<TextCluster> {
+        self.set_font_family(family);
+        self.set_font_size(font_size);
+
+        let fonts = font_chain(
+            &self.fonts,
+            &self.font_fallbacks,
+            &self.config.font_config.family,
+        );
+
+        if let Some((font, fallbacks)) = fonts.split_first() {
+            return font.measure_clusters(
+                text,
+                self.config.font_config.font_size,
+                fallbacks,
+                &mut self.glyph_cache,
+            );
+        }
+
+        vec![]
+    }
+
     /// Fills the current or given path with the current file style.
     pub fn fill(&mut self) {
         let rect = match self.path_rect.get_rect() {
             Some(rect) => rect,
             None => return, // The path is empty, do nothing
         };
+        self.path.winding = winding(self.config.fill_rule);
         self.draw_target.fill(
             &self.path,
             &brush_to_source(&self.config.fill_style, rect),
@@ -192,7 +460,19 @@ impl RenderContext2D {
             &brush_to_source(&self.config.stroke_style, rect),
             &raqote::StrokeStyle {
                 width: self.config.line_width as f32,
-                ..Default::default()
+                cap: match self.config.line_cap {
+                    LineCap::Butt => raqote::LineCap::Butt,
+                    LineCap::Round => raqote::LineCap::Round,
+                    LineCap::Square => raqote::LineCap::Square,
+                },
+                join: match self.config.line_join {
+                    LineJoin::Miter => raqote::LineJoin::Miter,
+                    LineJoin::Round => raqote::LineJoin::Round,
+                    LineJoin::Bevel => raqote::LineJoin::Bevel,
+                },
+                miter_limit: self.config.miter_limit as f32,
+                dash_array: self.config.line_dash.iter().map(|d| *d as f32).collect(),
+                dash_offset: self.config.line_dash_offset as f32,
             },
             &raqote::DrawOptions {
                 alpha: self.config.alpha,
@@ -201,6 +481,99 @@ impl RenderContext2D {
         );
     }
 
+    /// Fills the given path with the current fill style. The current path is not changed.
+    pub fn fill_path(&mut self, path: &Path2D) {
+        self.with_path(path, |ctx| ctx.fill());
+    }
+
+    /// Strokes the given path with the current stroke style. The current path is not changed.
+    pub fn stroke_path(&mut self, path: &Path2D) {
+        self.with_path(path, |ctx| ctx.stroke());
+    }
+
+    /// Creates a clipping path from the given path. The current path is not changed.
+    pub fn clip_path(&mut self, path: &Path2D) {
+        self.with_path(path, |ctx| ctx.clip());
+    }
+
+    // Replaces the current path with the given path while the function is called.
+    fn with_path<T, F: FnOnce(&mut Self) -> T>(&mut self, path: &Path2D, f: F) -> T {
+        let current_path = self.path.clone();
+        let current_path_rect = self.path_rect;
+
+        self.begin_path();
+        self.add_segments(path);
+        let result = f(self);
+
+        self.path = current_path;
+        self.path_rect.restore_path(current_path_rect);
+        result
+    }
+
+    // Builds the path of a rectangle with rounded corners like the rounded rectangles of borders are built.
+    fn rounded_rect_path(&mut self, rect: Rectangle, radii: [f64; 4]) -> raqote::Path {
+        let mut path = Path2D::new();
+        path.rounded_rect(rect.x(), rect.y(), rect.width(), rect.height(), radii);
+        self.with_path(&path, |ctx| ctx.path.clone())
+    }
+
+    /// Adds the segments of the given path to the current path.
+    pub fn add_path(&mut self, path: &Path2D) {
+        self.add_segments(path);
+    }
+
+    // Adds the segments of the path through the path functions of the render context.
+    fn add_segments(&mut self, path: &Path2D) {
+        for segment in path.segments() {
+            match *segment {
+                PathSegment::MoveTo { x, y } => self.move_to(x, y),
+                PathSegment::LineTo { x, y } => self.line_to(x, y),
+                PathSegment::QuadraticCurveTo { cpx, cpy, x, y } => {
+                    self.quadratic_curve_to(cpx, cpy, x, y)
+                }
+                PathSegment::BezierCurveTo {
+                    cp1x,
+                    cp1y,
+                    cp2x,
+                    cp2y,
+                    x,
+                    y,
+                } => self.bezier_curve_to(cp1x, cp1y, cp2x, cp2y, x, y),
+                PathSegment::Arc {
+                    x,
+                    y,
+                    radius,
+                    start_angle,
+                    end_angle,
+                    anticlockwise,
+                } => self.add_arc(x, y, radius, start_angle, end_angle, anticlockwise),
+                PathSegment::ArcTo {
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    radius,
+                } => self.arc_to(x1, y1, x2, y2, radius),
+                PathSegment::Ellipse {
+                    x,
+                    y,
+                    radius_x,
+                    radius_y,
+                    rotation,
+                    start_angle,
+                    end_angle,
+                } => self.ellipse(x, y, radius_x, radius_y, rotation, start_angle, end_angle),
+                PathSegment::Rect {
+                    x,
+                    y,
+                    width,
+                    height,
+                } => self.rect(x, y, width, height),
+                PathSegment::ClosePath => self.close_path(),
+            }
+        }
+    }
+
     /// Starts a new path by emptying the list of sub-paths. Call this when you want to create a new path.
     pub fn begin_path(&mut self) {
         self.path = raqote::Path {
@@ -228,19 +601,111 @@ impl RenderContext2D {
 
     /// Creates a circular arc centered at (x, y) with a radius of radius. The path starts at startAngle and ends at endAngle.
     pub fn arc(&mut self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64) {
+        self.add_arc(x, y, radius, start_angle, end_angle, false);
+    }
+
+    fn add_arc(
+        &mut self,
+        x: f64,
+        y: f64,
+        radius: f64,
+        start_angle: f64,
+        end_angle: f64,
+        anticlockwise: bool,
+    ) {
         let mut path_builder = raqote::PathBuilder::from(self.path.clone());
         path_builder.arc(
             x as f32,
             y as f32,
             radius as f32,
             start_angle as f32,
-            end_angle as f32,
+            arc_sweep(start_angle, end_angle, anticlockwise) as f32,
         );
         self.path = path_builder.finish();
         self.path_rect
             .record_arc(x, y, radius, start_angle, end_angle);
     }
 
+    /// Adds a circular arc with the given radius, that connects the line from the current point to (x1, y1) with
+    /// the line from (x1, y1) to (x2, y2). If the lines are parallel, a straight line to (x1, y1) is added.
+    pub fn arc_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, radius: f64) {
+        // like on a canvas a path without current point starts at the first control point
+        if !self.path_rect.has_current_point() {
+            self.move_to(x1, y1);
+        }
+
+        let current = self.path_rect.last_point();
+
+        match arc_to_arc(current, Point::new(x1, y1), Point::new(x2, y2), radius) {
+            Some((start, center, start_angle, end_angle, anticlockwise)) => {
+                self.line_to(start.x(), start.y());
+                self.add_arc(
+                    center.x(),
+                    center.y(),
+                    radius,
+                    start_angle,
+                    end_angle,
+                    anticlockwise,
+                );
+            }
+            None => self.line_to(x1, y1),
+        }
+    }
+
+    /// Creates an elliptical arc centered at (x, y) and rotated by rotation. The path starts at startAngle and ends
+    /// at endAngle.
+    #[allow(clippy::too_many_arguments)]
+    pub fn ellipse(
+        &mut self,
+        x: f64,
+        y: f64,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    ) {
+        let (start, curves) = ellipse_curves(
+            Point::new(x, y),
+            radius_x,
+            radius_y,
+            rotation,
+            start_angle,
+            arc_sweep(start_angle, end_angle, false),
+        );
+
+        let mut path_builder = raqote::PathBuilder::from(self.path.clone());
+
+        if self.path.ops.is_empty() {
+            path_builder.move_to(start.x() as f32, start.y() as f32);
+        } else {
+            path_builder.line_to(start.x() as f32, start.y() as f32);
+        }
+
+        for (cp1, cp2, end) in curves {
+            path_builder.cubic_to(
+                cp1.x() as f32,
+                cp1.y() as f32,
+                cp2.x() as f32,
+                cp2.y() as f32,
+                end.x() as f32,
+                end.y() as f32,
+            );
+        }
+
+        self.path = path_builder.finish();
+        self.path_rect
+            .record_ellipse(x, y, radius_x, radius_y, rotation, start_angle, end_angle);
+    }
+
+    /// Adds a rectangle with rounded corners to the current path. The radii are given in the order top left, top
+    /// right, bottom right and bottom left.
+    pub fn rounded_rect(&mut self, x: f64, y: f64, width: f64, height: f64, radii: [f64; 4]) {
+        let mut path = Path2D::new();
+        path.rounded_rect(x, y, width, height, radii);
+        self.add_segments(&path);
+    }
+
     /// Begins a new sub-path at the point specified by the given {x, y} coordinates.
 
     pub fn move_to(&mut self, x: f64, y: f64) {
@@ -279,6 +744,7 @@ impl RenderContext2D {
             x as f32,
             y as f32,
         );
+        self.path = path_builder.finish();
         self.path_rect
             .record_bezier_curve_to(cp1x, cp1y, cp2x, cp2y, x, y);
     }
@@ -302,6 +768,11 @@ impl RenderContext2D {
 
     /// Draws the image.
     pub fn draw_image(&mut self, image: &Image, x: f64, y: f64) {
+        if let Some(svg) = image.svg() {
+            self.draw_svg(svg, x, y, svg.width(), svg.height(), None);
+            return;
+        }
+
         self.draw_target.draw_image_at(
             x as f32,
             y as f32,
@@ -347,6 +818,126 @@ impl RenderContext2D {
         }
     }
 
+    /// Draws the given part of the image stretched to the given size.
+    pub fn draw_image_with_clip_and_size(
+        &mut self,
+        image: &Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        if clip.width() <= 0.0 || clip.height() <= 0.0 || width <= 0.0 || height <= 0.0 {
+            return;
+        }
+
+        if let Some(svg) = image.svg() {
+            self.draw_svg_with_clip(svg, clip, x, y, width, height, None);
+            return;
+        }
+
+        let scale_x = clip.width() / width;
+        let scale_y = clip.height() / height;
+
+        let mut path_builder = raqote::PathBuilder::new();
+        path_builder.rect(x as f32, y as f32, width as f32, height as f32);
+
+        // the transform of the source maps the drawn rectangle to the part of the image
+        self.draw_target.fill(
+            &path_builder.finish(),
+            &raqote::Source::Image(
+                raqote::Image {
+                    data: &image.data(),
+                    width: image.width() as i32,
+                    height: image.height() as i32,
+                },
+                raqote::ExtendMode::Pad,
+                raqote::FilterMode::Bilinear,
+                raqote::Transform::row_major(
+                    scale_x as f32,
+                    0.0,
+                    0.0,
+                    scale_y as f32,
+                    (clip.x() - x * scale_x) as f32,
+                    (clip.y() - y * scale_y) as f32,
+                ),
+            ),
+            &raqote::DrawOptions {
+                alpha: self.config.alpha,
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Draws the SVG document with the given size. If a color is given, the document is filled with it.
+    pub fn draw_svg(
+        &mut self,
+        svg: &Svg,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        color: Option<Color>,
+    ) {
+        let clip = Rectangle::new((0.0, 0.0), (svg.width(), svg.height()));
+        self.draw_svg_with_clip(svg, clip, x, y, width, height, color);
+    }
+
+    // Draws the given part of the SVG document stretched to the given size. The document is rasterized in the size of
+    // the pixels it covers, the image is cached until the size or the color changes.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_svg_with_clip(
+        &mut self,
+        svg: &Svg,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        color: Option<Color>,
+    ) {
+        if clip.width() <= 0.0 || clip.height() <= 0.0 || width <= 0.0 || height <= 0.0 {
+            return;
+        }
+
+        let transform = self.draw_target.get_transform();
+        let scale =
+            ((transform.m11 * transform.m22 - transform.m12 * transform.m21).abs() as f64).sqrt();
+        let pixel_width = (svg.width() * width / clip.width() * scale).ceil() as u32;
+        let pixel_height = (svg.height() * height / clip.height() * scale).ceil() as u32;
+        let color_key = color.map(|color| color.data);
+
+        let key = (svg.id(), pixel_width, pixel_height, color_key);
+
+        // the image is taken out of the cache while it is drawn and inserted again as the most recently used
+        let image = match self.svg_cache.remove(&key) {
+            Some(image) => Some(image),
+            None => svg.rasterize(pixel_width, pixel_height, color),
+        };
+
+        if let Some(image) = image {
+            // the aspect ratio of the document is kept by the rasterized image
+            let scale_x = image.width() / svg.width();
+            let scale_y = image.height() / svg.height();
+
+            self.draw_image_with_clip_and_size(
+                &image,
+                Rectangle::new(
+                    (clip.x() * scale_x, clip.y() * scale_y),
+                    (clip.width() * scale_x, clip.height() * scale_y),
+                ),
+                x,
+                y,
+                width,
+                height,
+            );
+
+            let size = image.width() as usize * image.height() as usize * mem::size_of::<u32>();
+            self.svg_cache.insert(key, image, size);
+        }
+    }
+
     pub fn draw_pipeline(
         &mut self,
         x: f64,
@@ -360,8 +951,111 @@ impl RenderContext2D {
         self.draw_render_target(&render_target, x, y);
     }
 
+    /// Draws the shadow of the rectangle with the given corner radii. An outer shadow is drawn around the rectangle
+    /// and is not painted below of it, an inset shadow is drawn inside of the rectangle.
+    pub fn draw_box_shadow(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radii: [f64; 4],
+        shadow: &Shadow,
+    ) {
+        if shadow.color.a() == 0 || width <= 0.0 || height <= 0.0 {
+            return;
+        }
+
+        let blur_radius = shadow.blur_radius(self.scale_factor);
+        let margin = shadow.blur_extent(self.scale_factor);
+        let rect = Rectangle::new((x, y), (width, height));
+        let (shape, shape_radii) = shadow.shape(rect, radii);
+
+        // the area of the shadow in logical units
+        let area = if shadow.inset { rect } else { shape };
+        let area = Rectangle::new(
+            (area.x() - margin, area.y() - margin),
+            (area.width() + 2.0 * margin, area.height() + 2.0 * margin),
+        );
+
+        // the shadow is rendered in physical pixels
+        let transform = *self.draw_target.get_transform();
+        let origin =
+            transform.transform_point(raqote::Point::new(area.x() as f32, area.y() as f32));
+        let origin = (origin.x.floor(), origin.y.floor());
+        let mask_width = (area.width() * self.scale_factor).ceil() as usize + 1;
+        let mask_height = (area.height() * self.scale_factor).ceil() as usize + 1;
+        let mask_transform = raqote::Transform::row_major(
+            transform.m11,
+            transform.m12,
+            transform.m21,
+            transform.m22,
+            transform.m31 - origin.0,
+            transform.m32 - origin.1,
+        );
+
+        let shape_path = self.rounded_rect_path(shape, shape_radii);
+        let mut mask = shape_mask(&shape_path, mask_width, mask_height, &mask_transform);
+
+        if shadow.inset {
+            for value in mask.iter_mut() {
+                *value = 255 - *value;
+            }
+        }
+
+        box_blur(&mut mask, mask_width, mask_height, blur_radius);
+
+        let box_path = self.rounded_rect_path(rect, radii);
+
+        // an outer shadow is not drawn below of the rectangle
+        if !shadow.inset {
+            let box_mask = shape_mask(&box_path, mask_width, mask_height, &mask_transform);
+            for (value, box_value) in mask.iter_mut().zip(box_mask) {
+                *value = (*value as u32 * (255 - box_value as u32) / 255) as u8;
+            }
+        }
+
+        let color = shadow.color;
+        let data: Vec<u32> = mask
+            .iter()
+            .map(|value| {
+                let a = *value as u32 * color.a() as u32 / 255;
+                let r = color.r() as u32 * a / 255;
+                let g = color.g() as u32 * a / 255;
+                let b = color.b() as u32 * a / 255;
+                (a << 24) | (r << 16) | (g << 8) | b
+            })
+            .collect();
+
+        if shadow.inset {
+            self.draw_target.push_clip(&box_path);
+        }
+
+        self.draw_target
+            .set_transform(&raqote::Transform::identity());
+        self.draw_target.draw_image_at(
+            origin.0,
+            origin.1,
+            &raqote::Image {
+                data: &data,
+                width: mask_width as i32,
+                height: mask_height as i32,
+            },
+            &raqote::DrawOptions {
+                alpha: self.config.alpha,
+                ..Default::default()
+            },
+        );
+        self.draw_target.set_transform(&transform);
+
+        if shadow.inset {
+            self.draw_target.pop_clip();
+        }
+    }
+
     /// Creates a clipping path from the current sub-paths. Everything drawn after clip() is called appears inside the clipping path only.
     pub fn clip(&mut self) {
+        self.path.winding = winding(self.config.fill_rule);
         self.draw_target.push_clip(&self.path);
         self.path_rect.record_clip();
         self.clips_count += 1;
@@ -374,6 +1068,42 @@ impl RenderContext2D {
         self.config.line_width = line_width;
     }
 
+    /// Sets how the ends of lines are drawn.
+    pub fn set_line_cap(&mut self, line_cap: LineCap) {
+        self.config.line_cap = line_cap;
+    }
+
+    /// Sets how connected segments of lines are joined.
+    pub fn set_line_join(&mut self, line_join: LineJoin) {
+        self.config.line_join = line_join;
+    }
+
+    /// Sets the limit of the ratio between the length of a miter join and the line width. Joins that exceed it are
+    /// beveled. Values that are not positive are ignored.
+    pub fn set_miter_limit(&mut self, miter_limit: f64) {
+        if miter_limit > 0.0 && miter_limit.is_finite() {
+            self.config.miter_limit = miter_limit;
+        }
+    }
+
+    /// Sets the dash pattern of lines as alternating lengths of dashes and gaps. A pattern with an odd number of
+    /// values is repeated, an empty pattern draws solid lines. Patterns with negative values are ignored.
+    pub fn set_line_dash(&mut self, segments: Vec<f64>) {
+        if let Some(line_dash) = line_dash(segments) {
+            self.config.line_dash = line_dash;
+        }
+    }
+
+    /// Sets the offset of the dash pattern of lines.
+    pub fn set_line_dash_offset(&mut self, offset: f64) {
+        self.config.line_dash_offset = offset;
+    }
+
+    /// Sets the rule that determines the inside of a path if it is filled or used as clip.
+    pub fn set_fill_rule(&mut self, fill_rule: FillRule) {
+        self.config.fill_rule = fill_rule;
+    }
+
     /// Sets the alpha value,
     pub fn set_alpha(&mut self, alpha: f32) {
         self.config.alpha = alpha;
@@ -381,7 +1111,8 @@ impl RenderContext2D {
 
     /// Specifies the font family.
     pub fn set_font_family(&mut self, family: impl Into<String>) {
-        self.config.font_config.family = family.into();
+        let family = family.into();
+        self.config.font_config.family = family;
     }
 
     /// Specifies the font size.
@@ -393,17 +1124,20 @@ impl RenderContext2D {
 
     /// Specifies the fill color to use inside shapes.
     pub fn set_fill_style(&mut self, fill_style: impl Into<Brush>) {
-        self.config.fill_style = fill_style.into();
+        let fill_style = fill_style.into();
+        self.config.fill_style = fill_style;
     }
 
     /// Specifies the fill stroke to use inside shapes.
     pub fn set_stroke_style(&mut self, stroke_style: impl Into<Brush>) {
-        self.config.stroke_style = stroke_style.into();
+        let stroke_style = stroke_style.into();
+        self.config.stroke_style = stroke_style;
     }
 
     // Transformations
 
-    /// Sets the transformation.
+    /// Sets the transformation. The transformation is applied in logical units, the scale factor
+    /// of the render context is applied on top of it.
     pub fn set_transform(
         &mut self,
         h_scaling: f64,
@@ -413,17 +1147,165 @@ impl RenderContext2D {
         h_moving: f64,
         v_moving: f64,
     ) {
+        self.transform = Transform::new(
+            h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+        );
         self.draw_target
             .set_transform(&raqote::Transform::row_major(
-                h_scaling as f32,
-                h_skewing as f32,
-                v_skewing as f32,
-                v_scaling as f32,
-                h_moving as f32,
-                v_moving as f32,
+                (h_scaling * self.scale_factor) as f32,
+                (h_skewing * self.scale_factor) as f32,
+                (v_skewing * self.scale_factor) as f32,
+                (v_scaling * self.scale_factor) as f32,
+                (h_moving * self.scale_factor - self.layer_origin().0) as f32,
+                (v_moving * self.scale_factor - self.layer_origin().1) as f32,
             ));
     }
 
+    /// Multiplies the current transformation with the given transformation, so that the content is transformed
+    /// relative to the current transformation.
+    pub fn transform(
+        &mut self,
+        h_scaling: f64,
+        h_skewing: f64,
+        v_skewing: f64,
+        v_scaling: f64,
+        h_moving: f64,
+        v_moving: f64,
+    ) {
+        let transform = Transform::new(
+            h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+        )
+        .then(&self.transform);
+
+        self.set_transform(
+            transform.m11,
+            transform.m12,
+            transform.m21,
+            transform.m22,
+            transform.m31,
+            transform.m32,
+        );
+    }
+
+    // Layers
+
+    /// Redirects all drawing to a new offscreen layer with the given bounds in logical units, until the layer is
+    /// popped. Drawing outside of the bounds is clipped.
+    pub fn push_layer(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        let (window_width, window_height) = match self.layers.first() {
+            Some(layer) => (layer.draw_target.width(), layer.draw_target.height()),
+            None => (self.draw_target.width(), self.draw_target.height()),
+        };
+
+        let rect = pixel_rect(
+            Rectangle::new((x, y), (width, height)),
+            self.scale_factor,
+            window_width as f64,
+            window_height as f64,
+        )
+        .unwrap_or_default();
+
+        let origin = self.layer_origin();
+        let transform = *self.draw_target.get_transform();
+        let draw_target = mem::replace(
+            &mut self.draw_target,
+            raqote::DrawTarget::new((rect.width() as i32).max(1), (rect.height() as i32).max(1)),
+        );
+
+        self.draw_target
+            .set_transform(&raqote::Transform::row_major(
+                transform.m11,
+                transform.m12,
+                transform.m21,
+                transform.m22,
+                transform.m31 + (origin.0 - rect.x()) as f32,
+                transform.m32 + (origin.1 - rect.y()) as f32,
+            ));
+        self.layers.push(Layer { draw_target, rect });
+    }
+
+    /// Pops the current layer and draws it with the given alpha value. If a key is given the content of the layer is
+    /// cached and could be drawn again with `draw_layer` until it is removed.
+    pub fn pop_layer(&mut self, alpha: f32, key: Option<u64>) {
+        let layer = match self.layers.pop() {
+            Some(layer) => layer,
+            None => return,
+        };
+
+        let draw_target = mem::replace(&mut self.draw_target, layer.draw_target);
+        let image = Image::from_data(
+            draw_target.width() as u32,
+            draw_target.height() as u32,
+            draw_target.get_data().to_vec(),
+        )
+        .unwrap();
+
+        // layers outside of the window are not drawn
+        if layer.rect.width() > 0.0 {
+            self.composite_layer(&image, layer.rect, alpha);
+        }
+
+        if let Some(key) = key {
+            self.cached_layers.insert(key, (image, layer.rect));
+        }
+    }
+
+    /// Draws the cached layer with the given key and alpha value.
+    pub fn draw_layer(&mut self, key: u64, alpha: f32) {
+        if let Some((image, rect)) = self.cached_layers.remove(&key) {
+            if rect.width() > 0.0 {
+                self.composite_layer(&image, rect, alpha);
+            }
+
+            self.cached_layers.insert(key, (image, rect));
+        }
+    }
+
+    /// Returns `true` if a layer with the given key is cached.
+    pub fn has_layer(&self, key: u64) -> bool {
+        self.cached_layers.contains_key(&key)
+    }
+
+    /// Removes the cached layer with the given key.
+    pub fn remove_layer(&mut self, key: u64) {
+        self.cached_layers.remove(&key);
+    }
+
+    /// Removes all cached layers.
+    pub fn clear_layers(&mut self) {
+        self.cached_layers.clear();
+    }
+
+    // Gets the position of the current layer in physical pixels of the window.
+    fn layer_origin(&self) -> (f64, f64) {
+        self.layers
+            .last()
+            .map_or((0.0, 0.0), |layer| (layer.rect.x(), layer.rect.y()))
+    }
+
+    // Draws the content of a layer at its position in physical pixels.
+    fn composite_layer(&mut self, image: &Image, rect: Rectangle, alpha: f32) {
+        let origin = self.layer_origin();
+        let transform = *self.draw_target.get_transform();
+
+        self.draw_target
+            .set_transform(&raqote::Transform::identity());
+        self.draw_target.draw_image_at(
+            (rect.x() - origin.0) as f32,
+            (rect.y() - origin.1) as f32,
+            &raqote::Image {
+                data: image.data(),
+                width: image.width() as i32,
+                height: image.height() as i32,
+            },
+            &raqote::DrawOptions {
+                alpha,
+                ..Default::default()
+            },
+        );
+        self.draw_target.set_transform(&transform);
+    }
+
     // Canvas states
 
     /// Saves the entire state of the canvas by pushing the current state onto a stack.
@@ -469,6 +1351,16 @@ impl RenderContext2D {
         self.draw_target.get_data()
     }
 
+    /// Gets the content of the render context as image.
+    pub fn to_image(&mut self) -> Image {
+        Image::from_data(
+            self.draw_target.width() as u32,
+            self.draw_target.height() as u32,
+            self.draw_target.get_data().to_vec(),
+        )
+        .unwrap_or_default()
+    }
+
     pub fn data_mut(&mut self) -> &mut [u32] {
         self.draw_target.get_data_mut()
     }
@@ -479,8 +1371,78 @@ impl RenderContext2D {
 
     pub fn start(&mut self) {
         self.clear(&Brush::from(self.background));
+        self.invalidated = false;
+        self.damage = vec![Rectangle::new(
+            (0.0, 0.0),
+            (
+                self.draw_target.width() as f64,
+                self.draw_target.height() as f64,
+            ),
+        )];
+    }
+
+    /// Starts a new render pipeline that only redraws the given region in logical units. The region is cleared with
+    /// the background and everything drawn until `finish` is clipped to it.
+    pub fn start_region(&mut self, region: Rectangle) {
+        let rect = pixel_rect(
+            region,
+            self.scale_factor,
+            self.draw_target.width() as f64,
+            self.draw_target.height() as f64,
+        )
+        .unwrap_or_default();
+
+        let (left, top) = (rect.x() as i32, rect.y() as i32);
+        let (right, bottom) = (left + rect.width() as i32, top + rect.height() as i32);
+
+        // the region clip is given in physical pixels and stays below all clips of the render pipeline
+        self.draw_target.push_clip_rect(raqote::IntRect::new(
+            raqote::IntPoint::new(left, top),
+            raqote::IntPoint::new(right, bottom),
+        ));
+        self.region = Some(rect);
+
+        let background = (self.background.a() as u32) << 24
+            | (self.background.r() as u32) << 16
+            | (self.background.g() as u32) << 8
+            | self.background.b() as u32;
+        let stride = self.draw_target.width() as usize;
+        let data = self.draw_target.get_data_mut();
+
+        for y in top as usize..bottom as usize {
+            for pixel in &mut data[y * stride + left as usize..y * stride + right as usize] {
+                *pixel = background;
+            }
+        }
+
+        if rect.width() > 0.0 {
+            self.damage.push(rect);
+        }
+    }
+
+    pub fn finish(&mut self) {
+        if self.region.take().is_some() {
+            self.draw_target.pop_clip();
+        }
+    }
+
+    /// Returns `true` if the content of the render context is not valid anymore, e.g. after a resize, and has to be
+    /// redrawn completely with `start`.
+    pub fn invalidated(&self) -> bool {
+        self.invalidated
+    }
+
+    /// Takes the regions in physical pixels that were redrawn since the last call.
+    pub fn take_damage(&mut self) -> Vec<Rectangle> {
+        std::mem::take(&mut self.damage)
+    }
+}
+
+fn winding(fill_rule: FillRule) -> raqote::Winding {
+    match fill_rule {
+        FillRule::NonZero => raqote::Winding::NonZero,
+        FillRule::EvenOdd => raqote::Winding::EvenOdd,
     }
-    pub fn finish(&mut self) {}
 }
 
 fn brush_to_source<'a>(brush: &Brush, frame: Rectangle) -> raqote::Source<'a> {
@@ -496,75 +1458,121 @@ fn brush_to_source<'a>(brush: &Brush, frame: Rectangle) -> raqote::Source<'a> {
             stops,
             repeat,
         }) => {
-            let spread = match repeat {
-                true => raqote::Spread::Repeat,
-                false => raqote::Spread::Pad,
-            };
-            match coords {
-                LinearGradientCoords::Ends { start, end } => {
-                    let g_stops =
-                        build_unit_percent_gradient(&stops, end.distance(*start), |p, c| {
-                            raqote::GradientStop {
-                                position: p as f32,
-                                color: raqote::Color::new(c.a(), c.r(), c.g(), c.b()),
-                            }
-                        });
-                    let start = frame.position() + *start;
-                    let end = frame.position() + *end;
-                    raqote::Source::new_linear_gradient(
-                        raqote::Gradient { stops: g_stops },
-                        raqote::Point::new(start.x() as f32, start.y() as f32),
-                        raqote::Point::new(end.x() as f32, end.y() as f32),
-                        spread,
-                    )
-                }
-                LinearGradientCoords::Angle {
-                    angle,
-                    displacement,
-                } => {
-                    let z = linear_gradient_ends_from_angle(*angle, frame.size());
-                    let disp = displacement.pixels(frame.size());
-                    let start = frame.position() + frame.size() / 2.0 + -z + disp;
-                    let end = frame.position() + frame.size() / 2.0 + z + disp;
-                    let g_stops =
-                        build_unit_percent_gradient(&stops, end.distance(start), |p, c| {
-                            raqote::GradientStop {
-                                position: p as f32,
-                                color: raqote::Color::new(c.a(), c.r(), c.g(), c.b()),
-                            }
-                        });
-                    raqote::Source::new_linear_gradient(
-                        raqote::Gradient { stops: g_stops },
-                        raqote::Point::new(start.x() as f32, start.y() as f32),
-                        raqote::Point::new(end.x() as f32, end.y() as f32),
-                        spread,
-                    )
-                }
-                LinearGradientCoords::Direction {
-                    direction,
-                    displacement,
-                } => {
-                    let width = frame.width();
-                    let height = frame.height();
-                    let (mut start, mut end) = direction.cross(width, height);
-                    let g_stops =
-                        build_unit_percent_gradient(&stops, end.distance(start), |p, c| {
-                            raqote::GradientStop {
-                                position: p as f32,
-                                color: raqote::Color::new(c.a(), c.r(), c.g(), c.b()),
-                            }
-                        });
-                    let displacement = displacement.pixels(frame.size());
-                    start = start + frame.position() + displacement;
-                    end = end + frame.position() + displacement;
-                    raqote::Source::new_linear_gradient(
-                        raqote::Gradient { stops: g_stops },
-                        raqote::Point::new(start.x() as f32, start.y() as f32),
-                        raqote::Point::new(end.x() as f32, end.y() as f32),
-                        spread,
-                    )
-                }
+            let (start, end) = linear_gradient_ends(coords, frame);
+            raqote::Source::new_linear_gradient(
+                raqote::Gradient {
+                    stops: gradient_stops(stops, end.distance(start)),
+                },
+                raqote::Point::new(start.x() as f32, start.y() as f32),
+                raqote::Point::new(end.x() as f32, end.y() as f32),
+                gradient_spread(*repeat),
+            )
+        }
+        Brush::Gradient(Gradient {
+            kind: GradientKind::Radial(radial),
+            stops,
+            repeat,
+        }) => {
+            let (center, radii) = radial.resolve(frame.size());
+            let center = frame.position() + center;
+
+            // a gradient without extent is drawn with its last color
+            if radii.x() <= 0.0 || radii.y() <= 0.0 {
+                let color = stops.last().map(|s| s.color).unwrap_or_default();
+                return brush_to_source(&Brush::SolidColor(color), frame);
             }
+
+            let g_stops = gradient_stops(stops, radii.x());
+
+            // maps the ellipse of the gradient to the unit circle
+            raqote::Source::RadialGradient(
+                raqote::Gradient { stops: g_stops },
+                gradient_spread(*repeat),
+                raqote::Transform::row_major(
+                    (1.0 / radii.x()) as f32,
+                    0.0,
+                    0.0,
+                    (1.0 / radii.y()) as f32,
+                    (-center.x() / radii.x()) as f32,
+                    (-center.y() / radii.y()) as f32,
+                ),
+            )
+        }
+        Brush::Gradient(Gradient {
+            kind: GradientKind::Conic(conic),
+            stops,
+            repeat,
+        }) => {
+            let center = frame.position() + conic.center.pixels(frame.size());
+            let g_stops = gradient_stops(stops, 1.0);
+
+            // raqote starts the sweep on the right side, a conic gradient on the top
+            let start_angle = conic.angle.to_degrees() - 90.0;
+
+            raqote::Source::new_sweep_gradient(
+                raqote::Gradient { stops: g_stops },
+                raqote::Point::new(center.x() as f32, center.y() as f32),
+                start_angle as f32,
+                (start_angle + 360.0) as f32,
+                gradient_spread(*repeat),
+            )
         }
     }
 }
+
+// Renders the given path into an alpha mask.
+fn shape_mask(
+    path: &raqote::Path,
+    width: usize,
+    height: usize,
+    transform: &raqote::Transform,
+) -> Vec<u8> {
+    let mut draw_target = raqote::DrawTarget::new(width as i32, height as i32);
+    draw_target.set_transform(transform);
+    draw_target.fill(
+        path,
+        &raqote::Source::Solid(raqote::SolidSource {
+            r: 0xff,
+            g: 0xff,
+            b: 0xff,
+            a: 0xff,
+        }),
+        &raqote::DrawOptions::default(),
+    );
+    draw_target
+        .get_data()
+        .iter()
+        .map(|p| (p >> 24) as u8)
+        .collect()
+}
+
+fn gradient_spread(repeat: bool) -> raqote::Spread {
+    match repeat {
+        true => raqote::Spread::Repeat,
+        false => raqote::Spread::Pad,
+    }
+}
+
+fn gradient_stops(stops: &[GradientStop], length: f64) -> Vec<raqote::GradientStop> {
+    build_unit_percent_gradient(stops, length, |p, c| raqote::GradientStop {
+        position: p as f32,
+        color: raqote::Color::new(c.a(), c.r(), c.g(), c.b()),
+    })
+}
+
+// Gets the font of the family followed by the registered fallback fonts.
+fn font_chain<'a>(
+    fonts: &'a HashMap<String, Font>,
+    fallbacks: &[String],
+    family: &str,
+) -> Vec<&'a Font> {
+    std::iter::once(family)
+        .chain(
+            fallbacks
+                .iter()
+                .map(|f| f.as_str())
+                .filter(|f| *f != family),
+        )
+        .filter_map(|f| fonts.get(f))
+        .collect()
+}
@@ -0,0 +1,147 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt,
+    hash::{Hash, Hasher},
+    path::Path,
+    sync::Arc,
+};
+
+use super::Image;
+use crate::utils::*;
+
+/// Holds a SVG document, that is rasterized in the size it is drawn with.
+#[derive(Clone, Default)]
+pub struct Svg {
+    id: u64,
+    data: Arc<Vec<u8>>,
+    size: Size,
+    source: String,
+}
+
+impl fmt::Debug for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Svg ( source: {})", self.source)
+    }
+}
+
+impl std::cmp::PartialEq for Svg {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Svg {
+    /// Loads a SVG document from its bytes in memory.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let tree = usvg::Tree::from_data(bytes, &usvg::Options::default())
+            .map_err(|err| format!("Could not load svg: {}", err))?;
+        let size = tree.svg_node().size;
+
+        // documents with the same content share their rasterized images
+        let mut hasher = DefaultHasher::new();
+        bytes.hash(&mut hasher);
+
+        Ok(Svg {
+            id: hasher.finish(),
+            data: Arc::new(bytes.to_vec()),
+            size: Size::new(size.width(), size.height()),
+            source: String::new(),
+        })
+    }
+
+    /// Loads a SVG document from file path.
+    pub fn from_path<P: AsRef<Path> + std::fmt::Debug + Clone>(path: P) -> Result<Self, String> {
+        let bytes = std::fs::read(path.clone())
+            .map_err(|_| format!("Could not load svg width path: {:?}", path))?;
+
+        let mut svg = Svg::from_bytes(&bytes)?;
+        svg.source = path.as_ref().to_string_lossy().to_string();
+        Ok(svg)
+    }
+
+    /// Gets the identifier of the document. Documents with the same content have the same identifier.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Gets the path the document was loaded from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Gets the natural width.
+    pub fn width(&self) -> f64 {
+        self.size.width()
+    }
+
+    /// Gets the natural height.
+    pub fn height(&self) -> f64 {
+        self.size.height()
+    }
+
+    /// Gets the content of the document.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns `true` if the document is empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Rasterizes the document to fit into the given size in pixels, while its aspect ratio is preserved. If a color
+    /// is given, the document is filled with it and only its shape is kept.
+    pub fn rasterize(&self, width: u32, height: u32, color: Option<Color>) -> Option<Image> {
+        if self.is_empty() || self.width() <= 0.0 || self.height() <= 0.0 {
+            return None;
+        }
+
+        let scale = (width as f64 / self.width()).min(height as f64 / self.height());
+        let width = ((self.width() * scale).round() as u32).max(1);
+        let height = ((self.height() * scale).round() as u32).max(1);
+
+        let tree = usvg::Tree::from_data(&self.data, &usvg::Options::default()).ok()?;
+        let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+        resvg::render(&tree, usvg::FitTo::Size(width, height), pixmap.as_mut())?;
+
+        // the pixels of the pixmap are premultiplied rgba
+        let data = pixmap
+            .data()
+            .chunks_exact(4)
+            .map(|p| {
+                let (r, g, b, a) = match color {
+                    Some(color) => {
+                        let a = p[3] as u32 * color.a() as u32 / 255;
+                        (
+                            color.r() as u32 * a / 255,
+                            color.g() as u32 * a / 255,
+                            color.b() as u32 * a / 255,
+                            a,
+                        )
+                    }
+                    None => (p[0] as u32, p[1] as u32, p[2] as u32, p[3] as u32),
+                };
+
+                (a << 24) | (r << 16) | (g << 8) | b
+            })
+            .collect();
+
+        Image::from_data(width, height, data).ok()
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for Svg {
+    fn from(s: &str) -> Svg {
+        Svg::from_path(super::image::os_path(s.to_string())).unwrap()
+    }
+}
+
+impl From<String> for Svg {
+    fn from(s: String) -> Svg {
+        Svg::from_path(super::image::os_path(s)).unwrap()
+    }
+}
+
+// --- Conversions ---
@@ -1,5 +1,8 @@
 use std::path::Path;
-use stdweb::{js, unstable::TryInto};
+use stdweb::{js, unstable::TryInto, web::TypedArray};
+
+use super::Svg;
+use crate::RenderTarget;
 
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Image {
@@ -59,6 +62,60 @@ impl Image {
         Ok(Image { source })
     }
 
+    /// Loads an image from the bytes of an image file in memory. Supports BMP and PNG extensions.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let bytes: TypedArray<u8> = bytes.into();
+
+        // the image is loaded from an object url of the bytes
+        let source: String = js!(
+            return URL.createObjectURL(new Blob([@{bytes}]));
+        )
+        .try_into()
+        .map_err(|_| "Could not load image from bytes.".to_string())?;
+
+        Self::from_path(source)
+    }
+
+    /// Creates a new image from the pixels of the given render target.
+    pub fn from_render_target(render_target: RenderTarget) -> Self {
+        let data: Vec<u8> = render_target
+            .data
+            .iter()
+            .flat_map(|pixel| {
+                vec![
+                    ((pixel & 0x00FF_0000) >> 16) as u8,
+                    ((pixel & 0x0000_FF00) >> 8) as u8,
+                    (pixel & 0x0000_00FF) as u8,
+                    ((pixel & 0xFF00_0000) >> 24) as u8,
+                ]
+            })
+            .collect();
+        let data: TypedArray<u8> = data.as_slice().into();
+
+        // the pixels are drawn on a canvas and the image is loaded from its data url
+        let source: String = js!(
+            var canvas = document.createElement("canvas");
+            canvas.width = @{render_target.width()};
+            canvas.height = @{render_target.height()};
+
+            var context = canvas.getContext("2d");
+            var image_data = context.createImageData(canvas.width, canvas.height);
+            image_data.data.set(@{data});
+            context.putImageData(image_data, 0, 0);
+
+            return canvas.toDataURL();
+        )
+        .try_into()
+        .unwrap_or_default();
+
+        Self::from_path(source).unwrap_or_default()
+    }
+
+    /// Creates a new image from a SVG document. The browser rasterizes it in the size it is drawn with.
+    pub fn from_svg(svg: Svg) -> Self {
+        Image::from_path(svg.source()).unwrap_or_default()
+    }
+
     /// Draws a u32 slice into the image.
     pub fn draw(&mut self, _data: &[u32]) {
         // todo
@@ -104,3 +161,9 @@ impl From<(u32, u32, Vec<u32>)> for Image {
         Image::new(image.0.into(), image.1.into())
     }
 }
+
+impl From<RenderTarget> for Image {
+    fn from(render_target: RenderTarget) -> Self {
+        Image::from_render_target(render_target)
+    }
+}
@@ -1,20 +1,30 @@
 use smallvec::SmallVec;
+use std::{
+    collections::{HashMap, HashSet},
+    io, mem,
+    path::Path,
+    sync::Arc,
+};
 use stdweb::{
     js,
     unstable::TryInto,
     web::{
-        document, html_element::CanvasElement, CanvasGradient, CanvasRenderingContext2d, FillRule,
+        document, html_element::CanvasElement, CanvasGradient, CanvasRenderingContext2d, TypedArray,
     },
+    Value,
 };
 
 // pub use crate::image::Image as InnerImage;
 use crate::{
-    common::*, utils::*, FontConfig, PipelineTrait, RenderConfig, RenderTarget, TextMetrics,
+    common::*, utils::*, FontConfig, Path2D, PathSegment, PipelineTrait, RenderConfig,
+    RenderTarget, TextCluster, TextMetrics,
 };
 
 pub use self::image::*;
+pub use self::svg::*;
 
 mod image;
+mod svg;
 
 type StatesOnStack = [(RenderConfig, PathRect); 2];
 
@@ -22,11 +32,21 @@ type StatesOnStack = [(RenderConfig, PathRect); 2];
 pub struct RenderContext2D {
     canvas_render_context_2_d: CanvasRenderingContext2d,
     font_config: FontConfig,
+    font_fallbacks: Vec<String>,
+    // the registered font families
+    fonts: HashSet<String>,
     config: RenderConfig,
     saved_state: SmallVec<StatesOnStack>,
     path_rect: PathRect,
     export_data: Vec<u32>,
     background: Color,
+    scale_factor: f64,
+    region_clip: bool,
+    invalidated: bool,
+    damage: Vec<Rectangle>,
+    // the contexts that are active again after the layers are popped with the bounds of the layers in physical pixels
+    layers: Vec<(CanvasRenderingContext2d, Rectangle)>,
+    cached_layers: HashMap<u64, (CanvasElement, Rectangle)>,
 }
 
 impl RenderContext2D {
@@ -50,9 +70,17 @@ impl RenderContext2D {
             saved_state: SmallVec::<StatesOnStack>::new(),
             canvas_render_context_2_d: ctx,
             font_config: FontConfig::default(),
+            font_fallbacks: vec![],
+            fonts: HashSet::new(),
             path_rect: PathRect::new(None),
             export_data,
             background: Color::default(),
+            scale_factor: 1.0,
+            region_clip: false,
+            invalidated: true,
+            damage: vec![],
+            layers: vec![],
+            cached_layers: HashMap::new(),
         }
     }
 
@@ -75,9 +103,17 @@ impl RenderContext2D {
             saved_state: SmallVec::<StatesOnStack>::new(),
             canvas_render_context_2_d,
             font_config: FontConfig::default(),
+            font_fallbacks: vec![],
+            fonts: HashSet::new(),
             path_rect: PathRect::new(None),
             export_data,
             background: Color::default(),
+            scale_factor: 1.0,
+            region_clip: false,
+            invalidated: true,
+            damage: vec![],
+            layers: vec![],
+            cached_layers: HashMap::new(),
         }
     }
 
@@ -124,6 +160,38 @@ impl RenderContext2D {
         self.measure_text(text)
     }
 
+    /// Gets the clusters of the text in logical order. The start and end of a cluster are byte indices of the text.
+    /// The browser shapes the text itself, so each char is used as a cluster.
+    pub fn measure_clusters(
+        &mut self,
+        text: &str,
+        font_size: f64,
+        family: impl Into<String>,
+    ) -> Vec<TextCluster> {
+        self.set_font_family(family);
+        self.set_font_size(font_size);
+
+        let mut clusters = vec![];
+        let mut x = 0.0;
+
+        for (start, c) in text.char_indices() {
+            let end = start + c.len_utf8();
+            let width = self.measure_text(&text[..end]).width - x;
+
+            clusters.push(TextCluster {
+                start,
+                end,
+                x,
+                width,
+                rtl: false,
+            });
+
+            x += width;
+        }
+
+        clusters
+    }
+
     /// Returns a TextMetrics object.
     pub fn measure_text(&mut self, text: &str) -> TextMetrics {
         TextMetrics {
@@ -139,7 +207,8 @@ impl RenderContext2D {
     /// Fills the current or given path with the current file style.
     pub fn fill(&mut self) {
         self.fill_style(&self.config.fill_style);
-        self.canvas_render_context_2_d.fill(FillRule::default());
+        self.canvas_render_context_2_d
+            .fill(canvas_fill_rule(self.config.fill_rule));
     }
 
     /// Strokes {outlines} the current or given path with the current stroke style.
@@ -148,6 +217,58 @@ impl RenderContext2D {
         self.canvas_render_context_2_d.stroke();
     }
 
+    /// Fills the given path with the current fill style. The current path is not changed.
+    pub fn fill_path(&mut self, path: &Path2D) {
+        let fill_rule = fill_rule_name(self.config.fill_rule);
+
+        self.with_path(path, |ctx, js_path| {
+            ctx.fill_style(&ctx.config.fill_style);
+            js!(@{&ctx.canvas_render_context_2_d}.fill(@{js_path}, @{fill_rule}););
+        });
+    }
+
+    /// Strokes the given path with the current stroke style. The current path is not changed.
+    pub fn stroke_path(&mut self, path: &Path2D) {
+        self.with_path(path, |ctx, js_path| {
+            ctx.stroke_style(&ctx.config.stroke_style);
+            js!(@{&ctx.canvas_render_context_2_d}.stroke(@{js_path}););
+        });
+    }
+
+    /// Creates a clipping path from the given path. The current path is not changed.
+    pub fn clip_path(&mut self, path: &Path2D) {
+        let fill_rule = fill_rule_name(self.config.fill_rule);
+
+        self.with_path(path, |ctx, js_path| {
+            js!(@{&ctx.canvas_render_context_2_d}.clip(@{js_path}, @{fill_rule}););
+            ctx.path_rect.record_clip();
+        });
+    }
+
+    // The canvas could not store its current path, so the given path is built as browser `Path2D` object.
+    fn with_path<T, F: FnOnce(&mut Self, &Value) -> T>(&mut self, path: &Path2D, f: F) -> T {
+        let current_path_rect = self.path_rect;
+        let js_path = js!(return new Path2D(););
+
+        self.path_rect.rebirth();
+        add_segments(&js_path, &mut self.path_rect, path);
+        let result = f(self, &js_path);
+
+        self.path_rect.restore_path(current_path_rect);
+        result
+    }
+
+    // Builds the given path as browser `Path2D` object.
+    fn js_path(&mut self, path: &Path2D) -> Value {
+        self.with_path(path, |_, js_path| js_path.clone())
+    }
+
+    /// Adds the segments of the given path to the current path.
+    pub fn add_path(&mut self, path: &Path2D) {
+        let canvas = Value::from(self.canvas_render_context_2_d.as_ref().clone());
+        add_segments(&canvas, &mut self.path_rect, path);
+    }
+
     /// Starts a new path by emptying the list of sub-paths. Call this when you want to create a new path.
     pub fn begin_path(&mut self) {
         self.canvas_render_context_2_d.begin_path();
@@ -175,6 +296,40 @@ impl RenderContext2D {
             .record_arc(x, y, radius, start_angle, end_angle);
     }
 
+    /// Adds a circular arc with the given radius to the current path, that connects the line from the current point
+    /// to (x1, y1) with the line from (x1, y1) to (x2, y2).
+    pub fn arc_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, radius: f64) {
+        let mut path = Path2D::new();
+        path.arc_to(x1, y1, x2, y2, radius);
+        self.add_path(&path);
+    }
+
+    /// Adds an elliptical arc centered at (x, y) and rotated by the given angle to the current path. The arc is drawn
+    /// clockwise from the start to the end angle.
+    #[allow(clippy::too_many_arguments)]
+    pub fn ellipse(
+        &mut self,
+        x: f64,
+        y: f64,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    ) {
+        let mut path = Path2D::new();
+        path.ellipse(x, y, radius_x, radius_y, rotation, start_angle, end_angle);
+        self.add_path(&path);
+    }
+
+    /// Adds a rectangle with rounded corners to the current path. The radii are given in the order top left, top
+    /// right, bottom right and bottom left.
+    pub fn rounded_rect(&mut self, x: f64, y: f64, width: f64, height: f64, radii: [f64; 4]) {
+        let mut path = Path2D::new();
+        path.rounded_rect(x, y, width, height, radii);
+        self.add_path(&path);
+    }
+
     /// Begins a new sub-path at the point specified by the given {x, y} coordinates.
 
     pub fn move_to(&mut self, x: f64, y: f64) {
@@ -248,6 +403,86 @@ impl RenderContext2D {
         );
     }
 
+    /// Draws the given part of the image stretched to the given size.
+    pub fn draw_image_with_clip_and_size(
+        &mut self,
+        image: &Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        js!(
+            var img = document.image_store.image(@{&image.source});
+
+            if(img == null) {
+                img = document.image_store.load_image(@{&image.source});
+                img.then(
+                    function(i) {
+                         @{&self.canvas_render_context_2_d}.drawImage(i, @{&clip.x()}, @{&clip.y()}, @{&clip.width()}, @{&clip.height()}, @{&x}, @{&y}, @{&width}, @{&height});
+                    }
+                )
+            } else {
+                @{&self.canvas_render_context_2_d}.drawImage(img, @{&clip.x()}, @{&clip.y()}, @{&clip.width()}, @{&clip.height()}, @{&x}, @{&y}, @{&width}, @{&height});
+            }
+        );
+    }
+
+    /// Draws the SVG document with the given size. If a color is given, the document is filled with it.
+    pub fn draw_svg(
+        &mut self,
+        svg: &Svg,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        color: Option<Color>,
+    ) {
+        let color = match color {
+            Some(color) => format!(
+                "rgba({}, {}, {}, {})",
+                color.r(),
+                color.g(),
+                color.b(),
+                color.a() as f64 / 255.0
+            ),
+            None => String::new(),
+        };
+
+        // a colored document is drawn on an offscreen canvas, where the color is applied to its shape
+        js!(
+            var context = @{&self.canvas_render_context_2_d};
+            var scale_factor = @{self.scale_factor};
+            var draw = function(img) {
+                if(@{&color} === "") {
+                    context.drawImage(img, @{x}, @{y}, @{width}, @{height});
+                    return;
+                }
+
+                var canvas = document.createElement("canvas");
+                canvas.width = Math.max(1, Math.ceil(@{width} * scale_factor));
+                canvas.height = Math.max(1, Math.ceil(@{height} * scale_factor));
+
+                var offscreen = canvas.getContext("2d");
+                offscreen.drawImage(img, 0, 0, canvas.width, canvas.height);
+                offscreen.globalCompositeOperation = "source-in";
+                offscreen.fillStyle = @{&color};
+                offscreen.fillRect(0, 0, canvas.width, canvas.height);
+
+                context.drawImage(canvas, @{x}, @{y}, @{width}, @{height});
+            };
+
+            var img = document.image_store.image(@{svg.source()});
+
+            if(img == null) {
+                document.image_store.load_image(@{svg.source()}).then(draw);
+            } else {
+                draw(img);
+            }
+        );
+    }
+
     pub fn draw_pipeline(
         &mut self,
         x: f64,
@@ -306,9 +541,87 @@ impl RenderContext2D {
         );
     }
 
+    /// Draws the shadow of the rectangle with the given corner radii. An outer shadow is drawn around the rectangle
+    /// and is not painted below of it, an inset shadow is drawn inside of the rectangle.
+    pub fn draw_box_shadow(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radii: [f64; 4],
+        shadow: &Shadow,
+    ) {
+        if shadow.color.a() == 0 || width <= 0.0 || height <= 0.0 {
+            return;
+        }
+
+        // the shape is drawn outside of the canvas, only its shadow is moved back to the visible area
+        let shift = 100_000.0;
+        let rect = Rectangle::new((x, y), (width, height));
+        let (shape, shape_radii) = shadow.shape(rect, radii);
+
+        let mut box_path = Path2D::new();
+        box_path.rounded_rect(x, y, width, height, radii);
+        let box_path = self.js_path(&box_path);
+
+        let mut shape_path = Path2D::new();
+        shape_path.rounded_rect(
+            shape.x() - shift,
+            shape.y(),
+            shape.width(),
+            shape.height(),
+            shape_radii,
+        );
+        let shape_path = self.js_path(&shape_path);
+
+        js!(
+            var ctx = @{&self.canvas_render_context_2_d};
+            var x = @{x};
+            var y = @{y};
+            var width = @{width};
+            var height = @{height};
+            var box_path = @{box_path};
+            var shape_path = @{shape_path};
+            var inset = @{shadow.inset};
+            var shift = @{shift};
+            var border = 10000;
+            var scale = ctx.getTransform ? ctx.getTransform().a : 1;
+
+            ctx.save();
+
+            if (inset) {
+                ctx.clip(box_path);
+            } else {
+                var outside = new Path2D();
+                outside.rect(x - border, y - border, width + 2 * border, height + 2 * border);
+                outside.addPath(box_path);
+                ctx.clip(outside, "evenodd");
+            }
+
+            ctx.shadowColor = @{shadow.color.to_string()};
+            ctx.shadowBlur = @{shadow.blur} * scale;
+            ctx.shadowOffsetX = shift * scale;
+            ctx.shadowOffsetY = 0;
+            ctx.fillStyle = "black";
+
+            if (inset) {
+                var ring = new Path2D();
+                ring.rect(x - shift - border, y - border, width + 2 * border, height + 2 * border);
+                ring.addPath(shape_path);
+                ctx.fill(ring, "evenodd");
+            } else {
+                ctx.fill(shape_path);
+            }
+
+            ctx.restore();
+        );
+    }
+
     /// Creates a clipping path from the current sub-paths. Everything drawn after clip() is called appears inside the clipping path only.
     pub fn clip(&mut self) {
-        self.canvas_render_context_2_d.clip(FillRule::EvenOdd);
+        self.canvas_render_context_2_d
+            .clip(canvas_fill_rule(self.config.fill_rule));
         self.path_rect.record_clip();
     }
 
@@ -320,6 +633,58 @@ impl RenderContext2D {
         self.canvas_render_context_2_d.set_line_width(line_width);
     }
 
+    /// Sets the shape used to draw the end points of lines.
+    pub fn set_line_cap(&mut self, line_cap: LineCap) {
+        self.config.line_cap = line_cap;
+        self.canvas_render_context_2_d.set_line_cap(match line_cap {
+            LineCap::Butt => stdweb::web::LineCap::Butt,
+            LineCap::Round => stdweb::web::LineCap::Round,
+            LineCap::Square => stdweb::web::LineCap::Square,
+        });
+    }
+
+    /// Sets the shape used to join two line segments where they meet.
+    pub fn set_line_join(&mut self, line_join: LineJoin) {
+        self.config.line_join = line_join;
+        self.canvas_render_context_2_d
+            .set_line_join(match line_join {
+                LineJoin::Miter => stdweb::web::LineJoin::Miter,
+                LineJoin::Round => stdweb::web::LineJoin::Round,
+                LineJoin::Bevel => stdweb::web::LineJoin::Bevel,
+            });
+    }
+
+    /// Sets the miter limit ratio. Values that are not positive are ignored.
+    pub fn set_miter_limit(&mut self, miter_limit: f64) {
+        if !miter_limit.is_finite() || miter_limit <= 0.0 {
+            return;
+        }
+
+        self.config.miter_limit = miter_limit;
+        self.canvas_render_context_2_d.set_miter_limit(miter_limit);
+    }
+
+    /// Sets the line dash pattern used when stroking lines. An empty list draws solid lines. If the number of
+    /// elements is odd, the elements are repeated. Patterns with negative values are ignored.
+    pub fn set_line_dash(&mut self, segments: Vec<f64>) {
+        if let Some(segments) = line_dash(segments) {
+            self.canvas_render_context_2_d
+                .set_line_dash(segments.clone());
+            self.config.line_dash = segments;
+        }
+    }
+
+    /// Sets the offset of the line dash pattern.
+    pub fn set_line_dash_offset(&mut self, offset: f64) {
+        self.config.line_dash_offset = offset;
+        self.canvas_render_context_2_d.set_line_dash_offset(offset);
+    }
+
+    /// Sets the rule that determines if a point is inside of a path on filling and clipping.
+    pub fn set_fill_rule(&mut self, fill_rule: FillRule) {
+        self.config.fill_rule = fill_rule;
+    }
+
     /// Sets the alpha value,
     pub fn set_alpha(&mut self, alpha: f32) {
         self.canvas_render_context_2_d
@@ -329,15 +694,25 @@ impl RenderContext2D {
     /// Specific the font family.
     pub fn set_font_family(&mut self, family: impl Into<String>) {
         self.font_config.family = family.into();
-        self.canvas_render_context_2_d
-            .set_font(&self.font_config.to_string());
+        self.apply_font();
     }
 
     /// Specifies the font size.
     pub fn set_font_size(&mut self, size: f64) {
         self.font_config.font_size = size;
-        self.canvas_render_context_2_d
-            .set_font(&self.font_config.to_string());
+        self.apply_font();
+    }
+
+    // Sets the font of the canvas, the fallback families are appended to the font family list of the canvas.
+    fn apply_font(&mut self) {
+        let mut font = self.font_config.to_string();
+
+        for family in &self.font_fallbacks {
+            font.push_str(", ");
+            font.push_str(family);
+        }
+
+        self.canvas_render_context_2_d.set_font(&font);
     }
 
     // Fill and stroke style
@@ -354,7 +729,8 @@ impl RenderContext2D {
 
     // Transformations
 
-    /// Sets the transformation.
+    /// Sets the transformation. The transformation is applied in logical units, the scale factor
+    /// of the render context is applied on top of it.
     pub fn set_transform(
         &mut self,
         h_scaling: f64,
@@ -364,11 +740,150 @@ impl RenderContext2D {
         h_moving: f64,
         v_moving: f64,
     ) {
+        let origin = self.layer_origin();
+
         self.canvas_render_context_2_d.set_transform(
+            h_scaling * self.scale_factor,
+            h_skewing * self.scale_factor,
+            v_skewing * self.scale_factor,
+            v_scaling * self.scale_factor,
+            h_moving * self.scale_factor - origin.0,
+            v_moving * self.scale_factor - origin.1,
+        );
+    }
+
+    /// Multiplies the current transformation with the given transformation, so that the content is transformed
+    /// relative to the current transformation.
+    pub fn transform(
+        &mut self,
+        h_scaling: f64,
+        h_skewing: f64,
+        v_skewing: f64,
+        v_scaling: f64,
+        h_moving: f64,
+        v_moving: f64,
+    ) {
+        // the current transform of the canvas already contains the scale factor and the layer origin
+        self.canvas_render_context_2_d.transform(
             h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
         );
     }
 
+    // Layers
+
+    /// Redirects all drawing to a new offscreen layer with the given bounds in logical units, until the layer is
+    /// popped. Drawing outside of the bounds is clipped.
+    pub fn push_layer(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        let window = match self.layers.first() {
+            Some((context, _)) => context.get_canvas(),
+            None => self.canvas_render_context_2_d.get_canvas(),
+        };
+
+        let rect = pixel_rect(
+            Rectangle::new((x, y), (width, height)),
+            self.scale_factor,
+            window.width() as f64,
+            window.height() as f64,
+        )
+        .unwrap_or_default();
+
+        let canvas: CanvasElement = document()
+            .create_element("canvas")
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        canvas.set_width((rect.width() as u32).max(1));
+        canvas.set_height((rect.height() as u32).max(1));
+
+        let context: CanvasRenderingContext2d = canvas.get_context().unwrap();
+        context.set_text_baseline(stdweb::web::TextBaseline::Middle);
+
+        let origin = self.layer_origin();
+        let outer = mem::replace(&mut self.canvas_render_context_2_d, context);
+
+        js!(
+            var transform = @{&outer}.getTransform();
+            @{&self.canvas_render_context_2_d}.setTransform(
+                transform.a,
+                transform.b,
+                transform.c,
+                transform.d,
+                transform.e + @{origin.0 - rect.x()},
+                transform.f + @{origin.1 - rect.y()}
+            );
+        );
+
+        self.layers.push((outer, rect));
+    }
+
+    /// Pops the current layer and draws it with the given alpha value. If a key is given the content of the layer is
+    /// cached and could be drawn again with `draw_layer` until it is removed.
+    pub fn pop_layer(&mut self, alpha: f32, key: Option<u64>) {
+        let (outer, rect) = match self.layers.pop() {
+            Some(layer) => layer,
+            None => return,
+        };
+
+        let canvas = mem::replace(&mut self.canvas_render_context_2_d, outer).get_canvas();
+
+        // layers outside of the window are not drawn
+        if rect.width() > 0.0 {
+            self.composite_layer(&canvas, rect, alpha);
+        }
+
+        if let Some(key) = key {
+            self.cached_layers.insert(key, (canvas, rect));
+        }
+    }
+
+    /// Draws the cached layer with the given key and alpha value.
+    pub fn draw_layer(&mut self, key: u64, alpha: f32) {
+        if let Some((canvas, rect)) = self.cached_layers.remove(&key) {
+            if rect.width() > 0.0 {
+                self.composite_layer(&canvas, rect, alpha);
+            }
+
+            self.cached_layers.insert(key, (canvas, rect));
+        }
+    }
+
+    /// Returns `true` if a layer with the given key is cached.
+    pub fn has_layer(&self, key: u64) -> bool {
+        self.cached_layers.contains_key(&key)
+    }
+
+    /// Removes the cached layer with the given key.
+    pub fn remove_layer(&mut self, key: u64) {
+        self.cached_layers.remove(&key);
+    }
+
+    /// Removes all cached layers.
+    pub fn clear_layers(&mut self) {
+        self.cached_layers.clear();
+    }
+
+    // Gets the position of the current layer in physical pixels of the window.
+    fn layer_origin(&self) -> (f64, f64) {
+        self.layers
+            .last()
+            .map_or((0.0, 0.0), |(_, rect)| (rect.x(), rect.y()))
+    }
+
+    // Draws the content of a layer at its position in physical pixels.
+    fn composite_layer(&self, canvas: &CanvasElement, rect: Rectangle, alpha: f32) {
+        let origin = self.layer_origin();
+
+        js!(
+            var ctx = @{&self.canvas_render_context_2_d};
+            ctx.save();
+            ctx.setTransform(1, 0, 0, 1, 0, 0);
+            ctx.globalAlpha = @{alpha};
+            ctx.drawImage(@{canvas}, @{rect.x() - origin.0}, @{rect.y() - origin.1});
+            ctx.restore();
+        );
+    }
+
     // Canvas states
 
     /// Saves the entire state of the canvas by pushing the current state onto a stack.
@@ -411,6 +926,33 @@ impl RenderContext2D {
         canvas_render_context_2_d: CanvasRenderingContext2d,
     ) {
         self.canvas_render_context_2_d = canvas_render_context_2_d;
+        self.set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+    }
+
+    /// Resizes the canvas of the render context. The size is given in physical pixels.
+    pub fn resize(&mut self, width: f64, height: f64) {
+        let canvas = self.canvas_render_context_2_d.get_canvas();
+        canvas.set_width(width as u32);
+        canvas.set_height(height as u32);
+
+        // resizing the canvas resets the state of its context
+        self.canvas_render_context_2_d
+            .set_text_baseline(stdweb::web::TextBaseline::Middle);
+        self.export_data = vec![0; (width * height) as usize];
+        self.set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+        self.invalidated = true;
+    }
+
+    /// Sets the scale factor that describes the ratio between physical pixels and logical units.
+    /// All drawing operations are given in logical units and scaled by this factor.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+        self.set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+    }
+
+    /// Gets the current scale factor.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
     }
 
     pub fn data(&mut self) -> &[u32] {
@@ -471,12 +1013,114 @@ impl RenderContext2D {
 
     pub fn start(&mut self) {
         let background = Brush::from(self.background);
-        self.clear(&background)
+        self.clear(&background);
+
+        let canvas = self.canvas_render_context_2_d.get_canvas();
+        self.invalidated = false;
+        self.damage = vec![Rectangle::new(
+            (0.0, 0.0),
+            (canvas.width() as f64, canvas.height() as f64),
+        )];
     }
-    pub fn finish(&mut self) {}
 
-    /// Dummy implementation to be compatible to other platforms.
-    pub fn register_font(&mut self, _family: &str, _font_file: &'static [u8]) {}
+    /// Starts a new render pipeline that only redraws the given region in logical units. The region is cleared with
+    /// the background and everything drawn until `finish` is clipped to it.
+    pub fn start_region(&mut self, region: Rectangle) {
+        let canvas = self.canvas_render_context_2_d.get_canvas();
+
+        if let Some(rect) = pixel_rect(
+            region,
+            self.scale_factor,
+            canvas.width() as f64,
+            canvas.height() as f64,
+        ) {
+            self.damage.push(rect);
+        }
+
+        self.save();
+        self.region_clip = true;
+        self.begin_path();
+        self.rect(region.x(), region.y(), region.width(), region.height());
+        self.clip();
+
+        self.canvas_render_context_2_d.clear_rect(
+            region.x(),
+            region.y(),
+            region.width(),
+            region.height(),
+        );
+        self.set_fill_style(self.background);
+        self.fill_rect(region.x(), region.y(), region.width(), region.height());
+    }
+
+    pub fn finish(&mut self) {
+        if self.region_clip {
+            self.restore();
+            self.region_clip = false;
+        }
+    }
+
+    /// Returns `true` if the content of the render context is not valid anymore and has to be redrawn completely
+    /// with `start`.
+    pub fn invalidated(&self) -> bool {
+        self.invalidated
+    }
+
+    /// Takes the regions in physical pixels that were redrawn since the last call.
+    pub fn take_damage(&mut self) -> Vec<Rectangle> {
+        std::mem::take(&mut self.damage)
+    }
+
+    /// Fonts are loaded by the browser, the family is only noted to be compatible to other platforms.
+    pub fn register_font(&mut self, family: &str, _font_file: &'static [u8]) {
+        self.fonts.insert(family.to_string());
+    }
+
+    /// Returns `true` if a font of the given family is registered.
+    pub fn has_font(&self, family: &str) -> bool {
+        self.fonts.contains(family)
+    }
+
+    /// Registers a new font from owned bytes with the `FontFace` api of the browser.
+    pub fn register_font_bytes(
+        &mut self,
+        family: &str,
+        font_data: impl Into<Arc<Vec<u8>>>,
+    ) -> io::Result<()> {
+        let font_data = TypedArray::<u8>::from(&font_data.into()[..]);
+        self.fonts.insert(family.to_string());
+
+        js!(
+            var font = new FontFace(@{family}, @{font_data}.buffer);
+            font.load().then(function(font) {
+                document.fonts.add(font);
+            });
+        );
+
+        Ok(())
+    }
+
+    /// Font files could not be read from the web, it returns always `Err`. Use `register_font_bytes` instead.
+    pub fn register_font_file(&mut self, _family: &str, _path: impl AsRef<Path>) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            "Font files could not be loaded on the web.",
+        ))
+    }
+
+    /// Font directories could not be read from the web, it returns always `Err`. Use `register_font_bytes` instead.
+    pub fn register_font_directory(&mut self, _path: impl AsRef<Path>) -> io::Result<Vec<String>> {
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            "Font directories could not be loaded on the web.",
+        ))
+    }
+
+    /// Sets the font families that are used in the given order for chars, that are missing in the selected font family.
+    pub fn set_font_fallbacks(&mut self, families: Vec<String>) {
+        self.font_fallbacks = families;
+        self.apply_font();
+    }
 
     fn fill_style<'a>(&self, brush: &Brush) {
         match brush {
@@ -489,13 +1133,9 @@ impl RenderContext2D {
                     Some(frame) => frame,
                     None => return, // There are no path to fill
                 };
-                match gradient.kind {
-                    GradientKind::Linear(coords) => {
-                        let web_gradient = self.linear_gradient(&coords, &gradient.stops, frame);
-                        self.canvas_render_context_2_d
-                            .set_fill_style_gradient(&web_gradient);
-                    }
-                }
+                let web_gradient = self.gradient(gradient, frame);
+                self.canvas_render_context_2_d
+                    .set_fill_style_gradient(&web_gradient);
             }
         }
     }
@@ -511,17 +1151,77 @@ impl RenderContext2D {
                     Some(frame) => frame,
                     None => return, // There are no path to fill
                 };
-                match gradient.kind {
-                    GradientKind::Linear(coords) => {
-                        let web_gradient = self.linear_gradient(&coords, &gradient.stops, frame);
-                        self.canvas_render_context_2_d
-                            .set_stroke_style_gradient(&web_gradient);
-                    }
-                }
+                let web_gradient = self.gradient(gradient, frame);
+                self.canvas_render_context_2_d
+                    .set_stroke_style_gradient(&web_gradient);
             }
         }
     }
 
+    fn gradient(&self, gradient: &Gradient, frame: Rectangle) -> CanvasGradient {
+        match gradient.kind {
+            GradientKind::Linear(coords) => self.linear_gradient(&coords, &gradient.stops, frame),
+            GradientKind::Radial(radial) => self.radial_gradient(&radial, &gradient.stops, frame),
+            GradientKind::Conic(conic) => self.conic_gradient(&conic, &gradient.stops, frame),
+        }
+    }
+
+    fn radial_gradient(
+        &self,
+        radial: &RadialGradient,
+        stops: &[GradientStop],
+        frame: Rectangle,
+    ) -> CanvasGradient {
+        let (center, radii) = radial.resolve(frame.size());
+        let center = frame.position() + center;
+
+        // the canvas supports only circles, an ellipse is drawn with its larger radius
+        let radius = radii.x().max(radii.y());
+
+        let web_gradient = self
+            .canvas_render_context_2_d
+            .create_radial_gradient(center.x(), center.y(), 0.0, center.x(), center.y(), radius)
+            .unwrap();
+
+        build_unit_percent_gradient(stops, radius, |p, c| {
+            web_gradient
+                .add_color_stop(p, c.to_string().as_str())
+                .unwrap();
+        });
+
+        web_gradient
+    }
+
+    fn conic_gradient(
+        &self,
+        conic: &ConicGradient,
+        stops: &[GradientStop],
+        frame: Rectangle,
+    ) -> CanvasGradient {
+        let center = frame.position() + conic.center.pixels(frame.size());
+
+        // the canvas starts the conic gradient on the right side, css on the top
+        let start_angle = conic.angle.to_radians() - std::f64::consts::FRAC_PI_2;
+
+        let web_gradient: CanvasGradient = js!(
+            return @{&self.canvas_render_context_2_d}.createConicGradient(
+                @{start_angle},
+                @{center.x()},
+                @{center.y()}
+            );
+        )
+        .try_into()
+        .unwrap();
+
+        build_unit_percent_gradient(stops, 1.0, |p, c| {
+            web_gradient
+                .add_color_stop(p, c.to_string().as_str())
+                .unwrap();
+        });
+
+        web_gradient
+    }
+
     fn linear_gradient(
         &self,
         coords: &LinearGradientCoords,
@@ -621,6 +1321,132 @@ impl RenderContext2D {
     }
 }
 
+fn canvas_fill_rule(fill_rule: FillRule) -> stdweb::web::FillRule {
+    match fill_rule {
+        FillRule::NonZero => stdweb::web::FillRule::NonZero,
+        FillRule::EvenOdd => stdweb::web::FillRule::EvenOdd,
+    }
+}
+
+fn fill_rule_name(fill_rule: FillRule) -> &'static str {
+    match fill_rule {
+        FillRule::NonZero => "nonzero",
+        FillRule::EvenOdd => "evenodd",
+    }
+}
+
+// Adds the segments of the path to the given canvas context or browser `Path2D` object, both provide the same path
+// methods. Arcs between two lines are resolved like on the other backends.
+fn add_segments(target: &Value, path_rect: &mut PathRect, path: &Path2D) {
+    for segment in path.segments() {
+        match *segment {
+            PathSegment::MoveTo { x, y } => {
+                js!(@{target}.moveTo(@{x}, @{y}););
+                path_rect.record_move_to(x, y);
+            }
+            PathSegment::LineTo { x, y } => {
+                js!(@{target}.lineTo(@{x}, @{y}););
+                path_rect.record_line_to(x, y);
+            }
+            PathSegment::QuadraticCurveTo { cpx, cpy, x, y } => {
+                js!(@{target}.quadraticCurveTo(@{cpx}, @{cpy}, @{x}, @{y}););
+                path_rect.record_quadratic_curve_to(cpx, cpy, x, y);
+            }
+            PathSegment::BezierCurveTo {
+                cp1x,
+                cp1y,
+                cp2x,
+                cp2y,
+                x,
+                y,
+            } => {
+                js!(@{target}.bezierCurveTo(@{cp1x}, @{cp1y}, @{cp2x}, @{cp2y}, @{x}, @{y}););
+                path_rect.record_bezier_curve_to(cp1x, cp1y, cp2x, cp2y, x, y);
+            }
+            PathSegment::Arc {
+                x,
+                y,
+                radius,
+                start_angle,
+                end_angle,
+                anticlockwise,
+            } => {
+                js!(@{target}.arc(@{x}, @{y}, @{radius}, @{start_angle}, @{end_angle}, @{anticlockwise}););
+                path_rect.record_arc(x, y, radius, start_angle, end_angle);
+            }
+            PathSegment::ArcTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                radius,
+            } => {
+                // the canvas starts a path without current point at the first control point
+                if !path_rect.has_current_point() {
+                    js!(@{target}.moveTo(@{x1}, @{y1}););
+                    path_rect.record_move_to(x1, y1);
+                }
+
+                let current = path_rect.last_point();
+
+                match arc_to_arc(current, Point::new(x1, y1), Point::new(x2, y2), radius) {
+                    Some((start, center, start_angle, end_angle, anticlockwise)) => {
+                        let (x, y) = (center.x(), center.y());
+
+                        js!(
+                            @{target}.lineTo(@{start.x()}, @{start.y()});
+                            @{target}.arc(@{x}, @{y}, @{radius}, @{start_angle}, @{end_angle}, @{anticlockwise});
+                        );
+                        path_rect.record_line_to(start.x(), start.y());
+                        path_rect.record_arc(x, y, radius, start_angle, end_angle);
+                    }
+                    None => {
+                        js!(@{target}.lineTo(@{x1}, @{y1}););
+                        path_rect.record_line_to(x1, y1);
+                    }
+                }
+            }
+            PathSegment::Ellipse {
+                x,
+                y,
+                radius_x,
+                radius_y,
+                rotation,
+                start_angle,
+                end_angle,
+            } => {
+                js!(
+                    @{target}.ellipse(
+                        @{x}, @{y}, @{radius_x}, @{radius_y}, @{rotation}, @{start_angle}, @{end_angle}, false
+                    );
+                );
+                path_rect.record_ellipse(
+                    x,
+                    y,
+                    radius_x,
+                    radius_y,
+                    rotation,
+                    start_angle,
+                    end_angle,
+                );
+            }
+            PathSegment::Rect {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                js!(@{target}.rect(@{x}, @{y}, @{width}, @{height}););
+                path_rect.record_rect(x, y, width, height);
+            }
+            PathSegment::ClosePath => {
+                js!(@{target}.closePath(););
+                path_rect.record_path_close();
+            }
+        }
+    }
+}
+
 // --- Conversions ---
 
 impl From<&str> for Image {
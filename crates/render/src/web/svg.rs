@@ -0,0 +1,67 @@
+use stdweb::{js, unstable::TryInto, web::TypedArray};
+
+use super::Image;
+
+/// Holds a SVG document. The browser rasterizes it in the size it is drawn with.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct Svg {
+    image: Image,
+}
+
+impl Svg {
+    /// Loads a SVG document from its bytes in memory.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let bytes: TypedArray<u8> = bytes.into();
+
+        let source: String = js!(
+            return URL.createObjectURL(new Blob([@{bytes}], { type: "image/svg+xml" }));
+        )
+        .try_into()
+        .map_err(|_| "Could not load svg from bytes.".to_string())?;
+
+        Self::from_path(source)
+    }
+
+    /// Loads a SVG document from file path.
+    pub fn from_path(path: impl Into<String>) -> Result<Self, String> {
+        Ok(Svg {
+            image: Image::from_path(path.into())?,
+        })
+    }
+
+    /// Gets the path the document was loaded from.
+    pub fn source(&self) -> &str {
+        &self.image.source
+    }
+
+    /// Gets the natural width.
+    pub fn width(&self) -> f64 {
+        self.image.width()
+    }
+
+    /// Gets the natural height.
+    pub fn height(&self) -> f64 {
+        self.image.height()
+    }
+
+    /// Returns `true` if the document is empty.
+    pub fn is_empty(&self) -> bool {
+        self.image.source.is_empty()
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for Svg {
+    fn from(s: &str) -> Svg {
+        Svg::from_path(s).unwrap()
+    }
+}
+
+impl From<String> for Svg {
+    fn from(s: String) -> Svg {
+        Svg::from_path(s).unwrap()
+    }
+}
+
+// --- Conversions ---
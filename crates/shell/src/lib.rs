@@ -67,6 +67,46 @@ pub enum WindowRequest {
 
     /// Request to change the title of the `Windows`.
     ChangeTitle(String),
+
+    /// Request to change the scale factor of the `Windows`. It overrides the scale factor provided by the backend.
+    ChangeScaleFactor(f64),
+
+    /// Request to move the `Windows`s to the given position on the screen.
+    Move { x: f64, y: f64 },
+
+    /// Request to resize the `Windows`s to the given (logical) size.
+    Resize { width: f64, height: f64 },
+
+    /// Request to minimize the `Windows`s.
+    Minimize,
+
+    /// Request to maximize the `Windows`s, so it fills the whole screen.
+    Maximize,
+
+    /// Request to restore the position and size of a maximized `Windows`s.
+    Restore,
+
+    /// Request to render the content of the `Windows`s with the given scale and save it as PNG to the given path.
+    ///
+    /// The request is ignored on the web, there is no file system to save the screenshot to.
+    Screenshot { path: String, scale: f64 },
+}
+
+/// Describes a stage of the application life cycle. It is used to inform the application about
+/// changes of the application shell.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LifecycleEvent {
+    /// The application shell is started.
+    Started,
+
+    /// A new window with the given title is created.
+    WindowCreated(String),
+
+    /// The last open window of the application was closed.
+    AllWindowsClosed,
+
+    /// The application shell is about to quit.
+    AboutToQuit,
 }
 
 /// Used to send a request to the application shell.
@@ -77,6 +117,9 @@ where
     /// Request redraw of the `Windows`s content.
     CreateWindow(W, WindowSettings, mpsc::Receiver<WindowRequest>),
 
+    /// Request to quit the application shell with all its windows.
+    Quit,
+
     None,
 }
 
@@ -107,9 +150,15 @@ pub struct WindowSettings {
     /// The initial position of the window.
     pub position: (f64, f64),
 
-    /// The initial size of the window.
+    /// The initial size of the window in logical units.
     pub size: (f64, f64),
 
+    /// Overrides the scale factor provided by the backend.
+    pub scale_factor: Option<f64>,
+
     /// List of fonts to register.
     pub fonts: HashMap<String, &'static [u8]>,
+
+    /// Font families that are used in the given order for chars, that are missing in the selected font.
+    pub font_fallbacks: Vec<String>,
 }
@@ -1,6 +1,6 @@
 //! This module contains a platform specific implementation of the window shell.
 
-use std::sync::mpsc;
+use std::{sync::mpsc, thread, time::Duration};
 
 pub use super::native::*;
 
@@ -17,6 +17,16 @@ mod window_builder;
 /// Does nothing. This function is only use by the web backend.
 pub fn initialize() {}
 
+/// Gets the size of the screen. minifb cannot detect the screen size, therefore it returns always `None`.
+pub fn screen_size() -> Option<(f64, f64)> {
+    None
+}
+
+/// Returns `false`, minifb cannot change the size of an open window.
+pub fn can_resize_window() -> bool {
+    false
+}
+
 /// Represents an application shell that could handle multiple windows.
 pub struct Shell<A: 'static>
 where
@@ -24,6 +34,10 @@ where
 {
     window_shells: Vec<Window<A>>,
     requests: mpsc::Receiver<ShellRequest<A>>,
+    lifecycle_handler: Option<Box<dyn FnMut(LifecycleEvent)>>,
+    lifecycle_events: Vec<LifecycleEvent>,
+    quit_on_last_window_closed: bool,
+    quit: bool,
 }
 
 impl<A> Shell<A>
@@ -35,6 +49,36 @@ where
         Shell {
             window_shells: vec![],
             requests,
+            lifecycle_handler: None,
+            lifecycle_events: vec![],
+            quit_on_last_window_closed: true,
+            quit: false,
+        }
+    }
+
+    /// Registers a handler that is called on each stage of the application life cycle.
+    pub fn lifecycle_handler<H: FnMut(LifecycleEvent) + 'static>(&mut self, handler: H) {
+        self.lifecycle_handler = Some(Box::new(handler));
+    }
+
+    /// Defines if the application shell quits after its last window is closed. Default is `true`.
+    pub fn quit_on_last_window_closed(&mut self, quit_on_last_window_closed: bool) {
+        self.quit_on_last_window_closed = quit_on_last_window_closed;
+    }
+
+    // Queues a life cycle event. Queued events are dispatched by the run loop.
+    fn push_lifecycle_event(&mut self, event: LifecycleEvent) {
+        self.lifecycle_events.push(event);
+    }
+
+    // Calls the life cycle handler with all queued events.
+    fn dispatch_lifecycle_events(&mut self) {
+        if let Some(handler) = &mut self.lifecycle_handler {
+            for event in self.lifecycle_events.drain(..) {
+                handler(event);
+            }
+        } else {
+            self.lifecycle_events.clear();
         }
     }
 
@@ -60,19 +104,38 @@ where
         }
 
         for request in requests {
-            if let ShellRequest::CreateWindow(adapter, settings, window_requests) = request {
-                self.create_window_from_settings(settings, adapter)
-                    .request_receiver(window_requests)
-                    .build();
+            match request {
+                ShellRequest::CreateWindow(adapter, settings, window_requests) => {
+                    self.create_window_from_settings(settings, adapter)
+                        .request_receiver(window_requests)
+                        .build();
+                }
+                ShellRequest::Quit => self.quit = true,
+                ShellRequest::None => {}
             }
         }
     }
 
     /// Runs (starts) the application shell and its windows.
     pub fn run(&mut self) {
+        self.lifecycle_events.insert(0, LifecycleEvent::Started);
+
         loop {
+            self.dispatch_lifecycle_events();
+
+            if self.quit {
+                break;
+            }
+
             if self.window_shells.is_empty() {
-                return;
+                if self.quit_on_last_window_closed {
+                    break;
+                }
+
+                // wait for new windows or a quit request
+                self.receive_requests();
+                thread::sleep(Duration::from_millis(16));
+                continue;
             }
 
             for i in 0..self.window_shells.len() {
@@ -92,11 +155,19 @@ where
 
                 if remove {
                     self.window_shells.remove(i);
+
+                    if self.window_shells.is_empty() {
+                        self.push_lifecycle_event(LifecycleEvent::AllWindowsClosed);
+                    }
+
                     break;
                 }
             }
 
             self.receive_requests();
         }
+
+        self.push_lifecycle_event(LifecycleEvent::AboutToQuit);
+        self.dispatch_lifecycle_events();
     }
 }
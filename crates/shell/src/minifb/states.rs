@@ -14,6 +14,7 @@ pub struct MouseState {
 #[derive(Copy, Clone, Default, Debug)]
 pub struct WindowState {
     pub size: (usize, usize),
+    pub position: (isize, isize),
     pub active: bool,
 }
 
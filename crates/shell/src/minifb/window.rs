@@ -26,8 +26,10 @@ where
     update: bool,
     redraw: bool,
     close: bool,
+    close_requested: bool,
     key_states: Vec<KeyState>,
     text_input: Rc<RefCell<Vec<String>>>,
+    scale_factor: f64,
 }
 
 impl<A> Window<A>
@@ -39,11 +41,13 @@ where
         adapter: A,
         render_context: RenderContext2D,
         request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+        scale_factor: f64,
         key_states: Vec<KeyState>,
         text_input: Rc<RefCell<Vec<String>>>,
     ) -> Self {
         let mut adapter = adapter;
         adapter.set_raw_window_handle(window.raw_window_handle());
+        adapter.scale_factor_changed(scale_factor);
 
         Window {
             window,
@@ -55,8 +59,10 @@ where
             update: true,
             redraw: true,
             close: false,
+            close_requested: false,
             key_states,
             text_input,
+            scale_factor,
         }
     }
 }
@@ -82,7 +88,10 @@ where
         };
 
         self.adapter.mouse_event(MouseEvent {
-            position: Point::new(self.mouse.mouse_pos.0 as f64, self.mouse.mouse_pos.1 as f64),
+            position: Point::new(
+                self.mouse.mouse_pos.0 as f64 / self.scale_factor,
+                self.mouse.mouse_pos.1 as f64 / self.scale_factor,
+            ),
             button,
             state,
         });
@@ -128,24 +137,38 @@ where
         }
     }
 
-    /// Check if the window is open.
+    /// Check if the window is open. The window stays open until the application sends a close request.
     pub fn is_open(&self) -> bool {
-        self.window.is_open() && !self.close
+        !self.close
     }
 
-    /// Updates the clipboard.
+    /// Updates the clipboard. minifb has no access to the system clipboard, therefore the clipboard
+    /// works only inside of the application.
     pub fn update_clipboard(&mut self) {
-        // todo
+        let mut clipboard_value = None;
+        self.adapter.clipboard_update(&mut clipboard_value);
     }
 
     /// Drain events and propagate the events to the adapter.
     pub fn drain_events(&mut self) {
         self.window.update();
 
+        // close button, the adapter decides if the window will be closed by sending a close request
+        // minifb keeps reporting a closed window after the close button was pressed, so a vetoed
+        // request could not be repeated
+        if !self.window.is_open() && !self.close_requested {
+            self.close_requested = true;
+            self.adapter.quit_event();
+            self.update = true;
+        }
+
         // mouse move
         if let Some(pos) = self.window.get_mouse_pos(minifb::MouseMode::Discard) {
             if (pos.0.floor(), pos.1.floor()) != self.mouse.mouse_pos {
-                self.adapter.mouse(pos.0 as f64, pos.1 as f64);
+                self.adapter.mouse(
+                    pos.0 as f64 / self.scale_factor,
+                    pos.1 as f64 / self.scale_factor,
+                );
                 self.mouse.mouse_pos = (pos.0.floor(), pos.1.floor());
                 self.update = true;
             }
@@ -200,8 +223,18 @@ where
                 self.window_state.size.1 as f64,
             );
             self.adapter.resize(
-                self.window_state.size.0 as f64,
-                self.window_state.size.1 as f64,
+                self.window_state.size.0 as f64 / self.scale_factor,
+                self.window_state.size.1 as f64 / self.scale_factor,
+            );
+            self.update = true;
+        }
+
+        // move
+        if self.window_state.position != self.window.get_position() {
+            self.window_state.position = self.window.get_position();
+            self.adapter.moved(
+                self.window_state.position.0 as f64,
+                self.window_state.position.1 as f64,
             );
             self.update = true;
         }
@@ -242,6 +275,29 @@ where
                         self.update = true;
                         self.redraw = true;
                     }
+                    WindowRequest::ChangeScaleFactor(scale_factor) => {
+                        // the physical size of the window is kept, only its logical size changes
+                        self.scale_factor = scale_factor;
+                        self.adapter.scale_factor_changed(scale_factor);
+                        self.adapter.resize(
+                            self.window_state.size.0 as f64 / scale_factor,
+                            self.window_state.size.1 as f64 / scale_factor,
+                        );
+                        self.update = true;
+                        self.redraw = true;
+                    }
+                    WindowRequest::Move { x, y } => {
+                        self.window.set_position(x as isize, y as isize);
+                    }
+                    WindowRequest::Resize { .. }
+                    | WindowRequest::Minimize
+                    | WindowRequest::Maximize
+                    | WindowRequest::Restore => {
+                        // minifb does not support to change the size or state of an open window
+                    }
+                    WindowRequest::Screenshot { path, scale } => {
+                        self.adapter.screenshot(&path, scale);
+                    }
                     WindowRequest::Close => {
                         self.close = true;
                     }
@@ -270,6 +326,9 @@ where
                     self.window_state.size.0 as usize,
                     self.window_state.size.1 as usize,
                 );
+
+                // minifb always uploads the whole buffer, the redrawn regions are not needed
+                self.render_context.take_damage();
                 // CONSOLE.time_end("render");
                 self.redraw = false;
                 //super::CONSOLE.time_end("complete");
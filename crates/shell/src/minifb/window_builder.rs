@@ -3,7 +3,7 @@ use std::{cell::RefCell, char, collections::HashMap, rc::Rc, sync::mpsc, time::D
 use super::{KeyState, Shell, Window};
 use crate::{
     event::Key, render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter,
-    WindowRequest, WindowSettings,
+    LifecycleEvent, WindowRequest, WindowSettings,
 };
 
 /// The `WindowBuilder` is used to construct a window shell for the minifb backend.
@@ -18,7 +18,9 @@ where
     always_on_top: bool,
     borderless: bool,
     fonts: HashMap<String, &'static [u8]>,
+    font_fallbacks: Vec<String>,
     bounds: Rectangle,
+    scale_factor: Option<f64>,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
 }
 
@@ -36,7 +38,9 @@ where
             always_on_top: false,
             borderless: false,
             fonts: HashMap::new(),
+            font_fallbacks: vec![],
             bounds: Rectangle::new((0.0, 0.0), (100.0, 75.0)),
+            scale_factor: None,
             request_receiver: None,
         }
     }
@@ -51,7 +55,9 @@ where
             always_on_top: settings.always_on_top,
             borderless: settings.borderless,
             fonts: settings.fonts,
+            font_fallbacks: settings.font_fallbacks,
             bounds: Rectangle::new(settings.position, (settings.size.0, settings.size.1)),
+            scale_factor: settings.scale_factor,
             request_receiver: None,
         }
     }
@@ -86,12 +92,24 @@ where
         self
     }
 
+    /// Overrides the scale factor provided by the backend.
+    pub fn scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = Some(scale_factor);
+        self
+    }
+
     /// Registers a new font with family key.
     pub fn font(mut self, family: impl Into<String>, font_file: &'static [u8]) -> Self {
         self.fonts.insert(family.into(), font_file);
         self
     }
 
+    /// Appends a font family to the fallback chain, that is used for chars that are missing in the selected font.
+    pub fn font_fallback(mut self, family: impl Into<String>) -> Self {
+        self.font_fallbacks.push(family.into());
+        self
+    }
+
     /// Register a window request receiver to communicate with the window shell from outside.
     pub fn request_receiver(mut self, request_receiver: mpsc::Receiver<WindowRequest>) -> Self {
         self.request_receiver = Some(request_receiver);
@@ -100,6 +118,11 @@ where
 
     /// Builds the window shell and add it to the application `Shell`.
     pub fn build(self) {
+        // minifb does not provide the scale factor of the screen
+        let scale_factor = self.scale_factor.unwrap_or(1.0);
+        let width = self.bounds.width() * scale_factor;
+        let height = self.bounds.height() * scale_factor;
+
         let window_options = minifb::WindowOptions {
             resize: self.resizeable,
             topmost: self.always_on_top,
@@ -111,8 +134,8 @@ where
 
         let mut window = minifb::Window::new(
             self.title.as_str(),
-            self.bounds.width() as usize,
-            self.bounds.height() as usize,
+            width as usize,
+            height as usize,
             window_options,
         )
         .unwrap_or_else(|e| {
@@ -130,17 +153,23 @@ where
 
         window.set_position(self.bounds.x() as isize, self.bounds.y() as isize);
 
-        let mut render_context = RenderContext2D::new(self.bounds.width(), self.bounds.height());
+        let mut render_context = RenderContext2D::new(width, height);
 
         for (family, font) in self.fonts {
             render_context.register_font(&family, font);
         }
 
+        render_context.set_font_fallbacks(self.font_fallbacks);
+
+        self.shell
+            .push_lifecycle_event(LifecycleEvent::WindowCreated(self.title));
+
         self.shell.window_shells.push(Window::new(
             window,
             self.adapter,
             render_context,
             self.request_receiver,
+            scale_factor,
             vec![
                 KeyState::new(minifb::Key::Backspace, Key::Backspace),
                 KeyState::new(minifb::Key::Left, Key::Left),
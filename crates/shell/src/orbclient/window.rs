@@ -40,6 +40,8 @@ where
     redraw: Arc<AtomicBool>,
     close: bool,
     has_clipboard_update: bool,
+    scale_factor: f64,
+    restore_bounds: Option<(i32, i32, u32, u32)>,
     #[cfg(not(target_os = "redox"))]
     _sdl2_sync_thread: Option<thread::JoinHandle<()>>,
 }
@@ -81,7 +83,11 @@ where
         adapter: A,
         render_context: RenderContext2D,
         request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+        scale_factor: f64,
     ) -> Self {
+        let mut adapter = adapter;
+        adapter.scale_factor_changed(scale_factor);
+
         Window {
             window,
             adapter,
@@ -93,6 +99,8 @@ where
             redraw: Arc::new(AtomicBool::new(true)),
             close: false,
             has_clipboard_update: true,
+            scale_factor,
+            restore_bounds: None,
         }
     }
 
@@ -102,11 +110,13 @@ where
         adapter: A,
         render_context: RenderContext2D,
         request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+        scale_factor: f64,
     ) -> Self {
         let mut adapter = adapter;
         let redraw: Arc<AtomicBool> = Arc::new(AtomicBool::new(true));
 
         adapter.set_raw_window_handle(window.raw_window_handle());
+        adapter.scale_factor_changed(scale_factor);
 
         let (request_receiver, _sdl2_sync_thread) = {
             if let Some(receiver) = request_receiver {
@@ -129,6 +139,8 @@ where
             redraw,
             close: false,
             has_clipboard_update: true,
+            scale_factor,
+            restore_bounds: None,
         }
     }
 
@@ -171,7 +183,10 @@ where
         };
 
         self.adapter.mouse_event(MouseEvent {
-            position: Point::new(self.mouse.mouse_pos.0 as f64, self.mouse.mouse_pos.1 as f64),
+            position: Point::new(
+                self.mouse.mouse_pos.0 as f64 / self.scale_factor,
+                self.mouse.mouse_pos.1 as f64 / self.scale_factor,
+            ),
             button,
             state,
         });
@@ -250,7 +265,10 @@ where
                 }
                 orbclient::EventOption::Mouse(event) => {
                     self.mouse.mouse_pos = (event.x as f32, event.y as f32);
-                    self.adapter.mouse(event.x as f64, event.y as f64);
+                    self.adapter.mouse(
+                        event.x as f64 / self.scale_factor,
+                        event.y as f64 / self.scale_factor,
+                    );
                     self.update = true;
                 }
                 orbclient::EventOption::MouseRelative(_) => {}
@@ -293,13 +311,20 @@ where
                     self.update = true;
                 }
                 orbclient::EventOption::Quit(_) => {
-                    self.close = true;
+                    // the adapter decides if the window will be closed by sending a close request
+                    self.adapter.quit_event();
                     self.update = true
                 }
                 orbclient::EventOption::Focus(_) => {}
-                orbclient::EventOption::Move(_) => {}
+                orbclient::EventOption::Move(event) => {
+                    self.adapter.moved(event.x as f64, event.y as f64);
+                    self.update = true;
+                }
                 orbclient::EventOption::Resize(event) => {
-                    self.adapter.resize(event.width as f64, event.height as f64);
+                    self.adapter.resize(
+                        event.width as f64 / self.scale_factor,
+                        event.height as f64 / self.scale_factor,
+                    );
                     self.render_context
                         .resize(event.width as f64, event.height as f64);
                     self.update = true;
@@ -323,6 +348,22 @@ where
         }
     }
 
+    // Sets the physical size of the window and informs the adapter about the new size.
+    fn set_size(&mut self, width: u32, height: u32) {
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        self.window.set_size(width, height);
+        self.adapter.resize(
+            width as f64 / self.scale_factor,
+            height as f64 / self.scale_factor,
+        );
+        self.render_context.resize(width as f64, height as f64);
+        self.update = true;
+        self.redraw.store(true, Ordering::Relaxed);
+    }
+
     /// Receives window request from the application and handles them.
     pub fn receive_requests(&mut self) {
         if let Some(request_receiver) = &self.request_receiver {
@@ -339,6 +380,55 @@ where
                         self.update = true;
                         self.redraw.store(true, Ordering::Relaxed)
                     }
+                    WindowRequest::ChangeScaleFactor(scale_factor) => {
+                        // the physical size of the window is kept, only its logical size changes
+                        self.scale_factor = scale_factor;
+                        self.adapter.scale_factor_changed(scale_factor);
+                        self.adapter.resize(
+                            self.window.width() as f64 / scale_factor,
+                            self.window.height() as f64 / scale_factor,
+                        );
+                        self.update = true;
+                        self.redraw.store(true, Ordering::Relaxed)
+                    }
+                    WindowRequest::Move { x, y } => {
+                        self.window.set_pos(x as i32, y as i32);
+                    }
+                    WindowRequest::Resize { width, height } => {
+                        self.set_size(
+                            (width * self.scale_factor) as u32,
+                            (height * self.scale_factor) as u32,
+                        );
+                    }
+                    WindowRequest::Minimize => {
+                        // orbclient does not support to minimize a window
+                    }
+                    WindowRequest::Maximize => {
+                        // orbclient does not support to maximize a window, it is emulated by filling the screen
+                        if self.restore_bounds.is_some() {
+                            continue;
+                        }
+
+                        if let Some((width, height)) = super::screen_size() {
+                            self.restore_bounds = Some((
+                                self.window.x(),
+                                self.window.y(),
+                                self.window.width(),
+                                self.window.height(),
+                            ));
+                            self.window.set_pos(0, 0);
+                            self.set_size(width as u32, height as u32);
+                        }
+                    }
+                    WindowRequest::Restore => {
+                        if let Some((x, y, width, height)) = self.restore_bounds.take() {
+                            self.window.set_pos(x, y);
+                            self.set_size(width, height);
+                        }
+                    }
+                    WindowRequest::Screenshot { path, scale } => {
+                        self.adapter.screenshot(&path, scale);
+                    }
                     WindowRequest::Close => {
                         self.close = true;
                     }
@@ -360,7 +450,11 @@ where
 
     /// Swaps the current frame buffer.
     pub fn render(&mut self) {
-        if self.redraw.load(Ordering::Relaxed) {
+        if self.redraw.load(Ordering::Relaxed)
+            && self.render_context.data().len() == self.window.data().len()
+        {
+            let damage = self.render_context.take_damage();
+            let stride = self.window.width() as usize;
             let bytes = self.render_context.data_u8_mut();
             let color_data = unsafe {
                 std::slice::from_raw_parts_mut(
@@ -369,13 +463,21 @@ where
                 )
             };
 
-            if color_data.len() == self.window.data().len() {
-                self.window.data_mut().clone_from_slice(color_data);
+            // only the pixels of the redrawn regions are copied to the window
+            let window_data = self.window.data_mut();
+            for rect in damage {
+                let (left, width) = (rect.x() as usize, rect.width() as usize);
 
-                // CONSOLE.time_end("render");
-                self.redraw.store(false, Ordering::Relaxed)
-                //super::CONSOLE.time_end("complete");
+                for y in rect.y() as usize..(rect.y() + rect.height()) as usize {
+                    let start = y * stride + left;
+                    window_data[start..start + width]
+                        .clone_from_slice(&color_data[start..start + width]);
+                }
             }
+
+            // CONSOLE.time_end("render");
+            self.redraw.store(false, Ordering::Relaxed)
+            //super::CONSOLE.time_end("complete");
         }
 
         self.window.sync();
@@ -2,8 +2,8 @@ use std::{collections::HashMap, sync::mpsc};
 
 use super::{Shell, Window};
 use crate::{
-    render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter, WindowRequest,
-    WindowSettings,
+    render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter, LifecycleEvent,
+    WindowRequest, WindowSettings,
 };
 
 /// The `WindowBuilder` is used to construct a window shell for the minifb backend.
@@ -18,7 +18,9 @@ where
     always_on_top: bool,
     borderless: bool,
     fonts: HashMap<String, &'static [u8]>,
+    font_fallbacks: Vec<String>,
     bounds: Rectangle,
+    scale_factor: Option<f64>,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
 }
 
@@ -36,7 +38,9 @@ where
             always_on_top: false,
             borderless: false,
             fonts: HashMap::new(),
+            font_fallbacks: vec![],
             bounds: Rectangle::new((0.0, 0.0), (100.0, 75.0)),
+            scale_factor: None,
             request_receiver: None,
         }
     }
@@ -51,7 +55,9 @@ where
             always_on_top: settings.always_on_top,
             borderless: settings.borderless,
             fonts: settings.fonts,
+            font_fallbacks: settings.font_fallbacks,
             bounds: Rectangle::new(settings.position, (settings.size.0, settings.size.1)),
+            scale_factor: settings.scale_factor,
             request_receiver: None,
         }
     }
@@ -86,12 +92,24 @@ where
         self
     }
 
+    /// Overrides the scale factor provided by the backend.
+    pub fn scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = Some(scale_factor);
+        self
+    }
+
     /// Registers a new font with family key.
     pub fn font(mut self, family: impl Into<String>, font_file: &'static [u8]) -> Self {
         self.fonts.insert(family.into(), font_file);
         self
     }
 
+    /// Appends a font family to the fallback chain, that is used for chars that are missing in the selected font.
+    pub fn font_fallback(mut self, family: impl Into<String>) -> Self {
+        self.font_fallbacks.push(family.into());
+        self
+    }
+
     /// Register a window request receiver to communicate with the window shell from outside.
     pub fn request_receiver(mut self, request_receiver: mpsc::Receiver<WindowRequest>) -> Self {
         self.request_receiver = Some(request_receiver);
@@ -100,7 +118,12 @@ where
 
     /// Builds the window shell and add it to the application `Shell`.
     pub fn build(self) {
-        let mut render_context = RenderContext2D::new(self.bounds.width(), self.bounds.height());
+        // orbclient does not provide the scale factor of the screen
+        let scale_factor = self.scale_factor.unwrap_or(1.0);
+        let width = self.bounds.width() * scale_factor;
+        let height = self.bounds.height() * scale_factor;
+
+        let mut render_context = RenderContext2D::new(width, height);
 
         let mut flags = vec![];
 
@@ -115,8 +138,8 @@ where
         let window = orbclient::Window::new_flags(
             self.bounds.x() as i32,
             self.bounds.y() as i32,
-            self.bounds.width() as u32,
-            self.bounds.height() as u32,
+            width as u32,
+            height as u32,
             self.title.as_str(),
             &flags,
         )
@@ -126,11 +149,17 @@ where
             render_context.register_font(&family, font);
         }
 
+        render_context.set_font_fallbacks(self.font_fallbacks);
+
+        self.shell
+            .push_lifecycle_event(LifecycleEvent::WindowCreated(self.title));
+
         self.shell.window_shells.push(Window::new(
             window,
             self.adapter,
             render_context,
             self.request_receiver,
+            scale_factor,
         ));
     }
 }
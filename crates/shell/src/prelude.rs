@@ -1,3 +1,4 @@
 pub use crate::{
-    event::*, platform::*, window_adapter::*, ShellRequest, WindowRequest, WindowSettings,
+    event::*, platform::*, window_adapter::*, LifecycleEvent, ShellRequest, WindowRequest,
+    WindowSettings,
 };
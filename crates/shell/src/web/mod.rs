@@ -1,6 +1,10 @@
 //! This module contains a platform specific implementation of the window shell.
 use std::sync::mpsc;
-use stdweb::web::window;
+use stdweb::{
+    js,
+    unstable::TryInto,
+    web::{window, CanvasRenderingContext2d},
+};
 
 use crate::prelude::*;
 
@@ -23,6 +27,39 @@ fn set_panic_hook() {
     console_error_panic_hook::set_once();
 }
 
+// Calculates the ratio between the physical pixels of the canvas and css pixels.
+fn device_pixel_ratio(ctx: &CanvasRenderingContext2d) -> f64 {
+    let device_pixel_ratio = window().device_pixel_ratio();
+
+    let backing_store_ratio = js! {
+        var ctx = @{ctx};
+         return ctx.webkitBackingStorePixelRatio ||
+             ctx.mozBackingStorePixelRatio ||
+             ctx.msBackingStorePixelRatio ||
+             ctx.oBackingStorePixelRatio ||
+             ctx.backingStorePixelRatio || 1;
+    };
+
+    js! {
+        return @{&device_pixel_ratio} / @{&backing_store_ratio};
+    }
+    .try_into()
+    .unwrap()
+}
+
+/// Gets the size of the screen, on the web it is the inner size of the browser window.
+pub fn screen_size() -> Option<(f64, f64)> {
+    Some((
+        window().inner_width() as f64,
+        window().inner_height() as f64,
+    ))
+}
+
+/// Returns `false`, the size of the canvas is managed by the browser window.
+pub fn can_resize_window() -> bool {
+    false
+}
+
 /// Initializes web stuff.
 pub fn initialize() {
     set_panic_hook();
@@ -36,6 +73,11 @@ where
 {
     window_shells: Vec<Window<A>>,
     requests: mpsc::Receiver<ShellRequest<A>>,
+    lifecycle_handler: Option<Box<dyn FnMut(LifecycleEvent)>>,
+    lifecycle_events: Vec<LifecycleEvent>,
+    quit_on_last_window_closed: bool,
+    running: bool,
+    quit: bool,
 }
 
 impl<A> Shell<A>
@@ -47,6 +89,37 @@ where
         Shell {
             window_shells: vec![],
             requests,
+            lifecycle_handler: None,
+            lifecycle_events: vec![],
+            quit_on_last_window_closed: true,
+            running: false,
+            quit: false,
+        }
+    }
+
+    /// Registers a handler that is called on each stage of the application life cycle.
+    pub fn lifecycle_handler<H: FnMut(LifecycleEvent) + 'static>(&mut self, handler: H) {
+        self.lifecycle_handler = Some(Box::new(handler));
+    }
+
+    /// Defines if the application shell quits after its last window is closed. Default is `true`.
+    pub fn quit_on_last_window_closed(&mut self, quit_on_last_window_closed: bool) {
+        self.quit_on_last_window_closed = quit_on_last_window_closed;
+    }
+
+    // Queues a life cycle event. Queued events are dispatched by the run loop.
+    fn push_lifecycle_event(&mut self, event: LifecycleEvent) {
+        self.lifecycle_events.push(event);
+    }
+
+    // Calls the life cycle handler with all queued events.
+    fn dispatch_lifecycle_events(&mut self) {
+        if let Some(handler) = &mut self.lifecycle_handler {
+            for event in self.lifecycle_events.drain(..) {
+                handler(event);
+            }
+        } else {
+            self.lifecycle_events.clear();
         }
     }
 
@@ -78,6 +151,7 @@ where
                         .request_receiver(window_requests)
                         .build();
                 }
+                ShellRequest::Quit => self.quit = true,
                 _ => {}
             }
         }
@@ -85,8 +159,17 @@ where
 
     /// Runs (starts) the application shell and its windows.
     pub fn run(mut self) {
+        if !self.running {
+            self.running = true;
+            self.lifecycle_events.insert(0, LifecycleEvent::Started);
+        }
+
         window().request_animation_frame(move |_| {
-            if self.window_shells.is_empty() {
+            self.dispatch_lifecycle_events();
+
+            if self.quit || (self.window_shells.is_empty() && self.quit_on_last_window_closed) {
+                self.push_lifecycle_event(LifecycleEvent::AboutToQuit);
+                self.dispatch_lifecycle_events();
                 return;
             }
 
@@ -105,6 +188,11 @@ where
 
                 if remove {
                     self.window_shells.remove(i);
+
+                    if self.window_shells.is_empty() {
+                        self.push_lifecycle_event(LifecycleEvent::AllWindowsClosed);
+                    }
+
                     break;
                 }
             }
@@ -7,7 +7,7 @@ use stdweb::{
     web::{document, event, html_element::CanvasElement, window, CanvasRenderingContext2d},
 };
 
-use super::EventState;
+use super::{device_pixel_ratio, EventState};
 use crate::{
     event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent},
     render::RenderContext2D,
@@ -32,6 +32,8 @@ where
     update: bool,
     redraw: bool,
     close: bool,
+    device_pixel_ratio: f64,
+    scale_factor: Option<f64>,
 }
 
 impl<A> Window<A>
@@ -44,8 +46,11 @@ where
         request_receiver: Option<mpsc::Receiver<WindowRequest>>,
         event_state: EventState,
         canvas: CanvasElement,
+        device_pixel_ratio: f64,
+        scale_factor: Option<f64>,
     ) -> Self {
         let mut adapter = adapter;
+        adapter.scale_factor_changed(scale_factor.unwrap_or(device_pixel_ratio));
 
         let web_handle = raw_window_handle::web::WebHandle {
             id: 1,
@@ -64,6 +69,8 @@ where
             update: true,
             redraw: true,
             close: false,
+            device_pixel_ratio,
+            scale_factor,
         }
     }
 }
@@ -86,27 +93,52 @@ impl<A> Window<A>
 where
     A: WindowAdapter,
 {
+    // Gets the scale factor of the window, it is the device pixel ratio if it is not overridden.
+    fn scale_factor(&self) -> f64 {
+        self.scale_factor.unwrap_or(self.device_pixel_ratio)
+    }
+
+    // Converts css pixels to logical units.
+    fn logical(&self, value: f64) -> f64 {
+        value * self.device_pixel_ratio / self.scale_factor()
+    }
+
     /// Check if the window is open.
     pub fn is_open(&self) -> bool {
         true
     }
 
-    /// Updates the clipboard.
+    /// Updates the clipboard. The text of the clipboard is written to the system clipboard of the browser.
+    /// Reading the system clipboard is not supported, the clipboard is read inside of the application.
     pub fn update_clipboard(&mut self) {
-        // todo
+        let mut clipboard_value = None;
+        self.adapter.clipboard_update(&mut clipboard_value);
+
+        if let Some(value) = clipboard_value {
+            js! {
+                if (navigator.clipboard) {
+                    navigator.clipboard.writeText(@{value});
+                }
+            }
+        }
     }
 
     /// Drain events and propagate the events to the adapter.
     pub fn drain_events(&mut self) {
         while let Some(event) = self.event_state.mouse_move_events.borrow_mut().pop() {
-            self.adapter
-                .mouse(event.client_x() as f64, event.client_y() as f64);
+            let x = self.logical(event.client_x() as f64);
+            let y = self.logical(event.client_y() as f64);
+            self.adapter.mouse(x, y);
             self.update = true;
         }
 
         while let Some(event) = self.event_state.mouse_down_events.borrow_mut().pop() {
+            let position = Point::new(
+                self.logical(event.client_x() as f64),
+                self.logical(event.client_y() as f64),
+            );
             self.adapter.mouse_event(MouseEvent {
-                position: Point::new(event.client_x() as f64, event.client_y() as f64),
+                position,
                 button: get_mouse_button(event.button()),
                 state: ButtonState::Down,
             });
@@ -114,8 +146,12 @@ where
         }
 
         while let Some(event) = self.event_state.mouse_up_events.borrow_mut().pop() {
+            let position = Point::new(
+                self.logical(event.client_x() as f64),
+                self.logical(event.client_y() as f64),
+            );
             self.adapter.mouse_event(MouseEvent {
-                position: Point::new(event.client_x() as f64, event.client_y() as f64),
+                position,
                 button: get_mouse_button(event.button()),
                 state: ButtonState::Up,
             });
@@ -129,11 +165,12 @@ where
 
         // todo tmp solution to map touch events to mouse vent
         while let Some(event) = self.event_state.touch_start_events.borrow_mut().pop() {
+            let position = Point::new(
+                self.logical(event.changed_touches()[0].client_x() as f64),
+                self.logical(event.changed_touches()[0].client_y() as f64),
+            );
             self.adapter.mouse_event(MouseEvent {
-                position: Point::new(
-                    event.changed_touches()[0].client_x() as f64,
-                    event.changed_touches()[0].client_y() as f64,
-                ),
+                position,
                 button: MouseButton::Left,
                 state: ButtonState::Down,
             });
@@ -141,11 +178,12 @@ where
         }
 
         while let Some(event) = self.event_state.touch_end_events.borrow_mut().pop() {
+            let position = Point::new(
+                self.logical(event.changed_touches()[0].client_x() as f64),
+                self.logical(event.changed_touches()[0].client_y() as f64),
+            );
             self.adapter.mouse_event(MouseEvent {
-                position: Point::new(
-                    event.changed_touches()[0].client_x() as f64,
-                    event.changed_touches()[0].client_y() as f64,
-                ),
+                position,
                 button: MouseButton::Left,
                 state: ButtonState::Up,
             });
@@ -154,10 +192,9 @@ where
         }
 
         while let Some(event) = self.event_state.touch_move_events.borrow_mut().pop() {
-            self.adapter.mouse(
-                event.changed_touches()[0].client_x() as f64,
-                event.changed_touches()[0].client_y() as f64,
-            );
+            let x = self.logical(event.changed_touches()[0].client_x() as f64);
+            let y = self.logical(event.changed_touches()[0].client_y() as f64);
+            self.adapter.mouse(x, y);
             self.update = true;
         }
 
@@ -197,9 +234,6 @@ where
                 .try_into()
                 .unwrap();
 
-            canvas.set_width(window_size.0 as u32);
-            canvas.set_height(window_size.1 as u32);
-
             js! {
                 document.body.style.padding = 0;
                 document.body.style.margin = 0;
@@ -207,40 +241,30 @@ where
                 @{&canvas}.style.margin = "0";
             }
 
-            let device_pixel_ratio = window().device_pixel_ratio();
             let ctx: CanvasRenderingContext2d = canvas.get_context().unwrap();
+            let ratio = device_pixel_ratio(&ctx);
 
-            let backing_store_ratio = js! {
-                var ctx = @{&ctx};
-                 return ctx.webkitBackingStorePixelRatio ||
-                     ctx.mozBackingStorePixelRatio ||
-                     ctx.msBackingStorePixelRatio ||
-                     ctx.oBackingStorePixelRatio ||
-                     ctx.backingStorePixelRatio || 1;
-            };
-
-            let ratio: f64 = js! {
-                return @{&device_pixel_ratio} / @{&backing_store_ratio};
+            // the canvas is rendered in physical pixels and shown in css pixels
+            canvas.set_width((window_size.0 * ratio) as u32);
+            canvas.set_height((window_size.1 * ratio) as u32);
+
+            js! {
+                @{&canvas}.style.width = @{window_size.0} + "px";
+                @{&canvas}.style.height = @{window_size.1} + "px";
             }
-            .try_into()
-            .unwrap();
-
-            if device_pixel_ratio != backing_store_ratio {
-                let old_width = canvas.width();
-                let old_height = canvas.height();
-                canvas.set_width((old_width as f64 * ratio) as u32);
-                canvas.set_height((old_height as f64 * ratio) as u32);
-
-                js! {
-                    @{&canvas}.style.width = @{&old_width} + "px";
-                    @{&canvas}.style.height = @{&old_height} + "px";
-                }
 
-                ctx.scale(ratio, ratio);
+            if (ratio - self.device_pixel_ratio).abs() > f64::EPSILON {
+                self.device_pixel_ratio = ratio;
+
+                if self.scale_factor.is_none() {
+                    self.adapter.scale_factor_changed(ratio);
+                }
             }
 
             self.render_context.set_canvas_render_context_2d(ctx);
-            self.adapter.resize(window_size.0, window_size.1);
+            let width = self.logical(window_size.0);
+            let height = self.logical(window_size.1);
+            self.adapter.resize(width, height);
             self.old_canvas = Some(self.canvas.clone());
             self.canvas = canvas;
             self.update = true;
@@ -261,6 +285,27 @@ where
                         self.update = true;
                         self.redraw = true;
                     }
+                    WindowRequest::ChangeScaleFactor(scale_factor) => {
+                        // the size of the canvas is kept, only its logical size changes
+                        self.scale_factor = Some(scale_factor);
+                        self.adapter.scale_factor_changed(scale_factor);
+                        self.adapter.resize(
+                            self.canvas.width() as f64 / scale_factor,
+                            self.canvas.height() as f64 / scale_factor,
+                        );
+                        self.update = true;
+                        self.redraw = true;
+                    }
+                    WindowRequest::Move { .. }
+                    | WindowRequest::Resize { .. }
+                    | WindowRequest::Minimize
+                    | WindowRequest::Maximize
+                    | WindowRequest::Restore => {
+                        // the canvas fills the browser window, it could not be moved or resized by the application
+                    }
+                    WindowRequest::Screenshot { .. } => {
+                        // there is no file system to save the screenshot to
+                    }
                     WindowRequest::Close => {
                         self.close = true;
                     }
@@ -275,6 +320,9 @@ where
             return;
         }
         self.adapter.run(&mut self.render_context);
+
+        // the widgets are drawn directly on the canvas, the redrawn regions are not needed
+        self.render_context.take_damage();
         self.update = false;
         self.redraw = true;
     }
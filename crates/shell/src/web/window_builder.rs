@@ -13,10 +13,10 @@ use stdweb::{
     web::{document, event, html_element::CanvasElement, window, CanvasRenderingContext2d},
 };
 
-use super::{EventState, Shell, Window};
+use super::{device_pixel_ratio, EventState, Shell, Window};
 use crate::{
-    render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter, WindowRequest,
-    WindowSettings,
+    render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter, LifecycleEvent,
+    WindowRequest, WindowSettings,
 };
 
 /// The `WindowBuilder` is used to construct a window shell for the web backend.
@@ -31,7 +31,9 @@ where
     always_on_top: bool,
     borderless: bool,
     fonts: HashMap<String, &'static [u8]>,
+    font_fallbacks: Vec<String>,
     bounds: Rectangle,
+    scale_factor: Option<f64>,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
 }
 
@@ -49,7 +51,9 @@ where
             always_on_top: false,
             borderless: false,
             fonts: HashMap::new(),
+            font_fallbacks: vec![],
             bounds: Rectangle::new((0.0, 0.0), (100.0, 75.0)),
+            scale_factor: None,
             request_receiver: None,
         }
     }
@@ -64,7 +68,9 @@ where
             always_on_top: settings.always_on_top,
             borderless: settings.borderless,
             fonts: settings.fonts,
+            font_fallbacks: settings.font_fallbacks,
             bounds: Rectangle::new(settings.position, (settings.size.0, settings.size.1)),
+            scale_factor: settings.scale_factor,
             request_receiver: None,
         }
     }
@@ -99,12 +105,24 @@ where
         self
     }
 
+    /// Overrides the scale factor provided by the backend.
+    pub fn scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = Some(scale_factor);
+        self
+    }
+
     /// Registers a new font with family key.
     pub fn font(mut self, family: impl Into<String>, font_file: &'static [u8]) -> Self {
         self.fonts.insert(family.into(), font_file);
         self
     }
 
+    /// Appends a font family to the fallback chain, that is used for chars that are missing in the selected font.
+    pub fn font_fallback(mut self, family: impl Into<String>) -> Self {
+        self.font_fallbacks.push(family.into());
+        self
+    }
+
     /// Register a window request receiver to communicate with the window shell from outside.
     pub fn request_receiver(mut self, request_receiver: mpsc::Receiver<WindowRequest>) -> Self {
         self.request_receiver = Some(request_receiver);
@@ -124,12 +142,6 @@ where
             window().inner_height() as f64,
         );
 
-        canvas.set_width(window_size.0 as u32);
-        canvas.set_height(window_size.1 as u32);
-
-        let adapter = &mut self.adapter;
-        adapter.resize(window_size.0, window_size.1);
-
         js! {
             document.body.style.padding = 0;
             document.body.style.margin = 0;
@@ -238,44 +250,34 @@ where
 
         document().body().unwrap().append_child(&canvas);
         let ctx: CanvasRenderingContext2d = canvas.get_context().unwrap();
+        let ratio = device_pixel_ratio(&ctx);
+        let scale_factor = self.scale_factor.unwrap_or(ratio);
 
-        let device_pixel_ratio = window().device_pixel_ratio();
+        // the canvas is rendered in physical pixels and shown in css pixels
+        canvas.set_width((window_size.0 * ratio) as u32);
+        canvas.set_height((window_size.1 * ratio) as u32);
 
-        let backing_store_ratio = js! {
-            var ctx = @{&ctx};
-             return ctx.webkitBackingStorePixelRatio ||
-                 ctx.mozBackingStorePixelRatio ||
-                 ctx.msBackingStorePixelRatio ||
-                 ctx.oBackingStorePixelRatio ||
-                 ctx.backingStorePixelRatio || 1;
-        };
-
-        let ratio: f64 = js! {
-            return @{&device_pixel_ratio} / @{&backing_store_ratio};
+        js! {
+            @{&canvas}.style.width = @{window_size.0} + "px";
+            @{&canvas}.style.height = @{window_size.1} + "px";
         }
-        .try_into()
-        .unwrap();
-
-        if device_pixel_ratio != backing_store_ratio {
-            let old_width = canvas.width();
-            let old_height = canvas.height();
-            canvas.set_width((old_width as f64 * ratio) as u32);
-            canvas.set_height((old_height as f64 * ratio) as u32);
-
-            js! {
-                @{&canvas}.style.width = @{&old_width} + "px";
-                @{&canvas}.style.height = @{&old_height} + "px";
-            }
 
-            ctx.scale(ratio, ratio);
-        }
+        let adapter = &mut self.adapter;
+        adapter.resize(
+            window_size.0 * ratio / scale_factor,
+            window_size.1 * ratio / scale_factor,
+        );
 
-        let render_context = RenderContext2D::from_context(canvas.get_context().unwrap());
+        let mut render_context = RenderContext2D::from_context(canvas.get_context().unwrap());
+        render_context.set_font_fallbacks(self.font_fallbacks);
 
         document().set_title(self.title.as_str());
 
         stdweb::event_loop();
 
+        self.shell
+            .push_lifecycle_event(LifecycleEvent::WindowCreated(self.title));
+
         self.shell.window_shells.push(Window::new(
             self.adapter,
             render_context,
@@ -293,6 +295,8 @@ where
                 resize_events: resize,
             },
             canvas,
+            ratio,
+            self.scale_factor,
         ));
     }
 }
@@ -1,20 +1,32 @@
 //! This module contains traits to inject custom logic into the window shell.
 
-use crate::render::RenderContext2D;
+use crate::render::{RenderBackend, RenderContext2D};
 use crate::{event::*, utils::Point};
 
 /// The `WindowAdapter` represents the bridge to the `Shell` backend.
 /// It receives events from the `Window` and runs it's own logic.  
-pub trait WindowAdapter {
+///
+/// The adapter is generic over the render context it draws on. The shells of OrbTk run it with the
+/// `RenderContext2D` of the platform, other rasterizers or test doubles could run it with their own `RenderBackend`.
+pub trait WindowAdapter<R: RenderBackend = RenderContext2D> {
     /// Sets raw window handle.
     fn set_raw_window_handle(&mut self, raw_window_handle: raw_window_handle::RawWindowHandle);
 
-    /// Used to update the clipboard, could be used to read and set the current clipboard value.
+    /// Used to update the clipboard, could be used to read and set the current clipboard value. If the backend has read
+    /// the system clipboard, `value` contains its text. After the call `value` contains the text that should be written to the
+    /// system clipboard or `None` if the system clipboard is not changed.
     fn clipboard_update(&mut self, value: &mut Option<String>);
 
-    /// Is called after the window is resized.
+    /// Is called after the window is resized. The size is given in logical units.
     fn resize(&mut self, _width: f64, _height: f64) {}
 
+    /// Is called after the window is moved. The position is given in screen coordinates.
+    fn moved(&mut self, _x: f64, _y: f64) {}
+
+    /// Is called if the scale factor of the window is changed. The scale factor describes the ratio
+    /// between physical pixels and logical units.
+    fn scale_factor_changed(&mut self, _scale_factor: f64) {}
+
     /// Is called after the mouse was moved.
     fn mouse(&mut self, _x: f64, _y: f64) {}
 
@@ -30,9 +42,14 @@ pub trait WindowAdapter {
     /// Is called when the keyboard emits an text input.
     fn text_input(&mut self, _text: String) {}
 
-    /// Is called after the quit event of the window is called.
+    /// Is called if closing the window is requested e.g. by the close button of the window decoration.
+    /// The window will be closed after the adapter sends a `WindowRequest::Close`.
     fn quit_event(&mut self) {}
 
+    /// Is called if a screenshot of the window is requested. It renders the content of the window with the given
+    /// scale and saves it as PNG to the given path.
+    fn screenshot(&mut self, _path: &str, _scale: f64) {}
+
     /// Gets the current mouse position.
     fn mouse_position(&self) -> Point;
 
@@ -46,5 +63,5 @@ pub trait WindowAdapter {
     fn text_drop_event(&mut self, text: String);
 
     /// Runs the inner logic of the shell adapter.
-    fn run(&mut self, render_context: &mut RenderContext2D);
+    fn run(&mut self, render_context: &mut R);
 }
@@ -12,6 +12,8 @@ use crate::{
 pub struct Theme {
     styles: HashMap<String, Style>,
     fonts: HashMap<String, &'static [u8]>,
+    font_fallbacks: Vec<String>,
+    svgs: HashMap<String, &'static [u8]>,
 }
 
 impl Theme {
@@ -26,6 +28,8 @@ impl Theme {
         Theme {
             styles,
             fonts: HashMap::new(),
+            font_fallbacks: vec![],
+            svgs: HashMap::new(),
         }
     }
 
@@ -40,6 +44,29 @@ impl Theme {
         &self.fonts
     }
 
+    /// Appends a font family to the fallback chain. The fallback fonts are used in the given order for chars,
+    /// that are missing in the selected font.
+    pub fn register_font_fallback(mut self, key: &str) -> Self {
+        self.font_fallbacks.push(key.to_string());
+        self
+    }
+
+    /// Returns the chain of fallback font families.
+    pub fn font_fallbacks(&self) -> &[String] {
+        &self.font_fallbacks
+    }
+
+    /// Registers a new SVG document as binary. It could be used as icon by its key.
+    pub fn register_svg(mut self, key: &str, svg: &'static [u8]) -> Self {
+        self.svgs.insert(key.to_string(), svg);
+        self
+    }
+
+    /// Returns the map of registered SVG documents.
+    pub fn svgs(&self) -> &HashMap<String, &'static [u8]> {
+        &self.svgs
+    }
+
     /// Returns a reference to the style corresponding to the key.
     pub fn style(&self, key: &str) -> Option<&Style> {
         self.styles.get(key)
@@ -134,6 +161,15 @@ impl Theme {
 
     // if the property value is a place holder replace it with the corresponding value of the resources
     fn read_value(property_value: &Value, resources: &HashMap<String, Value>) -> Value {
+        // the values of a map, e.g. the brushes of the border sides, could be place holders too
+        if let Value::Map(map) = property_value {
+            return Value::Map(
+                map.iter()
+                    .map(|(key, value)| (key.clone(), Theme::read_value(value, resources)))
+                    .collect(),
+            );
+        }
+
         if let Ok(value) = property_value.clone().into_rust::<String>() {
             if let Some(replace_value) = resources.get(&value.replace(RESOURCE_KEY, "")) {
                 return replace_value.clone();
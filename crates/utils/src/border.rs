@@ -1,11 +1,13 @@
-use super::{Brush, Thickness};
+use super::{BorderBrushes, BorderStyle, Brush, CornerRadius, Thickness};
 
 /// Used to build a border, specifying additional details.
 #[derive(Default)]
 pub struct BorderBuilder {
     brush: Brush,
+    brushes: BorderBrushes,
     thickness: Thickness,
-    radius: f64,
+    radius: CornerRadius,
+    style: BorderStyle,
 }
 
 impl BorderBuilder {
@@ -20,6 +22,12 @@ impl BorderBuilder {
         self
     }
 
+    /// Inserts the brushes of the single sides.
+    pub fn brushes<B: Into<BorderBrushes>>(mut self, brushes: B) -> Self {
+        self.brushes = brushes.into();
+        self
+    }
+
     /// Inserts a border thickness.
     pub fn thickness(mut self, thickness: Thickness) -> Self {
         self.thickness = thickness;
@@ -27,8 +35,14 @@ impl BorderBuilder {
     }
 
     /// Inserts a border radius.
-    pub fn radius(mut self, radius: f64) -> Self {
-        self.radius = radius;
+    pub fn radius<R: Into<CornerRadius>>(mut self, radius: R) -> Self {
+        self.radius = radius.into();
+        self
+    }
+
+    /// Inserts a border style.
+    pub fn style(mut self, style: BorderStyle) -> Self {
+        self.style = style;
         self
     }
 
@@ -36,18 +50,23 @@ impl BorderBuilder {
     pub fn build(self) -> Border {
         Border {
             brush: self.brush,
+            brushes: self.brushes,
             thickness: self.thickness,
             radius: self.radius,
+            style: self.style,
         }
     }
 }
 
-/// Describes a border of a shape with border `brush`, `thickness` and `radius`.
+/// Describes a border of a shape with border `brush`, `thickness`, `radius` and `style`. The sides could have their
+/// own `brushes` and the corners their own radius.
 #[derive(Clone, Default, Debug, PartialEq)]
 pub struct Border {
     brush: Brush,
+    brushes: BorderBrushes,
     thickness: Thickness,
-    radius: f64,
+    radius: CornerRadius,
+    style: BorderStyle,
 }
 
 impl Border {
@@ -66,6 +85,16 @@ impl Border {
         self.brush = brush.into();
     }
 
+    /// Gets the brushes of the single sides.
+    pub fn brushes(&self) -> &BorderBrushes {
+        &self.brushes
+    }
+
+    /// Sets the brushes of the single sides.
+    pub fn set_brushes<B: Into<BorderBrushes>>(&mut self, brushes: B) {
+        self.brushes = brushes.into();
+    }
+
     /// Gets the thickness.
     pub fn thickness(&self) -> Thickness {
         self.thickness
@@ -77,13 +106,23 @@ impl Border {
     }
 
     /// Gets the radius.
-    pub fn radius(&self) -> f64 {
+    pub fn radius(&self) -> CornerRadius {
         self.radius
     }
 
     /// Sets the radius.
-    pub fn set_radius(&mut self, radius: f64) {
-        self.radius = radius
+    pub fn set_radius<R: Into<CornerRadius>>(&mut self, radius: R) {
+        self.radius = radius.into()
+    }
+
+    /// Gets the style.
+    pub fn style(&self) -> BorderStyle {
+        self.style
+    }
+
+    /// Sets the style.
+    pub fn set_style(&mut self, style: BorderStyle) {
+        self.style = style;
     }
 }
 
@@ -102,10 +141,10 @@ pub trait Bordered {
     fn set_border_brush(&mut self, brush: Brush);
 
     /// Gets the border radius.
-    fn border_radius(&self) -> f64;
+    fn border_radius(&self) -> CornerRadius;
 
     /// Sets the border radius.
-    fn set_border_radius(&mut self, radius: f64);
+    fn set_border_radius(&mut self, radius: CornerRadius);
 
     /// Gets the complete border.
     fn border(&self) -> &Border;
@@ -144,7 +183,7 @@ mod tests {
 
         let builder = BorderBuilder::new();
         let border = builder.radius(radius).build();
-        assert_eq!(border.radius(), radius);
+        assert_eq!(border.radius(), CornerRadius::from(radius));
     }
 
     #[test]
@@ -173,6 +212,18 @@ mod tests {
 
         let mut border = Border::default();
         border.set_radius(radius);
-        assert_eq!(border.radius(), radius);
+        assert_eq!(border.radius(), CornerRadius::from(radius));
+    }
+
+    #[test]
+    fn test_corner_radius() {
+        let border = BorderBuilder::new()
+            .radius((4.0, 4.0, 0.0, 0.0))
+            .style(BorderStyle::Dashed)
+            .build();
+
+        assert_eq!(border.radius().top_right(), 4.0);
+        assert_eq!(border.radius().bottom_left(), 0.0);
+        assert_eq!(border.style(), BorderStyle::Dashed);
     }
 }
@@ -0,0 +1,117 @@
+use crate::{Brush, Value};
+
+/// Describes the brushes of the four sides of a border. A side without brush is drawn with the common border brush.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct BorderBrushes {
+    /// Brush of the left side.
+    pub left: Option<Brush>,
+
+    /// Brush of the top side.
+    pub top: Option<Brush>,
+
+    /// Brush of the right side.
+    pub right: Option<Brush>,
+
+    /// Brush of the bottom side.
+    pub bottom: Option<Brush>,
+}
+
+impl BorderBrushes {
+    /// Create new border brushes with the given parameters.
+    pub fn new(
+        left: impl Into<Option<Brush>>,
+        top: impl Into<Option<Brush>>,
+        right: impl Into<Option<Brush>>,
+        bottom: impl Into<Option<Brush>>,
+    ) -> Self {
+        BorderBrushes {
+            left: left.into(),
+            top: top.into(),
+            right: right.into(),
+            bottom: bottom.into(),
+        }
+    }
+
+    /// Returns `true` if no side has its own brush.
+    pub fn is_empty(&self) -> bool {
+        self.left.is_none() && self.top.is_none() && self.right.is_none() && self.bottom.is_none()
+    }
+
+    /// Gets the brushes of the sides in the order left, top, right and bottom. Sides without brush get the given
+    /// brush.
+    pub fn resolve(&self, brush: &Brush) -> [Brush; 4] {
+        let resolve = |side: &Option<Brush>| side.clone().unwrap_or_else(|| brush.clone());
+
+        [
+            resolve(&self.left),
+            resolve(&self.top),
+            resolve(&self.right),
+            resolve(&self.bottom),
+        ]
+    }
+}
+
+// --- Conversions ---
+
+impl From<Brush> for BorderBrushes {
+    fn from(brush: Brush) -> Self {
+        BorderBrushes::new(brush.clone(), brush.clone(), brush.clone(), brush)
+    }
+}
+
+impl From<&str> for BorderBrushes {
+    fn from(s: &str) -> Self {
+        BorderBrushes::from(Brush::from(s))
+    }
+}
+
+impl From<Value> for BorderBrushes {
+    fn from(v: Value) -> Self {
+        match v.0 {
+            ron::Value::String(value) => BorderBrushes::from(value.as_str()),
+            ron::Value::Map(map) => {
+                let mut border_brushes = BorderBrushes::default();
+
+                for (key, value) in map.iter() {
+                    if let (Ok(key), Ok(value)) = (
+                        key.clone().into_rust::<String>(),
+                        value.clone().into_rust::<String>(),
+                    ) {
+                        let brush = Some(Brush::from(value));
+
+                        match key.as_str() {
+                            "left" => border_brushes.left = brush,
+                            "top" => border_brushes.top = brush,
+                            "right" => border_brushes.right = brush,
+                            "bottom" => border_brushes.bottom = brush,
+                            _ => {}
+                        }
+                    }
+                }
+
+                border_brushes
+            }
+            _ => BorderBrushes::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_from_value() {
+        let value: ron::Value = ron::from_str("{ \"bottom\": \"#ff0000\" }").unwrap();
+        let border_brushes = BorderBrushes::from(Value(value));
+
+        assert_eq!(border_brushes.bottom, Some(Brush::from("#ff0000")));
+        assert!(border_brushes.top.is_none());
+
+        let brush = Brush::from("#000000");
+        assert_eq!(
+            border_brushes.resolve(&brush),
+            [brush.clone(), brush.clone(), brush, Brush::from("#ff0000")]
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use crate::Value;
+
+/// Describes how the line of a border is drawn.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum BorderStyle {
+    /// The border is drawn as solid line.
+    #[default]
+    Solid,
+
+    /// The border is drawn as line of dashes.
+    Dashed,
+
+    /// The border is drawn as line of round dots.
+    Dotted,
+}
+
+impl BorderStyle {
+    /// Gets the dash pattern of the style for the given border width. A solid border has an empty pattern.
+    pub fn dash_pattern(self, width: f64) -> Vec<f64> {
+        match self {
+            BorderStyle::Solid => vec![],
+            BorderStyle::Dashed => vec![width * 3.0, width * 2.0],
+            // the dots are drawn by the round line caps of the empty dashes
+            BorderStyle::Dotted => vec![0.0, width * 2.0],
+        }
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for BorderStyle {
+    fn from(t: &str) -> Self {
+        match t {
+            "Dashed" | "dashed" => BorderStyle::Dashed,
+            "Dotted" | "dotted" => BorderStyle::Dotted,
+            _ => BorderStyle::Solid,
+        }
+    }
+}
+
+impl From<Value> for BorderStyle {
+    fn from(v: Value) -> Self {
+        BorderStyle::from(v.get::<String>().as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let border_style: BorderStyle = "Dashed".into();
+        assert_eq!(border_style, BorderStyle::Dashed);
+
+        let border_style: BorderStyle = "dotted".into();
+        assert_eq!(border_style, BorderStyle::Dotted);
+
+        let border_style: BorderStyle = "other".into();
+        assert_eq!(border_style, BorderStyle::Solid);
+    }
+}
@@ -0,0 +1,323 @@
+use crate::{expression::parse_expression_with_complex, prelude::*};
+
+/// Describes one layer of a `BoxShadow`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Shadow {
+    /// Horizontal and vertical offset of the shadow.
+    pub offset: Point,
+
+    /// Blur radius of the shadow. A radius of zero draws a sharp shadow.
+    pub blur: f64,
+
+    /// Expands (positive) or shrinks (negative) the shadow.
+    pub spread: f64,
+
+    /// Color of the shadow.
+    pub color: Color,
+
+    /// If `true` the shadow is drawn inside of the shape, otherwise around of it.
+    pub inset: bool,
+}
+
+impl Shadow {
+    /// Creates a new shadow with the given offset, blur radius and color.
+    pub fn new(offset: impl Into<Point>, blur: f64, color: impl Into<Color>) -> Self {
+        Shadow {
+            offset: offset.into(),
+            blur,
+            color: color.into(),
+            ..Shadow::default()
+        }
+    }
+
+    /// Sets the spread of the shadow.
+    pub fn spread(mut self, spread: f64) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Draws the shadow inside of the shape.
+    pub fn inset(mut self) -> Self {
+        self.inset = true;
+        self
+    }
+
+    /// Gets the radius of the box blur in physical pixels, that draws the shadow at the given scale factor.
+    pub fn blur_radius(&self, scale_factor: f64) -> usize {
+        (self.blur.max(0.0) * scale_factor / 2.0).round() as usize
+    }
+
+    /// Gets the distance in logical units the blur spreads the shadow beyond its shape at the given scale factor.
+    pub fn blur_extent(&self, scale_factor: f64) -> f64 {
+        // three box blurs spread the shadow by three times of the blur radius
+        (3 * self.blur_radius(scale_factor) + 1) as f64 / scale_factor
+    }
+
+    /// Gets the shape of the shadow of a rectangle with the given corner radii in the order top left, top right,
+    /// bottom right and bottom left. The shape is moved by the offset and grown by the spread, an inset shadow is
+    /// shrunk. Its rounded corners grow and shrink with the shape.
+    pub fn shape(&self, rect: Rectangle, radii: [f64; 4]) -> (Rectangle, [f64; 4]) {
+        let spread = if self.inset {
+            -self.spread
+        } else {
+            self.spread
+        };
+
+        let shape = Rectangle::new(
+            (
+                rect.x() + self.offset.x() - spread,
+                rect.y() + self.offset.y() - spread,
+            ),
+            (
+                (rect.width() + 2.0 * spread).max(0.0),
+                (rect.height() + 2.0 * spread).max(0.0),
+            ),
+        );
+
+        let mut shape_radii = [0.0; 4];
+        for (shape_radius, radius) in shape_radii.iter_mut().zip(radii.iter()) {
+            if *radius > 0.0 {
+                *shape_radius = (radius + spread).max(0.0);
+            }
+        }
+
+        (shape, shape_radii)
+    }
+
+    // Reads a shadow definition e.g. `inset 0px 2px 4px 1px rgba(0, 0, 0, 0.4)`.
+    fn from_expression(expression: &Expression) -> Option<Shadow> {
+        let tokens = match expression {
+            Expression::Complex(v) => &v[..],
+            _ => std::slice::from_ref(expression),
+        };
+
+        let mut shadow = Shadow::default();
+        let mut lengths = vec![];
+        let mut color = None;
+
+        for token in tokens {
+            match token {
+                Expression::Other(keyword) if keyword == "inset" => shadow.inset = true,
+                Expression::Number(n, u) if u.is_empty() || u == "px" => {
+                    let length: f64 = (*n).into();
+                    lengths.push(length);
+                }
+                _ => color = Some(token.color()?),
+            }
+        }
+
+        match lengths[..] {
+            [x, y] => shadow.offset = Point::new(x, y),
+            [x, y, blur] => {
+                shadow.offset = Point::new(x, y);
+                shadow.blur = blur;
+            }
+            [x, y, blur, spread] => {
+                shadow.offset = Point::new(x, y);
+                shadow.blur = blur;
+                shadow.spread = spread;
+            }
+            _ => return None,
+        }
+
+        if let Some(color) = color {
+            shadow.color = color;
+        }
+
+        Some(shadow)
+    }
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Shadow {
+            offset: Point::default(),
+            blur: 0.0,
+            spread: 0.0,
+            color: Color::rgba(0, 0, 0, 128),
+            inset: false,
+        }
+    }
+}
+
+/// A `BoxShadow` describes the shadows of a widget and is used to express elevation. It could consist of multiple
+/// layers. The first layer is drawn on top.
+///
+/// The string declaration follows the css `box-shadow` syntax. Each layer is defined by its offset, an optional blur radius,
+/// an optional spread and an optional color. Lengths are given in pixels. Layers are separated by commas.
+///
+/// ```text
+/// [inset] {X offset}[px] {Y offset}[px] [{Blur radius}[px] [{Spread}[px]]] [{Color}]
+/// ```
+///
+/// # Examples
+///
+/// ```text
+/// .box_shadow("0 2 4 rgba(0, 0, 0, 0.4)")
+/// .box_shadow("0px 1px 2px #33000000, 0px 4px 8px 2px #1A000000")
+/// .box_shadow("inset 0 0 4 black")
+/// ```
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct BoxShadow {
+    shadows: Vec<Shadow>,
+}
+
+impl BoxShadow {
+    /// Creates a box shadow from the given layers.
+    pub fn new(shadows: Vec<Shadow>) -> Self {
+        BoxShadow { shadows }
+    }
+
+    /// Gets the layers of the box shadow.
+    pub fn shadows(&self) -> &[Shadow] {
+        &self.shadows
+    }
+
+    /// Gets the rectangle that is covered by a shape with the given bounds and its outer shadows, if they are drawn
+    /// at the given scale factor.
+    pub fn bounds(&self, rect: Rectangle, scale_factor: f64) -> Rectangle {
+        let mut bounds = rect;
+
+        for shadow in self.shadows.iter().filter(|s| !s.inset) {
+            let extent = (shadow.blur_extent(scale_factor) + shadow.spread).max(0.0);
+            bounds.join_with_rectangle(&Rectangle::new(
+                (
+                    rect.x() + shadow.offset.x() - extent,
+                    rect.y() + shadow.offset.y() - extent,
+                ),
+                (rect.width() + 2.0 * extent, rect.height() + 2.0 * extent),
+            ));
+        }
+
+        bounds
+    }
+
+    /// Returns `true` if the box shadow has no visible layer.
+    pub fn is_empty(&self) -> bool {
+        self.shadows.iter().all(|s| s.color.a() == 0)
+    }
+}
+
+impl From<Shadow> for BoxShadow {
+    fn from(shadow: Shadow) -> Self {
+        BoxShadow::new(vec![shadow])
+    }
+}
+
+impl From<Vec<Shadow>> for BoxShadow {
+    fn from(shadows: Vec<Shadow>) -> Self {
+        BoxShadow::new(shadows)
+    }
+}
+
+impl From<&str> for BoxShadow {
+    fn from(s: &str) -> Self {
+        let mut shadows = vec![];
+        let mut chars = s.chars().peekable();
+
+        while chars.peek().is_some() {
+            if let Some(expression) = parse_expression_with_complex(&mut chars) {
+                match Shadow::from_expression(&expression) {
+                    Some(shadow) => shadows.push(shadow),
+                    None => return BoxShadow::default(),
+                }
+            }
+
+            // skip the separator of the layers
+            chars.next();
+        }
+
+        BoxShadow::new(shadows)
+    }
+}
+
+impl From<String> for BoxShadow {
+    fn from(s: String) -> Self {
+        Self::from(&s[..])
+    }
+}
+
+impl From<Value> for BoxShadow {
+    fn from(v: Value) -> Self {
+        let value = v.get::<String>();
+        BoxShadow::from(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let box_shadow = BoxShadow::from("0 2px 4 rgba(0, 0, 0, 0.4)");
+        assert_eq!(
+            box_shadow.shadows(),
+            &[Shadow::new((0.0, 2.0), 4.0, Color::rgba(0, 0, 0, 102))]
+        );
+
+        let box_shadow = BoxShadow::from("inset 1 2 3 4 red, 5 6");
+        assert_eq!(
+            box_shadow.shadows(),
+            &[
+                Shadow::new((1.0, 2.0), 3.0, Color::rgb(255, 0, 0))
+                    .spread(4.0)
+                    .inset(),
+                Shadow::new((5.0, 6.0), 0.0, Shadow::default().color),
+            ]
+        );
+
+        assert!(BoxShadow::from("2px").shadows().is_empty());
+        assert!(BoxShadow::from("1 2 3 4 5").shadows().is_empty());
+        assert!(BoxShadow::from("").is_empty());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let rect = Rectangle::new((10.0, 10.0), (20.0, 20.0));
+        assert_eq!(BoxShadow::default().bounds(rect, 1.0), rect);
+
+        // inset shadows are painted inside of the shape
+        let box_shadow = BoxShadow::from("0 4 2 1, inset 0 0 8");
+        assert_eq!(
+            box_shadow.bounds(rect, 1.0),
+            Rectangle::new((5.0, 9.0), (30.0, 30.0))
+        );
+        assert_eq!(
+            box_shadow.bounds(rect, 2.0),
+            Rectangle::new((5.5, 9.5), (29.0, 29.0))
+        );
+    }
+
+    #[test]
+    fn test_shape() {
+        let rect = Rectangle::new((10.0, 10.0), (20.0, 20.0));
+
+        let shadow = Shadow::new((1.0, 2.0), 0.0, Color::rgb(0, 0, 0)).spread(2.0);
+        assert_eq!(
+            shadow.shape(rect, [4.0, 0.0, 1.0, 8.0]),
+            (
+                Rectangle::new((9.0, 10.0), (24.0, 24.0)),
+                [6.0, 0.0, 3.0, 10.0]
+            )
+        );
+
+        assert_eq!(
+            shadow.inset().shape(rect, [4.0, 0.0, 1.0, 8.0]),
+            (
+                Rectangle::new((13.0, 14.0), (16.0, 16.0)),
+                [2.0, 0.0, 0.0, 6.0]
+            )
+        );
+    }
+
+    #[test]
+    fn test_blur_extent() {
+        let shadow = Shadow::new(0.0, 4.0, Color::rgb(0, 0, 0));
+        assert_eq!(shadow.blur_radius(1.0), 2);
+        assert_eq!(shadow.blur_extent(1.0), 7.0);
+        assert_eq!(shadow.blur_radius(2.0), 4);
+        assert_eq!(shadow.blur_extent(2.0), 6.5);
+        assert_eq!(Shadow::default().blur_extent(1.0), 1.0);
+    }
+}
@@ -8,13 +8,14 @@ use crate::prelude::*;
 /// * gradients of colors
 /// * gradients with directions
 /// * gradients with angles
+/// * radial and conic gradients
 ///
 /// The string declaration of a `Brush` is composed combining the following
 /// syntax elements:
 ///
 /// 1. The `color name`
 /// 2. The `gradient` string
-///    * the gradient type (linear, repeating-linear)
+///    * the gradient type (linear, repeating-linear, radial, repeating-radial, conic, repeating-conic)
 ///    * gradient attributes (direction-identifier, angles, color names )
 ///
 /// ## Examples
@@ -27,6 +28,8 @@ use crate::prelude::*;
 /// .background("repeating-linear-gradient(0.25turn, rgba(255, 255, 0, 0.6), dodgerblue, deepskyblue)")
 /// .background("linear-gradient(-90deg, hsv(201, 94%, 80.5%), steelblue)")
 /// .background("linear-gradient(to top right, white, skyblue 60%, lightskyblue 80%, yellow 83%, yellow)")
+/// .background("radial-gradient(circle closest-side at 25% 50%, white, steelblue)")
+/// .background("conic-gradient(from 90deg at center, red, yellow 0.5turn, red)")
 /// ```
 /// Read on to see how the syntax is composed.
 ///
@@ -112,17 +115,31 @@ use crate::prelude::*;
 /// Lets look at some examples. The first one shows the
 /// structure of an angled gradient
 ///
-/// ```
+/// ```text
 /// [repeating-]linear-gradient({Gradient-angle}{deg|rad|turn}, ...) [{X Displacement}px {Y Displacement}px], {Color} [{Stop  position}{%|px}]
 /// ```
 ///
 /// The next example shows the structure of a gradient that will be
 /// rendered in a given direction
 ///
-/// ```
+/// ```text
 /// [repeating-]linear-gradient({direction-identifier}, {initial color-name}, {terminating color-name}
 /// ```
 ///
+/// A radial gradient starts at its center and ends on a circle or an ellipse. Its size is defined
+/// either by radiuses or by an extent keyword (`closest-side`, `closest-corner`, `farthest-side`, `farthest-corner`).
+/// The web backend draws an ellipse as a circle with the larger of both radiuses.
+///
+/// ```text
+/// [repeating-]radial-gradient([circle|ellipse] [{extent}|{Radius}{%|px} [{Radius}{%|px}]] [at {Position}], {Color} [{Stop position}{%|px}], ...)
+/// ```
+///
+/// A conic gradient rotates its colors around the center. The stop positions could also be given as angles.
+///
+/// ```text
+/// [repeating-]conic-gradient([from {Angle}{deg|rad|turn}] [at {Position}], {Color} [{Stop position}{%|deg|rad|turn}], ...)
+/// ```
+///
 //#[cfg(feature = "nightly")]
 //#[doc(include = "../colors.md")]
 
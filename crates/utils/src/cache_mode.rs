@@ -0,0 +1,39 @@
+/// Describes how the subtree of a widget is cached between render passes.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum CacheMode {
+    /// The subtree is drawn in each render pass.
+    #[default]
+    None,
+
+    /// The subtree is drawn in an offscreen layer that is reused until the widget or one of its children changes.
+    /// The children are clipped to the bounds of the widget.
+    Layer,
+}
+
+// --- Conversions ---
+
+impl From<&str> for CacheMode {
+    fn from(t: &str) -> Self {
+        match t {
+            "Layer" | "layer" => CacheMode::Layer,
+            _ => CacheMode::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let cache_mode: CacheMode = "layer".into();
+        assert_eq!(cache_mode, CacheMode::Layer);
+
+        let cache_mode: CacheMode = "Layer".into();
+        assert_eq!(cache_mode, CacheMode::Layer);
+
+        let cache_mode: CacheMode = "other".into();
+        assert_eq!(cache_mode, CacheMode::None);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::Value;
+
+/// Describes the radii of the four corners of a rectangle e.g. a border radius.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct CornerRadius {
+    /// Radius of the top left corner.
+    pub top_left: f64,
+
+    /// Radius of the top right corner.
+    pub top_right: f64,
+
+    /// Radius of the bottom right corner.
+    pub bottom_right: f64,
+
+    /// Radius of the bottom left corner.
+    pub bottom_left: f64,
+}
+
+impl CornerRadius {
+    /// Create a new corner radius with the given parameters.
+    pub fn new(top_left: f64, top_right: f64, bottom_right: f64, bottom_left: f64) -> Self {
+        CornerRadius {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    /// Gets top left.
+    pub fn top_left(&self) -> f64 {
+        self.top_left
+    }
+
+    /// Sets top left.
+    pub fn set_top_left(&mut self, top_left: f64) {
+        self.top_left = top_left;
+    }
+
+    /// Gets top right.
+    pub fn top_right(&self) -> f64 {
+        self.top_right
+    }
+
+    /// Sets top right.
+    pub fn set_top_right(&mut self, top_right: f64) {
+        self.top_right = top_right;
+    }
+
+    /// Gets bottom right.
+    pub fn bottom_right(&self) -> f64 {
+        self.bottom_right
+    }
+
+    /// Sets bottom right.
+    pub fn set_bottom_right(&mut self, bottom_right: f64) {
+        self.bottom_right = bottom_right;
+    }
+
+    /// Gets bottom left.
+    pub fn bottom_left(&self) -> f64 {
+        self.bottom_left
+    }
+
+    /// Sets bottom left.
+    pub fn set_bottom_left(&mut self, bottom_left: f64) {
+        self.bottom_left = bottom_left;
+    }
+
+    /// Gets the largest radius of all corners.
+    pub fn max(&self) -> f64 {
+        self.top_left
+            .max(self.top_right)
+            .max(self.bottom_right)
+            .max(self.bottom_left)
+    }
+
+    /// Returns `true` if all corners have the same radius.
+    pub fn is_uniform(&self) -> bool {
+        self.top_left == self.top_right
+            && self.top_left == self.bottom_right
+            && self.top_left == self.bottom_left
+    }
+
+    /// Returns `true` if no corner is rounded.
+    pub fn is_zero(&self) -> bool {
+        self.max() <= 0.0
+    }
+
+    /// Gets the radii that fit into a rectangle with the given size. Negative radii are set to zero. If the radii of
+    /// a side are larger than the side, all radii are reduced by the same factor.
+    pub fn fit(&self, width: f64, height: f64) -> CornerRadius {
+        let r = CornerRadius::new(
+            self.top_left.max(0.0),
+            self.top_right.max(0.0),
+            self.bottom_right.max(0.0),
+            self.bottom_left.max(0.0),
+        );
+
+        let factor = [
+            width.abs() / (r.top_left + r.top_right),
+            height.abs() / (r.top_right + r.bottom_right),
+            width.abs() / (r.bottom_right + r.bottom_left),
+            height.abs() / (r.bottom_left + r.top_left),
+        ]
+        .iter()
+        .filter(|f| f.is_finite())
+        .fold(1.0_f64, |min, f| min.min(*f));
+
+        CornerRadius::new(
+            r.top_left * factor,
+            r.top_right * factor,
+            r.bottom_right * factor,
+            r.bottom_left * factor,
+        )
+    }
+
+    /// Gets the radii in the order top left, top right, bottom right and bottom left.
+    pub fn radii(&self) -> [f64; 4] {
+        [
+            self.top_left,
+            self.top_right,
+            self.bottom_right,
+            self.bottom_left,
+        ]
+    }
+}
+
+// --- Trait implementations ---
+
+impl From<(i32, i32, i32, i32)> for CornerRadius {
+    fn from(r: (i32, i32, i32, i32)) -> Self {
+        CornerRadius::new(r.0 as f64, r.1 as f64, r.2 as f64, r.3 as f64)
+    }
+}
+
+impl From<i32> for CornerRadius {
+    fn from(r: i32) -> Self {
+        CornerRadius::from(r as f64)
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for CornerRadius {
+    fn from(r: (f64, f64, f64, f64)) -> Self {
+        CornerRadius::new(r.0, r.1, r.2, r.3)
+    }
+}
+
+impl From<f64> for CornerRadius {
+    fn from(r: f64) -> Self {
+        CornerRadius::new(r, r, r, r)
+    }
+}
+
+impl From<Value> for CornerRadius {
+    fn from(v: Value) -> Self {
+        match v.0 {
+            ron::Value::Number(value) => CornerRadius::from(value.into_f64()),
+            ron::Value::Map(map) => {
+                let mut corner_radius = CornerRadius::default();
+
+                for (key, value) in map.iter() {
+                    if let Ok(key) = key.clone().into_rust::<String>() {
+                        let value = value.clone().into_rust::<f64>().unwrap_or(0.0);
+
+                        match key.as_str() {
+                            "top_left" => corner_radius.top_left = value,
+                            "top_right" => corner_radius.top_right = value,
+                            "bottom_right" => corner_radius.bottom_right = value,
+                            "bottom_left" => corner_radius.bottom_left = value,
+                            _ => {}
+                        }
+                    }
+                }
+
+                corner_radius
+            }
+            _ => CornerRadius::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_into() {
+        let corner_radius: CornerRadius = (1.0, 2.0, 3.0, 4.0).into();
+        assert_eq!(corner_radius.radii(), [1.0, 2.0, 3.0, 4.0]);
+        assert!(!corner_radius.is_uniform());
+        assert_eq!(corner_radius.max(), 4.0);
+
+        let corner_radius: CornerRadius = 2.into();
+        assert!(corner_radius.is_uniform());
+        assert_eq!(corner_radius.top_left(), 2.0);
+    }
+
+    #[test]
+    fn test_fit() {
+        assert_eq!(
+            CornerRadius::new(5.0, 10.0, 0.0, -2.0).fit(100.0, 50.0),
+            CornerRadius::new(5.0, 10.0, 0.0, 0.0)
+        );
+
+        // the radii of the left side are too large
+        assert_eq!(
+            CornerRadius::new(20.0, 0.0, 0.0, 20.0).fit(100.0, 20.0),
+            CornerRadius::new(10.0, 0.0, 0.0, 10.0)
+        );
+    }
+
+    #[test]
+    fn test_from_value() {
+        let value: ron::Value = ron::from_str("{ \"top_left\": 4, \"top_right\": 4 }").unwrap();
+        assert_eq!(
+            CornerRadius::from(Value(value)),
+            CornerRadius::new(4.0, 4.0, 0.0, 0.0)
+        );
+
+        let value: ron::Value = ron::from_str("2").unwrap();
+        assert_eq!(CornerRadius::from(Value(value)), CornerRadius::from(2.0));
+    }
+}
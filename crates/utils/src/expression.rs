@@ -89,7 +89,11 @@ impl Expression {
                     None => return None,
                 };
                 let pos = match v[1] {
-                    Expression::Number(n, ref m) => OnLinePos::try_from((n, &m[..])).ok()?,
+                    Expression::Number(n, ref m) => match OnLinePos::try_from((n, &m[..])) {
+                        Ok(pos) => pos,
+                        // stops of conic gradients could be defined by angles, they are stored as part of a turn
+                        Err(_) => OnLinePos::from_unit_percent(v[1].angle()?.to_turn()),
+                    },
                     _ => return None,
                 };
                 Some(GradientStop {
@@ -136,6 +140,130 @@ impl Expression {
         }
     }
 
+    // Gets the parts of a complex expression, or the expression itself.
+    fn tokens(&self) -> &[Expression] {
+        match self {
+            Expression::Complex(v) => v,
+            _ => std::slice::from_ref(self),
+        }
+    }
+
+    // Reads the position of a radial or conic gradient e.g. `center`, `left top` or `10px 50%`.
+    fn plane_pos(tokens: &[Expression]) -> Option<OnPlanePos> {
+        if tokens.is_empty() || tokens.len() > 2 {
+            return None;
+        }
+
+        let percent = |pos| OnLinePos::new(pos, OnLinePosKind::Percentage);
+        let mut x = None;
+        let mut y = None;
+        let mut others = vec![];
+
+        for token in tokens {
+            match token {
+                Expression::Other(keyword) => match &keyword[..] {
+                    "left" => x = Some(percent(0.0)),
+                    "right" => x = Some(percent(100.0)),
+                    "top" => y = Some(percent(0.0)),
+                    "bottom" => y = Some(percent(100.0)),
+                    "center" => others.push(percent(50.0)),
+                    _ => return None,
+                },
+                Expression::Number(n, u) => others.push(OnLinePos::try_from((*n, &u[..])).ok()?),
+                _ => return None,
+            }
+        }
+
+        let mut others = others.into_iter();
+        let x = x.or_else(|| others.next()).unwrap_or_else(|| percent(50.0));
+        let y = y.or_else(|| others.next()).unwrap_or_else(|| percent(50.0));
+
+        Some(OnPlanePos::new(x, y))
+    }
+
+    // Reads the definition of a radial gradient e.g. `circle closest-side at left top` or `40px 20px at 50% 50%`.
+    fn radial_gradient(&self) -> Option<RadialGradient> {
+        let tokens = self.tokens();
+        let at = tokens
+            .iter()
+            .position(|t| *t == Expression::Other("at".to_string()));
+        let mut gradient = RadialGradient::default();
+        let mut shape = None;
+        let mut radius = vec![];
+
+        for token in &tokens[..at.unwrap_or_else(|| tokens.len())] {
+            match token {
+                Expression::Other(keyword) => match &keyword[..] {
+                    "circle" => shape = Some(RadialGradientShape::Circle),
+                    "ellipse" => shape = Some(RadialGradientShape::Ellipse),
+                    "closest-side" => {
+                        gradient.size =
+                            RadialGradientSize::Extent(RadialGradientExtent::ClosestSide)
+                    }
+                    "closest-corner" => {
+                        gradient.size =
+                            RadialGradientSize::Extent(RadialGradientExtent::ClosestCorner)
+                    }
+                    "farthest-side" => {
+                        gradient.size =
+                            RadialGradientSize::Extent(RadialGradientExtent::FarthestSide)
+                    }
+                    "farthest-corner" => {
+                        gradient.size =
+                            RadialGradientSize::Extent(RadialGradientExtent::FarthestCorner)
+                    }
+                    _ => return None,
+                },
+                Expression::Number(n, u) => radius.push(OnLinePos::try_from((*n, &u[..])).ok()?),
+                _ => return None,
+            }
+        }
+
+        // a single radius describes a circle
+        match radius[..] {
+            [] => {}
+            [r] => {
+                gradient.size = RadialGradientSize::Radius { x: r, y: r };
+                gradient.shape = RadialGradientShape::Circle;
+            }
+            [x, y] => gradient.size = RadialGradientSize::Radius { x, y },
+            _ => return None,
+        }
+
+        if let Some(shape) = shape {
+            gradient.shape = shape;
+        }
+
+        if let Some(at) = at {
+            gradient.center = Expression::plane_pos(&tokens[at + 1..])?;
+        }
+
+        Some(gradient)
+    }
+
+    // Reads the definition of a conic gradient e.g. `from 45deg at 10px 20px`.
+    fn conic_gradient(&self) -> Option<ConicGradient> {
+        let tokens = self.tokens();
+        let mut gradient = ConicGradient::default();
+        let mut i = 0;
+
+        while i < tokens.len() {
+            match &tokens[i] {
+                Expression::Other(keyword) if keyword == "from" => {
+                    gradient.angle = tokens.get(i + 1)?.angle()?;
+                    i += 2;
+                }
+                Expression::Other(keyword) if keyword == "at" => {
+                    gradient.center = Expression::plane_pos(&tokens[i + 1..])?;
+                    break;
+                }
+                _ => return None,
+            }
+        }
+
+        Some(gradient)
+    }
+
     pub fn css_gradient(&self) -> Option<Gradient> {
         let mut displacement = OnPlanePos::new(
             OnLinePos::new(0.0, OnLinePosKind::Pixels),
@@ -176,20 +304,37 @@ impl Expression {
         if args.is_empty() {
             return None;
         }
-        let (radial, repeat) = match &name[..] {
-            "repeating-linear-gradient" => (false, true),
-            "linear-gradient" => (false, false),
-            "radial-gradient" => (true, false),
-            "repeating-radial-gradient" => (true, true),
+        let (shape, repeat) = match &name[..] {
+            "repeating-linear-gradient" => ("linear", true),
+            "linear-gradient" => ("linear", false),
+            "radial-gradient" => ("radial", false),
+            "repeating-radial-gradient" => ("radial", true),
+            "conic-gradient" => ("conic", false),
+            "repeating-conic-gradient" => ("conic", true),
             _ => {
                 return None;
             }
         };
         let mut i = 0;
         let kind;
-        if radial {
-            // TODO: Implement radial gradients
-            return None;
+        if shape == "radial" {
+            let gradient = match args[0].radial_gradient() {
+                Some(gradient) => {
+                    i += 1;
+                    gradient
+                }
+                None => RadialGradient::default(),
+            };
+            kind = GradientKind::Radial(gradient);
+        } else if shape == "conic" {
+            let gradient = match args[0].conic_gradient() {
+                Some(gradient) => {
+                    i += 1;
+                    gradient
+                }
+                None => ConicGradient::default(),
+            };
+            kind = GradientKind::Conic(gradient);
         } else {
             let mut coords = LinearGradientCoords::Angle {
                 displacement,
@@ -507,3 +652,79 @@ impl Neg for OnLinePos {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn percent(x: f64, y: f64) -> OnPlanePos {
+        OnPlanePos::new(
+            OnLinePos::new(x, OnLinePosKind::Percentage),
+            OnLinePos::new(y, OnLinePosKind::Percentage),
+        )
+    }
+
+    #[test]
+    fn test_radial_gradient() {
+        let gradient = Expression::from("radial-gradient(red, blue)")
+            .css_gradient()
+            .unwrap();
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Radial(RadialGradient::default())
+        );
+        assert_eq!(gradient.stops.len(), 2);
+
+        let gradient = Expression::from(
+            "repeating-radial-gradient(circle closest-side at left top, red, blue 10px)",
+        )
+        .css_gradient()
+        .unwrap();
+        assert!(gradient.repeat);
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Radial(RadialGradient {
+                shape: RadialGradientShape::Circle,
+                size: RadialGradientSize::Extent(RadialGradientExtent::ClosestSide),
+                center: percent(0.0, 0.0),
+            })
+        );
+
+        let gradient = Expression::from("radial-gradient(40px 20% at 10px, red, blue)")
+            .css_gradient()
+            .unwrap();
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Radial(RadialGradient {
+                shape: RadialGradientShape::Ellipse,
+                size: RadialGradientSize::Radius {
+                    x: OnLinePos::new(40.0, OnLinePosKind::Pixels),
+                    y: OnLinePos::new(20.0, OnLinePosKind::Percentage),
+                },
+                center: OnPlanePos::new(
+                    OnLinePos::new(10.0, OnLinePosKind::Pixels),
+                    OnLinePos::new(50.0, OnLinePosKind::Percentage),
+                ),
+            })
+        );
+    }
+
+    #[test]
+    fn test_conic_gradient() {
+        let gradient =
+            Expression::from("conic-gradient(from 90deg at bottom right, red, blue 0.5turn)")
+                .css_gradient()
+                .unwrap();
+        match gradient.kind {
+            GradientKind::Conic(conic) => {
+                assert!((conic.angle.to_degrees() - 90.0).abs() < 0.0001);
+                assert_eq!(conic.center, percent(100.0, 100.0));
+            }
+            _ => panic!("expected conic gradient"),
+        }
+        assert_eq!(
+            gradient.stops[1].pos,
+            Some(OnLinePos::from_unit_percent(0.5))
+        );
+    }
+}
@@ -0,0 +1,43 @@
+/// Describes how the inside of a path is determined if it is filled or used as clip.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FillRule {
+    /// A point is inside, if the path winds around it more often in one direction than in the other.
+    NonZero,
+
+    /// A point is inside, if a ray from it crosses the path an odd number of times.
+    EvenOdd,
+}
+
+impl Default for FillRule {
+    fn default() -> FillRule {
+        FillRule::NonZero
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for FillRule {
+    fn from(t: &str) -> Self {
+        match t {
+            "EvenOdd" | "even_odd" | "evenodd" => FillRule::EvenOdd,
+            _ => FillRule::NonZero,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let fill_rule: FillRule = "EvenOdd".into();
+        assert_eq!(fill_rule, FillRule::EvenOdd);
+
+        let fill_rule: FillRule = "even_odd".into();
+        assert_eq!(fill_rule, FillRule::EvenOdd);
+
+        let fill_rule: FillRule = "other".into();
+        assert_eq!(fill_rule, FillRule::NonZero);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Angle, Color, OnLinePos, OnPlanePos, Point, RelativeDir};
+use crate::{Angle, Color, OnLinePos, OnLinePosKind, OnPlanePos, Point, RelativeDir, Size};
 
 /// Describes a position on a colorful gradient.
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -103,6 +103,132 @@ impl Default for LinearGradientCoords {
     }
 }
 
+/// Describes the shape of a radial gradient.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum RadialGradientShape {
+    Circle,
+    #[default]
+    Ellipse,
+}
+
+/// Describes the size of a radial gradient by the distance of its center to the sides or corners of the target figure.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum RadialGradientExtent {
+    ClosestSide,
+    ClosestCorner,
+    FarthestSide,
+    FarthestCorner,
+}
+
+/// Describes the size of a radial gradient.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum RadialGradientSize {
+    /// The size depends on the sides or corners of the target figure.
+    Extent(RadialGradientExtent),
+    /// Defines the horizontal and vertical radius. A circle uses only the horizontal radius.
+    Radius { x: OnLinePos, y: OnLinePos },
+}
+
+impl Default for RadialGradientSize {
+    fn default() -> RadialGradientSize {
+        RadialGradientSize::Extent(RadialGradientExtent::FarthestCorner)
+    }
+}
+
+/// Describes a colorful radial gradient. The gradient starts at its center and ends on the circle or ellipse
+/// that is defined by its size.
+///
+/// The web backend supports only circles, it draws an ellipse as a circle with the larger of both radiuses.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RadialGradient {
+    pub shape: RadialGradientShape,
+    pub size: RadialGradientSize,
+    /// Defines the center relative to the top left corner of the target figure.
+    pub center: OnPlanePos,
+}
+
+impl RadialGradient {
+    /// Calculates the center and the horizontal and vertical radius of the gradient in the given size of the target figure.
+    pub fn resolve(&self, size: Size) -> (Point, Point) {
+        let center = self.center.pixels(size);
+
+        let (x, y) = match self.size {
+            RadialGradientSize::Radius { x, y } => match self.shape {
+                RadialGradientShape::Circle => (x.pixels(size.width()), x.pixels(size.width())),
+                RadialGradientShape::Ellipse => (x.pixels(size.width()), y.pixels(size.height())),
+            },
+            RadialGradientSize::Extent(extent) => {
+                let horizontal = (center.x().abs(), (size.width() - center.x()).abs());
+                let vertical = (center.y().abs(), (size.height() - center.y()).abs());
+
+                let (x, y) = match extent {
+                    RadialGradientExtent::ClosestSide | RadialGradientExtent::ClosestCorner => {
+                        (horizontal.0.min(horizontal.1), vertical.0.min(vertical.1))
+                    }
+                    RadialGradientExtent::FarthestSide | RadialGradientExtent::FarthestCorner => {
+                        (horizontal.0.max(horizontal.1), vertical.0.max(vertical.1))
+                    }
+                };
+
+                match (self.shape, extent) {
+                    (RadialGradientShape::Circle, RadialGradientExtent::ClosestSide) => {
+                        (x.min(y), x.min(y))
+                    }
+                    (RadialGradientShape::Circle, RadialGradientExtent::FarthestSide) => {
+                        (x.max(y), x.max(y))
+                    }
+                    (RadialGradientShape::Circle, _) => {
+                        let radius = x.hypot(y);
+                        (radius, radius)
+                    }
+                    (RadialGradientShape::Ellipse, RadialGradientExtent::ClosestSide)
+                    | (RadialGradientShape::Ellipse, RadialGradientExtent::FarthestSide) => (x, y),
+                    // the ellipse keeps the aspect ratio of the sides and passes through the corner
+                    (RadialGradientShape::Ellipse, _) => {
+                        (x * std::f64::consts::SQRT_2, y * std::f64::consts::SQRT_2)
+                    }
+                }
+            }
+        };
+
+        (center, Point::new(x, y))
+    }
+}
+
+impl Default for RadialGradient {
+    fn default() -> RadialGradient {
+        RadialGradient {
+            shape: RadialGradientShape::default(),
+            size: RadialGradientSize::default(),
+            center: OnPlanePos::new(
+                OnLinePos::new(50.0, OnLinePosKind::Percentage),
+                OnLinePos::new(50.0, OnLinePosKind::Percentage),
+            ),
+        }
+    }
+}
+
+/// Describes a colorful conic gradient. The colors are rotated around its center, starting at the given angle.
+/// An angle of zero points to the top.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ConicGradient {
+    pub angle: Angle,
+    /// Defines the center relative to the top left corner of the target figure.
+    pub center: OnPlanePos,
+}
+
+impl Default for ConicGradient {
+    fn default() -> ConicGradient {
+        ConicGradient {
+            angle: Angle::zero(),
+            center: OnPlanePos::new(
+                OnLinePos::new(50.0, OnLinePosKind::Percentage),
+                OnLinePos::new(50.0, OnLinePosKind::Percentage),
+            ),
+        }
+    }
+}
+
 /// Describes a colorful gradient.
 #[derive(Clone, PartialEq, Debug)]
 pub struct Gradient {
@@ -133,7 +259,8 @@ impl Default for Gradient {
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum GradientKind {
     Linear(LinearGradientCoords),
-    // Radial(RadialGradient),
+    Radial(RadialGradient),
+    Conic(ConicGradient),
 }
 
 impl Default for GradientKind {
@@ -141,3 +268,60 @@ impl Default for GradientKind {
         GradientKind::Linear(LinearGradientCoords::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn percent(x: f64, y: f64) -> OnPlanePos {
+        OnPlanePos::new(
+            OnLinePos::new(x, OnLinePosKind::Percentage),
+            OnLinePos::new(y, OnLinePosKind::Percentage),
+        )
+    }
+
+    #[test]
+    fn test_radial_gradient_resolve() {
+        let size = Size::new(200.0, 100.0);
+
+        let gradient = RadialGradient {
+            shape: RadialGradientShape::Circle,
+            size: RadialGradientSize::Extent(RadialGradientExtent::ClosestSide),
+            center: percent(50.0, 50.0),
+        };
+        assert_eq!(
+            gradient.resolve(size),
+            (Point::new(100.0, 50.0), Point::new(50.0, 50.0))
+        );
+
+        let gradient = RadialGradient {
+            shape: RadialGradientShape::Ellipse,
+            size: RadialGradientSize::Extent(RadialGradientExtent::FarthestSide),
+            center: percent(25.0, 50.0),
+        };
+        assert_eq!(
+            gradient.resolve(size),
+            (Point::new(50.0, 50.0), Point::new(150.0, 50.0))
+        );
+
+        let gradient = RadialGradient {
+            shape: RadialGradientShape::Circle,
+            size: RadialGradientSize::Extent(RadialGradientExtent::FarthestCorner),
+            center: percent(0.0, 0.0),
+        };
+        assert_eq!(
+            gradient.resolve(size).1,
+            Point::new(200.0_f64.hypot(100.0), 200.0_f64.hypot(100.0))
+        );
+
+        let gradient = RadialGradient {
+            shape: RadialGradientShape::Ellipse,
+            size: RadialGradientSize::Radius {
+                x: OnLinePos::new(10.0, OnLinePosKind::Pixels),
+                y: OnLinePos::new(50.0, OnLinePosKind::Percentage),
+            },
+            center: percent(50.0, 50.0),
+        };
+        assert_eq!(gradient.resolve(size).1, Point::new(10.0, 50.0));
+    }
+}
@@ -1,49 +1,75 @@
 pub use self::alignment::*;
 pub use self::angle::*;
 pub use self::border::*;
+pub use self::border_brushes::*;
+pub use self::border_style::*;
+pub use self::box_shadow::*;
 pub use self::brush::*;
+pub use self::cache_mode::*;
 pub use self::color::*;
 pub use self::constraint::*;
+pub use self::corner_radius::*;
 pub use self::dirty_size::*;
 pub use self::expression::*;
+pub use self::fill_rule::*;
 pub use self::filter::*;
 pub use self::gradients::*;
+pub use self::line_cap::*;
+pub use self::line_join::*;
 pub use self::number::*;
 pub use self::orientation::*;
 pub use self::point::*;
 pub use self::rectangle::*;
 pub use self::relative_direction::*;
+pub use self::rich_text::*;
 pub use self::selection_mode::*;
 pub use self::size::*;
+pub use self::stretch::*;
 pub use self::string16::*;
 pub use self::text_alignment::*;
 pub use self::text_baseline::*;
+pub use self::text_trimming::*;
+pub use self::text_wrap::*;
 pub use self::thickness::*;
+pub use self::transform::*;
 pub use self::value::*;
 pub use self::visibility::*;
 
 mod alignment;
 mod angle;
 mod border;
+mod border_brushes;
+mod border_style;
+mod box_shadow;
 mod brush;
+mod cache_mode;
 mod color;
 mod constraint;
+mod corner_radius;
 mod dirty_size;
 mod expression;
+mod fill_rule;
 mod filter;
 mod gradients;
+mod line_cap;
+mod line_join;
 mod number;
 mod orientation;
 mod point;
 pub mod prelude;
 mod rectangle;
 mod relative_direction;
+mod rich_text;
 mod selection_mode;
 mod size;
 mod spacer;
+mod stretch;
 mod string16;
 mod text_alignment;
 mod text_baseline;
+mod text_trimming;
+mod text_wrap;
 mod thickness;
+mod transform;
 mod value;
 mod visibility;
@@ -0,0 +1,47 @@
+/// Describes how the ends of stroked lines are drawn.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LineCap {
+    /// The line ends exactly at its end point.
+    Butt,
+
+    /// The line ends with a half circle around its end point.
+    Round,
+
+    /// The line ends with a half square around its end point.
+    Square,
+}
+
+impl Default for LineCap {
+    fn default() -> LineCap {
+        LineCap::Butt
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for LineCap {
+    fn from(t: &str) -> Self {
+        match t {
+            "Round" | "round" => LineCap::Round,
+            "Square" | "square" => LineCap::Square,
+            _ => LineCap::Butt,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let line_cap: LineCap = "Round".into();
+        assert_eq!(line_cap, LineCap::Round);
+
+        let line_cap: LineCap = "square".into();
+        assert_eq!(line_cap, LineCap::Square);
+
+        let line_cap: LineCap = "other".into();
+        assert_eq!(line_cap, LineCap::Butt);
+    }
+}
@@ -0,0 +1,48 @@
+/// Describes how two connected segments of stroked lines are joined.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LineJoin {
+    /// The outer edges of the segments are extended until they meet. If the extension is longer than the miter
+    /// limit, the join is beveled.
+    Miter,
+
+    /// The corner is rounded.
+    Round,
+
+    /// The corner is cut off.
+    Bevel,
+}
+
+impl Default for LineJoin {
+    fn default() -> LineJoin {
+        LineJoin::Miter
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for LineJoin {
+    fn from(t: &str) -> Self {
+        match t {
+            "Round" | "round" => LineJoin::Round,
+            "Bevel" | "bevel" => LineJoin::Bevel,
+            _ => LineJoin::Miter,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let line_join: LineJoin = "Round".into();
+        assert_eq!(line_join, LineJoin::Round);
+
+        let line_join: LineJoin = "bevel".into();
+        assert_eq!(line_join, LineJoin::Bevel);
+
+        let line_join: LineJoin = "other".into();
+        assert_eq!(line_join, LineJoin::Miter);
+    }
+}
@@ -0,0 +1,497 @@
+use crate::prelude::*;
+
+/// Describes the weight of a font.
+///
+/// Fonts are registered by family keys like `Roboto-Regular` or `Roboto-Medium`. A weight is resolved to a
+/// font family by replacing the weight suffix of the family key, e.g. `Roboto-Regular` with
+/// `FontWeight::Bold` is resolved to `Roboto-Bold`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FontWeight {
+    Thin,
+    Light,
+    Regular,
+    Medium,
+    Bold,
+    Black,
+}
+
+impl FontWeight {
+    /// Gets the name of the weight, that is used as suffix of font family keys.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FontWeight::Thin => "Thin",
+            FontWeight::Light => "Light",
+            FontWeight::Regular => "Regular",
+            FontWeight::Medium => "Medium",
+            FontWeight::Bold => "Bold",
+            FontWeight::Black => "Black",
+        }
+    }
+
+    /// Resolves the font family key of the given family with this weight.
+    pub fn family(&self, family: &str) -> String {
+        let base = match family.rfind('-') {
+            Some(index) => &family[..index],
+            None => family,
+        };
+
+        format!("{}-{}", base, self.name())
+    }
+}
+
+impl Default for FontWeight {
+    fn default() -> Self {
+        FontWeight::Regular
+    }
+}
+
+impl From<&str> for FontWeight {
+    fn from(s: &str) -> FontWeight {
+        match s.to_lowercase().as_str() {
+            "thin" | "100" | "200" => FontWeight::Thin,
+            "light" | "300" => FontWeight::Light,
+            "medium" | "500" | "600" => FontWeight::Medium,
+            "bold" | "700" | "800" => FontWeight::Bold,
+            "black" | "900" => FontWeight::Black,
+            _ => FontWeight::Regular,
+        }
+    }
+}
+
+/// A `TextSpan` is a run of text with its own attributes. Attributes that are not set are taken from the widget
+/// that draws the text.
+///
+/// # Examples
+///
+/// ```text
+/// TextSpan::new("click here").foreground("#3b88c3").underline().link("https://redox-os.org")
+/// ```
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TextSpan {
+    /// The text of the span.
+    pub text: String,
+
+    /// Font family key of the span.
+    pub font: Option<String>,
+
+    /// Font size of the span.
+    pub font_size: Option<f64>,
+
+    /// Font weight of the span.
+    pub weight: Option<FontWeight>,
+
+    /// Foreground (text color) of the span.
+    pub foreground: Option<Brush>,
+
+    /// Background of the span.
+    pub background: Option<Brush>,
+
+    /// Draws a line under the text.
+    pub underline: bool,
+
+    /// Draws a line through the text.
+    pub strikethrough: bool,
+
+    /// Target of a link. If it is set, clicking the span emits a link event.
+    pub link: Option<String>,
+}
+
+impl TextSpan {
+    /// Creates a span with the given text.
+    pub fn new(text: impl Into<String>) -> Self {
+        TextSpan {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the font family key.
+    pub fn font(mut self, font: impl Into<String>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the font size.
+    pub fn font_size(mut self, font_size: f64) -> Self {
+        self.font_size = Some(font_size);
+        self
+    }
+
+    /// Sets the font weight.
+    pub fn weight(mut self, weight: impl Into<FontWeight>) -> Self {
+        self.weight = Some(weight.into());
+        self
+    }
+
+    /// Sets the font weight to bold.
+    pub fn bold(self) -> Self {
+        self.weight(FontWeight::Bold)
+    }
+
+    /// Sets the foreground.
+    pub fn foreground(mut self, foreground: impl Into<Brush>) -> Self {
+        self.foreground = Some(foreground.into());
+        self
+    }
+
+    /// Sets the background.
+    pub fn background(mut self, background: impl Into<Brush>) -> Self {
+        self.background = Some(background.into());
+        self
+    }
+
+    /// Draws a line under the text.
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Draws a line through the text.
+    pub fn strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+
+    /// Makes the span a link to the given target.
+    pub fn link(mut self, target: impl Into<String>) -> Self {
+        self.link = Some(target.into());
+        self
+    }
+
+    /// Gets the font family key of the span. The font family of the span or if it is not set the given default
+    /// family is resolved with the weight of the span.
+    pub fn family(&self, default: &str) -> String {
+        let family = self.font.as_deref().unwrap_or(default);
+
+        match self.weight {
+            Some(weight) => weight.family(family),
+            None => family.to_string(),
+        }
+    }
+
+    /// Gets the font family key of the span like `family`. If the resolved family is not available, e.g. no bold
+    /// face of the font is registered, the family of the span without weight is used.
+    pub fn available_family(&self, default: &str, is_available: impl Fn(&str) -> bool) -> String {
+        let family = self.family(default);
+
+        if is_available(&family) {
+            return family;
+        }
+
+        self.font.as_deref().unwrap_or(default).to_string()
+    }
+
+    // Creates an empty span with the attributes of the span.
+    fn style(&self) -> TextSpan {
+        TextSpan {
+            text: String::new(),
+            ..self.clone()
+        }
+    }
+}
+
+impl From<&str> for TextSpan {
+    fn from(s: &str) -> TextSpan {
+        TextSpan::new(s)
+    }
+}
+
+impl From<String> for TextSpan {
+    fn from(s: String) -> TextSpan {
+        TextSpan::new(s)
+    }
+}
+
+/// `RichText` is a text that consists of multiple spans with their own attributes like font, size, weight,
+/// colors, underline and strikethrough. Spans with a link are clickable.
+///
+/// A rich text could be created by a builder or from a small markup subset:
+///
+/// * `<b>`, `<strong>`: bold text
+/// * `<u>`: underlined text
+/// * `<s>`: strikethrough text
+/// * `<a href="target">`: link
+/// * `<span font="Roboto-Medium" size="14" weight="medium" color="#ff0000" background="yellow">`
+/// * `<br>`: line break
+/// * `&lt;`, `&gt;`, `&amp;`, `&quot;`: escaped chars
+///
+/// # Examples
+///
+/// ```text
+/// RichText::new().span("Hello ").span(TextSpan::new("world").bold())
+/// RichText::from("Hello <b>world</b>, read <a href=\"https://redox-os.org\">more</a>")
+/// ```
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct RichText {
+    spans: Vec<TextSpan>,
+}
+
+impl RichText {
+    /// Creates an empty rich text.
+    pub fn new() -> Self {
+        RichText::default()
+    }
+
+    /// Builder method that appends a span.
+    pub fn span(mut self, span: impl Into<TextSpan>) -> Self {
+        self.push(span);
+        self
+    }
+
+    /// Appends a span.
+    pub fn push(&mut self, span: impl Into<TextSpan>) {
+        self.spans.push(span.into());
+    }
+
+    /// Gets the spans.
+    pub fn spans(&self) -> &[TextSpan] {
+        &self.spans
+    }
+
+    /// Returns `true` if the rich text contains no text.
+    pub fn is_empty(&self) -> bool {
+        self.spans.iter().all(|s| s.text.is_empty())
+    }
+
+    /// Gets the text of all spans without attributes.
+    pub fn text(&self) -> String {
+        self.spans.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    /// Parses the markup subset, that is described on `RichText`. Unknown tags are ignored.
+    pub fn from_markup(markup: &str) -> Self {
+        let mut rich_text = RichText::new();
+        let mut styles = vec![TextSpan::default()];
+        let mut text = String::new();
+        let mut chars = markup.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '<' => {
+                    let mut tag = String::new();
+
+                    for c in chars.by_ref() {
+                        if c == '>' {
+                            break;
+                        }
+                        tag.push(c);
+                    }
+
+                    let style = styles.last().cloned().unwrap_or_default();
+                    rich_text.flush(&style, &mut text);
+
+                    let tag = tag.trim();
+
+                    if tag.starts_with('/') {
+                        if styles.len() > 1 {
+                            styles.pop();
+                        }
+                        continue;
+                    }
+
+                    let (name, attributes) = match tag.find(char::is_whitespace) {
+                        Some(index) => (&tag[..index], attributes(&tag[index..])),
+                        None => (tag.trim_end_matches('/'), vec![]),
+                    };
+
+                    if name == "br" {
+                        text.push('\n');
+                        continue;
+                    }
+
+                    let mut style = style.style();
+
+                    match name {
+                        "b" | "strong" => style.weight = Some(FontWeight::Bold),
+                        "u" => style.underline = true,
+                        "s" => style.strikethrough = true,
+                        _ => {}
+                    }
+
+                    for (key, value) in attributes {
+                        match (name, key.as_str()) {
+                            ("a", "href") => {
+                                style.link = Some(value);
+                                style.underline = true;
+                            }
+                            ("span", "font") => style.font = Some(value),
+                            ("span", "size") => style.font_size = value.parse().ok(),
+                            ("span", "weight") => style.weight = Some(value.as_str().into()),
+                            ("span", "color") => style.foreground = Some(value.as_str().into()),
+                            ("span", "background") => {
+                                style.background = Some(value.as_str().into())
+                            }
+                            _ => {}
+                        }
+                    }
+
+                    if !tag.ends_with('/') {
+                        styles.push(style);
+                    }
+                }
+                '&' => {
+                    let mut entity = String::new();
+
+                    while let Some(c) = chars.peek() {
+                        if *c == ';' || entity.len() > 5 {
+                            break;
+                        }
+                        entity.push(*c);
+                        chars.next();
+                    }
+
+                    match (entity.as_str(), chars.peek()) {
+                        ("lt", Some(';')) => text.push('<'),
+                        ("gt", Some(';')) => text.push('>'),
+                        ("amp", Some(';')) => text.push('&'),
+                        ("quot", Some(';')) => text.push('"'),
+                        _ => {
+                            text.push('&');
+                            text.push_str(&entity);
+                            continue;
+                        }
+                    }
+
+                    chars.next();
+                }
+                _ => text.push(c),
+            }
+        }
+
+        let style = styles.last().cloned().unwrap_or_default();
+        rich_text.flush(&style, &mut text);
+
+        rich_text
+    }
+
+    // Appends the text with the given style as new span.
+    fn flush(&mut self, style: &TextSpan, text: &mut String) {
+        if text.is_empty() {
+            return;
+        }
+
+        self.spans.push(TextSpan {
+            text: std::mem::take(text),
+            ..style.clone()
+        });
+    }
+}
+
+// Reads the attributes of a tag e.g. `href="target" size='12'`.
+fn attributes(s: &str) -> Vec<(String, String)> {
+    let mut attributes = vec![];
+    let mut rest = s.trim();
+
+    while let Some(index) = rest.find('=') {
+        let key = rest[..index].trim().to_string();
+        let value = rest[index + 1..].trim_start();
+
+        let quote = match value.chars().next() {
+            Some(c) if c == '"' || c == '\'' => c,
+            _ => break,
+        };
+
+        let end = match value[1..].find(quote) {
+            Some(end) => end + 1,
+            None => break,
+        };
+
+        attributes.push((key, value[1..end].to_string()));
+        rest = value[end + 1..].trim_start();
+    }
+
+    attributes
+}
+
+impl From<&str> for RichText {
+    fn from(s: &str) -> RichText {
+        RichText::from_markup(s)
+    }
+}
+
+impl From<String> for RichText {
+    fn from(s: String) -> RichText {
+        RichText::from_markup(&s)
+    }
+}
+
+impl From<TextSpan> for RichText {
+    fn from(span: TextSpan) -> RichText {
+        RichText { spans: vec![span] }
+    }
+}
+
+impl From<Vec<TextSpan>> for RichText {
+    fn from(spans: Vec<TextSpan>) -> RichText {
+        RichText { spans }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_font_weight() {
+        assert_eq!(FontWeight::Bold.family("Roboto-Regular"), "Roboto-Bold");
+        assert_eq!(FontWeight::Medium.family("Roboto"), "Roboto-Medium");
+        assert_eq!(FontWeight::from("700"), FontWeight::Bold);
+        assert_eq!(
+            TextSpan::new("a").bold().family("Roboto-Regular"),
+            "Roboto-Bold"
+        );
+        assert_eq!(
+            TextSpan::new("a").family("Roboto-Regular"),
+            "Roboto-Regular"
+        );
+    }
+
+    #[test]
+    fn test_available_family() {
+        let registered = |family: &str| family == "Roboto-Regular" || family == "Roboto-Medium";
+
+        assert_eq!(
+            TextSpan::new("a")
+                .bold()
+                .available_family("Roboto-Regular", registered),
+            "Roboto-Regular"
+        );
+        assert_eq!(
+            TextSpan::new("a")
+                .weight(FontWeight::Medium)
+                .available_family("Roboto-Regular", registered),
+            "Roboto-Medium"
+        );
+    }
+
+    #[test]
+    fn test_from_markup() {
+        let rich_text = RichText::from(
+            "Hello <b>bold <u>under</u></b>&lt;<a href=\"https://redox-os.org\">link</a><br/>\
+             <span size='14' color=\"#ff0000\">red</span>",
+        );
+
+        assert_eq!(
+            rich_text.spans(),
+            &[
+                TextSpan::new("Hello "),
+                TextSpan::new("bold ").bold(),
+                TextSpan::new("under").bold().underline(),
+                TextSpan::new("<"),
+                TextSpan::new("link")
+                    .underline()
+                    .link("https://redox-os.org"),
+                TextSpan::new("\n"),
+                TextSpan::new("red").font_size(14.0).foreground("#ff0000"),
+            ]
+        );
+
+        assert_eq!(rich_text.text(), "Hello bold under<link\nred");
+        assert_eq!(RichText::from("a & b").text(), "a & b");
+        assert_eq!(
+            RichText::from("<i>plain</i>").spans(),
+            &[TextSpan::new("plain")]
+        );
+        assert!(RichText::from("<b></b>").is_empty());
+    }
+}
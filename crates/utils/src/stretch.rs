@@ -0,0 +1,146 @@
+use crate::{Alignment, Rectangle, Size};
+
+/// Describes how an image is resized to fill its bounds.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum Stretch {
+    /// The image keeps its natural size.
+    #[default]
+    None,
+
+    /// The image is resized to fill the bounds. The aspect ratio is not preserved.
+    Fill,
+
+    /// The image is resized to fit into the bounds, while it preserves its aspect ratio.
+    Uniform,
+
+    /// The image is resized to fill the bounds, while it preserves its aspect ratio. Parts of the image that exceed
+    /// the bounds are cut off.
+    UniformToFill,
+}
+
+impl Stretch {
+    /// Calculates the part of an image with the given size, that is drawn, and the rectangle inside of the bounds it
+    /// is drawn to. The image is aligned inside of the bounds by the given alignments, a stretch alignment centers it.
+    /// Returns `None` if the image or the bounds are empty.
+    pub fn fit(
+        self,
+        size: Size,
+        bounds: Rectangle,
+        h_align: Alignment,
+        v_align: Alignment,
+    ) -> Option<(Rectangle, Rectangle)> {
+        if size.width() <= 0.0
+            || size.height() <= 0.0
+            || bounds.width() <= 0.0
+            || bounds.height() <= 0.0
+        {
+            return None;
+        }
+
+        let scale_x = bounds.width() / size.width();
+        let scale_y = bounds.height() / size.height();
+
+        let (scale_x, scale_y) = match self {
+            Stretch::None => (1.0, 1.0),
+            Stretch::Fill => (scale_x, scale_y),
+            Stretch::Uniform => (scale_x.min(scale_y), scale_x.min(scale_y)),
+            Stretch::UniformToFill => (scale_x.max(scale_y), scale_x.max(scale_y)),
+        };
+
+        let (source_x, source_width, x, width) =
+            fit_axis(size.width(), bounds.width(), scale_x, h_align);
+        let (source_y, source_height, y, height) =
+            fit_axis(size.height(), bounds.height(), scale_y, v_align);
+
+        Some((
+            Rectangle::new((source_x, source_y), (source_width, source_height)),
+            Rectangle::new((bounds.x() + x, bounds.y() + y), (width, height)),
+        ))
+    }
+}
+
+// Fits one axis of the image into the bounds. Returns the start and the length of the drawn part of the image and
+// the start and the length inside of the bounds.
+fn fit_axis(length: f64, available: f64, scale: f64, alignment: Alignment) -> (f64, f64, f64, f64) {
+    let scaled = length * scale;
+
+    // the part of the image that exceeds the bounds is cut off
+    if scaled > available {
+        let visible = available / scale;
+        return (align(alignment, length - visible), visible, 0.0, available);
+    }
+
+    (0.0, length, align(alignment, available - scaled), scaled)
+}
+
+fn align(alignment: Alignment, space: f64) -> f64 {
+    match alignment {
+        Alignment::Start => 0.0,
+        Alignment::End => space,
+        Alignment::Center | Alignment::Stretch => space / 2.0,
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for Stretch {
+    fn from(t: &str) -> Self {
+        match t {
+            "Fill" | "fill" => Stretch::Fill,
+            "Uniform" | "uniform" => Stretch::Uniform,
+            "UniformToFill" | "uniform_to_fill" => Stretch::UniformToFill,
+            _ => Stretch::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let stretch: Stretch = "fill".into();
+        assert_eq!(stretch, Stretch::Fill);
+
+        let stretch: Stretch = "Uniform".into();
+        assert_eq!(stretch, Stretch::Uniform);
+
+        let stretch: Stretch = "uniform_to_fill".into();
+        assert_eq!(stretch, Stretch::UniformToFill);
+
+        let stretch: Stretch = "other".into();
+        assert_eq!(stretch, Stretch::None);
+    }
+
+    #[test]
+    fn test_fit() {
+        let size = Size::new(20.0, 10.0);
+        let bounds = Rectangle::new((10.0, 10.0), (40.0, 40.0));
+        let full = Rectangle::new((0.0, 0.0), (20.0, 10.0));
+
+        assert_eq!(
+            Stretch::None.fit(size, bounds, Alignment::Start, Alignment::End),
+            Some((full, Rectangle::new((10.0, 40.0), (20.0, 10.0))))
+        );
+        assert_eq!(
+            Stretch::Fill.fit(size, bounds, Alignment::Center, Alignment::Center),
+            Some((full, bounds))
+        );
+        assert_eq!(
+            Stretch::Uniform.fit(size, bounds, Alignment::Stretch, Alignment::Stretch),
+            Some((full, Rectangle::new((10.0, 20.0), (40.0, 20.0))))
+        );
+
+        // the sides of the image are cut off
+        assert_eq!(
+            Stretch::UniformToFill.fit(size, bounds, Alignment::Center, Alignment::Center),
+            Some((Rectangle::new((5.0, 0.0), (10.0, 10.0)), bounds))
+        );
+
+        assert_eq!(
+            Stretch::Fill.fit(Size::default(), bounds, Alignment::Start, Alignment::Start),
+            None
+        );
+    }
+}
@@ -19,3 +19,23 @@ impl ToString for TextAlignment {
         }
     }
 }
+
+impl Default for TextAlignment {
+    fn default() -> TextAlignment {
+        TextAlignment::Left
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for TextAlignment {
+    fn from(t: &str) -> Self {
+        match t {
+            "Right" | "right" => TextAlignment::Right,
+            "Center" | "center" => TextAlignment::Center,
+            "Start" | "start" => TextAlignment::Start,
+            "End" | "end" => TextAlignment::End,
+            _ => TextAlignment::Left,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+/// Describes how a text is trimmed if it exceeds the available width or the maximum number of lines.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TextTrimming {
+    /// The text is not trimmed.
+    None,
+
+    /// The text is cut on a char boundary and an ellipsis (`…`) is appended.
+    Ellipsis,
+}
+
+impl Default for TextTrimming {
+    fn default() -> TextTrimming {
+        TextTrimming::None
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for TextTrimming {
+    fn from(t: &str) -> Self {
+        match t {
+            "Ellipsis" | "ellipsis" => TextTrimming::Ellipsis,
+            _ => TextTrimming::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let text_trimming: TextTrimming = "ellipsis".into();
+        assert_eq!(text_trimming, TextTrimming::Ellipsis);
+
+        let text_trimming: TextTrimming = "none".into();
+        assert_eq!(text_trimming, TextTrimming::None);
+    }
+}
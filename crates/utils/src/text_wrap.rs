@@ -0,0 +1,48 @@
+/// Describes how a text is wrapped if it exceeds the available width.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TextWrap {
+    /// The text is not wrapped.
+    None,
+
+    /// The text is wrapped on word boundaries. Words that are wider than the available width are wrapped on
+    /// char boundaries.
+    Word,
+
+    /// The text is wrapped on char boundaries.
+    Character,
+}
+
+impl Default for TextWrap {
+    fn default() -> TextWrap {
+        TextWrap::None
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for TextWrap {
+    fn from(t: &str) -> Self {
+        match t {
+            "Word" | "word" => TextWrap::Word,
+            "Character" | "character" => TextWrap::Character,
+            _ => TextWrap::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let text_wrap: TextWrap = "word".into();
+        assert_eq!(text_wrap, TextWrap::Word);
+
+        let text_wrap: TextWrap = "Character".into();
+        assert_eq!(text_wrap, TextWrap::Character);
+
+        let text_wrap: TextWrap = "other".into();
+        assert_eq!(text_wrap, TextWrap::None);
+    }
+}
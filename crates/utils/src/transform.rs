@@ -0,0 +1,330 @@
+use crate::{Angle, Point, Rectangle};
+
+/// Describes a 2D affine transformation matrix.
+///
+/// A point is transformed by `x' = m11 * x + m21 * y + m31` and `y' = m12 * x + m22 * y + m32`. The fields follow the
+/// parameter order of `RenderContext2D::set_transform`.
+///
+/// # Examples
+/// ```rust
+/// use orbtk_utils::{Point, Transform};
+///
+/// let transform = Transform::scaling(2.0, 2.0).translate(10.0, 0.0);
+///
+/// assert_eq!(transform.transform_point(Point::new(1.0, 1.0)), Point::new(12.0, 2.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transform {
+    /// Horizontal scaling.
+    pub m11: f64,
+
+    /// Vertical skewing.
+    pub m12: f64,
+
+    /// Horizontal skewing.
+    pub m21: f64,
+
+    /// Vertical scaling.
+    pub m22: f64,
+
+    /// Horizontal moving.
+    pub m31: f64,
+
+    /// Vertical moving.
+    pub m32: f64,
+}
+
+impl Transform {
+    /// Creates a new transform from the given matrix values.
+    pub fn new(m11: f64, m12: f64, m21: f64, m22: f64, m31: f64, m32: f64) -> Self {
+        Transform {
+            m11,
+            m12,
+            m21,
+            m22,
+            m31,
+            m32,
+        }
+    }
+
+    /// Creates a transform that does not change anything.
+    pub fn identity() -> Self {
+        Transform::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Creates a transform that moves by the given offset.
+    pub fn translation(x: f64, y: f64) -> Self {
+        Transform::new(1.0, 0.0, 0.0, 1.0, x, y)
+    }
+
+    /// Creates a transform that scales by the given factors.
+    pub fn scaling(x: f64, y: f64) -> Self {
+        Transform::new(x, 0.0, 0.0, y, 0.0, 0.0)
+    }
+
+    /// Creates a transform that rotates clockwise by the given angle.
+    pub fn rotation(angle: Angle) -> Self {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        Transform::new(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    /// Creates a transform that skews by the given horizontal and vertical angles.
+    pub fn skewing(x: Angle, y: Angle) -> Self {
+        Transform::new(
+            1.0,
+            y.to_radians().tan(),
+            x.to_radians().tan(),
+            1.0,
+            0.0,
+            0.0,
+        )
+    }
+
+    /// Moves after the current transformation.
+    pub fn translate(self, x: f64, y: f64) -> Self {
+        self.then(&Transform::translation(x, y))
+    }
+
+    /// Scales after the current transformation.
+    pub fn scale(self, x: f64, y: f64) -> Self {
+        self.then(&Transform::scaling(x, y))
+    }
+
+    /// Rotates after the current transformation.
+    pub fn rotate(self, angle: Angle) -> Self {
+        self.then(&Transform::rotation(angle))
+    }
+
+    /// Skews after the current transformation.
+    pub fn skew(self, x: Angle, y: Angle) -> Self {
+        self.then(&Transform::skewing(x, y))
+    }
+
+    /// Returns a transform that applies this transform first and then `other`.
+    pub fn then(&self, other: &Transform) -> Transform {
+        Transform::new(
+            self.m11 * other.m11 + self.m12 * other.m21,
+            self.m11 * other.m12 + self.m12 * other.m22,
+            self.m21 * other.m11 + self.m22 * other.m21,
+            self.m21 * other.m12 + self.m22 * other.m22,
+            self.m31 * other.m11 + self.m32 * other.m21 + other.m31,
+            self.m31 * other.m12 + self.m32 * other.m22 + other.m32,
+        )
+    }
+
+    /// Returns `true` if the transform does not change anything.
+    pub fn is_identity(&self) -> bool {
+        *self == Transform::identity()
+    }
+
+    /// Gets the determinant of the matrix.
+    pub fn determinant(&self) -> f64 {
+        self.m11 * self.m22 - self.m12 * self.m21
+    }
+
+    /// Gets the inverse transform. Returns `None` if the transform cannot be inverted e.g. a scaling by zero.
+    pub fn inverse(&self) -> Option<Transform> {
+        let det = self.determinant();
+
+        if det.abs() < f64::EPSILON {
+            return None;
+        }
+
+        Some(Transform::new(
+            self.m22 / det,
+            -self.m12 / det,
+            -self.m21 / det,
+            self.m11 / det,
+            (self.m21 * self.m32 - self.m22 * self.m31) / det,
+            (self.m12 * self.m31 - self.m11 * self.m32) / det,
+        ))
+    }
+
+    /// Transforms the given point.
+    pub fn transform_point(&self, point: Point) -> Point {
+        Point::new(
+            self.m11 * point.x() + self.m21 * point.y() + self.m31,
+            self.m12 * point.x() + self.m22 * point.y() + self.m32,
+        )
+    }
+
+    /// Transforms the given rectangle and returns the axis aligned bounding box of the result.
+    pub fn transform_rect(&self, rect: Rectangle) -> Rectangle {
+        if self.is_identity() {
+            return rect;
+        }
+
+        let corners = [
+            self.transform_point(rect.position()),
+            self.transform_point(Point::new(rect.x() + rect.width(), rect.y())),
+            self.transform_point(Point::new(rect.x(), rect.y() + rect.height())),
+            self.transform_point(Point::new(
+                rect.x() + rect.width(),
+                rect.y() + rect.height(),
+            )),
+        ];
+
+        let min = corners[1..]
+            .iter()
+            .fold(corners[0], |min, corner| min.min(*corner));
+        let max = corners[1..]
+            .iter()
+            .fold(corners[0], |max, corner| max.max(*corner));
+
+        Rectangle::new(min, (max.x() - min.x(), max.y() - min.y()))
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::identity()
+    }
+}
+
+/// Describes the transformation of a widget and its children.
+///
+/// The transform is applied around the `origin`, which is relative to the bounds of the widget. An origin of
+/// `(0.5, 0.5)` transforms around the center of the widget, `(0.0, 0.0)` around its top left corner.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RenderTransform {
+    /// The transformation matrix.
+    pub transform: Transform,
+
+    /// The relative origin of the transformation.
+    pub origin: Point,
+}
+
+impl RenderTransform {
+    /// Creates a new render transform around the center of the widget.
+    pub fn new(transform: Transform) -> Self {
+        RenderTransform {
+            transform,
+            ..RenderTransform::default()
+        }
+    }
+
+    /// Sets the relative origin of the transformation.
+    pub fn origin(mut self, x: f64, y: f64) -> Self {
+        self.origin = Point::new(x, y);
+        self
+    }
+
+    /// Returns `true` if the render transform does not change anything.
+    pub fn is_identity(&self) -> bool {
+        self.transform.is_identity()
+    }
+
+    /// Gets the transformation matrix for the given widget bounds.
+    pub fn matrix(&self, bounds: Rectangle) -> Transform {
+        if self.is_identity() {
+            return Transform::identity();
+        }
+
+        let x = bounds.x() + bounds.width() * self.origin.x();
+        let y = bounds.y() + bounds.height() * self.origin.y();
+
+        Transform::translation(-x, -y)
+            .then(&self.transform)
+            .then(&Transform::translation(x, y))
+    }
+}
+
+impl Default for RenderTransform {
+    fn default() -> Self {
+        RenderTransform {
+            transform: Transform::identity(),
+            origin: Point::new(0.5, 0.5),
+        }
+    }
+}
+
+impl From<Transform> for RenderTransform {
+    fn from(t: Transform) -> Self {
+        RenderTransform::new(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point_eq(left: Point, right: Point) {
+        assert!(
+            (left.x() - right.x()).abs() < 0.000_01,
+            "{:?} != {:?}",
+            left,
+            right
+        );
+        assert!(
+            (left.y() - right.y()).abs() < 0.000_01,
+            "{:?} != {:?}",
+            left,
+            right
+        );
+    }
+
+    #[test]
+    fn test_then() {
+        let transform = Transform::scaling(2.0, 3.0).translate(10.0, 20.0);
+        assert_point_eq(
+            transform.transform_point(Point::new(1.0, 1.0)),
+            Point::new(12.0, 23.0),
+        );
+
+        let transform = Transform::rotation(Angle::from_degrees(90.0));
+        assert_point_eq(
+            transform.transform_point(Point::new(1.0, 0.0)),
+            Point::new(0.0, 1.0),
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        let transform = Transform::rotation(Angle::from_degrees(30.0))
+            .scale(2.0, 0.5)
+            .skew(Angle::from_degrees(10.0), Angle::zero())
+            .translate(5.0, -7.0);
+        let inverse = transform.inverse().unwrap();
+        let point = Point::new(3.0, 4.0);
+
+        assert_point_eq(
+            inverse.transform_point(transform.transform_point(point)),
+            point,
+        );
+        assert!(Transform::scaling(0.0, 1.0).inverse().is_none());
+    }
+
+    #[test]
+    fn test_transform_rect() {
+        let rect = Rectangle::new((0.0, 0.0), (10.0, 20.0));
+        let transform = Transform::rotation(Angle::from_degrees(90.0));
+        let result = transform.transform_rect(rect);
+
+        assert_point_eq(result.position(), Point::new(-20.0, 0.0));
+        assert_point_eq(
+            Point::new(result.width(), result.height()),
+            Point::new(20.0, 10.0),
+        );
+    }
+
+    #[test]
+    fn test_matrix() {
+        let bounds = Rectangle::new((10.0, 10.0), (20.0, 20.0));
+        let render_transform = RenderTransform::new(Transform::scaling(2.0, 2.0));
+
+        // the center stays in place
+        assert_point_eq(
+            render_transform
+                .matrix(bounds)
+                .transform_point(Point::new(20.0, 20.0)),
+            Point::new(20.0, 20.0),
+        );
+        assert_point_eq(
+            render_transform
+                .origin(0.0, 0.0)
+                .matrix(bounds)
+                .transform_point(Point::new(20.0, 20.0)),
+            Point::new(30.0, 30.0),
+        );
+    }
+}
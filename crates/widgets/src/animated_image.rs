@@ -0,0 +1,225 @@
+use crate::{api::prelude::*, proc_macros::*, render::prelude::*};
+
+// Sent to the widget after the current frame was shown for its delay. It contains the playback generation to drop
+// timers of a previous playback.
+#[derive(Clone, Debug, PartialEq)]
+struct FrameElapsed(u64);
+
+/// Plays the `frames` of the `AnimatedImage`.
+#[derive(Default, AsAny)]
+pub struct AnimatedImageState {
+    frames: ImageFrames,
+    index: usize,
+    playing: bool,
+    generation: u64,
+}
+
+impl AnimatedImageState {
+    fn sync(&mut self, ctx: &mut Context) {
+        let frames_changed = ctx.widget().get::<ImageFrames>("frames") != &self.frames;
+        let playing = *ctx.widget().get::<bool>("playing");
+
+        if !frames_changed && playing == self.playing {
+            return;
+        }
+
+        if frames_changed {
+            self.frames = ctx.widget().clone("frames");
+            self.index = 0;
+            self.show(ctx);
+        }
+
+        // a new playback drops the timer of the previous one
+        self.generation += 1;
+        self.playing = playing;
+
+        if !self.playing {
+            return;
+        }
+
+        let looping = *ctx.widget().get::<bool>("looping");
+        let index = resume_index(self.index, self.frames.len(), looping);
+
+        if index != self.index {
+            self.index = index;
+            self.show(ctx);
+        }
+
+        self.schedule(ctx);
+    }
+
+    fn show(&self, ctx: &mut Context) {
+        let image = self
+            .frames
+            .frame(self.index)
+            .map(|frame| frame.image.clone())
+            .unwrap_or_default();
+
+        ctx.widget().set("image", image);
+    }
+
+    fn schedule(&self, ctx: &mut Context) {
+        if !self.frames.is_animated() {
+            return;
+        }
+
+        if let Some(frame) = self.frames.frame(self.index) {
+            ctx.message_adapter().send_message_delayed(
+                FrameElapsed(self.generation),
+                ctx.entity(),
+                frame.delay,
+            );
+        }
+    }
+
+    fn next_frame(&mut self, ctx: &mut Context) {
+        let looping = *ctx.widget().get::<bool>("looping");
+
+        match next_index(self.index, self.frames.len(), looping) {
+            Some(index) => {
+                self.index = index;
+                self.show(ctx);
+                self.schedule(ctx);
+            }
+            None => {
+                self.playing = false;
+                ctx.widget().set("playing", false);
+            }
+        }
+    }
+}
+
+// Gets the frame a started or resumed playback shows first. A paused playback continues with the current frame, only
+// a finished playback of an animation that does not loop starts again with the first frame.
+fn resume_index(index: usize, len: usize, looping: bool) -> usize {
+    if !looping && index > 0 && index + 1 >= len {
+        return 0;
+    }
+
+    index
+}
+
+// Gets the frame that follows the current frame. Returns `None` if the playback stops on the last frame.
+fn next_index(index: usize, len: usize, looping: bool) -> Option<usize> {
+    if index + 1 < len {
+        return Some(index + 1);
+    }
+
+    if looping {
+        Some(0)
+    } else {
+        None
+    }
+}
+
+impl State for AnimatedImageState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.sync(ctx);
+    }
+
+    fn messages(
+        &mut self,
+        mut messages: MessageReader,
+        _registry: &mut Registry,
+        ctx: &mut Context,
+    ) {
+        for FrameElapsed(generation) in messages.read::<FrameElapsed>() {
+            if generation == self.generation && self.playing {
+                self.next_frame(ctx);
+            }
+        }
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.sync(ctx);
+    }
+}
+
+widget!(
+    /// The `AnimatedImage` widget is used to play an animated image like a GIF or an APNG. It is not interactive.
+    ///
+    /// Each frame is shown for its delay. Only the widget itself is redrawn if the frame changes. The playback is
+    /// paused by setting `playing` to `false` and continues with the current frame if it is set to `true` again, a
+    /// finished playback of an animation that does not loop starts again with the first frame.
+    ///
+    /// **style:** `animated-image`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// AnimatedImage::new()
+    ///     .frames("path/to/animation.gif")
+    ///     .looping(false)
+    ///     .build(ctx)
+    /// ```
+    AnimatedImage<AnimatedImageState> {
+        /// Sets or shares the frames property. It contains the frames that are played.
+        ///
+        /// Set frames property:
+        /// * &str: `AnimatedImage::new().frames("path/to/animation.gif").build(ctx)`
+        /// * String: `AnimatedImage::new().frames(String::from()).build(ctx)`
+        /// * ImageFrames: `AnimatedImage::new().frames(ImageFrames::new(frames)).build(ctx)`
+        frames: ImageFrames,
+
+        /// Sets or shares the image property. It is the currently shown frame.
+        image: Image,
+
+        /// Sets or shares the playing property. If it is `true` the frames are played.
+        playing: bool,
+
+        /// Sets or shares the looping property. If it is `true` the playback starts again with the first frame after
+        /// the last frame, otherwise the playback stops on the last frame and `playing` is set to `false`.
+        looping: bool,
+
+        /// Sets or shares the stretch property. It describes how the frames are resized to fill the bounds of the
+        /// widget.
+        stretch: Stretch
+    }
+);
+
+impl Template for AnimatedImage {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("AnimatedImage")
+            .style("animated-image")
+            .frames(ImageFrames::default())
+            .image(Image::default())
+            .playing(true)
+            .looping(true)
+            .stretch(Stretch::None)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        ImageRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        FixedSizeLayout::new().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_and_resume() {
+        // a paused playback continues with the current frame, also on the last frame of a looping animation
+        assert_eq!(resume_index(1, 3, true), 1);
+        assert_eq!(resume_index(2, 3, true), 2);
+        assert_eq!(resume_index(1, 3, false), 1);
+
+        // a finished playback starts again with the first frame
+        assert_eq!(resume_index(2, 3, false), 0);
+        assert_eq!(resume_index(0, 1, false), 0);
+    }
+
+    #[test]
+    fn test_next_frame() {
+        assert_eq!(next_index(0, 3, false), Some(1));
+        assert_eq!(next_index(2, 3, true), Some(0));
+
+        // a playback that does not loop stops on the last frame
+        assert_eq!(next_index(2, 3, false), None);
+        assert_eq!(next_index(0, 1, false), None);
+    }
+}
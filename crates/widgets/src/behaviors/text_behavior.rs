@@ -1,7 +1,7 @@
 use crate::{
     api::prelude::*,
     proc_macros::*,
-    render::TextMetrics,
+    render::{TextCluster, TextMetrics},
     shell::prelude::{Key, KeyEvent},
     theme_default::fonts,
     Cursor, TextBlock,
@@ -111,7 +111,7 @@ impl TextBehaviorState {
         let mut text = String16::from(ctx.get_widget(self.target).clone::<String>("text"));
         text.insert_str(selection.start(), insert_text.as_str());
 
-        selection.set(selection.start() + insert_text.chars().count());
+        selection.set(selection.start() + insert_text.encode_utf16().count());
         self.set_selection(ctx, selection);
 
         self.update_selection = true;
@@ -138,13 +138,14 @@ impl TextBehaviorState {
             return;
         }
 
-        selection.set(selection.start() - 1);
+        // removes the complete cluster in front of the cursor
+        let end = selection.start();
+        let start = previous_boundary(&self.clusters(ctx), end);
+        selection.set(start);
 
         let mut text = String16::from(ctx.get_widget(self.target).clone::<String>("text"));
 
-        let removed_width = self
-            .measure(ctx, selection.start(), selection.start() + 1)
-            .width;
+        let removed_width = self.measure(ctx, start, end).width;
 
         let mut offset = *Cursor::offset_ref(&ctx.get_widget(self.cursor));
         offset = (offset + removed_width).min(0.);
@@ -152,7 +153,9 @@ impl TextBehaviorState {
         Cursor::offset_set(&mut ctx.get_widget(self.cursor), offset);
         TextBlock::offset_set(&mut ctx.get_widget(self.text_block), offset);
 
-        text.remove(selection.start());
+        for i in (start..end).rev() {
+            text.remove(i);
+        }
 
         self.set_text(ctx, text.to_string());
         self.set_selection(ctx, selection);
@@ -177,7 +180,12 @@ impl TextBehaviorState {
 
         let mut text = String16::from(ctx.get_widget(self.target).clone::<String>("text"));
 
-        text.remove(selection.start());
+        // removes the complete cluster behind the cursor
+        let end = next_boundary(&self.clusters(ctx), selection.start(), len);
+
+        for i in (selection.start()..end).rev() {
+            text.remove(i);
+        }
 
         self.set_text(ctx, text.to_string());
     }
@@ -226,13 +234,15 @@ impl TextBehaviorState {
         let selection = self.selection(ctx);
         let (start, end) = self.selection_start_end(selection);
 
-        let cursor_start_measure = self.measure(ctx, 0, selection.start());
-        Cursor::cursor_x_set(&mut ctx.get_widget(self.cursor), cursor_start_measure.width);
+        let clusters = self.clusters(ctx);
+
+        let cursor_x = cursor_position(&clusters, selection.start());
+        Cursor::cursor_x_set(&mut ctx.get_widget(self.cursor), cursor_x);
 
-        let start_measure = self.measure(ctx, 0, start);
-        Cursor::selection_x_set(&mut ctx.get_widget(self.cursor), start_measure.width);
-        let length_measure = self.measure(ctx, start, end);
-        Cursor::selection_width_set(&mut ctx.get_widget(self.cursor), length_measure.width);
+        let start_x = cursor_position(&clusters, start);
+        let end_x = cursor_position(&clusters, end);
+        Cursor::selection_x_set(&mut ctx.get_widget(self.cursor), start_x.min(end_x));
+        Cursor::selection_width_set(&mut ctx.get_widget(self.cursor), (end_x - start_x).abs());
 
         if self.direction == Direction::None {
             return;
@@ -243,14 +253,14 @@ impl TextBehaviorState {
         let width = Cursor::bounds_ref(&ctx.get_widget(self.cursor)).width();
         let delta = width - offset;
 
-        if self.direction == Direction::Right && cursor_start_measure.width > delta {
-            let offset_delta = delta - cursor_start_measure.width;
+        if self.direction == Direction::Right && cursor_x > delta {
+            let offset_delta = delta - cursor_x;
             Cursor::offset_set(&mut ctx.get_widget(self.cursor), offset + offset_delta);
             TextBlock::offset_set(&mut ctx.get_widget(self.text_block), offset + offset_delta);
         }
 
-        if self.direction == Direction::Left && cursor_start_measure.width + offset < 0. {
-            let offset_delta = cursor_start_measure.width + offset;
+        if self.direction == Direction::Left && cursor_x + offset < 0. {
+            let offset_delta = cursor_x + offset;
             Cursor::offset_set(&mut ctx.get_widget(self.cursor), offset - offset_delta);
             TextBlock::offset_set(&mut ctx.get_widget(self.text_block), offset - offset_delta);
         }
@@ -276,7 +286,7 @@ impl TextBehaviorState {
         self.direction = Direction::Left;
         let mut selection = self.selection(ctx);
         if selection.start() as i32 > 0 {
-            selection.set_start(selection.start() - 1);
+            selection.set_start(previous_boundary(&self.clusters(ctx), selection.start()));
         }
         self.set_selection(ctx, selection);
     }
@@ -284,22 +294,26 @@ impl TextBehaviorState {
     fn expand_selection_right(&mut self, ctx: &mut Context) {
         self.direction = Direction::Right;
         let mut selection = self.selection(ctx);
-        if selection.start() < self.len(ctx) {
-            selection.set_start(selection.start() + 1);
+        let len = self.len(ctx);
+        if selection.start() < len {
+            selection.set_start(next_boundary(&self.clusters(ctx), selection.start(), len));
         }
         self.set_selection(ctx, selection);
     }
 
     fn move_selection_left(&mut self, ctx: &mut Context) {
         self.direction = Direction::Left;
-        let selection = move_selection_left(self.selection(ctx));
-        self.set_selection(ctx, selection);
+        let selection = self.selection(ctx);
+        let previous = previous_boundary(&self.clusters(ctx), selection.start());
+        self.set_selection(ctx, move_selection_left(selection, previous));
     }
 
     fn move_selection_right(&mut self, ctx: &mut Context) {
         self.direction = Direction::Right;
-        let selection = move_selection_right(self.selection(ctx), self.len(ctx));
-        self.set_selection(ctx, selection);
+        let selection = self.selection(ctx);
+        let len = self.len(ctx);
+        let next = next_boundary(&self.clusters(ctx), selection.start(), len);
+        self.set_selection(ctx, move_selection_right(selection, next));
     }
 
     // -- Selection --
@@ -488,7 +502,7 @@ impl TextBehaviorState {
     // gets the len of the text
     fn len(&self, ctx: &mut Context) -> usize {
         TextBlock::text_ref(&ctx.get_widget(self.text_block))
-            .chars()
+            .encode_utf16()
             .count()
     }
 
@@ -552,30 +566,42 @@ impl TextBehaviorState {
         0
     }
 
-    // Returns a vector with a tuple of each char's starting index (usize) and position (f64)
+    // Returns a vector with a tuple of each cluster boundary index (usize) and position (f64)
     fn map_chars_index_to_position(&self, ctx: &mut Context) -> Vec<(usize, f64)> {
-        let len = self.len(ctx);
+        let clusters = self.clusters(ctx);
 
         // start x position of the cursor is start position of the text element + padding left
         let start_position: f64 = ctx.widget().get::<Point>("position").x()
             + ctx.get_widget(self.target).get::<Thickness>("padding").left
             + *TextBlock::offset_ref(&ctx.get_widget(self.text_block));
 
-        // array which will hold char index and it's x position
-        let mut position_index: Vec<(usize, f64)> = Vec::with_capacity(len);
-        position_index.push((0, start_position));
-
-        for i in 0..len {
-            let bound_width: f64 = self.measure(ctx, 0, i + 1).width;
-
-            let next_position: f64 = start_position + bound_width;
+        // array which will hold the boundary index and it's x position
+        let mut position_index: Vec<(usize, f64)> = Vec::with_capacity(clusters.len() + 1);
+        position_index.push((0, start_position + cursor_position(&clusters, 0)));
 
-            position_index.push((i + 1, next_position));
+        for cluster in &clusters {
+            position_index.push((
+                cluster.end,
+                start_position + cursor_position(&clusters, cluster.end),
+            ));
         }
 
         position_index
     }
 
+    // gets the clusters of the shaped text, the start and end of the clusters are char indices
+    fn clusters(&self, ctx: &mut Context) -> Vec<TextCluster> {
+        let font = TextBehavior::font_clone(&ctx.widget());
+        let font_size = *TextBehavior::font_size_ref(&ctx.widget());
+        let text = TextBlock::text_clone(&ctx.get_widget(self.text_block));
+
+        let clusters = ctx
+            .render_context_2_d()
+            .measure_clusters(text.as_str(), font_size, &font);
+
+        utf16_clusters(text.as_str(), clusters)
+    }
+
     // measure text part
     fn measure(&self, ctx: &mut Context, start: usize, end: usize) -> TextMetrics {
         let font = TextBehavior::font_clone(&ctx.widget());
@@ -587,7 +613,7 @@ impl TextBehaviorState {
         {
             return ctx
                 .render_context_2_d()
-                .measure(text_part.as_str(), font_size, font);
+                .measure(text_part.as_str(), font_size, &font);
         }
 
         TextMetrics::default()
@@ -890,12 +916,13 @@ impl Template for TextBehavior {
 
 // --- Helpers --
 
-fn move_selection_left(mut selection: TextSelection) -> TextSelection {
+// Moves the selection to the left. `previous` is the cluster boundary in front of the selection start.
+fn move_selection_left(mut selection: TextSelection, previous: usize) -> TextSelection {
     match selection.start().cmp(&selection.end()) {
         std::cmp::Ordering::Less => selection.set_end(selection.start()),
         std::cmp::Ordering::Equal => {
             if selection.start() as i32 > 0 {
-                selection.set(selection.start() - 1);
+                selection.set(previous);
             }
         }
         std::cmp::Ordering::Greater => selection.set_start(selection.end()),
@@ -904,12 +931,13 @@ fn move_selection_left(mut selection: TextSelection) -> TextSelection {
     selection
 }
 
-fn move_selection_right(mut selection: TextSelection, len: usize) -> TextSelection {
+// Moves the selection to the right. `next` is the cluster boundary behind the selection start.
+fn move_selection_right(mut selection: TextSelection, next: usize) -> TextSelection {
     match selection.start().cmp(&selection.end()) {
         std::cmp::Ordering::Less => selection.set_start(selection.end()),
         std::cmp::Ordering::Equal => {
-            if selection.start() < len {
-                selection.set(selection.start() + 1);
+            if selection.start() < next {
+                selection.set(next);
             }
         }
         std::cmp::Ordering::Greater => selection.set_end(selection.start()),
@@ -918,6 +946,70 @@ fn move_selection_right(mut selection: TextSelection, len: usize) -> TextSelecti
     selection
 }
 
+// Converts the byte indices of the clusters to the UTF-16 indices used by the selection of the text.
+fn utf16_clusters(text: &str, clusters: Vec<TextCluster>) -> Vec<TextCluster> {
+    clusters
+        .into_iter()
+        .map(|c| TextCluster {
+            start: text[..c.start].encode_utf16().count(),
+            end: text[..c.end].encode_utf16().count(),
+            ..c
+        })
+        .collect()
+}
+
+// Gets the x position of the cursor in front of the char with the given index. In a right-to-left run the
+// cursor is placed on the right side of the cluster.
+fn cursor_position(clusters: &[TextCluster], index: usize) -> f64 {
+    if let Some(cluster) = clusters.iter().find(|c| c.start == index) {
+        return if cluster.rtl {
+            cluster.x + cluster.width
+        } else {
+            cluster.x
+        };
+    }
+
+    // the cursor is placed behind the last cluster
+    if let Some(cluster) = clusters.iter().find(|c| c.start < index && index <= c.end) {
+        return if cluster.rtl {
+            cluster.x
+        } else {
+            cluster.x + cluster.width
+        };
+    }
+
+    0.0
+}
+
+// Gets the cluster boundary in front of the given index.
+fn previous_boundary(clusters: &[TextCluster], index: usize) -> usize {
+    if clusters.is_empty() {
+        return index.saturating_sub(1);
+    }
+
+    clusters
+        .iter()
+        .map(|c| c.start)
+        .filter(|start| *start < index)
+        .max()
+        .unwrap_or(0)
+}
+
+// Gets the cluster boundary behind the given index.
+fn next_boundary(clusters: &[TextCluster], index: usize, len: usize) -> usize {
+    if clusters.is_empty() {
+        return (index + 1).min(len);
+    }
+
+    clusters
+        .iter()
+        .map(|c| c.end)
+        .filter(|end| *end > index)
+        .min()
+        .unwrap_or(len)
+        .min(len)
+}
+
 // --- Helpers --
 
 #[cfg(test)]
@@ -928,25 +1020,25 @@ mod tests {
     fn test_move_selection_left() {
         //  check left bounds
         let selection = TextSelection::new(0, 0);
-        let result = move_selection_left(selection);
+        let result = move_selection_left(selection, 0);
         assert_eq!(result.start(), 0);
         assert_eq!(result.end(), 0);
 
         // start == end
         let selection = TextSelection::new(1, 1);
-        let result = move_selection_left(selection);
+        let result = move_selection_left(selection, 0);
         assert_eq!(result.start(), 0);
         assert_eq!(result.end(), 0);
 
         // start < end
         let selection = TextSelection::new(4, 6);
-        let result = move_selection_left(selection);
+        let result = move_selection_left(selection, 3);
         assert_eq!(result.start(), 4);
         assert_eq!(result.end(), 4);
 
         // start > end
         let selection = TextSelection::new(6, 4);
-        let result = move_selection_left(selection);
+        let result = move_selection_left(selection, 5);
         assert_eq!(result.start(), 4);
         assert_eq!(result.end(), 4);
     }
@@ -955,28 +1047,105 @@ mod tests {
     fn test_move_selection_right() {
         //  check left bounds
         let selection = TextSelection::new(4, 4);
-        let len = 5;
-        let result = move_selection_right(selection, len);
+        let result = move_selection_right(selection, 5);
         assert_eq!(result.start(), 5);
         assert_eq!(result.end(), 5);
 
         // start == end
         let selection = TextSelection::new(3, 3);
-        let len = 5;
-        let result = move_selection_right(selection, len);
+        let result = move_selection_right(selection, 4);
         assert_eq!(result.start(), 4);
         assert_eq!(result.end(), 4);
 
         // start < end
         let selection = TextSelection::new(4, 6);
-        let result = move_selection_right(selection, len);
+        let result = move_selection_right(selection, 5);
         assert_eq!(result.start(), 6);
         assert_eq!(result.end(), 6);
 
         // start > end
         let selection = TextSelection::new(6, 4);
-        let result = move_selection_right(selection, len);
+        let result = move_selection_right(selection, 7);
         assert_eq!(result.start(), 6);
         assert_eq!(result.end(), 6);
     }
+
+    fn cluster(start: usize, end: usize, x: f64, width: f64, rtl: bool) -> TextCluster {
+        TextCluster {
+            start,
+            end,
+            x,
+            width,
+            rtl,
+        }
+    }
+
+    #[test]
+    fn test_utf16_clusters() {
+        let clusters = utf16_clusters(
+            "añb",
+            vec![
+                cluster(0, 1, 0.0, 5.0, false),
+                cluster(1, 3, 5.0, 5.0, false),
+                cluster(3, 4, 10.0, 5.0, false),
+            ],
+        );
+
+        assert_eq!(clusters[1].start, 1);
+        assert_eq!(clusters[1].end, 2);
+        assert_eq!(clusters[2].start, 2);
+        assert_eq!(clusters[2].end, 3);
+
+        // a char outside of the basic multilingual plane is encoded as surrogate pair
+        let clusters = utf16_clusters(
+            "a\u{1f600}b",
+            vec![
+                cluster(0, 1, 0.0, 5.0, false),
+                cluster(1, 5, 5.0, 10.0, false),
+                cluster(5, 6, 15.0, 5.0, false),
+            ],
+        );
+
+        assert_eq!(clusters[1].start, 1);
+        assert_eq!(clusters[1].end, 3);
+        assert_eq!(clusters[2].start, 3);
+        assert_eq!(clusters[2].end, 4);
+    }
+
+    #[test]
+    fn test_cursor_position() {
+        // "ab" followed by a right-to-left run of two clusters
+        let clusters = vec![
+            cluster(0, 1, 0.0, 5.0, false),
+            cluster(1, 2, 5.0, 5.0, false),
+            cluster(2, 3, 16.0, 6.0, true),
+            cluster(3, 4, 10.0, 6.0, true),
+        ];
+
+        assert!(cursor_position(&clusters, 0).abs() < f64::EPSILON);
+        assert!((cursor_position(&clusters, 1) - 5.0).abs() < f64::EPSILON);
+        assert!((cursor_position(&clusters, 2) - 22.0).abs() < f64::EPSILON);
+        assert!((cursor_position(&clusters, 3) - 16.0).abs() < f64::EPSILON);
+        assert!((cursor_position(&clusters, 4) - 10.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_boundaries() {
+        // the second cluster contains a char with a combining mark
+        let clusters = vec![
+            cluster(0, 1, 0.0, 5.0, false),
+            cluster(1, 3, 5.0, 5.0, false),
+            cluster(3, 4, 10.0, 5.0, false),
+        ];
+
+        assert_eq!(previous_boundary(&clusters, 3), 1);
+        assert_eq!(previous_boundary(&clusters, 0), 0);
+        assert_eq!(next_boundary(&clusters, 1, 4), 3);
+        assert_eq!(next_boundary(&clusters, 4, 4), 4);
+
+        // without clusters each char is a boundary
+        assert_eq!(previous_boundary(&[], 3), 2);
+        assert_eq!(next_boundary(&[], 3, 4), 4);
+        assert_eq!(next_boundary(&[], 4, 4), 4);
+    }
 }
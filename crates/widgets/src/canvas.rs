@@ -1,19 +1,135 @@
 use crate::{api::prelude::*, proc_macros::*};
 
 widget!(
-    /// Canvas is used to render 3D graphics.
+    /// Canvas is used to render 3D graphics with a render pipeline or vector graphics with a 2D render pipeline.
+    ///
+    /// The 2D render pipeline draws with the same API as the built-in render objects. It is redrawn if the
+    /// `draw_version` is changed.
+    ///
+    /// **style:** `canvas-three`
     Canvas {
         /// Sets or shares the render pipeline.
-        render_pipeline: DefaultRenderPipeline
+        render_pipeline: DefaultRenderPipeline,
+
+        /// Sets or shares the 2D render pipeline.
+        render_pipeline_2_d: DefaultRenderPipeline2D,
+
+        /// Sets or shares the draw version. Change it to redraw the 2D render pipeline.
+        draw_version: usize
     }
 );
 
 impl Template for Canvas {
     fn template(self, _: Entity, _: &mut BuildContext) -> Self {
-        self.name("Canvas").style("canvas-three")
+        self.name("Canvas")
+            .style("canvas-three")
+            .render_pipeline(DefaultRenderPipeline::default())
+            .render_pipeline_2_d(DefaultRenderPipeline2D::default())
+            .draw_version(0)
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
         PipelineRenderObject.into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        render::{Pipeline2DTrait, PlatformBackend, RenderPipeline2D},
+        shell::{
+            event::{ButtonState, MouseButton, MouseEvent},
+            window_adapter::WindowAdapter as _,
+        },
+        test_utils::TestWindow,
+        Window,
+    };
+    use std::any::Any;
+
+    const COLOR: &str = "#123456";
+
+    #[derive(Clone, PartialEq, Pipeline2D)]
+    struct RectPipeline;
+
+    impl RenderPipeline2D for RectPipeline {
+        fn draw(&self, render_context: &mut PlatformBackend, size: Size) {
+            render_context.set_fill_style(Brush::from(COLOR));
+            render_context.fill_rect(0.0, 0.0, size.width(), size.height());
+        }
+    }
+
+    struct Redraw;
+
+    // increments the draw version of the canvas if the canvas is clicked
+    #[derive(Default, AsAny)]
+    struct CanvasViewState;
+
+    impl State for CanvasViewState {
+        fn messages(&mut self, mut messages: MessageReader, _: &mut Registry, ctx: &mut Context) {
+            for _ in messages.read::<Redraw>() {
+                let mut canvas = ctx.child("canvas");
+                let draw_version = *canvas.get::<usize>("draw_version") + 1;
+                canvas.set("draw_version", draw_version);
+            }
+        }
+    }
+
+    widget!(CanvasView<CanvasViewState>);
+
+    impl Template for CanvasView {
+        fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+            self.child(
+                Canvas::new()
+                    .id("canvas")
+                    .render_pipeline_2_d(DefaultRenderPipeline2D(Box::new(RectPipeline)))
+                    .on_mouse_down(move |states, _| {
+                        states.send_message(Redraw, id);
+                        true
+                    })
+                    .build(ctx),
+            )
+        }
+    }
+
+    // Gets the number of times the pipeline was drawn.
+    fn draws(window: &TestWindow) -> usize {
+        let brush = Brush::from(COLOR);
+
+        window
+            .backend
+            .fills
+            .iter()
+            .filter(|(rect, fill)| {
+                *rect == Rectangle::new((0.0, 0.0), (100.0, 50.0)) && *fill == brush
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_draw_version() {
+        let mut window = TestWindow::new(|ctx| {
+            Window::new()
+                .size(100.0, 50.0)
+                .child(CanvasView::new().build(ctx))
+                .build(ctx)
+        });
+
+        // the pipeline is drawn with the size of the canvas
+        window.run();
+        assert_eq!(draws(&window), 1);
+
+        // the pipeline is not redrawn without a new draw version
+        window.run();
+        assert_eq!(draws(&window), 1);
+
+        window.adapter.mouse(50.0, 25.0);
+        window.adapter.mouse_event(MouseEvent {
+            position: Point::new(50.0, 25.0),
+            button: MouseButton::Left,
+            state: ButtonState::Down,
+        });
+        window.run();
+        assert_eq!(draws(&window), 2);
+    }
+}
@@ -10,7 +10,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -18,6 +18,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
@@ -66,6 +72,8 @@ impl Template for CheckBox {
             .border_radius(2.0)
             .border_width(0.0)
             .border_brush("transparent")
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
             .padding((8.0, 0.0, 8.0, 0.0))
             .foreground(colors::LINK_WATER_COLOR)
             .text("")
@@ -97,6 +105,8 @@ impl Template for CheckBox {
                                             .border_radius(id)
                                             .border_width(id)
                                             .border_brush(id)
+                                            .border_brushes(id)
+                                            .border_style(id)
                                             .padding(id)
                                             .opacity(id)
                                             .child(
@@ -119,7 +119,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -127,6 +127,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the foreground property.
         foreground: Brush,
 
@@ -188,6 +194,8 @@ impl Template for ComboBoxItem {
             .border_radius(0)
             .border_width(0)
             .border_brush("transparent")
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
             .foreground(colors::LINK_WATER_COLOR)
             .font_size(32)
             .font("Roboto-Regular")
@@ -334,7 +342,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -342,6 +350,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the foreground property.
         foreground: Brush,
 
@@ -407,6 +421,8 @@ impl Template for ComboBox {
             .border_radius(id)
             .border_width(id)
             .border_brush(id)
+            .border_brushes(id)
+            .border_style(id)
             .padding(id)
             .child(
                 Grid::new()
@@ -469,6 +485,8 @@ impl Template for ComboBox {
             .min_width(80.0)
             .selected(false)
             .selected_index(-1)
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
             .child(
                 MouseBehavior::new()
                     .pressed(id)
@@ -6,8 +6,11 @@ widget!(
         /// Sets or shares the background property.
         background: Brush,
 
-        /// Sets or shares the border radius property.
-        border_radius: f64,
+        /// Sets or shares the border radius property. The corners could have different radii.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the box shadow property.
+        box_shadow: BoxShadow,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -15,6 +18,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the padding property.
         padding: Thickness
     }
@@ -28,6 +37,9 @@ impl Template for Container {
             .border_radius(0.0)
             .border_width(0.0)
             .border_brush("transparent")
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
+            .box_shadow(BoxShadow::default())
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
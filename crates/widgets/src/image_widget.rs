@@ -1,23 +1,122 @@
 use crate::{api::prelude::*, proc_macros::*, render::prelude::*};
 
+/// Requests the image of the `source` property from the `ImageCache` and updates the `image` property with it.
+#[derive(Default, AsAny)]
+pub struct ImageWidgetState {
+    source: String,
+}
+
+impl ImageWidgetState {
+    fn load(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        let source = ctx.widget().clone_or_default::<String>("source");
+
+        if source == self.source {
+            return;
+        }
+
+        self.source = source;
+
+        if self.source.is_empty() {
+            return;
+        }
+
+        let state = registry
+            .get::<ImageCache>("image_cache")
+            .load(self.source.as_str(), ctx.entity());
+        self.show(state, ctx);
+    }
+
+    fn show(&self, state: ImageLoadState, ctx: &mut Context) {
+        let image = match state {
+            ImageLoadState::Loading => ctx.widget().clone_or_default::<Image>("placeholder"),
+            ImageLoadState::Loaded(image) => image,
+            ImageLoadState::Failed(_) => ctx.widget().clone_or_default::<Image>("error_image"),
+        };
+
+        ctx.widget().set("image", image);
+    }
+}
+
+impl State for ImageWidgetState {
+    fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        self.load(registry, ctx);
+    }
+
+    fn messages(
+        &mut self,
+        mut messages: MessageReader,
+        registry: &mut Registry,
+        ctx: &mut Context,
+    ) {
+        for ImageLoaded(source) in messages.read::<ImageLoaded>() {
+            // the source could be changed while the image was loaded
+            if source != self.source {
+                continue;
+            }
+
+            if let Some(state) = registry.get::<ImageCache>("image_cache").get(&source) {
+                self.show(state, ctx);
+            }
+        }
+    }
+
+    fn update(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        self.load(registry, ctx);
+    }
+}
+
 widget!(
     /// The `ImageWidget` widget is used to draw an image. It is not interactive.
     ///
+    /// By default the image is drawn in its natural size. With `stretch` or `nine_slice` the widget is sized by its
+    /// constraint and alignment and the image is resized to fill it.
+    ///
+    /// With the `source` property the image is loaded in the background by the `ImageCache` service and shared with all
+    /// widgets with the same source. The `placeholder` is shown while the image is loaded, the `error_image` if it
+    /// could not be loaded.
+    ///
     /// **style:** `image-widget`
-    ImageWidget {
+    ImageWidget<ImageWidgetState> {
         /// Sets or shares the image property.
         ///
         /// Set image property:
         /// * &str: `Image::new().image("path/to/image.png").build(xt)`
+        /// * &str: `Image::new().image("path/to/image.svg").build(xt)`, SVG documents are rasterized in the drawn size
         /// * String: `Image::new().image(String::from()).build(xt)`
         /// * (width: u32, height: u32, data: Vec<u32>): `Image::new().image((width, height, vec![0; width * height]));`
-        image: Image
+        /// * RenderTarget: `Image::new().image(render_target);`
+        image: Image,
+
+        /// Sets or shares the source property. It is the path of an image that is loaded in the background and set as
+        /// `image`.
+        source: String,
+
+        /// Sets or shares the placeholder property. It is shown while the image of the `source` is loaded.
+        placeholder: Image,
+
+        /// Sets or shares the error image property. It is shown if the image of the `source` could not be loaded.
+        error_image: Image,
+
+        /// Sets or shares the stretch property. It describes how the image is resized to fill the bounds of the
+        /// widget. The image is aligned inside of the bounds by `h_align` and `v_align`.
+        stretch: Stretch,
+
+        /// Sets or shares the nine slice property. If it is set, the image is split by the given insets in pixels into
+        /// nine parts. The corners keep their size, the edges and the center are stretched to fill the bounds.
+        nine_slice: Thickness
     }
 );
 
 impl Template for ImageWidget {
     fn template(self, _: Entity, _: &mut BuildContext) -> Self {
-        self.name("ImageWidget").style("image-widget").image("")
+        self.name("ImageWidget")
+            .style("image-widget")
+            .image("")
+            .source("")
+            .placeholder(Image::default())
+            .error_image(Image::default())
+            .stretch(Stretch::None)
+            .nine_slice(0)
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
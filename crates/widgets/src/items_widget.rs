@@ -50,7 +50,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -58,6 +58,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
@@ -90,6 +96,8 @@ impl Template for ItemsWidget {
             .border_radius(2.0)
             .border_width(1.0)
             .border_brush(colors::BOMBAY_COLOR)
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
             .padding(2.0)
             .orientation("vertical")
             .child(Stack::new().id("items_panel").orientation(id).build(ctx))
@@ -10,6 +10,7 @@ pub(crate) use orbtk_render as render;
 pub(crate) use orbtk_shell as shell;
 pub(crate) use orbtk_theme_default as theme_default;
 
+pub use self::animated_image::*;
 pub use self::button::*;
 pub use self::canvas::*;
 pub use self::check_box::*;
@@ -32,14 +33,18 @@ pub use self::scroll_indicator::*;
 pub use self::scroll_viewer::*;
 pub use self::slider::*;
 pub use self::stack::*;
+pub use self::svg_icon::*;
 pub use self::switch::*;
 pub use self::tab_widget::*;
 pub use self::text_block::*;
 pub use self::text_box::*;
+pub use self::title_bar::*;
 pub use self::toggle_button::*;
 pub use self::window::*;
+pub use self::window_chrome::*;
 
 pub mod behaviors;
+mod animated_image;
 mod button;
 mod canvas;
 mod check_box;
@@ -62,9 +67,14 @@ mod scroll_indicator;
 mod scroll_viewer;
 mod slider;
 mod stack;
+mod svg_icon;
 mod switch;
 mod tab_widget;
 mod text_block;
 mod text_box;
+#[cfg(test)]
+mod test_utils;
+mod title_bar;
 mod toggle_button;
 mod window;
+mod window_chrome;
@@ -211,7 +211,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -219,6 +219,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the foreground property.
         foreground: Brush,
 
@@ -258,6 +264,8 @@ impl Template for ListViewItem {
             .border_radius(0.0)
             .border_width(0.0)
             .border_brush("transparent")
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
             .foreground(colors::LINK_WATER_COLOR)
             .font_size(32.0)
             .font("Roboto-Regular")
@@ -292,7 +300,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -300,6 +308,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
@@ -353,6 +367,8 @@ impl Template for ListView {
             .border_radius(2.0)
             .border_width(1.0)
             .border_brush(colors::BOMBAY_COLOR)
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
             .padding(2.0)
             .selection_mode("single")
             .selected_indices(HashSet::new())
@@ -364,6 +380,8 @@ impl Template for ListView {
                     .border_radius(id)
                     .border_width(id)
                     .border_brush(id)
+                    .border_brushes(id)
+                    .border_style(id)
                     .padding(id)
                     .opacity(id)
                     .child(scroll_viewer)
@@ -177,11 +177,17 @@ widget!(
         /// Sets or shares the border color property
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the border width property
         border_width: Thickness,
 
         /// Sets or shares the border radius property
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the focused property
         focused: bool,
@@ -230,6 +236,8 @@ impl Template for NumericBox {
             .background("transparent")
             .foreground(colors::LINK_WATER_COLOR)
             .border_brush("#647b91")
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
             .border_width(1.0)
             .border_radius(3.0)
             .focused(false)
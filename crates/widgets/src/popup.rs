@@ -52,7 +52,10 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
+
+        /// Sets or shares the box shadow property.
+        box_shadow: BoxShadow,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -60,6 +63,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
@@ -81,6 +90,9 @@ impl Template for Popup {
             .border_radius(0.0)
             .border_width(0.0)
             .border_brush("transparent")
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
+            .box_shadow(BoxShadow::default())
             .on_mouse_down(|_, _| true)
     }
 
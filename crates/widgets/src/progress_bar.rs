@@ -85,13 +85,19 @@ widget!(
         indicator_background: Brush,
 
         /// Defines the border radius of the indicator.
-        indicator_border_radius: f64,
+        indicator_border_radius: CornerRadius,
 
         /// Sets or shares the border color property
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the border radius property
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border width property
         border_width: Thickness,
@@ -112,6 +118,8 @@ impl Template for ProgressBar {
             .background("#000000")
             .indicator_background("#EFD035")
             .border_brush("#BABABA")
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
             .border_radius(4)
             .indicator_border_radius(4)
             .border_width(1)
@@ -179,7 +179,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -187,6 +187,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Indicates if the widget is hovered by the mouse cursor.
         hover: bool,
 
@@ -210,6 +216,8 @@ impl Template for Slider {
             .val(0.0)
             .height(24.0)
             .border_radius(2.0)
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
             .container_margin((0, 11, 0, 11))
             .accent_margin((0, 11, 0, 11))
             .child(
@@ -226,6 +234,8 @@ impl Template for Slider {
                                     .border_radius(id)
                                     .background(id)
                                     .border_brush(id)
+                                    .border_brushes(id)
+                                    .border_style(id)
                                     .border_width(id)
                                     .build(ctx),
                             )
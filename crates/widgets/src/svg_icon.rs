@@ -0,0 +1,80 @@
+use crate::{api::prelude::*, proc_macros::*, render::prelude::*, theme_default::prelude::*};
+
+/// Resolves the `icon` of the `SvgIcon` to its SVG document.
+#[derive(Default, AsAny)]
+pub struct SvgIconState {
+    icon: String,
+}
+
+impl SvgIconState {
+    fn load_svg(&mut self, ctx: &mut Context) {
+        let icon = ctx.widget().clone_or_default::<String>("icon");
+
+        if icon == self.icon {
+            return;
+        }
+
+        // an icon registered in the theme is preferred to a file with the same path
+        let svg = match ctx.theme.svgs().get(&icon) {
+            Some(bytes) => Svg::from_bytes(bytes),
+            None if icon.is_empty() => Ok(Svg::default()),
+            None => Svg::from_path(icon.as_str()),
+        }
+        .unwrap_or_else(|err| {
+            // an icon that could not be loaded is not drawn
+            crate::shell::CONSOLE.log(format!("Could not load icon {}: {}", icon, err));
+            Svg::default()
+        });
+
+        ctx.widget().set("svg", svg);
+        self.icon = icon;
+    }
+}
+
+impl State for SvgIconState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.load_svg(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.load_svg(ctx);
+    }
+}
+
+widget!(
+    /// The `SvgIcon` widget is used to draw a SVG document as icon. It is not interactive.
+    ///
+    /// The document is rasterized in the size it is drawn with and filled with the `foreground` brush. Without a
+    /// width and height the icon is drawn in the natural size of the document.
+    ///
+    /// **style:** `svg-icon`
+    SvgIcon<SvgIconState> {
+        /// Sets or shares the icon property. It is the key of a SVG document registered in the theme or the path to
+        /// a SVG file.
+        icon: String,
+
+        /// Sets or shares the svg property. It is set from the `icon` property.
+        svg: Svg,
+
+        /// Sets or shares the foreground property. The icon is filled with it, if it is a solid color.
+        foreground: Brush
+    }
+);
+
+impl Template for SvgIcon {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("SvgIcon")
+            .style("svg-icon")
+            .icon("")
+            .svg(Svg::default())
+            .foreground(colors::LINK_WATER_COLOR)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        SvgRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        FixedSizeLayout::new().into()
+    }
+}
@@ -88,7 +88,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -96,6 +96,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
@@ -123,6 +129,8 @@ impl Template for Switch {
             .height(30.0)
             .border_radius(8.0)
             .border_width(1.0)
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
             .padding(4.0)
             .container_margin((2, 8))
             .child(
@@ -140,6 +148,8 @@ impl Template for Switch {
                             .opacity(id)
                             .background(id)
                             .border_brush(id)
+                            .border_brushes(id)
+                            .border_style(id)
                             .border_width(id)
                             .border_radius(id)
                             .margin(("container_margin", id))
@@ -66,7 +66,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -74,6 +74,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
@@ -165,6 +171,8 @@ impl Template for TabHeader {
             .border_radius(4)
             .border_width(0)
             .border_brush("transparent")
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
             .padding((16, 0, 16, 0))
             .foreground(colors::LINK_WATER_COLOR)
             .text("Unnamed Tab")
@@ -509,7 +517,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -517,6 +525,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the padding property.
         padding: Thickness
     }
@@ -541,27 +555,33 @@ impl TabWidget {
 
 impl Template for TabWidget {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
-        self.name("TabWidget").style("tab_widget").child(
-            Grid::new()
-                .rows("34, *")
-                .child(
-                    Stack::new()
-                        .id(HEADER_CONTAINER)
-                        .orientation("horizontal")
-                        .spacing(id)
-                        .build(ctx),
-                )
-                .child(
-                    Container::new()
-                        .id(BODY_CONTAINER)
-                        .background(id)
-                        .border_brush(id)
-                        .border_width(id)
-                        .border_radius(id)
-                        .attach(Grid::row(1))
-                        .build(ctx),
-                )
-                .build(ctx),
-        )
+        self.name("TabWidget")
+            .style("tab_widget")
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
+            .child(
+                Grid::new()
+                    .rows("34, *")
+                    .child(
+                        Stack::new()
+                            .id(HEADER_CONTAINER)
+                            .orientation("horizontal")
+                            .spacing(id)
+                            .build(ctx),
+                    )
+                    .child(
+                        Container::new()
+                            .id(BODY_CONTAINER)
+                            .background(id)
+                            .border_brush(id)
+                            .border_brushes(id)
+                            .border_style(id)
+                            .border_width(id)
+                            .border_radius(id)
+                            .attach(Grid::row(1))
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
     }
 }
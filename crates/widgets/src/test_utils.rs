@@ -0,0 +1,213 @@
+//! Helpers to run widgets in a window without a shell.
+
+use std::{io, sync::mpsc, sync::Arc};
+
+use crate::{
+    api::prelude::*,
+    render::{
+        prelude::{Image, Svg},
+        Path2D, PipelineTrait, RenderBackend, RenderTarget, TextCluster, TextMetrics,
+    },
+    shell::{window_adapter::WindowAdapter as _, WindowRequest, WindowSettings},
+    theme_default::prelude::*,
+    Window,
+};
+
+/// Render backend that only records the filled rectangles, the drawn frames and the scale factor.
+/// Text has no size.
+pub struct RecordingBackend {
+    fill_style: Brush,
+    pub scale_factor: f64,
+    pub frames: usize,
+    pub fills: Vec<(Rectangle, Brush)>,
+}
+
+impl Default for RecordingBackend {
+    fn default() -> Self {
+        RecordingBackend {
+            fill_style: Brush::default(),
+            scale_factor: 1.0,
+            frames: 0,
+            fills: vec![],
+        }
+    }
+}
+
+impl RenderBackend for RecordingBackend {
+    type Image = Image;
+    type Svg = Svg;
+
+    fn fill_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.fills.push((
+            Rectangle::new((x, y), (width, height)),
+            self.fill_style.clone(),
+        ));
+    }
+
+    fn set_fill_style(&mut self, fill_style: Brush) {
+        self.fill_style = fill_style;
+    }
+
+    fn finish(&mut self) {
+        self.frames += 1;
+    }
+
+    fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+    }
+
+    fn register_font_bytes(&mut self, _: &str, _: Arc<Vec<u8>>) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn measure_text(&mut self, _: &str) -> TextMetrics {
+        TextMetrics::default()
+    }
+
+    fn measure_clusters(&mut self, _: &str, _: f64, _: &str) -> Vec<TextCluster> {
+        vec![]
+    }
+
+    fn take_damage(&mut self) -> Vec<Rectangle> {
+        vec![]
+    }
+
+    fn invalidated(&self) -> bool {
+        false
+    }
+
+    fn has_font(&self, _: &str) -> bool {
+        true
+    }
+
+    fn has_layer(&self, _: u64) -> bool {
+        false
+    }
+
+    // not recorded
+    fn set_background(&mut self, _: Color) {}
+    fn resize(&mut self, _: f64, _: f64) {}
+    fn start(&mut self) {}
+    fn start_region(&mut self, _: Rectangle) {}
+    fn register_font(&mut self, _: &str, _: &'static [u8]) {}
+    fn set_font_fallbacks(&mut self, _: Vec<String>) {}
+    fn fill_text(&mut self, _: &str, _: f64, _: f64) {}
+    fn stroke_rect(&mut self, _: f64, _: f64, _: f64, _: f64) {}
+    fn fill(&mut self) {}
+    fn stroke(&mut self) {}
+    fn fill_path(&mut self, _: &Path2D) {}
+    fn stroke_path(&mut self, _: &Path2D) {}
+    fn clip_path(&mut self, _: &Path2D) {}
+    fn add_path(&mut self, _: &Path2D) {}
+    fn begin_path(&mut self) {}
+    fn close_path(&mut self) {}
+    fn rect(&mut self, _: f64, _: f64, _: f64, _: f64) {}
+    fn arc(&mut self, _: f64, _: f64, _: f64, _: f64, _: f64) {}
+    fn arc_to(&mut self, _: f64, _: f64, _: f64, _: f64, _: f64) {}
+    fn ellipse(&mut self, _: f64, _: f64, _: f64, _: f64, _: f64, _: f64, _: f64) {}
+    fn rounded_rect(&mut self, _: f64, _: f64, _: f64, _: f64, _: [f64; 4]) {}
+    fn move_to(&mut self, _: f64, _: f64) {}
+    fn line_to(&mut self, _: f64, _: f64) {}
+    fn quadratic_curve_to(&mut self, _: f64, _: f64, _: f64, _: f64) {}
+    fn bezier_curve_to(&mut self, _: f64, _: f64, _: f64, _: f64, _: f64, _: f64) {}
+    fn clip(&mut self) {}
+    fn draw_render_target(&mut self, _: &RenderTarget, _: f64, _: f64) {}
+    fn draw_image(&mut self, _: &Image, _: f64, _: f64) {}
+    fn draw_image_with_clip(&mut self, _: &Image, _: Rectangle, _: f64, _: f64) {}
+    fn draw_image_with_clip_and_size(
+        &mut self,
+        _: &Image,
+        _: Rectangle,
+        _: f64,
+        _: f64,
+        _: f64,
+        _: f64,
+    ) {
+    }
+    fn draw_svg(&mut self, _: &Svg, _: f64, _: f64, _: f64, _: f64, _: Option<Color>) {}
+    fn draw_pipeline(&mut self, _: f64, _: f64, _: f64, _: f64, _: Box<dyn PipelineTrait>) {}
+    fn draw_box_shadow(&mut self, _: f64, _: f64, _: f64, _: f64, _: [f64; 4], _: &Shadow) {}
+    fn set_line_width(&mut self, _: f64) {}
+    fn set_line_cap(&mut self, _: LineCap) {}
+    fn set_line_join(&mut self, _: LineJoin) {}
+    fn set_miter_limit(&mut self, _: f64) {}
+    fn set_line_dash(&mut self, _: Vec<f64>) {}
+    fn set_line_dash_offset(&mut self, _: f64) {}
+    fn set_fill_rule(&mut self, _: FillRule) {}
+    fn set_alpha(&mut self, _: f32) {}
+    fn set_font_family(&mut self, _: &str) {}
+    fn set_font_size(&mut self, _: f64) {}
+    fn set_stroke_style(&mut self, _: Brush) {}
+    fn set_transform(&mut self, _: f64, _: f64, _: f64, _: f64, _: f64, _: f64) {}
+    fn transform(&mut self, _: f64, _: f64, _: f64, _: f64, _: f64, _: f64) {}
+    fn push_layer(&mut self, _: f64, _: f64, _: f64, _: f64) {}
+    fn pop_layer(&mut self, _: f32, _: Option<u64>) {}
+    fn draw_layer(&mut self, _: u64, _: f32) {}
+    fn remove_layer(&mut self, _: u64) {}
+    fn clear_layers(&mut self) {}
+    fn save(&mut self) {}
+    fn restore(&mut self) {}
+    fn clear(&mut self, _: &Brush) {}
+}
+
+/// A window that is run without a shell. The requests the window sends to the shell are collected.
+pub struct TestWindow {
+    pub adapter: WindowAdapter<RecordingBackend>,
+    pub settings: WindowSettings,
+    pub backend: RecordingBackend,
+    requests: mpsc::Receiver<WindowRequest>,
+}
+
+impl TestWindow {
+    /// Creates the window from the given builder closure with the default theme.
+    pub fn new<F: Fn(&mut BuildContext) -> Entity + 'static>(create_fn: F) -> Self {
+        let (sender, _) = mpsc::channel();
+        let (adapter, settings, requests) = create_window(
+            "",
+            theme_default(),
+            sender,
+            create_fn,
+            None,
+            Clipboard::new(),
+            RuntimeFonts::new(),
+            ImageCache::new(),
+        );
+
+        TestWindow {
+            adapter,
+            settings,
+            backend: RecordingBackend::default(),
+            requests,
+        }
+    }
+
+    /// Runs the event, layout and render pipeline of the window once.
+    pub fn run(&mut self) {
+        self.adapter.run(&mut self.backend);
+    }
+
+    /// Takes the requests the window has sent to the shell since the last call.
+    pub fn take_requests(&mut self) -> Vec<WindowRequest> {
+        self.requests.try_iter().collect()
+    }
+}
+
+#[test]
+fn test_render_backend() {
+    let mut window = TestWindow::new(|ctx| Window::new().size(120.0, 80.0).build(ctx));
+
+    assert_eq!(window.settings.size, (120.0, 80.0));
+
+    window.run();
+
+    assert_eq!(window.backend.frames, 1);
+    assert!(window
+        .backend
+        .fills
+        .iter()
+        .any(|(rect, _)| *rect == Rectangle::new((0.0, 0.0), (120.0, 80.0))));
+}
@@ -1,14 +1,69 @@
-use crate::{api::prelude::*, proc_macros::*, theme_default::prelude::*};
+use crate::{
+    api::prelude::*, proc_macros::*, shell::prelude::MouseButton, theme_default::prelude::*,
+};
 
 enum TextAction {
     Localize,
+    Press(Point),
+    Release(Point),
 }
 
-/// Handles the localization of the text.
+/// Handles the localization of the text and the clicks on links of the rich text.
 #[derive(Debug, Clone, Default, AsAny)]
-pub struct TextBlockState;
+pub struct TextBlockState {
+    pressed_link: Option<usize>,
+}
 
 impl TextBlockState {
+    // Gets the index of the link span at the given mouse position.
+    fn link_at(&self, ctx: &mut Context, position: Point) -> Option<usize> {
+        let rich_text = TextBlock::rich_text_clone(&ctx.widget());
+
+        if rich_text.spans().iter().all(|span| span.link.is_none()) {
+            return None;
+        }
+
+        let font = TextBlock::font_clone(&ctx.widget());
+        let font_size = *TextBlock::font_size_ref(&ctx.widget());
+        let bounds = *ctx.widget().get::<Rectangle>("bounds");
+        let widget_position = *ctx.widget().get::<Point>("position");
+        let offset = *TextBlock::offset_ref(&ctx.widget());
+        let options = TextLayoutOptions::from_widget(&ctx.widget(), bounds.width());
+
+        RichTextLayout::new(
+            ctx.render_context_2_d(),
+            &rich_text,
+            &font,
+            font_size,
+            options,
+        )
+        .span_at(Point::new(
+            position.x() - widget_position.x() - offset,
+            position.y() - widget_position.y(),
+        ))
+        .filter(|index| rich_text.spans()[*index].link.is_some())
+    }
+
+    fn release(&mut self, ctx: &mut Context, position: Point) {
+        let pressed_link = match self.pressed_link.take() {
+            Some(pressed_link) => pressed_link,
+            None => return,
+        };
+
+        if self.link_at(ctx, position) != Some(pressed_link) {
+            return;
+        }
+
+        if let Some(link) = TextBlock::rich_text_ref(&ctx.widget()).spans()[pressed_link]
+            .link
+            .clone()
+        {
+            let entity = ctx.entity();
+            ctx.event_adapter()
+                .push_event_direct(entity, LinkClickedEvent(entity, link));
+        }
+    }
+
     fn localize(&self, ctx: &mut Context) {
         if !*TextBlock::localizable_ref(&ctx.widget()) {
             return;
@@ -39,19 +94,40 @@ impl State for TextBlockState {
         for message in messages.read::<TextAction>() {
             match message {
                 TextAction::Localize => self.localize(ctx),
+                TextAction::Press(position) => self.pressed_link = self.link_at(ctx, position),
+                TextAction::Release(position) => self.release(ctx, position),
             }
         }
     }
 }
 
 widget!(
-    /// The `TextBlock` widget is used to draw text. It is not interactive.
+    /// The `TextBlock` widget is used to draw text. It is not interactive, except of the links of a rich text.
+    ///
+    /// Lines are broken on newlines. If `text_wrap` is set, the text is wrapped to the available width. With
+    /// `max_lines` and `text_trimming` long texts are cut with an ellipsis.
+    ///
+    /// If the `rich_text` is not empty, it is drawn instead of the `text`. Spans of the rich text without own
+    /// font, font size or foreground use the properties of the `TextBlock`. The rich text is wrapped at word
+    /// boundaries, `line_height`, `max_lines`, `text_trimming` and `text_align` are applied to it as well.
     ///
     /// **style:** `text-block`
-    TextBlock<TextBlockState> {
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// TextBlock::new()
+    ///     .rich_text("Read the <b>docs</b> on <a href=\"https://docs.rs/orbtk\">docs.rs</a>")
+    ///     .on_link_click(|_, _, link| println!("{}", link))
+    ///     .build(ctx)
+    /// ```
+    TextBlock<TextBlockState>: MouseHandler, LinkClickedHandler {
         /// Sets or shares the text property.
         text: String,
 
+        /// Sets or shares the rich text property. A rich text consists of spans with their own attributes.
+        rich_text: RichText,
+
         /// If the `TextBlock` is localizable and the localized text is not empty, the localized_text will be drawn.
         localized_text: String,
 
@@ -71,7 +147,23 @@ widget!(
         offset: f64,
 
         /// Defines if the text is localizable. If set to `false` the text will not be localized.
-        localizable: bool
+        localizable: bool,
+
+        /// Sets or shares the text wrap property. It describes how the text is wrapped if it exceeds the available width.
+        text_wrap: TextWrap,
+
+        /// Sets or shares the line height property. If it is `0.0` the line height is derived from the font size.
+        line_height: f64,
+
+        /// Sets or shares the max lines property. If it is `0` the number of lines is not limited.
+        max_lines: usize,
+
+        /// Sets or shares the text trimming property. It describes how the text is trimmed if it exceeds the
+        /// available width or the max lines.
+        text_trimming: TextTrimming,
+
+        /// Sets or shares the text alignment property.
+        text_align: TextAlignment
     }
 );
 
@@ -79,13 +171,30 @@ impl Template for TextBlock {
     fn template(self, id: Entity, _: &mut BuildContext) -> Self {
         self.name("TextBlock")
             .text("")
+            .rich_text(RichText::default())
             .foreground(colors::LINK_WATER_COLOR)
             .font_size(fonts::FONT_SIZE_12)
             .font("Roboto-Regular")
             .localizable(true)
+            .text_wrap(TextWrap::None)
+            .line_height(0.0)
+            .max_lines(0)
+            .text_trimming(TextTrimming::None)
+            .text_align(TextAlignment::Left)
             .on_changed("text", move |ctx, _| {
                 ctx.send_message(TextAction::Localize, id)
             })
+            .on_mouse_down(move |ctx, m| {
+                if m.button == MouseButton::Left {
+                    ctx.send_message(TextAction::Press(m.position), id);
+                }
+                false
+            })
+            .on_mouse_up(move |ctx, m| {
+                if m.button == MouseButton::Left {
+                    ctx.send_message(TextAction::Release(m.position), id);
+                }
+            })
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
@@ -36,7 +36,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -44,6 +44,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
@@ -103,6 +109,8 @@ impl Template for TextBox {
             .padding(4.0)
             .background(colors::LYNCH_COLOR)
             .border_brush("transparent")
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
             .border_width(0.0)
             .border_radius(2.0)
             .min_width(128.0)
@@ -117,6 +125,8 @@ impl Template for TextBox {
                     .border_radius(id)
                     .border_width(id)
                     .border_brush(id)
+                    .border_brushes(id)
+                    .border_style(id)
                     .padding(id)
                     .child(
                         Grid::new()
@@ -0,0 +1,303 @@
+use crate::{
+    api::prelude::*,
+    prelude::*,
+    proc_macros::*,
+    shell::prelude::{MouseButton, WindowRequest},
+    theme_default::prelude::*,
+};
+
+// --- KEYS --
+pub static STYLE_TITLE_BAR: &str = "title_bar";
+pub static STYLE_TITLE_BAR_BUTTON: &str = "title_bar_button";
+pub static STYLE_TITLE_BAR_CLOSE_BUTTON: &str = "title_bar_close_button";
+static ID_MAXIMIZE_BUTTON: &str = "id_maximize_button";
+// --- KEYS --
+
+#[derive(Copy, Clone)]
+enum TitleBarAction {
+    Minimize,
+    ToggleMaximize,
+    Close,
+    DragStart,
+    Drag(Point),
+    DragEnd,
+}
+
+/// The `TitleBarState` handles the buttons and the drag region of the `TitleBar`.
+#[derive(Default, AsAny)]
+pub struct TitleBarState {
+    actions: Vec<TitleBarAction>,
+    drag_start: Option<Point>,
+    window_position: Point,
+    maximized: bool,
+}
+
+impl TitleBarState {
+    fn action(&mut self, action: TitleBarAction) {
+        self.actions.push(action);
+    }
+
+    // the drag start is set directly by the event handler, so following mouse moves are not lost
+    fn start_drag(&mut self, position: Point) {
+        self.drag_start = Some(position);
+        self.action(TitleBarAction::DragStart);
+    }
+
+    fn is_dragging(&self) -> bool {
+        self.drag_start.is_some()
+    }
+
+    fn toggle_maximize(&self, ctx: &mut Context) {
+        let maximized = *Window::maximized_ref(&ctx.window());
+        Window::maximized_set(&mut ctx.window(), !maximized);
+    }
+
+    // closing the window is requested like a close of the window frame, so it could be vetoed
+    fn close(&self, ctx: &mut Context) {
+        let window = ctx.entity_of_window();
+        ctx.event_adapter()
+            .push_event_direct(window, WindowEvent::CloseRequested(CloseRequest::new()));
+    }
+
+    fn drag_start(&mut self, ctx: &mut Context) {
+        // a maximized window fills the screen and could not be moved
+        if *Window::maximized_ref(&ctx.window()) {
+            self.drag_start = None;
+            return;
+        }
+
+        self.window_position = *Window::screen_position_ref(&ctx.window());
+    }
+
+    fn drag(&mut self, position: Point, ctx: &mut Context) {
+        if let Some(drag_start) = self.drag_start {
+            let scale_factor = *Window::scale_factor_ref(&ctx.window());
+            self.window_position =
+                drag_window_position(self.window_position, drag_start, position, scale_factor);
+
+            ctx.send_window_request(WindowRequest::Move {
+                x: self.window_position.x(),
+                y: self.window_position.y(),
+            });
+        }
+    }
+
+    // updates the icon of the maximize button if the window is maximized or restored
+    fn update_maximize_icon(&mut self, ctx: &mut Context) {
+        let maximized = *Window::maximized_ref(&ctx.window());
+
+        if self.maximized == maximized {
+            return;
+        }
+
+        self.maximized = maximized;
+
+        let icon = if maximized {
+            material_icons_font::MD_FILTER_NONE
+        } else {
+            material_icons_font::MD_CROP_SQUARE
+        };
+
+        Button::icon_set(&mut ctx.child(ID_MAXIMIZE_BUTTON), icon);
+    }
+}
+
+impl State for TitleBarState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        for action in std::mem::take(&mut self.actions) {
+            match action {
+                TitleBarAction::Minimize => ctx.send_window_request(WindowRequest::Minimize),
+                TitleBarAction::ToggleMaximize => self.toggle_maximize(ctx),
+                TitleBarAction::Close => self.close(ctx),
+                TitleBarAction::DragStart => self.drag_start(ctx),
+                TitleBarAction::Drag(position) => self.drag(position, ctx),
+                TitleBarAction::DragEnd => self.drag_start = None,
+            }
+        }
+
+        self.update_maximize_icon(ctx);
+    }
+}
+
+widget!(
+    /// The `TitleBar` is used as window decoration of a borderless window. It shows the title of the window
+    /// and buttons to minimize, maximize and close the window. The window could be moved by dragging the title bar.
+    ///
+    /// **style:** `title_bar`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Window::new()
+    ///     .borderless(true)
+    ///     .child(
+    ///         WindowChrome::new()
+    ///             .child(TitleBar::new().title("My app").build(ctx))
+    ///             .build(ctx),
+    ///     )
+    ///     .build(ctx)
+    /// ```
+    TitleBar<TitleBarState>: MouseHandler {
+        /// Sets or shares the title.
+        title: String,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the padding property.
+        padding: Thickness
+    }
+);
+
+impl TitleBar {
+    fn create_button(
+        id: Entity,
+        style: &str,
+        icon: &str,
+        column: usize,
+        action: TitleBarAction,
+    ) -> Button {
+        Button::new()
+            .style(style)
+            .icon(icon)
+            .v_align("stretch")
+            .attach(Grid::column(column))
+            .on_click(move |states, _| {
+                states.get_mut::<TitleBarState>(id).action(action);
+                true
+            })
+    }
+}
+
+impl Template for TitleBar {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let minimize_button = TitleBar::create_button(
+            id,
+            STYLE_TITLE_BAR_BUTTON,
+            material_icons_font::MD_MINIMIZE,
+            1,
+            TitleBarAction::Minimize,
+        )
+        .build(ctx);
+
+        let maximize_button = TitleBar::create_button(
+            id,
+            STYLE_TITLE_BAR_BUTTON,
+            material_icons_font::MD_CROP_SQUARE,
+            2,
+            TitleBarAction::ToggleMaximize,
+        )
+        .id(ID_MAXIMIZE_BUTTON)
+        .build(ctx);
+
+        let close_button = TitleBar::create_button(
+            id,
+            STYLE_TITLE_BAR_CLOSE_BUTTON,
+            material_icons_font::MD_CLOSE,
+            3,
+            TitleBarAction::Close,
+        )
+        .build(ctx);
+
+        self.name("TitleBar")
+            .style(STYLE_TITLE_BAR)
+            .height(32.0)
+            .child(
+                Grid::new()
+                    .columns("*, auto, auto, auto")
+                    .child(
+                        TextBlock::new()
+                            .v_align("center")
+                            .margin(("padding", id))
+                            .text(("title", id))
+                            .foreground(id)
+                            .font_size(id)
+                            .font(id)
+                            .build(ctx),
+                    )
+                    .child(minimize_button)
+                    .child(maximize_button)
+                    .child(close_button)
+                    .build(ctx),
+            )
+            .on_mouse_down(move |states, m| {
+                if m.button != MouseButton::Left {
+                    return false;
+                }
+
+                states.get_mut::<TitleBarState>(id).start_drag(m.position);
+                true
+            })
+            .on_global_mouse_move(move |states, p| {
+                if states.get::<TitleBarState>(id).is_dragging() {
+                    states
+                        .get_mut::<TitleBarState>(id)
+                        .action(TitleBarAction::Drag(p));
+                }
+            })
+            .on_global_mouse_up(move |states, _| {
+                states
+                    .get_mut::<TitleBarState>(id)
+                    .action(TitleBarAction::DragEnd);
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        GridLayout::new().into()
+    }
+}
+
+// --- Helpers --
+
+// Calculates the new position of the window on the screen. The drag start is the position of the mouse on the window
+// if the drag is started. The mouse keeps this position relative to the window while it is moved.
+fn drag_window_position(
+    window_position: Point,
+    drag_start: Point,
+    mouse_position: Point,
+    scale_factor: f64,
+) -> Point {
+    window_position + (mouse_position - drag_start) * scale_factor
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drag_window_position() {
+        assert_eq!(
+            drag_window_position(
+                Point::new(100.0, 50.0),
+                Point::new(10.0, 10.0),
+                Point::new(15.0, 5.0),
+                1.0
+            ),
+            Point::new(105.0, 45.0)
+        );
+        assert_eq!(
+            drag_window_position(
+                Point::new(100.0, 50.0),
+                Point::new(10.0, 10.0),
+                Point::new(15.0, 5.0),
+                2.0
+            ),
+            Point::new(110.0, 40.0)
+        );
+    }
+}
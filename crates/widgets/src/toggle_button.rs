@@ -12,7 +12,10 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
+
+        /// Sets or shares the box shadow property.
+        box_shadow: BoxShadow,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -20,6 +23,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
@@ -72,6 +81,9 @@ impl Template for ToggleButton {
             .border_radius(4.0)
             .border_width(0.0)
             .border_brush("transparent")
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
+            .box_shadow(BoxShadow::default())
             .padding((16.0, 0.0, 16.0, 0.0))
             .foreground(colors::LINK_WATER_COLOR)
             .text("")
@@ -99,6 +111,9 @@ impl Template for ToggleButton {
                                     .border_radius(id)
                                     .border_width(id)
                                     .border_brush(id)
+                                    .border_brushes(id)
+                                    .border_style(id)
+                                    .box_shadow(id)
                                     .padding(id)
                                     .child(
                                         Stack::new()
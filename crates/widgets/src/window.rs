@@ -23,6 +23,10 @@ struct WindowState {
     actions: VecDeque<Action>,
     background: Brush,
     title: String,
+    scale_factor: f64,
+    maximized: bool,
+    geometry: WindowGeometry,
+    geometry_settings: Option<(String, Settings)>,
 }
 
 impl WindowState {
@@ -30,9 +34,67 @@ impl WindowState {
         self.actions.push_front(action);
     }
 
-    fn resize(&self, width: f64, height: f64, ctx: &mut Context) {
+    fn resize(&mut self, width: f64, height: f64, ctx: &mut Context) {
         Window::bounds_mut(&mut ctx.window()).set_size(width, height);
         Window::constraint_mut(&mut ctx.window()).set_size(width, height);
+
+        // keeps the geometry of the not maximized window
+        if !self.geometry.maximized {
+            self.geometry.size = (width, height);
+        }
+    }
+
+    fn moved(&mut self, x: f64, y: f64, ctx: &mut Context) {
+        Window::screen_position_set(&mut ctx.widget(), Point::new(x, y));
+
+        if !self.geometry.maximized {
+            self.geometry.position = (x, y);
+        }
+    }
+
+    fn init_geometry(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        let constraint = *Window::constraint_ref(&ctx.widget());
+        let position = *Window::position_ref(&ctx.widget());
+
+        self.geometry = WindowGeometry::new(
+            (position.x(), position.y()),
+            (constraint.width(), constraint.height()),
+            *Window::maximized_ref(&ctx.widget()),
+        );
+
+        if !*Window::persist_geometry_ref(&ctx.widget()) {
+            return;
+        }
+
+        let id: String = ctx.widget().clone_or_default("id");
+        let key = WindowGeometry::settings_key(id.as_str());
+        let settings = registry.get::<Settings>("settings").clone();
+
+        // the geometry of a restored maximized window is the screen, use the saved geometry instead
+        if let Ok(geometry) = settings.load::<WindowGeometry>(key.as_str()) {
+            if geometry.is_valid() && self.geometry.maximized {
+                self.geometry.position = geometry.position;
+                self.geometry.size = geometry.size;
+            }
+        }
+
+        self.geometry_settings = Some((key, settings));
+    }
+
+    fn save_geometry(&self) {
+        if let Some((key, settings)) = &self.geometry_settings {
+            if let Err(err) = settings.save(key.as_str(), &self.geometry) {
+                crate::shell::CONSOLE.log(format!(
+                    "Could not save the geometry of the window: {:?}",
+                    err
+                ));
+            }
+        }
+    }
+
+    fn scale_factor_changed(&mut self, scale_factor: f64, ctx: &mut Context) {
+        self.scale_factor = scale_factor;
+        Window::scale_factor_set(&mut ctx.widget(), scale_factor);
     }
 
     fn active_changed(&self, active: bool, ctx: &mut Context) {
@@ -72,9 +134,21 @@ impl WindowState {
 }
 
 impl State for WindowState {
-    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+    fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
         self.set_background(ctx);
         self.title = ctx.widget().clone("title");
+        self.init_geometry(registry, ctx);
+
+        let position = *Window::position_ref(&ctx.widget());
+        Window::screen_position_set(&mut ctx.widget(), position);
+
+        // if the scale factor is not overridden the window uses the scale factor of the backend
+        if *Window::scale_factor_ref(&ctx.widget()) <= 0.0 {
+            let scale_factor = ctx.scale_factor();
+            Window::scale_factor_set(&mut ctx.widget(), scale_factor);
+        }
+
+        self.scale_factor = *Window::scale_factor_ref(&ctx.widget());
     }
 
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
@@ -89,15 +163,43 @@ impl State for WindowState {
             ctx.send_window_request(WindowRequest::ChangeTitle(self.title.clone()));
         }
 
+        // the state starts as not maximized, so a window that is created or restored maximized is maximized here
+        let maximized = *Window::maximized_ref(&ctx.widget());
+
+        if self.maximized != maximized {
+            self.maximized = maximized;
+
+            if maximized {
+                ctx.send_window_request(WindowRequest::Maximize);
+            } else {
+                ctx.send_window_request(WindowRequest::Restore);
+            }
+        }
+
+        self.geometry.maximized = maximized;
+
+        let scale_factor = *Window::scale_factor_ref(&ctx.widget());
+
+        if scale_factor > 0.0 && (self.scale_factor - scale_factor).abs() > f64::EPSILON {
+            self.scale_factor = scale_factor;
+            ctx.send_window_request(WindowRequest::ChangeScaleFactor(scale_factor));
+        }
+
         if let Some(action) = self.actions.pop_front() {
             match action {
                 Action::WindowEvent(window_event) => match window_event {
                     WindowEvent::Resize { width, height } => {
                         self.resize(width, height, ctx);
                     }
+                    WindowEvent::Move { x, y } => {
+                        self.moved(x, y, ctx);
+                    }
                     WindowEvent::ActiveChanged(active) => {
                         self.active_changed(active, ctx);
                     }
+                    WindowEvent::ScaleFactorChanged(scale_factor) => {
+                        self.scale_factor_changed(scale_factor, ctx);
+                    }
                     _ => {}
                 },
                 Action::FocusEvent(focus_event) => match focus_event {
@@ -137,6 +239,20 @@ widget!(
         /// Sets or shares a value that describes if the current window is active.
         active: bool,
 
+        /// Sets or shares a value that describes if the window is maximized.
+        maximized: bool,
+
+        /// Sets or shares the position of the window on the screen. It is updated if the window is moved.
+        screen_position: Point,
+
+        /// Sets or shares the flag if the geometry (position, size and maximized state) of the window is saved
+        /// on close and restored on start. The geometry is stored with the `Settings` service by the id of the window.
+        persist_geometry: bool,
+
+        /// Sets or shares the scale factor of the window, the ratio between physical pixels and logical units.
+        /// If it is not set, the scale factor provided by the backend is used.
+        scale_factor: f64,
+
         /// Access the current keyboard state e.g. to check modifiers.
         keyboard_state: KeyboardState,
 
@@ -161,6 +277,22 @@ impl Window {
         })
     }
 
+    /// Registers a handler that is called if closing the window is requested. If the handler cancels the
+    /// request the window stays open. A window with a cancelled request could be closed later by sending
+    /// a `WindowRequest::Close`.
+    pub fn on_close_requested<H: Fn(&mut StatesContext, CloseRequest) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.on_window_event(move |ctx, event| {
+            if let WindowEvent::CloseRequested(request) = event {
+                return handler(ctx, request);
+            }
+
+            false
+        })
+    }
+
     fn on_focus_event<H: Fn(&mut StatesContext, FocusEvent) -> bool + 'static>(
         self,
         handler: H,
@@ -181,6 +313,13 @@ impl Template for Window {
             .resizeable(false)
             .always_on_top(false)
             .on_window_event(move |ctx, event| {
+                // the geometry is saved on every close request, so it is also kept if the request is
+                // cancelled and the window is closed later by a `WindowRequest::Close`
+                if let WindowEvent::CloseRequested(_) = event {
+                    ctx.get::<WindowState>(id).save_geometry();
+                    return false;
+                }
+
                 ctx.get_mut::<WindowState>(id)
                     .push_action(Action::WindowEvent(event));
                 true
@@ -200,3 +339,65 @@ impl Template for Window {
         GridLayout::new().into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shell::window_adapter::WindowAdapter as _, test_utils::TestWindow};
+
+    fn is_close(request: &WindowRequest) -> bool {
+        matches!(request, WindowRequest::Close)
+    }
+
+    #[test]
+    fn test_close_requested() {
+        let mut window = TestWindow::new(|ctx| Window::new().build(ctx));
+        window.run();
+        window.take_requests();
+
+        window.adapter.quit_event();
+        window.run();
+
+        assert!(window.take_requests().iter().any(is_close));
+    }
+
+    #[test]
+    fn test_close_request_cancelled() {
+        let mut window = TestWindow::new(|ctx| {
+            Window::new()
+                .on_close_requested(|_, request| {
+                    request.cancel();
+                    true
+                })
+                .build(ctx)
+        });
+        window.run();
+        window.take_requests();
+
+        window.adapter.quit_event();
+        window.run();
+
+        assert!(!window.take_requests().iter().any(is_close));
+    }
+
+    #[test]
+    fn test_scale_factor() {
+        let window = TestWindow::new(|ctx| Window::new().build(ctx));
+        assert_eq!(window.settings.scale_factor, None);
+
+        let mut window =
+            TestWindow::new(|ctx| Window::new().size(120.0, 80.0).scale_factor(2.0).build(ctx));
+        assert_eq!(window.settings.scale_factor, Some(2.0));
+
+        // the shell applies the requested scale factor, the widgets are still rendered in logical units
+        window.adapter.scale_factor_changed(2.0);
+        window.run();
+
+        assert_eq!(window.backend.scale_factor, 2.0);
+        assert!(window
+            .backend
+            .fills
+            .iter()
+            .any(|(rect, _)| *rect == Rectangle::new((0.0, 0.0), (120.0, 80.0))));
+    }
+}
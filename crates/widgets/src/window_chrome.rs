@@ -0,0 +1,385 @@
+use crate::{
+    api::prelude::*,
+    prelude::*,
+    proc_macros::*,
+    shell::prelude::{can_resize_window, MouseButton, WindowRequest},
+};
+
+// --- KEYS --
+pub static STYLE_WINDOW_CHROME: &str = "window_chrome";
+// --- KEYS --
+
+// Describes the edges of the window that are moved by a resize.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+struct ResizeEdges {
+    left: bool,
+    top: bool,
+    right: bool,
+    bottom: bool,
+}
+
+impl ResizeEdges {
+    fn is_empty(&self) -> bool {
+        !self.left && !self.top && !self.right && !self.bottom
+    }
+}
+
+#[derive(Copy, Clone)]
+enum WindowChromeAction {
+    ResizeStart,
+    Resize { edges: ResizeEdges, position: Point },
+}
+
+/// The `WindowChromeState` handles the resize hit zones of the `WindowChrome`.
+#[derive(Default, AsAny)]
+pub struct WindowChromeState {
+    actions: Vec<WindowChromeAction>,
+    bounds: Rectangle,
+    resize_border: f64,
+    resizeable: bool,
+    edges: ResizeEdges,
+    reference: Point,
+    window_position: Point,
+    window_size: (f64, f64),
+}
+
+impl WindowChromeState {
+    fn action(&mut self, action: WindowChromeAction) {
+        self.actions.push(action);
+    }
+
+    // the edges are set directly by the event handler, so following mouse moves are not lost
+    fn start_resize(&mut self, position: Point) -> bool {
+        if !self.resizeable {
+            return false;
+        }
+
+        self.edges = resize_edges(position, self.bounds, self.resize_border);
+
+        if self.edges.is_empty() {
+            return false;
+        }
+
+        self.reference = position;
+        self.action(WindowChromeAction::ResizeStart);
+        true
+    }
+
+    fn resize(&mut self, position: Point) {
+        if !self.edges.is_empty() {
+            self.action(WindowChromeAction::Resize {
+                edges: self.edges,
+                position,
+            });
+        }
+    }
+
+    fn end_resize(&mut self) {
+        self.edges = ResizeEdges::default();
+    }
+
+    fn resize_start(&mut self, ctx: &mut Context) {
+        let bounds = *Window::bounds_ref(&ctx.window());
+        self.window_position = *Window::screen_position_ref(&ctx.window());
+        self.window_size = (bounds.width(), bounds.height());
+    }
+
+    fn resize_window(&mut self, edges: ResizeEdges, position: Point, ctx: &mut Context) {
+        let border = self.resize_border;
+        let constraint = *Window::constraint_ref(&ctx.window());
+        let scale_factor = *Window::scale_factor_ref(&ctx.window());
+
+        let (window_position, window_size, reference) = resize_window(
+            edges,
+            self.window_position,
+            self.window_size,
+            self.reference,
+            position,
+            (
+                constraint.min_width().max(2.0 * border),
+                constraint.min_height().max(2.0 * border),
+            ),
+            scale_factor,
+        );
+
+        if window_position != self.window_position {
+            ctx.send_window_request(WindowRequest::Move {
+                x: window_position.x(),
+                y: window_position.y(),
+            });
+        }
+
+        if window_size != self.window_size {
+            ctx.send_window_request(WindowRequest::Resize {
+                width: window_size.0,
+                height: window_size.1,
+            });
+        }
+
+        self.window_position = window_position;
+        self.window_size = window_size;
+        self.reference = reference;
+    }
+}
+
+impl State for WindowChromeState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        for action in std::mem::take(&mut self.actions) {
+            match action {
+                WindowChromeAction::ResizeStart => self.resize_start(ctx),
+                WindowChromeAction::Resize { edges, position } => {
+                    self.resize_window(edges, position, ctx)
+                }
+            }
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let bounds = *WindowChrome::bounds_ref(&ctx.widget());
+        let position = *WindowChrome::position_ref(&ctx.widget());
+        self.bounds = Rectangle::new(position, (bounds.width(), bounds.height()));
+        self.resize_border = *WindowChrome::resize_border_ref(&ctx.widget());
+
+        // a maximized window fills the screen and could not be resized, some backends could
+        // only move a window, then dragging an edge would move it instead of resizing
+        self.resizeable = can_resize_window()
+            && *Window::resizeable_ref(&ctx.window())
+            && !*Window::maximized_ref(&ctx.window());
+    }
+}
+
+widget!(
+    /// The `WindowChrome` draws the frame of a borderless window. Its edges are used as hit zones to resize the window.
+    /// Use it as root of the window content together with a `TitleBar`.
+    ///
+    /// **style:** `window_chrome`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Window::new()
+    ///     .borderless(true)
+    ///     .resizeable(true)
+    ///     .child(
+    ///         WindowChrome::new()
+    ///             .child(
+    ///                 Grid::new()
+    ///                     .rows("auto, *")
+    ///                     .child(TitleBar::new().title("My app").build(ctx))
+    ///                     .child(TextBlock::new().text("Content").attach(Grid::row(1)).build(ctx))
+    ///                     .build(ctx),
+    ///             )
+    ///             .build(ctx),
+    ///     )
+    ///     .build(ctx)
+    /// ```
+    WindowChrome<WindowChromeState>: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the border brushes property. It overrides the border brush of single sides.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the width of the resize hit zones along the edges.
+        resize_border: f64
+    }
+);
+
+impl Template for WindowChrome {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("WindowChrome")
+            .style(STYLE_WINDOW_CHROME)
+            .padding(4.0)
+            .resize_border(4.0)
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .border_brushes(BorderBrushes::default())
+            .border_style(BorderStyle::Solid)
+            .on_mouse_down(move |states, m| {
+                if m.button != MouseButton::Left {
+                    return false;
+                }
+
+                states
+                    .get_mut::<WindowChromeState>(id)
+                    .start_resize(m.position)
+            })
+            .on_global_mouse_move(move |states, p| {
+                states.get_mut::<WindowChromeState>(id).resize(p);
+            })
+            .on_global_mouse_up(move |states, _| {
+                states.get_mut::<WindowChromeState>(id).end_resize();
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        PaddingLayout::new().into()
+    }
+}
+
+// --- Helpers --
+
+// Checks which edges of the given bounds are hit by the mouse.
+fn resize_edges(mouse_position: Point, bounds: Rectangle, border: f64) -> ResizeEdges {
+    ResizeEdges {
+        left: mouse_position.x() < bounds.x() + border,
+        top: mouse_position.y() < bounds.y() + border,
+        right: mouse_position.x() >= bounds.x() + bounds.width() - border,
+        bottom: mouse_position.y() >= bounds.y() + bounds.height() - border,
+    }
+}
+
+// Calculates the new position and size of the window if the given edges are dragged. The reference is the mouse position
+// on the window of the last resize step. Because the left and top edges move the window, the mouse keeps its position
+// relative to the window on these edges. Returns the new position, the new size and the new reference.
+fn resize_window(
+    edges: ResizeEdges,
+    window_position: Point,
+    window_size: (f64, f64),
+    reference: Point,
+    mouse_position: Point,
+    min_size: (f64, f64),
+    scale_factor: f64,
+) -> (Point, (f64, f64), Point) {
+    let (x, width, shift_x) = resize_axis(
+        (edges.left, edges.right),
+        window_position.x(),
+        window_size.0,
+        mouse_position.x() - reference.x(),
+        min_size.0,
+        scale_factor,
+    );
+
+    let (y, height, shift_y) = resize_axis(
+        (edges.top, edges.bottom),
+        window_position.y(),
+        window_size.1,
+        mouse_position.y() - reference.y(),
+        min_size.1,
+        scale_factor,
+    );
+
+    (
+        Point::new(x, y),
+        (width, height),
+        reference + Point::new(shift_x, shift_y),
+    )
+}
+
+// Resizes the window along one axis by the given delta of the mouse. Returns the new position, the new size and
+// the shift of the reference.
+fn resize_axis(
+    (start, end): (bool, bool),
+    position: f64,
+    size: f64,
+    delta: f64,
+    min_size: f64,
+    scale_factor: f64,
+) -> (f64, f64, f64) {
+    if end {
+        let new_size = (size + delta).max(min_size);
+        return (position, new_size, new_size - size);
+    }
+
+    if start {
+        let new_size = (size - delta).max(min_size);
+        return (position + (size - new_size) * scale_factor, new_size, 0.0);
+    }
+
+    (position, size, 0.0)
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resize_edges() {
+        let bounds = Rectangle::new((0.0, 0.0), (100.0, 50.0));
+
+        assert!(resize_edges(Point::new(50.0, 25.0), bounds, 4.0).is_empty());
+        assert_eq!(
+            resize_edges(Point::new(2.0, 25.0), bounds, 4.0),
+            ResizeEdges {
+                left: true,
+                ..ResizeEdges::default()
+            }
+        );
+        assert_eq!(
+            resize_edges(Point::new(98.0, 49.0), bounds, 4.0),
+            ResizeEdges {
+                right: true,
+                bottom: true,
+                ..ResizeEdges::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_resize_window() {
+        let right_bottom = ResizeEdges {
+            right: true,
+            bottom: true,
+            ..ResizeEdges::default()
+        };
+
+        assert_eq!(
+            resize_window(
+                right_bottom,
+                Point::new(10.0, 10.0),
+                (100.0, 50.0),
+                Point::new(98.0, 48.0),
+                Point::new(108.0, 43.0),
+                (20.0, 20.0),
+                1.0
+            ),
+            (
+                Point::new(10.0, 10.0),
+                (110.0, 45.0),
+                Point::new(108.0, 43.0)
+            )
+        );
+
+        let left_top = ResizeEdges {
+            left: true,
+            top: true,
+            ..ResizeEdges::default()
+        };
+
+        assert_eq!(
+            resize_window(
+                left_top,
+                Point::new(10.0, 10.0),
+                (100.0, 50.0),
+                Point::new(2.0, 2.0),
+                Point::new(-3.0, 42.0),
+                (20.0, 20.0),
+                2.0
+            ),
+            (Point::new(0.0, 70.0), (105.0, 20.0), Point::new(2.0, 2.0))
+        );
+    }
+}
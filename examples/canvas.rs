@@ -133,20 +133,16 @@ impl RenderPipeline for CubePipeline {
 }
 
 // OrbTk 2D drawing
-#[derive(Clone, Default, PartialEq, Pipeline)]
+#[derive(Clone, Default, PartialEq, Pipeline2D)]
 struct Graphic2DPipeline;
 
-impl RenderPipeline for Graphic2DPipeline {
-    fn draw(&self, render_target: &mut RenderTarget) {
-        let mut render_context =
-            RenderContext2D::new(render_target.width(), render_target.height());
-
+impl RenderPipeline2D for Graphic2DPipeline {
+    fn draw(&self, render_context: &mut PlatformBackend, size: Size) {
         let rect_width = 120.0;
         let rect_height = 120.0;
 
-        let rect_x = (render_target.width() - rect_width) / 2.0;
-        let rect_y = (render_target.height() - rect_height) / 2.0;
-        // render_context.set_fill_style(utils::Brush::SolidColor(Color::from("#000000")));
+        let rect_x = (size.width() - rect_width) / 2.0;
+        let rect_y = (size.height() - rect_height) / 2.0;
 
         render_context.set_fill_style(utils::Brush::Gradient(Gradient {
             kind: GradientKind::Linear(LinearGradientCoords::Ends {
@@ -170,24 +166,20 @@ impl RenderPipeline for Graphic2DPipeline {
             repeat: false,
         }));
         render_context.fill_rect(rect_x, rect_y, rect_width, rect_height);
-        render_context.register_font(
-            "Roboto-Regular",
-            include_bytes!("../crates/theme_default/assets/fonts/Roboto-Regular.ttf"),
-        );
         render_context.set_font_size(60.0);
         render_context.set_font_family("Roboto-Regular");
-        render_context.set_fill_style("lynch");
+        render_context.set_fill_style(Brush::from("lynch"));
         let orb_metrics = render_context.measure_text("Orb");
         render_context.fill_text(
             "Orb",
             rect_x + rect_width - orb_metrics.width,
             rect_y - orb_metrics.height,
         );
-        render_context.set_fill_style("goldendream");
-        render_context.save();
+        render_context.set_fill_style(Brush::from("goldendream"));
         let rotation = (270.0f64).to_radians();
         let tk_metrics = render_context.measure_text("Tk");
-        render_context.set_transform(
+        // the rotation is relative to the canvas, `set_transform` would discard its position
+        render_context.transform(
             rotation.cos(),
             -rotation.sin(),
             rotation.sin(),
@@ -196,8 +188,6 @@ impl RenderPipeline for Graphic2DPipeline {
             rect_y,
         );
         render_context.fill_text("Tk", 0.0, 0.0);
-        render_context.restore();
-        render_target.draw(render_context.data());
     }
 }
 
@@ -278,7 +268,7 @@ impl Template for MainView {
                     .child(
                         Canvas::new()
                             .attach(Grid::row(3))
-                            .render_pipeline(DefaultRenderPipeline(Box::new(
+                            .render_pipeline_2_d(DefaultRenderPipeline2D(Box::new(
                                 Graphic2DPipeline::default(),
                             )))
                             .build(ctx),